@@ -16,10 +16,10 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain, EvaluationDomainCache, Evaluations, FFTPlan};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use criterion::{criterion_group, criterion_main, Bencher, BenchmarkId, Criterion};
 use std::cmp::min;
@@ -101,6 +101,45 @@ fn bench_coset_ifft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
     });
 }
 
+fn bench_mul_polynomials<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let rng = &mut TestRng::default();
+    let p = DensePolynomial::<F>::rand(*degree - 1, rng);
+    let q = DensePolynomial::<F>::rand(*degree - 1, rng);
+
+    b.iter(|| &p * &q);
+}
+
+fn bench_mul_polynomials_with_plan<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let rng = &mut TestRng::default();
+    let p = DensePolynomial::<F>::rand(*degree - 1, rng);
+    let q = DensePolynomial::<F>::rand(*degree - 1, rng);
+    let cache = EvaluationDomainCache::new(FFTPlan::new(EvaluationDomain::new(2 * *degree).unwrap()));
+
+    b.iter(|| cache.mul_polynomials(&p, &q));
+}
+
+/// Returns random evaluations over a domain of the given size, and a plan over a domain four times larger,
+/// as the prover rounds interpolate over the constraint domain with the precomputation of a larger domain.
+fn sample_interpolation<F: PrimeField>(degree: usize) -> (Evaluations<F>, FFTPlan<F>) {
+    let rng = &mut TestRng::default();
+    let domain = EvaluationDomain::new(degree).unwrap();
+    let evals = (0..domain.size()).map(|_| F::rand(rng)).collect();
+    (Evaluations::from_vec_and_domain(evals, domain), FFTPlan::new(EvaluationDomain::new(4 * degree).unwrap()))
+}
+
+fn bench_interpolate<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let (evaluations, root) = sample_interpolation::<F>(*degree);
+
+    b.iter(|| evaluations.interpolate_with_pc_by_ref(root.ifft_precomputation()));
+}
+
+fn bench_interpolate_with_plan<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    let (evaluations, root) = sample_interpolation::<F>(*degree);
+    let cache = EvaluationDomainCache::new(root);
+
+    b.iter(|| cache.interpolate(evaluations.clone()));
+}
+
 fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     let description = format!("{name:?} - subgroup_fft_in_place");
     setup_bench(c, &description, bench_fft_in_place::<F>);
@@ -112,8 +151,28 @@ fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     setup_bench(c, &description, bench_coset_ifft_in_place::<F>);
 }
 
+fn mul_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
+    // Multiply two polynomials of degree 2^{16} - 1, as in the prover rounds of a 2^{16} constraint circuit.
+    let degree = 1 << 16;
+    let mut group = c.benchmark_group(format!("{name:?} - mul_polynomials"));
+    group.bench_with_input(BenchmarkId::new("unplanned", degree), &degree, bench_mul_polynomials::<F>);
+    group.bench_with_input(BenchmarkId::new("planned", degree), &degree, bench_mul_polynomials_with_plan::<F>);
+    group.finish();
+}
+
+fn interpolate_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
+    // Interpolate over a domain of size 2^{16}, as in the prover rounds of a 2^{16} constraint circuit.
+    let degree = 1 << 16;
+    let mut group = c.benchmark_group(format!("{name:?} - interpolate"));
+    group.bench_with_input(BenchmarkId::new("unplanned", degree), &degree, bench_interpolate::<F>);
+    group.bench_with_input(BenchmarkId::new("planned", degree), &degree, bench_interpolate_with_plan::<F>);
+    group.finish();
+}
+
 fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
+    mul_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
+    interpolate_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
 criterion_group!(benches, bench_bls12_377);
@@ -46,6 +46,22 @@ fn variable_base_bls12_377(c: &mut Criterion) {
     }
 }
 
+fn variable_base_backends_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(300_000);
+
+    let mut group = c.benchmark_group("VariableBase MSM backends on BLS12-377");
+    for log_size in 12..=18 {
+        let size = 1 << log_size;
+        for backend in MsmBackend::ALL {
+            group.bench_function(format!("{backend} (2^{log_size})"), |b| {
+                b.iter(|| VariableBase::msm_with_backend(backend, &bases[..size], &scalars[..size]))
+            });
+        }
+    }
+    group.finish();
+}
+
 fn variable_base_edwards_bls12(c: &mut Criterion) {
     use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
     let (bases, scalars) = create_scalar_bases::<EdwardsAffine, Fr>(1_000_000);
@@ -60,7 +76,7 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_backends_bls12_377, variable_base_edwards_bls12
 }
 
 criterion_main!(variable_base_group);
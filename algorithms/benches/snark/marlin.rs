@@ -220,6 +220,26 @@ fn snark_prove(c: &mut Criterion) {
     });
 }
 
+fn snark_prove_2_16(c: &mut Criterion) {
+    c.bench_function("snark_prove_2_16", move |b| {
+        let num_constraints = 1 << 16;
+        let num_variables = 1 << 16;
+        let rng = &mut TestRng::default();
+
+        let x = Fr::rand(rng);
+        let y = Fr::rand(rng);
+
+        let max_degree =
+            AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(num_constraints, num_variables, num_constraints).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let circuit = Benchmark::<Fr> { a: Some(x), b: Some(y), num_constraints, num_variables };
+        let params = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        b.iter(|| MarlinInst::prove(&fs_parameters, &params.0, &circuit, rng).unwrap())
+    });
+}
+
 fn snark_xor_prove(c: &mut Criterion) {
     c.bench_function("snark_xor_prove", move |b| {
         let num_xors = 10000;
@@ -469,7 +489,7 @@ criterion_group! {
     name = marlin_snark;
     config = Criterion::default().sample_size(10);
     //targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
-    targets = snark_xor_prove, snark_lookup_prove
+    targets = snark_prove_2_16, snark_xor_prove, snark_lookup_prove
 }
 
 criterion_main!(marlin_snark);
@@ -71,6 +71,31 @@ fn test_poseidon_sponge_consistency() {
     }
 }
 
+#[test]
+fn test_poseidon_sponge_absorb_bytes_canonical() {
+    const RATE: usize = 2;
+    let sponge_param = Arc::new(Fr::default_poseidon_parameters::<RATE>().unwrap());
+
+    // Absorbs the given bytes into a fresh sponge, and squeezes a field element.
+    let absorb_and_squeeze = |bytes: &[u8]| {
+        let mut sponge = PoseidonSponge::<Fr, RATE, 1>::new_with_parameters(&sponge_param);
+        sponge.absorb_bytes_canonical(bytes);
+        sponge.squeeze_native_field_elements(1)[0]
+    };
+
+    // Check inputs that are empty, shorter than a chunk, and that span several chunks.
+    let inputs: Vec<Vec<u8>> =
+        vec![vec![], vec![0u8], vec![0u8, 0u8], vec![1u8; 31], vec![1u8; 32], (0..100u8).collect()];
+    let outputs = inputs.iter().map(|input| absorb_and_squeeze(input)).collect::<Vec<_>>();
+
+    for (input, output) in inputs.iter().zip_eq(&outputs) {
+        // Ensure the same input always yields the same output.
+        assert_eq!(*output, absorb_and_squeeze(input));
+    }
+    // Ensure distinct inputs, including those that only differ by trailing zeros, yield distinct outputs.
+    assert_eq!(outputs.iter().unique().count(), outputs.len());
+}
+
 #[test]
 fn bls12_377_fr_poseidon_default_parameters_test() {
     fn single_rate_test<const RATE: usize>() {
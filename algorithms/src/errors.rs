@@ -14,9 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::polycommit::sonic_pc::TrimMetadata;
 use snarkvm_fields::ConstraintFieldError;
 use snarkvm_r1cs::SynthesisError;
 
+use core::fmt::Display;
+
 #[derive(Debug, Error)]
 pub enum SNARKError {
     #[error("{}", _0)]
@@ -28,6 +31,9 @@ pub enum SNARKError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}", _0)]
+    Custom(String),
+
     #[error("Expected a circuit-specific SRS in SNARK")]
     ExpectedCircuitSpecificSRS,
 
@@ -45,6 +51,20 @@ pub enum SNARKError {
 
     #[error("terminated")]
     Terminated,
+
+    #[error("Incompatible keys: the committer key {0} is not compatible with the verifier key {1}")]
+    IncompatibleKeys(Box<TrimMetadata>, Box<TrimMetadata>),
+}
+
+impl SNARKError {
+    /// Returns a `SNARKError::Custom` that prefixes this error with the given context.
+    /// A `SNARKError::Terminated` is returned unchanged, so that callers may still detect it.
+    pub fn with_context<C: Display>(self, context: C) -> Self {
+        match self {
+            Self::Terminated => Self::Terminated,
+            error => Self::Custom(format!("{context}: {error}")),
+        }
+    }
 }
 
 impl From<SynthesisError> for SNARKError {
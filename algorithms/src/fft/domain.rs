@@ -31,15 +31,16 @@ use crate::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{polynomial::PolyMultiplier, DensePolynomial, DomainCoeff, Evaluations, SparsePolynomial},
 };
-use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
+use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field, PrimeField};
 #[cfg(feature = "parallel")]
 use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 
+use parking_lot::RwLock;
 use rand::Rng;
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, collections::BTreeMap, fmt, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -930,15 +931,200 @@ impl<F: FftField> IFFTPrecomputation<F> {
     }
 }
 
+/// A plan for (inverse) FFTs over a fixed domain, which caches the twiddle factors
+/// and the bit-reversal permutation of the domain.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FFTPlan<F: FftField> {
+    fft_precomputation: FFTPrecomputation<F>,
+    ifft_precomputation: IFFTPrecomputation<F>,
+    /// The pairs of indices that are swapped by the bit-reversal permutation.
+    bit_reversal: Vec<(usize, usize)>,
+}
+
+impl<F: FftField> FFTPlan<F> {
+    /// Initializes a new plan for the given domain.
+    pub fn new(domain: EvaluationDomain<F>) -> Self {
+        let fft_precomputation = domain.precompute_fft();
+        let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
+        Self::from_precomputations(fft_precomputation, ifft_precomputation)
+    }
+
+    /// Initializes a new plan from the given precomputations, which must be over the same domain.
+    pub fn from_precomputations(
+        fft_precomputation: FFTPrecomputation<F>,
+        ifft_precomputation: IFFTPrecomputation<F>,
+    ) -> Self {
+        assert_eq!(fft_precomputation.domain, ifft_precomputation.domain);
+        let domain = fft_precomputation.domain;
+        let bit_reversal = (1..domain.size.saturating_sub(1))
+            .filter_map(|idx| {
+                let ridx = bitrev(idx, domain.log_size_of_group);
+                (idx < ridx).then_some((idx as usize, ridx as usize))
+            })
+            .collect();
+        Self { fft_precomputation, ifft_precomputation, bit_reversal }
+    }
+
+    /// Returns the plan for the given subdomain, or `None` if the domain is larger than the domain of this plan.
+    pub fn plan_for_subdomain(&self, domain: &EvaluationDomain<F>) -> Option<Self> {
+        let fft_precomputation = self.fft_precomputation.precomputation_for_subdomain(domain)?.into_owned();
+        let ifft_precomputation = self.ifft_precomputation.precomputation_for_subdomain(domain)?.into_owned();
+        Some(Self::from_precomputations(fft_precomputation, ifft_precomputation))
+    }
+
+    /// Returns the domain of this plan.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        self.fft_precomputation.domain
+    }
+
+    /// Returns the FFT precomputation of this plan.
+    pub fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
+        &self.fft_precomputation
+    }
+
+    /// Returns the IFFT precomputation of this plan.
+    pub fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
+        &self.ifft_precomputation
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        let domain = self.domain();
+        coeffs.resize(domain.size(), T::zero());
+        domain.io_helper_with_roots(coeffs, &self.fft_precomputation.roots);
+        self.bit_reverse(coeffs);
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let domain = self.domain();
+        evals.resize(domain.size(), T::zero());
+        self.bit_reverse(evals);
+        domain.oi_helper_with_roots(evals, &self.ifft_precomputation.inverse_roots);
+        cfg_iter_mut!(evals).for_each(|val| *val *= domain.size_inv);
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the vector in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        EvaluationDomain::<F>::distribute_powers(coeffs, F::multiplicative_generator());
+        self.fft_in_place(coeffs);
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let domain = self.domain();
+        evals.resize(domain.size(), T::zero());
+        self.bit_reverse(evals);
+        domain.oi_helper_with_roots(evals, &self.ifft_precomputation.inverse_roots);
+        EvaluationDomain::<F>::distribute_powers_and_mul_by_const(evals, domain.generator_inv, domain.size_inv);
+    }
+
+    /// Applies the bit-reversal permutation of the domain to the given vector.
+    fn bit_reverse<T>(&self, xi: &mut [T]) {
+        for (idx, ridx) in &self.bit_reversal {
+            xi.swap(*idx, *ridx);
+        }
+    }
+}
+
+/// A thread-safe cache of FFT plans, keyed by domain size.
+///
+/// Plans for subdomains of the root plan are derived from its twiddle factors,
+/// while plans for larger domains are computed from scratch. Each plan is only computed once.
+#[derive(Debug)]
+pub struct EvaluationDomainCache<F: FftField> {
+    root: Arc<FFTPlan<F>>,
+    plans: RwLock<BTreeMap<usize, Arc<FFTPlan<F>>>>,
+}
+
+impl<F: FftField> EvaluationDomainCache<F> {
+    /// Initializes a new cache with the given root plan.
+    pub fn new(root: FFTPlan<F>) -> Self {
+        let root = Arc::new(root);
+        let plans = RwLock::new([(root.domain().size(), root.clone())].into_iter().collect());
+        Self { root, plans }
+    }
+
+    /// Returns the root plan of this cache.
+    pub fn root(&self) -> &FFTPlan<F> {
+        &self.root
+    }
+
+    /// Returns the number of plans in the cache.
+    pub fn len(&self) -> usize {
+        self.plans.read().len()
+    }
+
+    /// Returns `true` if the cache contains no plans.
+    pub fn is_empty(&self) -> bool {
+        self.plans.read().is_empty()
+    }
+
+    /// Returns the plan for the given domain, computing and caching it if necessary.
+    pub fn plan(&self, domain: &EvaluationDomain<F>) -> Arc<FFTPlan<F>> {
+        if let Some(plan) = self.plans.read().get(&domain.size()) {
+            return plan.clone();
+        }
+        let plan = Arc::new(match self.root.plan_for_subdomain(domain) {
+            Some(plan) => plan,
+            None => FFTPlan::new(*domain),
+        });
+        self.plans.write().entry(domain.size()).or_insert(plan).clone()
+    }
+}
+
+impl<F: PrimeField> EvaluationDomainCache<F> {
+    /// Returns the product of the given polynomials, using the cached plan for the product domain.
+    /// This is equivalent to `a * b`, and likewise panics if the field does not contain
+    /// a sufficiently large subgroup for the product.
+    pub fn mul_polynomials(&self, a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+        if a.is_zero() || b.is_zero() {
+            return DensePolynomial::zero();
+        }
+        let domain = EvaluationDomain::new(a.degree() + b.degree() + 2).unwrap();
+        let plan = self.plan(&domain);
+        let mut multiplier = PolyMultiplier::new();
+        multiplier.add_precomputation(plan.fft_precomputation(), plan.ifft_precomputation());
+        multiplier.add_polynomial_ref(a, "a");
+        multiplier.add_polynomial_ref(b, "b");
+        multiplier.multiply().unwrap()
+    }
+
+    /// Returns the polynomial interpolating the given evaluations, using the cached plan for their domain.
+    /// This is equivalent to `evaluations.interpolate()`.
+    pub fn interpolate(&self, evaluations: Evaluations<F>) -> DensePolynomial<F> {
+        let domain = evaluations.domain();
+        let mut evals = evaluations.evaluations;
+        self.plan(&domain).ifft_in_place(&mut evals);
+        DensePolynomial::from_coefficients_vec(evals)
+    }
+}
+
+impl<F: FftField> Clone for EvaluationDomainCache<F> {
+    fn clone(&self) -> Self {
+        Self { root: self.root.clone(), plans: RwLock::new(self.plans.read().clone()) }
+    }
+}
+
+impl<F: FftField> PartialEq for EvaluationDomainCache<F> {
+    /// Returns `true` if the caches have the same root plan, as all other plans are derived.
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root
+    }
+}
+
+impl<F: FftField> Eq for EvaluationDomainCache<F> {}
+
 #[cfg(test)]
 mod tests {
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     use crate::fft::domain::FFTOrder;
-    use crate::fft::{DensePolynomial, EvaluationDomain};
+    use crate::fft::{DensePolynomial, EvaluationDomain, EvaluationDomainCache, Evaluations, FFTPlan};
     use rand::Rng;
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{FftField, Field, One, Zero};
     use snarkvm_utilities::{TestRng, Uniform};
+    use std::sync::Arc;
 
     #[test]
     fn vanishing_polynomial_evaluation() {
@@ -1134,6 +1320,78 @@ mod tests {
         }
     }
 
+    /// Tests that the FFT plans output the same result as the unplanned FFTs.
+    #[test]
+    fn test_fft_plan_matches_unplanned() {
+        let mut rng = TestRng::default();
+        for log_domain in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain).unwrap();
+            let plan = FFTPlan::new(domain);
+            let coeffs = DensePolynomial::<Fr>::rand((1 << log_domain) - 1, &mut rng).coeffs;
+
+            let mut planned = coeffs.clone();
+            plan.fft_in_place(&mut planned);
+            assert_eq!(domain.fft(&coeffs), planned);
+            plan.ifft_in_place(&mut planned);
+            assert_eq!(coeffs, planned);
+
+            let mut planned = coeffs.clone();
+            plan.coset_fft_in_place(&mut planned);
+            assert_eq!(domain.coset_fft(&coeffs), planned);
+            plan.coset_ifft_in_place(&mut planned);
+            assert_eq!(coeffs, planned);
+        }
+    }
+
+    /// Tests that the cached plans for subdomains match the plans computed from scratch.
+    #[test]
+    fn test_evaluation_domain_cache() {
+        let root_domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        let cache = EvaluationDomainCache::new(FFTPlan::new(root_domain));
+        assert_eq!(cache.len(), 1);
+
+        for log_domain in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain).unwrap();
+            let plan = cache.plan(&domain);
+            assert_eq!(plan.as_ref(), &FFTPlan::new(domain));
+            // Ensure the plan is cached.
+            assert!(Arc::ptr_eq(&plan, &cache.plan(&domain)));
+        }
+        assert_eq!(cache.len(), 12);
+
+        // Ensure the cache can be shared across threads.
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<EvaluationDomainCache<Fr>>();
+    }
+
+    /// Tests that the polynomial products via the cache match the unplanned products.
+    #[test]
+    fn test_evaluation_domain_cache_mul_polynomials() {
+        let mut rng = TestRng::default();
+        let cache = EvaluationDomainCache::new(FFTPlan::new(EvaluationDomain::<Fr>::new(1 << 8).unwrap()));
+        for (a_degree, b_degree) in [(0, 0), (1, 5), (17, 64), (127, 128), (200, 300)] {
+            let a = DensePolynomial::<Fr>::rand(a_degree, &mut rng);
+            let b = DensePolynomial::<Fr>::rand(b_degree, &mut rng);
+            assert_eq!(cache.mul_polynomials(&a, &b), &a * &b);
+        }
+        // Ensure the zero polynomial is handled.
+        let a = DensePolynomial::<Fr>::rand(10, &mut rng);
+        assert!(cache.mul_polynomials(&a, &DensePolynomial::zero()).is_zero());
+    }
+
+    /// Tests that the interpolations via the cache match the unplanned interpolations.
+    #[test]
+    fn test_evaluation_domain_cache_interpolate() {
+        let mut rng = TestRng::default();
+        let cache = EvaluationDomainCache::new(FFTPlan::new(EvaluationDomain::<Fr>::new(1 << 8).unwrap()));
+        for log_domain in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain).unwrap();
+            let evals = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let evaluations = Evaluations::from_vec_and_domain(evals, domain);
+            assert_eq!(cache.interpolate(evaluations.clone()), evaluations.interpolate());
+        }
+    }
+
     /// Tests that the FFTs output the correct result.
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
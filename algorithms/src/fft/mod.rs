@@ -18,7 +18,7 @@
 //! including FFTs.
 
 pub mod domain;
-pub use domain::EvaluationDomain;
+pub use domain::{EvaluationDomain, EvaluationDomainCache, FFTPlan};
 
 pub mod evaluations;
 pub use evaluations::Evaluations;
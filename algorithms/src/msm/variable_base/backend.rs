@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+
+use anyhow::{bail, Error};
+use core::{
+    any::TypeId,
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// The cached backend override from the environment (0 = unread, 1 = unset, 2 = standard, 3 = batched).
+static ENV_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+/// The algorithm used to compute a variable-base multi-scalar multiplication.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MsmBackend {
+    /// Pippenger's algorithm, accumulating the buckets in projective coordinates.
+    Standard,
+    /// Pippenger's algorithm, accumulating the buckets with batched affine additions.
+    Batched,
+}
+
+impl MsmBackend {
+    /// All of the backends.
+    pub const ALL: [Self; 2] = [Self::Standard, Self::Batched];
+    /// The environment variable that overrides the selected backend, e.g. for benchmarking.
+    pub const ENV_VAR: &'static str = "SNARKVM_MSM_BACKEND";
+
+    /// Returns the backend to use for the given curve.
+    ///
+    /// The environment override takes precedence. Otherwise, batched affine additions are used for BLS12-377 G1,
+    /// unless the CPU is an x86-64 without ADX and BMI2, on which the extra field multiplications are not amortized.
+    pub fn select<G: AffineCurve>() -> Self {
+        if let Some(backend) = Self::from_env() {
+            return backend;
+        }
+        match TypeId::of::<G>() == TypeId::of::<G1Affine>() && Self::has_fast_multiplication() {
+            true => Self::Batched,
+            false => Self::Standard,
+        }
+    }
+
+    /// Returns the backend set in the `SNARKVM_MSM_BACKEND` environment variable, if any.
+    /// The variable is read once, and an unrecognized value is ignored.
+    pub fn from_env() -> Option<Self> {
+        match ENV_BACKEND.load(Ordering::Relaxed) {
+            2 => Some(Self::Standard),
+            3 => Some(Self::Batched),
+            1 => None,
+            _ => {
+                let backend = std::env::var(Self::ENV_VAR).ok().and_then(|value| value.parse().ok());
+                let cached = match backend {
+                    Some(Self::Standard) => 2,
+                    Some(Self::Batched) => 3,
+                    None => 1,
+                };
+                ENV_BACKEND.store(cached, Ordering::Relaxed);
+                backend
+            }
+        }
+    }
+
+    /// Returns `true` if the CPU has the instructions that accelerate field multiplication.
+    fn has_fast_multiplication() -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            std::is_x86_feature_detected!("adx") && std::is_x86_feature_detected!("bmi2")
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            true
+        }
+    }
+}
+
+impl FromStr for MsmBackend {
+    type Err = Error;
+
+    /// Parses the backend from its name.
+    fn from_str(backend: &str) -> Result<Self, Self::Err> {
+        match backend.trim().to_ascii_lowercase().as_str() {
+            "standard" | "pippenger" => Ok(Self::Standard),
+            "batched" => Ok(Self::Batched),
+            _ => bail!("Unknown MSM backend '{backend}' (expected 'standard' or 'batched')"),
+        }
+    }
+}
+
+impl fmt::Display for MsmBackend {
+    /// Displays the name of the backend.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Standard => write!(f, "standard"),
+            Self::Batched => write!(f, "batched"),
+        }
+    }
+}
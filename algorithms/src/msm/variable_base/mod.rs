@@ -14,38 +14,49 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod backend;
+pub use backend::*;
+
 pub mod batched;
 pub mod standard;
 
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_curves::traits::AffineCurve;
 use snarkvm_fields::PrimeField;
 
-use core::any::TypeId;
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+use {core::any::TypeId, snarkvm_curves::bls12_377::G1Affine};
 
 pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-        // For BLS12-377, we perform variable base MSM using a batched addition technique.
-        if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
-            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
-                let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
-                    bases, scalars,
-                );
-                if let Ok(result) = result {
-                    return result;
-                }
+        // For BLS12-377, we first attempt to perform variable base MSM on the GPU.
+        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+        // TODO SNP: where to set the threshold
+        if TypeId::of::<G>() == TypeId::of::<G1Affine>() && scalars.len() > 1024 {
+            let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
+                bases, scalars,
+            );
+            if let Ok(result) = result {
+                return result;
             }
-            batched::msm(bases, scalars)
         }
-        // For all other curves, we perform variable base MSM using Pippenger's algorithm.
-        else {
-            standard::msm(bases, scalars)
+        // Otherwise, we perform variable base MSM with the backend selected for the curve and CPU.
+        Self::msm_with_backend(MsmBackend::select::<G>(), bases, scalars)
+    }
+
+    /// Performs variable base MSM with the given backend.
+    pub fn msm_with_backend<G: AffineCurve>(
+        backend: MsmBackend,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
+        match backend {
+            MsmBackend::Standard => standard::msm(bases, scalars),
+            MsmBackend::Batched => batched::msm(bases, scalars),
         }
     }
 
@@ -107,6 +118,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_backends_agree() {
+        use rand::Rng;
+        use snarkvm_curves::{edwards_bls12, ProjectiveCurve};
+
+        let mut rng = TestRng::default();
+        for _ in 0..10 {
+            let msm_size = rng.gen_range(1..2000);
+
+            // Ensure all backends agree on BLS12-377.
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+            for backend in MsmBackend::ALL {
+                let candidate = VariableBase::msm_with_backend(backend, bases.as_slice(), scalars.as_slice());
+                assert_eq!(expected, candidate.to_affine(), "Backend: {backend}, MSM size: {msm_size}");
+            }
+
+            // Ensure all backends agree on Edwards-BLS12.
+            let (bases, scalars) =
+                create_scalar_bases::<edwards_bls12::EdwardsAffine, edwards_bls12::Fr>(&mut rng, msm_size);
+            let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+            for backend in MsmBackend::ALL {
+                let candidate = VariableBase::msm_with_backend(backend, bases.as_slice(), scalars.as_slice());
+                assert_eq!(expected, candidate.to_affine(), "Backend: {backend}, MSM size: {msm_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_backend_from_str() {
+        for backend in MsmBackend::ALL {
+            assert_eq!(backend, backend.to_string().parse().unwrap());
+        }
+        assert_eq!(MsmBackend::Standard, " Pippenger ".parse().unwrap());
+        assert!("gpu".parse::<MsmBackend>().is_err());
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
@@ -57,13 +57,18 @@ pub struct UniversalParams<E: PairingEngine> {
 
 impl<E: PairingEngine> UniversalParams<E> {
     pub fn load() -> Result<Self> {
-        let powers = Arc::new(RwLock::new(PowersOfG::<E>::load()?));
+        Ok(Self::from_powers(PowersOfG::<E>::load()?))
+    }
+
+    /// Initializes the universal parameters from the given powers.
+    pub fn from_powers(powers: PowersOfG<E>) -> Self {
+        let powers = Arc::new(RwLock::new(powers));
         let h = E::G2Affine::prime_subgroup_generator();
         let prepared_h = h.prepare();
         let prepared_beta_h = powers.read().beta_h().prepare();
         let supported_degree_bounds = vec![1 << 10, 1 << 15, 1 << 20, 1 << 25, 1 << 30];
 
-        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h })
+        Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h }
     }
 
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
@@ -29,6 +29,7 @@ use crate::{
 use anyhow::anyhow;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_parameters::testnet3::{PowersOfG, MAX_NUM_POWERS};
 use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
 
 use core::{
@@ -37,7 +38,10 @@ use core::{
     sync::atomic::{AtomicBool, Ordering},
 };
 use itertools::Itertools;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
 use rand_core::RngCore;
+use std::collections::BTreeMap;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -99,6 +103,76 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(params)
     }
 
+    /// Samples public parameters supporting polynomials up to `max_degree`,
+    /// deriving all of the randomness deterministically from `seed`.
+    ///
+    /// Anyone who knows the seed knows the trapdoor and can forge proofs.
+    /// This is intended for reproducible tests and benchmarks only, and must never be used in production.
+    /// Note that circuits requiring more than `max_degree + 1` powers are not supported by these parameters.
+    pub fn setup_from_seed(max_degree: usize, seed: [u8; 32]) -> Result<UniversalParams<E>, PCError> {
+        if max_degree < 1 || max_degree + 1 >= MAX_NUM_POWERS / 2 {
+            return Err(anyhow!("Cannot sample parameters for a degree of {max_degree}").into());
+        }
+        let setup_time = start_timer!(|| format!("KZG10::SetupFromSeed with degree {max_degree}"));
+        let rng = &mut ChaChaRng::from_seed(seed);
+
+        // Sample the trapdoor.
+        let beta = E::Fr::rand(rng);
+        let beta_inverse = beta.inverse().ok_or_else(|| anyhow!("Sampled a zero trapdoor"))?;
+        let g = E::G1Affine::prime_subgroup_generator();
+        let gamma_g = E::G1Projective::rand(rng).to_affine();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        // The highest power of beta in the SRS.
+        let srs_max_degree = MAX_NUM_POWERS - 1;
+        // The first power of beta in the shifted powers, which support degree bounds up to `max_degree`.
+        let lowest_shift_degree = srs_max_degree - max_degree;
+
+        // Compute `{ \beta^i G }` for `i` in `0..=max_degree`.
+        let mut powers_of_beta = vec![E::Fr::one()];
+        for i in 1..=max_degree {
+            powers_of_beta.push(powers_of_beta[i - 1] * beta);
+        }
+        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(
+            cfg_iter!(powers_of_beta).map(|power| g.mul(*power)).collect(),
+        );
+
+        // Compute `{ \beta^i G }` for `i` in `lowest_shift_degree..=srs_max_degree`.
+        let lowest_shift_power = beta.pow([lowest_shift_degree as u64]);
+        let shifted_powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(
+            cfg_iter!(powers_of_beta).map(|power| g.mul(lowest_shift_power * power)).collect(),
+        );
+
+        // Compute `{ \beta^i \gamma G }` for the powers needed by the degree bounds, and hiding bounds up to 2.
+        let degree_bounds = KZGDegreeBounds::Marlin.get_list::<E::Fr>(max_degree);
+        let mut powers_of_beta_times_gamma_g = BTreeMap::new();
+        let shifted_gamma_degrees = degree_bounds.iter().flat_map(|d| (0..=3).map(move |i| srs_max_degree - d + i));
+        let gamma_degrees = (0..=3).chain(shifted_gamma_degrees);
+        for degree in gamma_degrees.filter(|degree| *degree <= srs_max_degree + 1) {
+            powers_of_beta_times_gamma_g
+                .entry(degree)
+                .or_insert_with(|| gamma_g.mul(beta.pow([degree as u64])).to_affine());
+        }
+
+        // Compute `{ \beta^{-(srs_max_degree - d)} H }` for each degree bound `d`.
+        let negative_powers_of_beta_h = degree_bounds
+            .iter()
+            .map(|d| (*d, h.mul(beta_inverse.pow([(srs_max_degree - d) as u64])).to_affine()))
+            .collect();
+
+        let beta_h = h.mul(beta).to_affine();
+
+        let powers = PowersOfG::from_parts(
+            powers_of_beta_g,
+            shifted_powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+        )?;
+        end_timer!(setup_time);
+        Ok(UniversalParams::from_powers(powers))
+    }
+
     /// Outputs a commitment to `polynomial`.
     pub fn commit(
         powers: &Powers<E>,
@@ -363,6 +363,19 @@ impl<E: PairingEngine> CommitterKey<E> {
     pub fn supported_degree(&self) -> usize {
         self.powers_of_beta_g.len() - 1
     }
+
+    /// Returns the metadata describing the universal parameters and trimming of `self`.
+    pub fn trim_metadata(&self) -> TrimMetadata {
+        TrimMetadata {
+            srs_hash: TrimMetadata::hash_generators::<E>(
+                self.powers_of_beta_g.first(),
+                self.powers_of_beta_times_gamma_g.first(),
+            ),
+            supported_degree: self.powers_of_beta_g.len().saturating_sub(1),
+            supported_hiding_bound: Some(self.powers_of_beta_times_gamma_g.len().saturating_sub(2)),
+            degree_bounds: self.enforced_degree_bounds.clone(),
+        }
+    }
 }
 
 /// `VerifierKey` is used to check evaluation proofs for a given commitment.
@@ -486,6 +499,139 @@ impl<E: PairingEngine> VerifierKey<E> {
     pub fn supported_degree(&self) -> usize {
         self.supported_degree
     }
+
+    /// Returns the metadata describing the universal parameters and trimming of `self`.
+    /// The verifier key does not record a hiding bound, so `supported_hiding_bound` is `None`.
+    pub fn trim_metadata(&self) -> TrimMetadata {
+        TrimMetadata {
+            srs_hash: TrimMetadata::hash_generators::<E>(Some(&self.vk.g), Some(&self.vk.gamma_g)),
+            supported_degree: self.supported_degree,
+            supported_hiding_bound: None,
+            degree_bounds: self
+                .degree_bounds_and_neg_powers_of_h
+                .as_ref()
+                .map(|bounds| bounds.iter().map(|(degree_bound, _)| *degree_bound).collect()),
+        }
+    }
+}
+
+/// `TrimMetadata` describes the universal parameters a committer or verifier key was trimmed from,
+/// and the degrees it was trimmed to. It is derived from the keys, and is not serialized with them,
+/// but it can be serialized on its own, to record the committer key alongside a verifier key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrimMetadata {
+    /// The SHA-256 hash of the generators `g` and `gamma_g` of the universal parameters.
+    pub srs_hash: [u8; 32],
+    /// The maximum degree supported by the trimmed key.
+    pub supported_degree: usize,
+    /// The maximum hiding bound supported by the trimmed key.
+    /// This is `None` if the key does not record its hiding bound.
+    pub supported_hiding_bound: Option<usize>,
+    /// The degree bounds that are supported by the trimmed key.
+    /// This is `None` if the key does not support enforcing any degree bounds.
+    pub degree_bounds: Option<Vec<usize>>,
+}
+
+impl TrimMetadata {
+    /// The version of the serialized metadata.
+    const VERSION: u8 = 1;
+
+    /// Returns `true` if a committer key with the metadata `self` can be checked by a verifier key
+    /// with the metadata `other`, i.e. both keys were trimmed from the same universal parameters, to the
+    /// same degree, and every degree bound the committer key enforces is supported by the verifier key.
+    pub fn is_compatible_with(&self, other: &Self) -> bool {
+        let hiding_bounds_are_compatible = match (self.supported_hiding_bound, other.supported_hiding_bound) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        let degree_bounds_are_compatible = match (&self.degree_bounds, &other.degree_bounds) {
+            (Some(committer), Some(verifier)) => committer.iter().all(|bound| verifier.contains(bound)),
+            (Some(committer), None) => committer.is_empty(),
+            (None, _) => true,
+        };
+        self.srs_hash == other.srs_hash
+            && self.supported_degree == other.supported_degree
+            && hiding_bounds_are_compatible
+            && degree_bounds_are_compatible
+    }
+
+    /// Returns the SHA-256 hash of the given generators.
+    fn hash_generators<E: PairingEngine>(g: Option<&E::G1Affine>, gamma_g: Option<&E::G1Affine>) -> [u8; 32] {
+        let mut hash_input = Vec::new();
+        for generator in [g, gamma_g].into_iter().flatten() {
+            // Writing an affine point to a vector is infallible.
+            generator.write_le(&mut hash_input).expect("Failed to serialize a generator");
+        }
+        sha256(&hash_input)
+    }
+}
+
+impl FromBytes for TrimMetadata {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        // Read the version.
+        let version: u8 = FromBytes::read_le(&mut reader)?;
+        if version != Self::VERSION {
+            return Err(error("Invalid trim metadata version"));
+        }
+        // Read the SRS hash and the supported degree.
+        let srs_hash: [u8; 32] = FromBytes::read_le(&mut reader)?;
+        let supported_degree = u64::read_le(&mut reader)? as usize;
+        // Read the supported hiding bound.
+        let supported_hiding_bound = match bool::read_le(&mut reader)? {
+            true => Some(u64::read_le(&mut reader)? as usize),
+            false => None,
+        };
+        // Read the degree bounds.
+        let degree_bounds = match bool::read_le(&mut reader)? {
+            true => {
+                let num_bounds = u32::read_le(&mut reader)?;
+                let mut degree_bounds = Vec::new();
+                for _ in 0..num_bounds {
+                    degree_bounds.push(u64::read_le(&mut reader)? as usize);
+                }
+                Some(degree_bounds)
+            }
+            false => None,
+        };
+        Ok(Self { srs_hash, supported_degree, supported_hiding_bound, degree_bounds })
+    }
+}
+
+impl ToBytes for TrimMetadata {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the SRS hash and the supported degree.
+        self.srs_hash.write_le(&mut writer)?;
+        (self.supported_degree as u64).write_le(&mut writer)?;
+        // Write the supported hiding bound.
+        self.supported_hiding_bound.is_some().write_le(&mut writer)?;
+        if let Some(hiding_bound) = self.supported_hiding_bound {
+            (hiding_bound as u64).write_le(&mut writer)?;
+        }
+        // Write the degree bounds.
+        self.degree_bounds.is_some().write_le(&mut writer)?;
+        if let Some(degree_bounds) = &self.degree_bounds {
+            u32::try_from(degree_bounds.len()).map_err(|_| error("Too many degree bounds"))?.write_le(&mut writer)?;
+            for degree_bound in degree_bounds {
+                (*degree_bound as u64).write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TrimMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{ srs_hash: {}, supported_degree: {}, supported_hiding_bound: {:?}, degree_bounds: {:?} }}",
+            hex::encode(self.srs_hash),
+            self.supported_degree,
+            self.supported_hiding_bound,
+            self.degree_bounds
+        )
+    }
 }
 
 impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifierKey<E> {
@@ -555,6 +701,16 @@ impl<E: PairingEngine> BatchProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
+
+    /// Returns the number of evaluation proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the batch contains no evaluation proofs.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
@@ -818,7 +974,7 @@ pub fn evaluate_query_set<'a, F: PrimeField>(
 }
 
 /// A proof of satisfaction of linear combinations.
-#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Clone, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchLCProof<E: PairingEngine> {
     /// Evaluation proof.
     pub proof: BatchProof<E>,
@@ -826,10 +982,66 @@ pub struct BatchLCProof<E: PairingEngine> {
     pub evaluations: Option<Vec<E::Fr>>,
 }
 
+/// The (compressed) serialized size of a `BatchLCProof`, in bytes, broken down by component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BatchLCProofSizeSummary {
+    /// The size of the opening proofs, i.e. the KZG witnesses and their hiding evaluations.
+    pub opening_points: usize,
+    /// The size of the evaluations.
+    pub evaluations: usize,
+}
+
+impl BatchLCProofSizeSummary {
+    /// Returns the total serialized size, in bytes.
+    pub fn total(&self) -> usize {
+        self.opening_points + self.evaluations
+    }
+}
+
 impl<E: PairingEngine> BatchLCProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.proof.is_hiding()
     }
+
+    /// Returns the number of evaluations carried in the proof.
+    pub fn num_evaluations(&self) -> usize {
+        self.evaluations.as_ref().map_or(0, |evaluations| evaluations.len())
+    }
+
+    /// Returns the KZG opening witnesses, one for each distinct query point.
+    pub fn proof_points(&self) -> Vec<E::G1Affine> {
+        self.proof.0.iter().map(|proof| proof.w).collect()
+    }
+
+    /// Returns `true` if the opening proofs carry hiding randomness.
+    pub fn randomness_is_hiding(&self) -> bool {
+        self.proof.is_hiding()
+    }
+
+    /// Returns the compressed serialized size of the proof, broken down into opening points and evaluations.
+    pub fn serialized_size_summary(&self) -> BatchLCProofSizeSummary {
+        BatchLCProofSizeSummary {
+            opening_points: self.proof.serialized_size(Compress::Yes),
+            evaluations: self.evaluations.serialized_size(Compress::Yes),
+        }
+    }
+}
+
+impl<E: PairingEngine> fmt::Debug for BatchLCProof<E> {
+    /// Summarizes the proof, without printing every group and field element.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let proof_points = self.proof_points();
+        let evaluations = self.evaluations.as_deref().unwrap_or_default();
+        f.debug_struct("BatchLCProof")
+            .field("num_proof_points", &proof_points.len())
+            .field("first_proof_point", &proof_points.first())
+            .field("last_proof_point", &proof_points.last())
+            .field("is_hiding", &self.randomness_is_hiding())
+            .field("num_evaluations", &self.num_evaluations())
+            .field("first_evaluation", &evaluations.first())
+            .field("last_evaluation", &evaluations.last())
+            .finish()
+    }
 }
 
 impl<E: PairingEngine> FromBytes for BatchLCProof<E> {
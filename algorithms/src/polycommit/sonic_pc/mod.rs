@@ -63,6 +63,12 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         kzg10::KZG10::load_srs(max_degree).map_err(Into::into)
     }
 
+    /// Samples an SRS deterministically from `seed`. This is for tests and benchmarks only.
+    /// See [`KZG10::setup_from_seed`](kzg10::KZG10::setup_from_seed) for details.
+    pub fn setup_from_seed(max_degree: usize, seed: [u8; 32]) -> Result<UniversalParams<E>, PCError> {
+        kzg10::KZG10::setup_from_seed(max_degree, seed)
+    }
+
     pub fn trim(
         pp: &UniversalParams<E>,
         supported_degree: usize,
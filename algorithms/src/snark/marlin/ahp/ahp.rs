@@ -40,6 +40,17 @@ pub struct AHPForR1CS<F: Field, MM: MarlinMode> {
     mode: PhantomData<MM>,
 }
 
+/// The maximum circuit dimensions supported by an SRS of a given degree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintCapacity {
+    /// The maximum number of constraints.
+    pub num_constraints: usize,
+    /// The maximum number of variables, including the "one" variable.
+    pub num_variables: usize,
+    /// The maximum number of non-zero entries in any of the constraint matrices.
+    pub num_non_zero: usize,
+}
+
 pub(crate) fn witness_label(poly: &str, i: usize) -> String {
     format!("{poly}_{i:0>8}")
 }
@@ -72,22 +83,66 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
     /// must be with respect to the number of formatted public inputs.
     pub fn max_degree(num_constraints: usize, num_variables: usize, num_non_zero: usize) -> Result<usize, AHPError> {
         let padded_matrix_dim = matrices::padded_matrix_dim(num_variables, num_constraints);
-        let zk_bound = Self::zk_bound().unwrap_or(0);
         let constraint_domain_size = EvaluationDomain::<F>::compute_size_of_domain(padded_matrix_dim)
             .ok_or(AHPError::PolynomialDegreeTooLarge)?;
         let non_zero_domain_size =
             EvaluationDomain::<F>::compute_size_of_domain(num_non_zero).ok_or(AHPError::PolynomialDegreeTooLarge)?;
 
-        Ok(*[
+        Ok(core::cmp::max(
+            Self::max_constraint_domain_degree(constraint_domain_size),
+            Self::max_non_zero_domain_degree(non_zero_domain_size),
+        ))
+    }
+
+    /// The maximum number of constraints, variables, and non-zero entries supported by an SRS
+    /// of the given degree. This is the inverse of [`Self::max_degree`].
+    /// As in `max_degree`, the number of variables includes the "one" variable.
+    pub fn max_constraints_for_degree(srs_degree: usize) -> Result<ConstraintCapacity, AHPError> {
+        let constraint_domain_size = Self::largest_domain_size_within(srs_degree, Self::max_constraint_domain_degree)
+            .ok_or(AHPError::SRSDegreeTooSmall(srs_degree))?;
+        let non_zero_domain_size = Self::largest_domain_size_within(srs_degree, Self::max_non_zero_domain_degree)
+            .ok_or(AHPError::SRSDegreeTooSmall(srs_degree))?;
+
+        Ok(ConstraintCapacity {
+            num_constraints: constraint_domain_size,
+            num_variables: constraint_domain_size,
+            num_non_zero: non_zero_domain_size,
+        })
+    }
+
+    /// The maximum degree of the polynomials defined over a constraint domain of the given size.
+    fn max_constraint_domain_degree(constraint_domain_size: usize) -> usize {
+        let zk_bound = Self::zk_bound().unwrap_or(0);
+        *[
             2 * constraint_domain_size + zk_bound - 2,
             if MM::ZK { constraint_domain_size + 3 } else { 0 }, //  mask_poly
             constraint_domain_size,
             constraint_domain_size,
-            non_zero_domain_size - 1, // non-zero polynomials
         ]
         .iter()
         .max()
-        .unwrap())
+        .unwrap()
+    }
+
+    /// The maximum degree of the polynomials defined over a non-zero domain of the given size.
+    fn max_non_zero_domain_degree(non_zero_domain_size: usize) -> usize {
+        non_zero_domain_size - 1
+    }
+
+    /// Returns the largest admissible domain size whose polynomials fit within `srs_degree`,
+    /// or `None` if not even a domain of size one fits.
+    fn largest_domain_size_within(srs_degree: usize, degree_of: impl Fn(usize) -> usize) -> Option<usize> {
+        let mut size = EvaluationDomain::<F>::compute_size_of_domain(1)?;
+        if degree_of(size) > srs_degree {
+            return None;
+        }
+        while let Some(next) = size.checked_mul(2).and_then(EvaluationDomain::<F>::compute_size_of_domain) {
+            if degree_of(next) > srs_degree {
+                break;
+            }
+            size = next;
+        }
+        Some(size)
     }
 
     /// Get all the strict degree bounds enforced in the AHP.
@@ -195,6 +250,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let mut linear_combinations = BTreeMap::new();
 
         let lincheck_time = start_timer!(|| "Lincheck");
+        // The per-instance lookup polynomials are only checked if the prover sent them.
+        let num_lookup_instances = if state.has_lookups { state.batch_size } else { 0 };
         // Lincheck sumcheck:
         let z_b_s = (0..state.batch_size)
             .map(|i| {
@@ -202,31 +259,31 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 LinearCombination::new(z_b_i.clone(), [(F::one(), z_b_i)])
             })
             .collect::<Vec<_>>();
-        let f_s = (0..state.batch_size)
+        let f_s = (0..num_lookup_instances)
             .map(|i| {
                 let f_i = witness_label("f", i);
                 LinearCombination::new(f_i.clone(), [(F::one(), f_i)])
             })
             .collect::<Vec<_>>();
-        let s_1_s = (0..state.batch_size)
+        let s_1_s = (0..num_lookup_instances)
             .map(|i| {
                 let s_1_i = witness_label("s_1", i);
                 LinearCombination::new(s_1_i.clone(), [(F::one(), s_1_i)])
             })
             .collect::<Vec<_>>();
-        let s_2_s = (0..state.batch_size)
+        let s_2_s = (0..num_lookup_instances)
             .map(|i| {
                 let s_2_i = witness_label("s_2", i);
                 LinearCombination::new(s_2_i.clone(), [(F::one(), s_2_i)])
             })
             .collect::<Vec<_>>();
-        let z_2_s = (0..state.batch_size)
+        let z_2_s = (0..num_lookup_instances)
             .map(|i| {
                 let z_2_i = witness_label("z_2", i);
                 LinearCombination::new(z_2_i.clone(), [(F::one(), z_2_i)])
             })
             .collect::<Vec<_>>();
-        let delta_s_1_omega_s = (0..state.batch_size)
+        let delta_s_1_omega_s = (0..num_lookup_instances)
             .map(|i| {
                 let delta_s_1_omega_i = witness_label("delta_omega_s_1", i);
                 LinearCombination::new(delta_s_1_omega_i.clone(), [(F::one(), delta_s_1_omega_i)])
@@ -287,21 +344,24 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             let epsilon_one_plus_delta = epsilon * one_plus_delta;
             let mut rowcheck = LinearCombination::empty("lincheck_sumcheck");
             for (i, combiner) in batch_combiners.iter().enumerate() {
+                if state.has_lookups {
+                    rowcheck
+                        // Plookup table checks
+                        .add(one_plus_delta
+                            * (epsilon + f_s_at_beta[i])
+                            * (epsilon_one_plus_delta + table_at_beta + delta_table_omega_at_beta)
+                            * combiner, witness_label("z_2", i))
+                        .add((epsilon_one_plus_delta + s_1_s_at_beta[i] + delta * s_2_s_at_beta[i])
+                            * (epsilon_one_plus_delta + s_2_s_at_beta[i] + delta_s_1_omega_s_at_beta[i])
+                            * combiner * -F::one(), witness_label("omega_z_2", i))
+                        .add((z_2_s_at_beta[i] - F::one()) * combiner, "l_1")
+                        // Plookup rowcheck
+                        .add(s_l_at_beta * combiner, witness_label("z_a", i))
+                        .add(zeta * z_b_s_at_beta[i] * s_l_at_beta * combiner, LCTerm::One)
+                        .add(zeta_squared * s_l_at_beta * combiner, witness_label("z_c", i))
+                        .add(-f_s_at_beta[i] * s_l_at_beta * combiner, LCTerm::One);
+                }
                 rowcheck
-                    // Plookup table checks
-                    .add(one_plus_delta
-                        * (epsilon + f_s_at_beta[i])
-                        * (epsilon_one_plus_delta + table_at_beta + delta_table_omega_at_beta)
-                        * combiner, witness_label("z_2", i))
-                    .add((epsilon_one_plus_delta + s_1_s_at_beta[i] + delta * s_2_s_at_beta[i])
-                        * (epsilon_one_plus_delta + s_2_s_at_beta[i] + delta_s_1_omega_s_at_beta[i])
-                        * combiner * -F::one(), witness_label("omega_z_2", i))
-                    .add((z_2_s_at_beta[i] - F::one()) * combiner, "l_1")
-                    // Plookup rowcheck
-                    .add(s_l_at_beta * combiner, witness_label("z_a", i))
-                    .add(zeta * z_b_s_at_beta[i] * s_l_at_beta * combiner, LCTerm::One)
-                    .add(zeta_squared * s_l_at_beta * combiner, witness_label("z_c", i))
-                    .add(-f_s_at_beta[i] * s_l_at_beta * combiner, LCTerm::One)
                     // Rowcheck
                     .add(z_b_s_at_beta[i] * s_m_at_beta * combiner, witness_label("z_a", i))
                     .add(s_m_at_beta * combiner * -F::one(), witness_label("z_c", i));
@@ -545,7 +605,11 @@ impl<F: PrimeField> SelectorPolynomial<F> for EvaluationDomain<F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fft::{DensePolynomial, Evaluations};
+    use crate::{
+        fft::{DensePolynomial, Evaluations},
+        snark::marlin::{MarlinHidingMode, MarlinNonHidingMode},
+    };
+    use rand::Rng;
     use snarkvm_curves::bls12_377::fr::Fr;
     use snarkvm_fields::{One, Zero};
     use snarkvm_utilities::rand::{TestRng, Uniform};
@@ -591,6 +655,55 @@ mod tests {
         }
     }
 
+    fn check_max_constraints_for_degree<MM: MarlinMode>(rng: &mut TestRng) {
+        for _ in 0..1000 {
+            let num_constraints = rng.gen_range(1..1 << 20);
+            let num_variables = rng.gen_range(1..1 << 20);
+            let num_non_zero = rng.gen_range(1..1 << 22);
+
+            // The capacity of the SRS for a circuit must cover the circuit.
+            let degree = AHPForR1CS::<Fr, MM>::max_degree(num_constraints, num_variables, num_non_zero).unwrap();
+            let capacity = AHPForR1CS::<Fr, MM>::max_constraints_for_degree(degree).unwrap();
+            assert!(capacity.num_constraints >= num_constraints);
+            assert!(capacity.num_variables >= num_variables);
+            assert!(capacity.num_non_zero >= num_non_zero);
+
+            // A circuit at full capacity must fit within the SRS.
+            let capacity_degree = AHPForR1CS::<Fr, MM>::max_degree(
+                capacity.num_constraints,
+                capacity.num_variables,
+                capacity.num_non_zero,
+            )
+            .unwrap();
+            assert!(capacity_degree <= degree);
+
+            // The same holds for an arbitrary SRS degree.
+            let srs_degree = rng.gen_range(8..1 << 22);
+            let capacity = AHPForR1CS::<Fr, MM>::max_constraints_for_degree(srs_degree).unwrap();
+            let capacity_degree = AHPForR1CS::<Fr, MM>::max_degree(
+                capacity.num_constraints,
+                capacity.num_variables,
+                capacity.num_non_zero,
+            )
+            .unwrap();
+            assert!(capacity_degree <= srs_degree);
+        }
+    }
+
+    #[test]
+    fn test_max_constraints_for_degree() {
+        let rng = &mut TestRng::default();
+        check_max_constraints_for_degree::<MarlinHidingMode>(rng);
+        check_max_constraints_for_degree::<MarlinNonHidingMode>(rng);
+    }
+
+    #[test]
+    fn test_max_constraints_for_degree_too_small() {
+        assert!(AHPForR1CS::<Fr, MarlinNonHidingMode>::max_constraints_for_degree(0).is_err());
+        assert!(AHPForR1CS::<Fr, MarlinHidingMode>::max_constraints_for_degree(3).is_err());
+        assert!(AHPForR1CS::<Fr, MarlinHidingMode>::max_constraints_for_degree(4).is_ok());
+    }
+
     #[test]
     fn test_summation() {
         let rng = &mut TestRng::default();
@@ -29,6 +29,10 @@ pub enum AHPError {
     NonSquareMatrix,
     /// During synthesis, our polynomials ended up being too high of degree
     PolynomialDegreeTooLarge,
+    /// The SRS degree is too small to support any circuit.
+    SRSDegreeTooSmall(usize),
+    /// The prover message contains a sum that is zero.
+    ZeroProverMessageSum(String),
 }
 
 impl From<snarkvm_r1cs::errors::SynthesisError> for AHPError {
@@ -15,11 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use core::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::{
     fft::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         EvaluationDomain,
+        EvaluationDomainCache,
+        FFTPlan,
     },
     polycommit::sonic_pc::LabeledPolynomial,
     snark::marlin::{ahp::matrices::MatrixArithmetization, AHPForR1CS, CircuitInfo, MarlinMode, Matrix},
@@ -54,6 +57,8 @@ pub struct Circuit<F: PrimeField, MM: MarlinMode> {
 
     pub fft_precomputation: FFTPrecomputation<F>,
     pub ifft_precomputation: IFFTPrecomputation<F>,
+    /// The cache of FFT plans, which is shared by the prover rounds and across clones of the circuit.
+    pub fft_cache: Arc<EvaluationDomainCache<F>>,
 
     /// Selectors.
     pub s_m: LabeledPolynomial<F>,
@@ -80,6 +85,11 @@ impl<F: PrimeField, MM: MarlinMode> Circuit<F, MM> {
         crate::fft::EvaluationDomain::<F>::new(self.index_info.num_constraints).unwrap().size()
     }
 
+    /// Returns `true` if the R1CS instance has lookup constraints, i.e. if the lookup selector is not zero.
+    pub fn has_lookups(&self) -> bool {
+        self.s_l_evals.iter().any(|s| !s.is_zero())
+    }
+
     /// Iterate over the indexed polynomials.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
         // Alphabetical order
@@ -179,6 +189,10 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
             non_zero_c_domain_size,
         )
         .ok_or(SerializationError::InvalidData)?;
+        let fft_cache = Arc::new(EvaluationDomainCache::new(FFTPlan::from_precomputations(
+            fft_precomputation.clone(),
+            ifft_precomputation.clone(),
+        )));
 
         Ok(Circuit {
             index_info,
@@ -190,6 +204,7 @@ impl<F: PrimeField, MM: MarlinMode> CanonicalDeserialize for Circuit<F, MM> {
             c_arith: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             fft_precomputation,
             ifft_precomputation,
+            fft_cache,
             s_m: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             s_l: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
             s_l_evals: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    fft::{EvaluationDomain, Evaluations},
+    fft::{EvaluationDomain, EvaluationDomainCache, Evaluations, FFTPlan},
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{
@@ -34,7 +34,7 @@ use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSyst
 use snarkvm_utilities::cfg_into_iter;
 
 use core::marker::PhantomData;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -89,6 +89,10 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             non_zero_c_domain.size(),
         )
         .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let fft_cache = Arc::new(EvaluationDomainCache::new(FFTPlan::from_precomputations(
+            fft_precomputation.clone(),
+            ifft_precomputation.clone(),
+        )));
         end_timer!(fft_precomp_time);
 
         let s_m = LabeledPolynomial::new(
@@ -126,6 +130,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             lookup_tables,
             fft_precomputation,
             ifft_precomputation,
+            fft_cache,
             mode: PhantomData,
         })
     }
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::snark::marlin::AHPError;
 use snarkvm_fields::Field;
 use snarkvm_utilities::{error, serialize::*, ToBytes, Write};
 
@@ -25,6 +26,20 @@ pub struct FifthMessage<F: Field> {
     pub sum_c: F,
 }
 
+impl<F: Field> FifthMessage<F> {
+    /// Ensures that none of the sums are zero.
+    ///
+    /// This is a quick sanity check, and does not replace verification of the proof.
+    pub fn check_nonzero(&self) -> Result<(), AHPError> {
+        for (label, sum) in [("sum_a", &self.sum_a), ("sum_b", &self.sum_b), ("sum_c", &self.sum_c)] {
+            if sum.is_zero() {
+                return Err(AHPError::ZeroProverMessageSum(label.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<F: Field> ToBytes for FifthMessage<F> {
     fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
         CanonicalSerialize::serialize_compressed(self, &mut w).map_err(|_| error("Could not serialize ProverMsg"))
@@ -20,7 +20,7 @@ mod constraint_system;
 pub(crate) use constraint_system::*;
 
 mod message;
-pub(crate) use message::*;
+pub use message::*;
 
 mod oracles;
 pub(crate) use oracles::*;
@@ -28,4 +28,4 @@ pub(crate) use oracles::*;
 mod round_functions;
 
 mod state;
-pub(self) use state::*;
+pub(crate) use state::*;
@@ -169,8 +169,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let x_evals = {
             let mut coeffs = x_poly.coeffs.clone();
-            coeffs.resize(constraint_domain.size(), F::zero());
-            constraint_domain.in_order_fft_in_place_with_pc(&mut coeffs, state.fft_precomputation());
+            state.fft_cache().plan(&constraint_domain).fft_in_place(&mut coeffs);
             coeffs
         };
 
@@ -181,8 +180,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 _ => w_extended[k - (k / ratio) - 1] - x_evals[k],
             })
             .collect();
-        let w_poly = EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, constraint_domain)
-            .interpolate_with_pc(state.ifft_precomputation());
+        let w_poly =
+            state.fft_cache().interpolate(EvaluationsOnDomain::from_vec_and_domain(w_poly_evals, constraint_domain));
         let (w_poly, remainder) = w_poly.divide_by_vanishing_poly(input_domain).unwrap();
         assert!(remainder.is_zero());
 
@@ -206,7 +205,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let evals = EvaluationsOnDomain::from_vec_and_domain(evaluations.to_vec(), constraint_domain);
 
-        let mut poly = evals.interpolate_with_pc_by_ref(state.ifft_precomputation());
+        let mut poly = state.fft_cache().interpolate(evals.clone());
         if should_randomize {
             poly += &(&v_H * r.unwrap());
         }
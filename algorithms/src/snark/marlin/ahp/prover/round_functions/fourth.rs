@@ -65,78 +65,86 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let zeta_squared = zeta.square();
         let one_plus_delta = F::one() + *delta;
         let epsilon_one_plus_delta = *epsilon * one_plus_delta;
-        let table = state.second_round_oracles.as_ref().unwrap().table.polynomial().as_dense().unwrap();
-        let delta_table_omega =
-            state.second_round_oracles.as_ref().unwrap().delta_table_omega.polynomial().as_dense().unwrap();
+        let fft_cache = state.fft_cache();
+        let second_round_oracles = state.second_round_oracles.as_ref().unwrap();
+        let table = second_round_oracles.table.polynomial().as_dense().unwrap();
+        let delta_table_omega = second_round_oracles.delta_table_omega.polynomial().as_dense().unwrap();
+        // The per-instance lookup polynomials are omitted if the circuit has no lookup constraints.
+        let has_lookups = !second_round_oracles.batches.is_empty();
         let row = cfg_iter!(state.first_round_oracles.as_ref().unwrap().batches)
-            .zip_eq(&state.second_round_oracles.as_ref().unwrap().batches)
             .zip_eq(batch_combiners)
-            .map(|((b, c), combiner)| {
-                let mut table = table.clone();
-                let mut row_check = {
-                    let z_a = b.z_a_poly.polynomial().as_dense().unwrap();
-                    let mut z_b = b.z_b_poly.polynomial().as_dense().unwrap().clone();
-                    let mut z_c = b.z_c_poly.polynomial().as_dense().unwrap().clone();
-                    let f = c.f_poly.polynomial().as_dense().unwrap();
-                    let mul_check = state.index.s_m.polynomial().as_dense().unwrap() * &(&(z_a * &z_b) - &z_c);
-                    cfg_iter_mut!(z_b.coeffs).for_each(|b| *b *= zeta);
-                    cfg_iter_mut!(z_c.coeffs).for_each(|c| *c *= zeta_squared);
-                    let lookup_check =
-                        state.index.s_l.polynomial().as_dense().unwrap() * &(&(&(z_a + &z_b) + &z_c) - f);
-
-                    &mul_check + &lookup_check
-                };
-
-                let lookup_poly = {
-                    let mut f = c.f_poly.polynomial().as_dense().unwrap().clone();
-                    let mut s_1 = c.s_1_poly.polynomial().as_dense().unwrap().clone();
-                    let mut s_2 = c.s_2_poly.polynomial().as_dense().unwrap().clone();
-                    let mut z_2 = c.z_2_poly.polynomial().as_dense().unwrap().clone();
-                    let delta_s_1_omega = c.delta_s_1_omega_poly.polynomial().as_dense().unwrap();
-                    let z_2_omega = c.z_2_omega_poly.polynomial().as_dense().unwrap();
-                    let l_1 = state.index.l_1.polynomial().as_dense().unwrap();
-                    let first = {
-                        if f.degree() > 0 {
-                            f.coeffs[0] += epsilon;
-                        } else {
-                            f.coeffs.push(*epsilon);
-                        }
-
-                        if table.degree() > 0 {
-                            table.coeffs[0] += epsilon_one_plus_delta;
-                        } else {
-                            table.coeffs.push(epsilon_one_plus_delta);
-                        }
-
-                        let a = &table + delta_table_omega;
-                        &(&z_2.clone() * &(f * one_plus_delta)) * &a
+            .enumerate()
+            .map(|(i, (b, combiner))| {
+                let z_a = b.z_a_poly.polynomial().as_dense().unwrap();
+                let mut z_b = b.z_b_poly.polynomial().as_dense().unwrap().clone();
+                let mut z_c = b.z_c_poly.polynomial().as_dense().unwrap().clone();
+                let s_m = state.index.s_m.polynomial().as_dense().unwrap();
+                let mut row_check = fft_cache.mul_polynomials(s_m, &(&fft_cache.mul_polynomials(z_a, &z_b) - &z_c));
+
+                if has_lookups {
+                    let c = &second_round_oracles.batches[i];
+                    let mut table = table.clone();
+
+                    let lookup_check = {
+                        let f = c.f_poly.polynomial().as_dense().unwrap();
+                        cfg_iter_mut!(z_b.coeffs).for_each(|b| *b *= zeta);
+                        cfg_iter_mut!(z_c.coeffs).for_each(|c| *c *= zeta_squared);
+                        let s_l = state.index.s_l.polynomial().as_dense().unwrap();
+                        fft_cache.mul_polynomials(s_l, &(&(&(z_a + &z_b) + &z_c) - f))
                     };
 
-                    let second = {
-                        if s_1.degree() > 0 {
-                            s_1.coeffs[0] += epsilon_one_plus_delta;
-                        } else {
-                            s_1.coeffs.push(epsilon_one_plus_delta);
-                        }
-                        let a = &s_1 + &(s_2.clone() * *delta);
-
-                        if s_2.degree() > 0 {
-                            s_2.coeffs[0] += epsilon_one_plus_delta;
-                        } else {
-                            s_2.coeffs.push(epsilon_one_plus_delta);
-                        }
-                        let b = &s_2 + delta_s_1_omega;
-
-                        &(&(z_2_omega * -F::one()) * &a) * &b
+                    let lookup_poly = {
+                        let mut f = c.f_poly.polynomial().as_dense().unwrap().clone();
+                        let mut s_1 = c.s_1_poly.polynomial().as_dense().unwrap().clone();
+                        let mut s_2 = c.s_2_poly.polynomial().as_dense().unwrap().clone();
+                        let mut z_2 = c.z_2_poly.polynomial().as_dense().unwrap().clone();
+                        let delta_s_1_omega = c.delta_s_1_omega_poly.polynomial().as_dense().unwrap();
+                        let z_2_omega = c.z_2_omega_poly.polynomial().as_dense().unwrap();
+                        let l_1 = state.index.l_1.polynomial().as_dense().unwrap();
+                        let first = {
+                            if f.degree() > 0 {
+                                f.coeffs[0] += epsilon;
+                            } else {
+                                f.coeffs.push(*epsilon);
+                            }
+
+                            if table.degree() > 0 {
+                                table.coeffs[0] += epsilon_one_plus_delta;
+                            } else {
+                                table.coeffs.push(epsilon_one_plus_delta);
+                            }
+
+                            let a = &table + delta_table_omega;
+                            fft_cache.mul_polynomials(&fft_cache.mul_polynomials(&z_2, &(f * one_plus_delta)), &a)
+                        };
+
+                        let second = {
+                            if s_1.degree() > 0 {
+                                s_1.coeffs[0] += epsilon_one_plus_delta;
+                            } else {
+                                s_1.coeffs.push(epsilon_one_plus_delta);
+                            }
+                            let a = &s_1 + &(s_2.clone() * *delta);
+
+                            if s_2.degree() > 0 {
+                                s_2.coeffs[0] += epsilon_one_plus_delta;
+                            } else {
+                                s_2.coeffs.push(epsilon_one_plus_delta);
+                            }
+                            let b = &s_2 + delta_s_1_omega;
+
+                            fft_cache.mul_polynomials(&fft_cache.mul_polynomials(&(z_2_omega * -F::one()), &a), &b)
+                        };
+
+                        z_2.coeffs[0] -= F::one();
+                        let third = fft_cache.mul_polynomials(&z_2, l_1);
+
+                        &(&first + &second) + &third
                     };
 
-                    z_2.coeffs[0] -= F::one();
-                    let third = &z_2 * l_1;
-
-                    &(&first + &second) + &third
-                };
-
-                row_check += &lookup_poly;
+                    row_check += &lookup_check;
+                    row_check += &lookup_poly;
+                }
 
                 // Apply linear combination coefficient
                 cfg_iter_mut!(row_check.coeffs).for_each(|c| *c *= combiner);
@@ -93,8 +93,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let table = LabeledPolynomial::new(
             "table".to_string(),
-            Evaluations::from_vec_and_domain(table_evals.clone(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state.fft_cache().interpolate(Evaluations::from_vec_and_domain(table_evals.clone(), constraint_domain)),
             None,
             None,
         );
@@ -104,8 +103,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let delta_table_omega = LabeledPolynomial::new(
             "delta_table_omega".to_string(),
-            Evaluations::from_vec_and_domain(delta_table_omega_evals.clone(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state
+                .fft_cache()
+                .interpolate(Evaluations::from_vec_and_domain(delta_table_omega_evals.clone(), constraint_domain)),
             None,
             None,
         );
@@ -114,33 +114,38 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let z_b = state.z_b.take().unwrap();
         let z_c = state.z_c.take().unwrap();
 
-        let batches = cfg_iter!(z_a)
-            .enumerate()
-            .zip_eq(z_b)
-            .zip_eq(z_c)
-            .map(|(((i, z_a), z_b), z_c)| {
-                Self::calculate_table_polys(
-                    witness_label("f", i),
-                    witness_label("s_1", i),
-                    witness_label("s_2", i),
-                    witness_label("z_2", i),
-                    witness_label("delta_omega_s_1", i),
-                    witness_label("omega_z_2", i),
-                    &table_evals,
-                    &delta_table_omega_evals,
-                    z_a,
-                    &z_b,
-                    &z_c,
-                    &state.index.s_l_evals,
-                    *zeta,
-                    *delta,
-                    *epsilon,
-                    &state,
-                )
-            })
-            .collect::<Vec<prover::SecondEntry<F>>>();
+        // Without lookup constraints, the lookup argument holds trivially, so the per-instance lookup polynomials
+        // are omitted, and the verifier skips the lookup checks.
+        let batches = match state.index.has_lookups() {
+            true => cfg_iter!(z_a)
+                .enumerate()
+                .zip_eq(z_b)
+                .zip_eq(z_c)
+                .map(|(((i, z_a), z_b), z_c)| {
+                    Self::calculate_table_polys(
+                        witness_label("f", i),
+                        witness_label("s_1", i),
+                        witness_label("s_2", i),
+                        witness_label("z_2", i),
+                        witness_label("delta_omega_s_1", i),
+                        witness_label("omega_z_2", i),
+                        &table_evals,
+                        &delta_table_omega_evals,
+                        z_a,
+                        &z_b,
+                        &z_c,
+                        &state.index.s_l_evals,
+                        *zeta,
+                        *delta,
+                        *epsilon,
+                        &state,
+                    )
+                })
+                .collect::<Vec<prover::SecondEntry<F>>>(),
+            false => Vec::new(),
+        };
 
-        assert_eq!(batches.len(), batch_size);
+        assert!(batches.is_empty() || batches.len() == batch_size);
 
         let oracles = prover::SecondOracles { batches, table, delta_table_omega };
         assert!(oracles.matches_info(&Self::second_round_polynomial_info(batch_size)));
@@ -186,8 +191,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let f_poly = LabeledPolynomial::new(
             label_f.to_string(),
-            Evaluations::from_vec_and_domain(f_evals.clone(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state.fft_cache().interpolate(Evaluations::from_vec_and_domain(f_evals.clone(), constraint_domain)),
             None,
             None,
         );
@@ -199,15 +203,13 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let (s_1_evals, s_2_evals): (Vec<F>, Vec<F>) = s_evals.chunks(2).map(|els| (els[0], els[1])).unzip();
         let s_1_poly = LabeledPolynomial::new(
             label_s_1.to_string(),
-            Evaluations::from_vec_and_domain(s_1_evals.clone(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state.fft_cache().interpolate(Evaluations::from_vec_and_domain(s_1_evals.clone(), constraint_domain)),
             None,
             None,
         );
         let s_2_poly = LabeledPolynomial::new(
             label_s_2.to_string(),
-            Evaluations::from_vec_and_domain(s_2_evals.clone(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state.fft_cache().interpolate(Evaluations::from_vec_and_domain(s_2_evals.clone(), constraint_domain)),
             None,
             None,
         );
@@ -249,16 +251,16 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         let z_2_poly = LabeledPolynomial::new(
             label_z_2.to_string(),
-            Evaluations::from_vec_and_domain(z_2_evals.clone(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state.fft_cache().interpolate(Evaluations::from_vec_and_domain(z_2_evals.clone(), constraint_domain)),
             None,
             None,
         );
 
         let delta_s_1_omega_poly = LabeledPolynomial::new(
             label_delta_s_1_omega.to_string(),
-            Evaluations::from_vec_and_domain(delta_s_1_omega_evals.to_vec(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state
+                .fft_cache()
+                .interpolate(Evaluations::from_vec_and_domain(delta_s_1_omega_evals.to_vec(), constraint_domain)),
             None,
             None,
         );
@@ -266,8 +268,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let z_2_omega_evals = &[&z_2_evals[1..], &[z_2_evals[0]]].concat();
         let z_2_omega_poly = LabeledPolynomial::new(
             label_z_2_omega.to_string(),
-            Evaluations::from_vec_and_domain(z_2_omega_evals.to_vec(), constraint_domain)
-                .interpolate_with_pc_by_ref(state.ifft_precomputation()),
+            state
+                .fft_cache()
+                .interpolate(Evaluations::from_vec_and_domain(z_2_omega_evals.to_vec(), constraint_domain)),
             None,
             None,
         );
@@ -19,13 +19,7 @@ use std::collections::BTreeMap;
 
 use crate::{
     fft,
-    fft::{
-        domain::IFFTPrecomputation,
-        polynomial::PolyMultiplier,
-        DensePolynomial,
-        EvaluationDomain,
-        SparsePolynomial,
-    },
+    fft::{polynomial::PolyMultiplier, DensePolynomial, EvaluationDomain, EvaluationDomainCache, SparsePolynomial},
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{
@@ -136,8 +130,9 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let mul_domain_size = (constraint_domain.size() + summed_z_m.coeffs.len()).max(t.coeffs.len() + z.len());
         let mul_domain =
             EvaluationDomain::new(mul_domain_size).expect("field is not smooth enough to construct domain");
+        let mul_plan = state.fft_cache().plan(&mul_domain);
         let mut multiplier = PolyMultiplier::new();
-        multiplier.add_precomputation(state.fft_precomputation(), state.ifft_precomputation());
+        multiplier.add_precomputation(mul_plan.fft_precomputation(), mul_plan.ifft_precomputation());
         multiplier.add_polynomial(summed_z_m, "summed_z_m");
         multiplier.add_polynomial(z, "z");
         multiplier.add_polynomial(t, "t");
@@ -168,7 +163,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         let constraint_domain = state.constraint_domain;
         let summed_z_m_poly_time = start_timer!(|| "Compute z_m poly");
 
-        let ifft_precomputation = &state.index.ifft_precomputation;
+        let fft_cache = state.fft_cache();
         let first_msg = state.first_round_oracles.as_ref().unwrap();
         let mut job_pool = ExecutionPool::with_capacity(2 * state.batch_size);
         // let eta_b_over_eta_c = eta_b * eta_c.inverse().unwrap();
@@ -214,7 +209,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 &state.input_domain,
                 &state.constraint_domain,
                 &r_alpha_x_evals,
-                ifft_precomputation,
+                fft_cache,
             );
             end_timer!(t_poly_time);
             t
@@ -229,7 +224,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         input_domain: &EvaluationDomain<F>,
         constraint_domain: &EvaluationDomain<F>,
         r_alpha_x_on_h: &[F],
-        ifft_precomputation: &IFFTPrecomputation<F>,
+        fft_cache: &EvaluationDomainCache<F>,
     ) -> DensePolynomial<F> {
         let mut t_evals_on_h = vec![F::zero(); constraint_domain.size()];
         for (matrix, eta) in matrices.iter().zip_eq(matrix_randomizers) {
@@ -240,6 +235,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 }
             }
         }
-        fft::Evaluations::from_vec_and_domain(t_evals_on_h, *constraint_domain).interpolate_with_pc(ifft_precomputation)
+        fft_cache.interpolate(fft::Evaluations::from_vec_and_domain(t_evals_on_h, *constraint_domain))
     }
 }
@@ -21,6 +21,7 @@ use crate::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         DensePolynomial,
         EvaluationDomain,
+        EvaluationDomainCache,
         Evaluations as EvaluationsOnDomain,
     },
     snark::marlin::{
@@ -180,4 +181,9 @@ impl<'a, F: PrimeField, MM: MarlinMode> State<'a, F, MM> {
     pub fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
         &self.index.ifft_precomputation
     }
+
+    /// Returns the cache of FFT plans for the circuit.
+    pub fn fft_cache(&self) -> &EvaluationDomainCache<F> {
+        self.index.fft_cache.as_ref()
+    }
 }
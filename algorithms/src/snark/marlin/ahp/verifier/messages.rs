@@ -69,6 +69,7 @@ pub struct FifthMessage<F> {
 #[derive(Clone, Debug)]
 pub struct QuerySet<F> {
     pub batch_size: usize,
+    pub has_lookups: bool,
     pub g_1_query: (String, F),
     pub z_b_query: (String, F),
     pub f_query: (String, F),
@@ -102,6 +103,7 @@ impl<F: PrimeField> QuerySet<F> {
         // use the "virtual oracle" z_a * z_b
         Self {
             batch_size: state.batch_size,
+            has_lookups: state.has_lookups,
             g_1_query: ("beta".into(), beta),
             z_b_query: ("beta".into(), beta),
             f_query: ("beta".into(), beta),
@@ -129,19 +131,21 @@ impl<F: PrimeField> QuerySet<F> {
         for i in 0..self.batch_size {
             query_set.insert((witness_label("z_b", i), self.z_b_query.clone()));
         }
-        for i in 0..self.batch_size {
+        // The per-instance lookup polynomials are only queried if the prover sent them.
+        let num_lookup_instances = if self.has_lookups { self.batch_size } else { 0 };
+        for i in 0..num_lookup_instances {
             query_set.insert((witness_label("f", i), self.f_query.clone()));
         }
-        for i in 0..self.batch_size {
+        for i in 0..num_lookup_instances {
             query_set.insert((witness_label("s_1", i), self.s_1_query.clone()));
         }
-        for i in 0..self.batch_size {
+        for i in 0..num_lookup_instances {
             query_set.insert((witness_label("s_2", i), self.s_2_query.clone()));
         }
-        for i in 0..self.batch_size {
+        for i in 0..num_lookup_instances {
             query_set.insert((witness_label("z_2", i), self.z_2_query.clone()));
         }
-        for i in 0..self.batch_size {
+        for i in 0..num_lookup_instances {
             query_set.insert((witness_label("delta_omega_s_1", i), self.delta_s_1_omega_query.clone()));
         }
         query_set.insert(("g_1".into(), self.g_1_query.clone()));
@@ -29,6 +29,8 @@ use snarkvm_fields::PrimeField;
 #[derive(Debug)]
 pub struct State<F: PrimeField, MM: MarlinMode> {
     pub(in crate::snark::marlin) batch_size: usize,
+    /// Whether the prover sent the per-instance lookup oracles. If not, the lookup checks are skipped.
+    pub(in crate::snark::marlin) has_lookups: bool,
     pub(crate) input_domain: EvaluationDomain<F>,
     pub(crate) constraint_domain: EvaluationDomain<F>,
     pub(crate) non_zero_a_domain: EvaluationDomain<F>,
@@ -33,9 +33,11 @@ use snarkvm_fields::PrimeField;
 
 impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
     /// Output the first message and next round state.
+    /// If `has_lookups` is `false`, the lookup checks are skipped in the subsequent rounds.
     pub fn verifier_first_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
         index_info: CircuitInfo<TargetField>,
         batch_size: usize,
+        has_lookups: bool,
         fs_rng: &mut R,
     ) -> Result<(FirstMessage<TargetField>, State<TargetField, MM>), AHPError> {
         // Check that the R1CS is a square matrix.
@@ -80,6 +82,7 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
 
         let new_state = State {
             batch_size,
+            has_lookups,
             input_domain,
             constraint_domain,
             non_zero_a_domain,
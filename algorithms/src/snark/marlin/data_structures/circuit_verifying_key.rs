@@ -15,13 +15,19 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    crypto_hash::sha256::sha256,
     fft::EvaluationDomain,
     polycommit::sonic_pc,
-    snark::marlin::{ahp::indexer::*, CircuitProvingKey, MarlinMode, PreparedCircuitVerifyingKey},
+    snark::marlin::{
+        ahp::{indexer::*, AHPForR1CS},
+        CircuitProvingKey,
+        MarlinMode,
+        PreparedCircuitVerifyingKey,
+    },
     Prepare,
 };
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField, Zero};
 use snarkvm_r1cs::SynthesisError;
 use snarkvm_utilities::{
     error,
@@ -37,6 +43,7 @@ use snarkvm_utilities::{
 
 use anyhow::Result;
 use core::{fmt, marker::PhantomData, str::FromStr};
+use itertools::Itertools;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Verification key for a specific index (i.e., R1CS matrices).
@@ -170,6 +177,23 @@ impl<E: PairingEngine, MM: MarlinMode> CircuitVerifyingKey<E, MM> {
     pub fn iter(&self) -> impl Iterator<Item = &sonic_pc::Commitment<E>> {
         self.circuit_commitments.iter()
     }
+
+    /// Returns `true` if the circuit has lookup constraints.
+    /// The index commitments are not hiding, so the lookup selector is zero if and only if its commitment is zero.
+    pub fn has_lookups(&self) -> bool {
+        AHPForR1CS::<E::Fr, MM>::index_polynomial_info()
+            .keys()
+            .zip_eq(&self.circuit_commitments)
+            .any(|(label, commitment)| label == "s_l" && !commitment.0.is_zero())
+    }
+
+    /// Returns the ID of the verifying key, as the SHA-256 hash of its canonical (compressed) serialization.
+    /// This is a cheap fingerprint for comparing verifying keys and using them as cache keys.
+    pub fn id(&self) -> Result<[u8; 32]> {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut bytes)?;
+        Ok(sha256(&bytes))
+    }
 }
 
 impl<E: PairingEngine, MM: MarlinMode> ToConstraintField<E::Fq> for CircuitVerifyingKey<E, MM> {
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo},
+    snark::marlin::{ahp::prover, MarlinMode},
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+/// A job to commit to a single first round polynomial, which may be computed on a separate machine.
+///
+/// The polynomial is given in coefficient form, as the hiding randomizers in zero-knowledge mode
+/// raise its degree beyond the size of the constraint domain.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FirstRoundJob<F: PrimeField> {
+    /// The labeled polynomial to commit to.
+    polynomial: LabeledPolynomial<F>,
+}
+
+impl<F: PrimeField> FirstRoundJob<F> {
+    /// Initializes a new first round job.
+    pub(crate) fn new(polynomial: LabeledPolynomial<F>) -> Self {
+        Self { polynomial }
+    }
+
+    /// Returns the label of the polynomial.
+    pub fn label(&self) -> &str {
+        self.polynomial.label()
+    }
+
+    /// Returns the label, degree bound, and hiding bound of the polynomial.
+    pub fn polynomial_info(&self) -> &PolynomialInfo {
+        self.polynomial.info()
+    }
+
+    /// Returns the polynomial to commit to.
+    pub fn polynomial(&self) -> &LabeledPolynomial<F> {
+        &self.polynomial
+    }
+}
+
+impl<F: PrimeField> ToBytes for FirstRoundJob<F> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize first round job"))
+    }
+}
+
+impl<F: PrimeField> FromBytes for FirstRoundJob<F> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize first round job"))
+    }
+}
+
+/// The state of a batch proof that is paused after the first round of the prover,
+/// while its first round commitments are computed externally.
+pub struct FirstRoundSession<'a, E: PairingEngine, FS, MM: MarlinMode> {
+    /// The state of the prover after the first round.
+    pub(in crate::snark::marlin) prover_state: prover::State<'a, E::Fr, MM>,
+    /// The Fiat-Shamir sponge, before absorbing the first round commitments.
+    pub(in crate::snark::marlin) sponge: FS,
+    /// The polynomial info of the first round jobs, in order.
+    pub(in crate::snark::marlin) polynomial_info: Vec<PolynomialInfo>,
+    /// The type name of the circuit being proven.
+    pub(in crate::snark::marlin) circuit_name: &'static str,
+}
+
+impl<'a, E: PairingEngine, FS, MM: MarlinMode> FirstRoundSession<'a, E, FS, MM> {
+    /// Returns the polynomial info of the first round jobs, in the order the commitments are expected.
+    pub fn polynomial_info(&self) -> &[PolynomialInfo] {
+        &self.polynomial_info
+    }
+
+    /// Returns the type name of the circuit being proven.
+    pub fn circuit_name(&self) -> &'static str {
+        self.circuit_name
+    }
+}
@@ -26,6 +26,10 @@ pub use circuit_proving_key::*;
 pub(super) mod circuit_verifying_key;
 pub use circuit_verifying_key::*;
 
+/// The Marlin first round jobs, for committing to the first round polynomials externally.
+pub(super) mod first_round_job;
+pub use first_round_job::*;
+
 /// The Marlin prepared circuit verifying key.
 pub(super) mod prepared_circuit_verifying_key;
 pub use prepared_circuit_verifying_key::*;
@@ -37,3 +41,7 @@ pub use proof::*;
 /// The Marlin universal SRS.
 pub(super) mod universal_srs;
 pub use universal_srs::*;
+
+/// The outcome of verifying a Marlin proof.
+pub(super) mod verify_outcome;
+pub use verify_outcome::*;
@@ -17,15 +17,21 @@
 use crate::{polycommit::sonic_pc, snark::marlin::ahp, SNARKError};
 
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField};
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
     serialize::*,
     FromBytes,
+    FromBytesDeserializer,
+    ToBits,
     ToBytes,
+    ToBytesSerializer,
 };
 
+use anyhow::anyhow;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Commitments<E: PairingEngine> {
     pub witness_commitments: Vec<WitnessCommitments<E>>,
@@ -52,14 +58,48 @@ pub struct Commitments<E: PairingEngine> {
 }
 
 impl<E: PairingEngine> Commitments<E> {
+    /// Returns the group elements of all commitments, in the following order:
+    /// 1. the `w`, `z_a`, `z_b`, and `z_c` commitments of each instance,
+    /// 2. the `f`, `s_1`, `s_2`, `z_2`, `delta_s_1_omega`, and `z_2_omega` commitments of each instance,
+    /// 3. the `mask_poly` commitment (if it exists), followed by the `table`, `delta_table_omega`,
+    ///    `g_1`, `h_1`, `g_a`, `g_b`, `g_c`, and `h_2` commitments.
+    pub fn iter_group_elements(&self) -> impl Iterator<Item = &E::G1Affine> {
+        let witnesses = self.witness_commitments.iter().flat_map(|comm| [&comm.w, &comm.z_a, &comm.z_b, &comm.z_c]);
+        let lookups = self
+            .lookup_commitments
+            .iter()
+            .flat_map(|comm| [&comm.f, &comm.s_1, &comm.s_2, &comm.z_2, &comm.delta_s_1_omega, &comm.z_2_omega]);
+        let named = self.mask_poly.iter().chain([
+            &self.table,
+            &self.delta_table_omega,
+            &self.g_1,
+            &self.h_1,
+            &self.g_a,
+            &self.g_b,
+            &self.g_c,
+            &self.h_2,
+        ]);
+        witnesses.chain(lookups).chain(named).map(|comm| &comm.0)
+    }
+
+    /// Returns `true` if the commitments include the per-instance lookup commitments.
+    pub fn has_lookups(&self) -> bool {
+        !self.lookup_commitments.is_empty()
+    }
+
     fn serialize_with_mode<W: snarkvm_utilities::Write>(
         &self,
+        fixed_layout: bool,
         mut writer: W,
         compress: Compress,
     ) -> Result<(), snarkvm_utilities::SerializationError> {
         for comm in &self.witness_commitments {
             comm.serialize_with_mode(&mut writer, compress)?;
         }
+        // The fixed layout always includes the lookup commitments, and does not flag their presence.
+        if !fixed_layout {
+            CanonicalSerialize::serialize_with_mode(&self.has_lookups(), &mut writer, compress)?;
+        }
         for comm in &self.lookup_commitments {
             comm.serialize_with_mode(&mut writer, compress)?;
         }
@@ -77,10 +117,9 @@ impl<E: PairingEngine> Commitments<E> {
 
     fn serialized_size(&self, compress: Compress) -> usize {
         let mut size = 0;
-        size += self.witness_commitments.len()
-            * CanonicalSerialize::serialized_size(&self.witness_commitments[0], compress);
-        size +=
-            self.lookup_commitments.len() * CanonicalSerialize::serialized_size(&self.lookup_commitments[0], compress);
+        size += self.witness_commitments.iter().map(|comm| comm.serialized_size(compress)).sum::<usize>();
+        size += CanonicalSerialize::serialized_size(&self.has_lookups(), compress);
+        size += self.lookup_commitments.iter().map(|comm| comm.serialized_size(compress)).sum::<usize>();
         size += CanonicalSerialize::serialized_size(&self.mask_poly, compress);
         size += CanonicalSerialize::serialized_size(&self.table, compress);
         size += CanonicalSerialize::serialized_size(&self.delta_table_omega, compress);
@@ -95,6 +134,7 @@ impl<E: PairingEngine> Commitments<E> {
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_size: usize,
+        fixed_layout: bool,
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -104,8 +144,15 @@ impl<E: PairingEngine> Commitments<E> {
             witness_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
         let mut lookup_commitments = Vec::new();
-        for _ in 0..batch_size {
-            lookup_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        // The fixed layout always includes the lookup commitments, and does not flag their presence.
+        let has_lookups = match fixed_layout {
+            true => true,
+            false => CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if has_lookups {
+            for _ in 0..batch_size {
+                lookup_commitments.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+            }
         }
         Ok(Commitments {
             witness_commitments,
@@ -187,26 +234,15 @@ pub struct Evaluations<F: PrimeField> {
 impl<F: PrimeField> Evaluations<F> {
     fn serialize_with_mode<W: snarkvm_utilities::Write>(
         &self,
+        fixed_layout: bool,
         mut writer: W,
         compress: Compress,
     ) -> Result<(), snarkvm_utilities::SerializationError> {
         for z_b_eval in &self.z_b_evals {
             CanonicalSerialize::serialize_with_mode(z_b_eval, &mut writer, compress)?;
         }
-        for f_eval in &self.f_evals {
-            CanonicalSerialize::serialize_with_mode(f_eval, &mut writer, compress)?;
-        }
-        for s_1_eval in &self.s_1_evals {
-            CanonicalSerialize::serialize_with_mode(s_1_eval, &mut writer, compress)?;
-        }
-        for s_2_eval in &self.s_2_evals {
-            CanonicalSerialize::serialize_with_mode(s_2_eval, &mut writer, compress)?;
-        }
-        for z_2_eval in &self.z_2_evals {
-            CanonicalSerialize::serialize_with_mode(z_2_eval, &mut writer, compress)?;
-        }
-        for delta_s_1_omega_eval in &self.delta_s_1_omega_evals {
-            CanonicalSerialize::serialize_with_mode(delta_s_1_omega_eval, &mut writer, compress)?;
+        for lookup_evals in self.lookup_evals() {
+            serialize_optional_evals(lookup_evals, fixed_layout, &mut writer, compress)?;
         }
         CanonicalSerialize::serialize_with_mode(&self.s_m_eval, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.s_l_eval, &mut writer, compress)?;
@@ -222,11 +258,10 @@ impl<F: PrimeField> Evaluations<F> {
     fn serialized_size(&self, compress: Compress) -> usize {
         let mut size = 0;
         size += self.z_b_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
-        size += self.f_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
-        size += self.s_1_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
-        size += self.s_2_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
-        size += self.z_2_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
-        size += self.delta_s_1_omega_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
+        for lookup_evals in self.lookup_evals() {
+            size += CanonicalSerialize::serialized_size(&!lookup_evals.is_empty(), compress);
+            size += lookup_evals.iter().map(|s| s.serialized_size(compress)).sum::<usize>();
+        }
         size += CanonicalSerialize::serialized_size(&self.s_m_eval, compress);
         size += CanonicalSerialize::serialized_size(&self.s_l_eval, compress);
         size += CanonicalSerialize::serialized_size(&self.table_eval, compress);
@@ -240,6 +275,7 @@ impl<F: PrimeField> Evaluations<F> {
 
     fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_size: usize,
+        fixed_layout: bool,
         mut reader: R,
         compress: Compress,
         validate: Validate,
@@ -248,26 +284,12 @@ impl<F: PrimeField> Evaluations<F> {
         for _ in 0..batch_size {
             z_b_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
         }
-        let mut f_evals = Vec::with_capacity(batch_size);
-        for _ in 0..batch_size {
-            f_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
-        }
-        let mut s_1_evals = Vec::with_capacity(batch_size);
-        for _ in 0..batch_size {
-            s_1_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
-        }
-        let mut s_2_evals = Vec::with_capacity(batch_size);
-        for _ in 0..batch_size {
-            s_2_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
-        }
-        let mut z_2_evals = Vec::with_capacity(batch_size);
-        for _ in 0..batch_size {
-            z_2_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
-        }
-        let mut delta_s_1_omega_evals = Vec::with_capacity(batch_size);
-        for _ in 0..batch_size {
-            delta_s_1_omega_evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
-        }
+        let f_evals = deserialize_optional_evals(batch_size, fixed_layout, &mut reader, compress, validate)?;
+        let s_1_evals = deserialize_optional_evals(batch_size, fixed_layout, &mut reader, compress, validate)?;
+        let s_2_evals = deserialize_optional_evals(batch_size, fixed_layout, &mut reader, compress, validate)?;
+        let z_2_evals = deserialize_optional_evals(batch_size, fixed_layout, &mut reader, compress, validate)?;
+        let delta_s_1_omega_evals =
+            deserialize_optional_evals(batch_size, fixed_layout, &mut reader, compress, validate)?;
         Ok(Evaluations {
             z_b_evals,
             f_evals,
@@ -315,6 +337,12 @@ impl<F: PrimeField> Evaluations<F> {
         }
     }
 
+    /// Returns the per-instance evaluations of the lookup polynomials, i.e. those of `f`, `s_1`, `s_2`, `z_2`,
+    /// and `delta_s_1_omega`, in their serialization order.
+    pub(crate) fn lookup_evals(&self) -> [&Vec<F>; 5] {
+        [&self.f_evals, &self.s_1_evals, &self.s_2_evals, &self.z_2_evals, &self.delta_s_1_omega_evals]
+    }
+
     pub(crate) fn get(&self, label: &str) -> Option<F> {
         if label.starts_with("z_b_") {
             let index = label.strip_prefix("z_b_").expect("should be able to strip identified prefix");
@@ -350,6 +378,44 @@ impl<F: PrimeField> Evaluations<F> {
     }
 }
 
+/// Serializes the given per-instance evaluations, preceded by a flag indicating if they are present.
+/// The fixed layout always includes the evaluations, and does not flag their presence.
+fn serialize_optional_evals<F: PrimeField, W: snarkvm_utilities::Write>(
+    evals: &[F],
+    fixed_layout: bool,
+    mut writer: W,
+    compress: Compress,
+) -> Result<(), snarkvm_utilities::SerializationError> {
+    if !fixed_layout {
+        CanonicalSerialize::serialize_with_mode(&!evals.is_empty(), &mut writer, compress)?;
+    }
+    for eval in evals {
+        CanonicalSerialize::serialize_with_mode(eval, &mut writer, compress)?;
+    }
+    Ok(())
+}
+
+/// Deserializes per-instance evaluations, which are either absent or present for each of the `batch_size` instances.
+/// The fixed layout always includes the evaluations, and does not flag their presence.
+fn deserialize_optional_evals<F: PrimeField, R: snarkvm_utilities::Read>(
+    batch_size: usize,
+    fixed_layout: bool,
+    mut reader: R,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<F>, snarkvm_utilities::SerializationError> {
+    let is_present = match fixed_layout {
+        true => true,
+        false => CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+    };
+    let num_evals = if is_present { batch_size } else { 0 };
+    let mut evals = Vec::with_capacity(num_evals);
+    for _ in 0..num_evals {
+        evals.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+    }
+    Ok(evals)
+}
+
 impl<F: PrimeField> Valid for Evaluations<F> {
     fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
         self.z_b_evals.check()?;
@@ -391,6 +457,12 @@ impl<F: PrimeField> Evaluations<F> {
     }
 }
 
+/// The flag set in the serialized batch size of a proof, which marks the layout with optional lookup elements.
+/// Proofs serialized with the legacy fixed layout, in which the lookup elements are always present,
+/// do not set this flag. They are rejected by `CanonicalDeserialize`, and are only read and written
+/// by `Proof::deserialize_legacy_with_mode` and `Proof::serialize_legacy_with_mode`.
+const PROOF_LAYOUT_FLAG: u64 = 1 << 63;
+
 /// A zkSNARK proof.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Proof<E: PairingEngine> {
@@ -419,31 +491,168 @@ impl<E: PairingEngine> Proof<E> {
         msg: ahp::prover::FifthMessage<E::Fr>,
         pc_proof: sonic_pc::BatchLCProof<E>,
     ) -> Result<Self, SNARKError> {
+        Self::check_batch_size(batch_size, &commitments, &evaluations)?;
+        Ok(Self { batch_size, commitments, evaluations, msg, pc_proof })
+    }
+
+    pub fn batch_size(&self) -> Result<usize, SNARKError> {
+        Self::check_batch_size(self.batch_size, &self.commitments, &self.evaluations)?;
+        Ok(self.batch_size)
+    }
+
+    /// Returns `true` if the proof includes the per-instance lookup commitments.
+    pub fn has_lookups(&self) -> bool {
+        self.commitments.has_lookups()
+    }
+
+    /// Ensures there are `batch_size` witness commitments and `z_b` evaluations,
+    /// and that the lookup commitments and evaluations are either all absent, or all present for every instance.
+    fn check_batch_size(
+        batch_size: usize,
+        commitments: &Commitments<E>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(), SNARKError> {
         if commitments.witness_commitments.len() != batch_size {
             return Err(SNARKError::BatchSizeMismatch);
         }
         if evaluations.z_b_evals.len() != batch_size {
             return Err(SNARKError::BatchSizeMismatch);
         }
-        Ok(Self { batch_size, commitments, evaluations, msg, pc_proof })
-    }
-
-    pub fn batch_size(&self) -> Result<usize, SNARKError> {
-        if self.commitments.witness_commitments.len() != self.batch_size {
+        let num_lookup_instances = if commitments.has_lookups() { batch_size } else { 0 };
+        if commitments.lookup_commitments.len() != num_lookup_instances {
             return Err(SNARKError::BatchSizeMismatch);
         }
-        if self.evaluations.z_b_evals.len() != self.batch_size {
+        if evaluations.lookup_evals().iter().any(|evals| evals.len() != num_lookup_instances) {
             return Err(SNARKError::BatchSizeMismatch);
         }
-        Ok(self.batch_size)
+        Ok(())
+    }
+
+    /// Returns the prover message, i.e. `sum_a`, `sum_b`, and `sum_c`.
+    pub const fn fifth_message(&self) -> &ahp::prover::FifthMessage<E::Fr> {
+        &self.msg
+    }
+
+    /// Returns the proof serialized in compressed form, which is smaller to store or transmit.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>, SerializationError> {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_with_mode(&mut bytes, Compress::Yes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the proof serialized in uncompressed form, which is faster to deserialize.
+    pub fn to_uncompressed_bytes(&self) -> Result<Vec<u8>, SerializationError> {
+        let mut bytes = Vec::with_capacity(self.uncompressed_size());
+        self.serialize_with_mode(&mut bytes, Compress::No)?;
+        Ok(bytes)
+    }
+
+    /// Deserializes a proof from its compressed form.
+    pub fn from_compressed(bytes: &[u8]) -> Result<Self, SerializationError> {
+        Self::deserialize_with_mode(bytes, Compress::Yes, Validate::Yes)
+    }
+
+    /// Deserializes a proof from its uncompressed form.
+    pub fn from_uncompressed(bytes: &[u8]) -> Result<Self, SerializationError> {
+        Self::deserialize_with_mode(bytes, Compress::No, Validate::Yes)
+    }
+
+    /// Serializes the proof with the legacy fixed layout, which does not set the layout flag.
+    /// This re-emits the original encoding of proofs read with `Proof::deserialize_legacy_with_mode`.
+    pub fn serialize_legacy_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        if !self.has_lookups() {
+            return Err(anyhow!("Proof without lookup elements cannot use the legacy fixed layout").into());
+        }
+        let batch_size = u64::try_from(self.batch_size).map_err(|_| SerializationError::InvalidData)?;
+        if batch_size & PROOF_LAYOUT_FLAG != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+        CanonicalSerialize::serialize_with_mode(&batch_size, &mut writer, compress)?;
+        Commitments::serialize_with_mode(&self.commitments, true, &mut writer, compress)?;
+        Evaluations::serialize_with_mode(&self.evaluations, true, &mut writer, compress)?;
+        CanonicalSerialize::serialize_with_mode(&self.msg, &mut writer, compress)?;
+        CanonicalSerialize::serialize_with_mode(&self.pc_proof, &mut writer, compress)?;
+        Ok(())
+    }
+
+    /// Deserializes a proof with the legacy fixed layout, in which the lookup elements are always present,
+    /// such as the proofs in the genesis block.
+    pub fn deserialize_legacy_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let batch_size: u64 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        if batch_size & PROOF_LAYOUT_FLAG != 0 {
+            return Err(anyhow!("Proof sets the layout flag, and is not in the legacy fixed layout").into());
+        }
+        Self::deserialize_with_layout(batch_size, true, reader, compress, validate)
+    }
+
+    /// Deserializes the rest of a proof with the given batch size and layout.
+    fn deserialize_with_layout<R: Read>(
+        batch_size: u64,
+        fixed_layout: bool,
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let batch_size = usize::try_from(batch_size).map_err(|_| SerializationError::InvalidData)?;
+        let commitments =
+            Commitments::deserialize_with_mode(batch_size, fixed_layout, &mut reader, compress, validate)?;
+        let evaluations =
+            Evaluations::deserialize_with_mode(batch_size, fixed_layout, &mut reader, compress, validate)?;
+        let msg = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let pc_proof = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        Proof::new(batch_size, commitments, evaluations, msg, pc_proof).map_err(|_| SerializationError::InvalidData)
+    }
+
+    /// Reads a proof serialized with the given `compress` mode, and returns it serialized in the other mode.
+    pub fn recompress(bytes: &[u8], compress: Compress) -> Result<Vec<u8>, SerializationError> {
+        match compress {
+            Compress::Yes => Self::from_compressed(bytes)?.to_uncompressed_bytes(),
+            Compress::No => Self::from_uncompressed(bytes)?.to_compressed_bytes(),
+        }
+    }
+}
+
+impl<E: PairingEngine> ToConstraintField<E::Fq> for Proof<E> {
+    /// Returns the proof as base field elements, in the following order:
+    /// 1. the batch size,
+    /// 2. the coordinates of each commitment, in the order of `Commitments::iter_group_elements`,
+    /// 3. the evaluations, in the order of `Evaluations::to_field_elements`,
+    /// 4. the prover message, i.e. `sum_a`, `sum_b`, and `sum_c`.
+    ///
+    /// Each scalar field element is embedded into the base field by its bits.
+    /// The evaluation proof from the polynomial commitment is not included.
+    fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
+        let mut res = E::Fq::from(self.batch_size as u128).to_field_elements()?;
+        for commitment in self.commitments.iter_group_elements() {
+            res.append(&mut commitment.to_field_elements()?);
+        }
+        let message = [self.msg.sum_a, self.msg.sum_b, self.msg.sum_c];
+        let scalars = self.evaluations.to_field_elements().into_iter().chain(message);
+        for scalar in scalars {
+            let mut elements: Vec<E::Fq> = scalar.to_bigint().to_bits_le().as_slice().to_field_elements()?;
+            res.append(&mut elements);
+        }
+        Ok(res)
     }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
-        CanonicalSerialize::serialize_with_mode(&self.batch_size, &mut writer, compress)?;
-        Commitments::serialize_with_mode(&self.commitments, &mut writer, compress)?;
-        Evaluations::serialize_with_mode(&self.evaluations, &mut writer, compress)?;
+        let batch_size = u64::try_from(self.batch_size).map_err(|_| SerializationError::InvalidData)?;
+        if batch_size & PROOF_LAYOUT_FLAG != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+        CanonicalSerialize::serialize_with_mode(&(batch_size | PROOF_LAYOUT_FLAG), &mut writer, compress)?;
+        Commitments::serialize_with_mode(&self.commitments, false, &mut writer, compress)?;
+        Evaluations::serialize_with_mode(&self.evaluations, false, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.msg, &mut writer, compress)?;
         CanonicalSerialize::serialize_with_mode(&self.pc_proof, &mut writer, compress)?;
         Ok(())
@@ -451,7 +660,7 @@ impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
 
     fn serialized_size(&self, mode: Compress) -> usize {
         let mut size = 0;
-        size += CanonicalSerialize::serialized_size(&self.batch_size, mode);
+        size += CanonicalSerialize::serialized_size(&PROOF_LAYOUT_FLAG, mode);
         size += Commitments::serialized_size(&self.commitments, mode);
         size += Evaluations::serialized_size(&self.evaluations, mode);
         size += CanonicalSerialize::serialized_size(&self.msg, mode);
@@ -476,14 +685,15 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError> {
-        let batch_size = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
-        Ok(Proof {
-            batch_size,
-            commitments: Commitments::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            evaluations: Evaluations::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            msg: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-            pc_proof: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
-        })
+        let batch_size: u64 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        if batch_size & PROOF_LAYOUT_FLAG == 0 {
+            return Err(anyhow!(
+                "Proof does not set the layout flag; proofs in the legacy fixed layout must be read with \
+                 `Proof::deserialize_legacy_with_mode`"
+            )
+            .into());
+        }
+        Self::deserialize_with_layout(batch_size & !PROOF_LAYOUT_FLAG, false, reader, compress, validate)
     }
 }
 
@@ -498,3 +708,251 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
         Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize Proof"))
     }
 }
+
+impl<E: PairingEngine> Serialize for Proof<E> {
+    /// Serializes the proof into structured JSON with hex-encoded elements, or as canonical bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => ProofJson::from(self).serialize(serializer),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, E: PairingEngine> Deserialize<'de> for Proof<E> {
+    /// Deserializes the proof from structured JSON with hex-encoded elements, or from canonical bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => ProofJson::deserialize(deserializer)?.try_into().map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proof"),
+        }
+    }
+}
+
+/// A value that is (de)serialized as the hex encoding of its compressed canonical bytes.
+struct CanonicalHex<T>(T);
+
+impl<T: CanonicalSerialize> Serialize for CanonicalHex<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::with_capacity(self.0.compressed_size());
+        self.0.serialize_compressed(&mut bytes).map_err(ser::Error::custom)?;
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+}
+
+impl<'de, T: CanonicalDeserialize> Deserialize<'de> for CanonicalHex<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = hex::decode(String::deserialize(deserializer)?).map_err(de::Error::custom)?;
+        Ok(Self(T::deserialize_compressed(&*bytes).map_err(de::Error::custom)?))
+    }
+}
+
+/// The human-readable form of a `Proof`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct ProofJson<E: PairingEngine> {
+    batch_size: usize,
+    commitments: CommitmentsJson<E>,
+    evaluations: EvaluationsJson<E::Fr>,
+    msg: FifthMessageJson<E::Fr>,
+    pc_proof: CanonicalHex<sonic_pc::BatchLCProof<E>>,
+}
+
+/// The human-readable form of `Commitments`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct CommitmentsJson<E: PairingEngine> {
+    witness_commitments: Vec<WitnessCommitmentsJson<E>>,
+    mask_poly: Option<CanonicalHex<sonic_pc::Commitment<E>>>,
+    lookup_commitments: Vec<LookupCommitmentsJson<E>>,
+    table: CanonicalHex<sonic_pc::Commitment<E>>,
+    delta_table_omega: CanonicalHex<sonic_pc::Commitment<E>>,
+    g_1: CanonicalHex<sonic_pc::Commitment<E>>,
+    h_1: CanonicalHex<sonic_pc::Commitment<E>>,
+    g_a: CanonicalHex<sonic_pc::Commitment<E>>,
+    g_b: CanonicalHex<sonic_pc::Commitment<E>>,
+    g_c: CanonicalHex<sonic_pc::Commitment<E>>,
+    h_2: CanonicalHex<sonic_pc::Commitment<E>>,
+}
+
+/// The human-readable form of `WitnessCommitments`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct WitnessCommitmentsJson<E: PairingEngine> {
+    w: CanonicalHex<sonic_pc::Commitment<E>>,
+    z_a: CanonicalHex<sonic_pc::Commitment<E>>,
+    z_b: CanonicalHex<sonic_pc::Commitment<E>>,
+    z_c: CanonicalHex<sonic_pc::Commitment<E>>,
+}
+
+/// The human-readable form of `LookupCommitments`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct LookupCommitmentsJson<E: PairingEngine> {
+    f: CanonicalHex<sonic_pc::Commitment<E>>,
+    s_1: CanonicalHex<sonic_pc::Commitment<E>>,
+    s_2: CanonicalHex<sonic_pc::Commitment<E>>,
+    z_2: CanonicalHex<sonic_pc::Commitment<E>>,
+    delta_s_1_omega: CanonicalHex<sonic_pc::Commitment<E>>,
+    z_2_omega: CanonicalHex<sonic_pc::Commitment<E>>,
+}
+
+/// The human-readable form of `Evaluations`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct EvaluationsJson<F: PrimeField> {
+    z_b_evals: Vec<CanonicalHex<F>>,
+    f_evals: Vec<CanonicalHex<F>>,
+    s_1_evals: Vec<CanonicalHex<F>>,
+    s_2_evals: Vec<CanonicalHex<F>>,
+    z_2_evals: Vec<CanonicalHex<F>>,
+    delta_s_1_omega_evals: Vec<CanonicalHex<F>>,
+    s_m_eval: CanonicalHex<F>,
+    s_l_eval: CanonicalHex<F>,
+    table_eval: CanonicalHex<F>,
+    delta_table_omega_eval: CanonicalHex<F>,
+    g_1_eval: CanonicalHex<F>,
+    g_a_eval: CanonicalHex<F>,
+    g_b_eval: CanonicalHex<F>,
+    g_c_eval: CanonicalHex<F>,
+}
+
+/// The human-readable form of the prover's `FifthMessage`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct FifthMessageJson<F: PrimeField> {
+    sum_a: CanonicalHex<F>,
+    sum_b: CanonicalHex<F>,
+    sum_c: CanonicalHex<F>,
+}
+
+/// Returns the hex wrappers of the given values.
+fn to_hex_vec<T: Clone>(values: &[T]) -> Vec<CanonicalHex<T>> {
+    values.iter().cloned().map(CanonicalHex).collect()
+}
+
+/// Returns the values of the given hex wrappers.
+fn from_hex_vec<T>(values: Vec<CanonicalHex<T>>) -> Vec<T> {
+    values.into_iter().map(|value| value.0).collect()
+}
+
+impl<E: PairingEngine> From<&Proof<E>> for ProofJson<E> {
+    fn from(proof: &Proof<E>) -> Self {
+        let commitments = &proof.commitments;
+        let evaluations = &proof.evaluations;
+        Self {
+            batch_size: proof.batch_size,
+            commitments: CommitmentsJson {
+                witness_commitments: commitments
+                    .witness_commitments
+                    .iter()
+                    .map(|comm| WitnessCommitmentsJson {
+                        w: CanonicalHex(comm.w),
+                        z_a: CanonicalHex(comm.z_a),
+                        z_b: CanonicalHex(comm.z_b),
+                        z_c: CanonicalHex(comm.z_c),
+                    })
+                    .collect(),
+                mask_poly: commitments.mask_poly.map(CanonicalHex),
+                lookup_commitments: commitments
+                    .lookup_commitments
+                    .iter()
+                    .map(|comm| LookupCommitmentsJson {
+                        f: CanonicalHex(comm.f),
+                        s_1: CanonicalHex(comm.s_1),
+                        s_2: CanonicalHex(comm.s_2),
+                        z_2: CanonicalHex(comm.z_2),
+                        delta_s_1_omega: CanonicalHex(comm.delta_s_1_omega),
+                        z_2_omega: CanonicalHex(comm.z_2_omega),
+                    })
+                    .collect(),
+                table: CanonicalHex(commitments.table),
+                delta_table_omega: CanonicalHex(commitments.delta_table_omega),
+                g_1: CanonicalHex(commitments.g_1),
+                h_1: CanonicalHex(commitments.h_1),
+                g_a: CanonicalHex(commitments.g_a),
+                g_b: CanonicalHex(commitments.g_b),
+                g_c: CanonicalHex(commitments.g_c),
+                h_2: CanonicalHex(commitments.h_2),
+            },
+            evaluations: EvaluationsJson {
+                z_b_evals: to_hex_vec(&evaluations.z_b_evals),
+                f_evals: to_hex_vec(&evaluations.f_evals),
+                s_1_evals: to_hex_vec(&evaluations.s_1_evals),
+                s_2_evals: to_hex_vec(&evaluations.s_2_evals),
+                z_2_evals: to_hex_vec(&evaluations.z_2_evals),
+                delta_s_1_omega_evals: to_hex_vec(&evaluations.delta_s_1_omega_evals),
+                s_m_eval: CanonicalHex(evaluations.s_m_eval),
+                s_l_eval: CanonicalHex(evaluations.s_l_eval),
+                table_eval: CanonicalHex(evaluations.table_eval),
+                delta_table_omega_eval: CanonicalHex(evaluations.delta_table_omega_eval),
+                g_1_eval: CanonicalHex(evaluations.g_1_eval),
+                g_a_eval: CanonicalHex(evaluations.g_a_eval),
+                g_b_eval: CanonicalHex(evaluations.g_b_eval),
+                g_c_eval: CanonicalHex(evaluations.g_c_eval),
+            },
+            msg: FifthMessageJson {
+                sum_a: CanonicalHex(proof.msg.sum_a),
+                sum_b: CanonicalHex(proof.msg.sum_b),
+                sum_c: CanonicalHex(proof.msg.sum_c),
+            },
+            pc_proof: CanonicalHex(proof.pc_proof.clone()),
+        }
+    }
+}
+
+impl<E: PairingEngine> TryFrom<ProofJson<E>> for Proof<E> {
+    type Error = SNARKError;
+
+    fn try_from(proof: ProofJson<E>) -> Result<Self, Self::Error> {
+        let ProofJson { batch_size, commitments, evaluations, msg, pc_proof } = proof;
+
+        let commitments = Commitments {
+            witness_commitments: commitments
+                .witness_commitments
+                .into_iter()
+                .map(|comm| WitnessCommitments { w: comm.w.0, z_a: comm.z_a.0, z_b: comm.z_b.0, z_c: comm.z_c.0 })
+                .collect(),
+            mask_poly: commitments.mask_poly.map(|comm| comm.0),
+            lookup_commitments: commitments
+                .lookup_commitments
+                .into_iter()
+                .map(|comm| LookupCommitments {
+                    f: comm.f.0,
+                    s_1: comm.s_1.0,
+                    s_2: comm.s_2.0,
+                    z_2: comm.z_2.0,
+                    delta_s_1_omega: comm.delta_s_1_omega.0,
+                    z_2_omega: comm.z_2_omega.0,
+                })
+                .collect(),
+            table: commitments.table.0,
+            delta_table_omega: commitments.delta_table_omega.0,
+            g_1: commitments.g_1.0,
+            h_1: commitments.h_1.0,
+            g_a: commitments.g_a.0,
+            g_b: commitments.g_b.0,
+            g_c: commitments.g_c.0,
+            h_2: commitments.h_2.0,
+        };
+        let evaluations = Evaluations {
+            z_b_evals: from_hex_vec(evaluations.z_b_evals),
+            f_evals: from_hex_vec(evaluations.f_evals),
+            s_1_evals: from_hex_vec(evaluations.s_1_evals),
+            s_2_evals: from_hex_vec(evaluations.s_2_evals),
+            z_2_evals: from_hex_vec(evaluations.z_2_evals),
+            delta_s_1_omega_evals: from_hex_vec(evaluations.delta_s_1_omega_evals),
+            s_m_eval: evaluations.s_m_eval.0,
+            s_l_eval: evaluations.s_l_eval.0,
+            table_eval: evaluations.table_eval.0,
+            delta_table_omega_eval: evaluations.delta_table_omega_eval.0,
+            g_1_eval: evaluations.g_1_eval.0,
+            g_a_eval: evaluations.g_a_eval.0,
+            g_b_eval: evaluations.g_b_eval.0,
+            g_c_eval: evaluations.g_c_eval.0,
+        };
+        let msg = ahp::prover::FifthMessage { sum_a: msg.sum_a.0, sum_b: msg.sum_b.0, sum_c: msg.sum_c.0 };
+
+        Proof::new(batch_size, commitments, evaluations, msg, pc_proof.0)
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt;
+
+/// The outcome of verifying a Marlin proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerifyOutcome {
+    /// The proof is valid.
+    Valid,
+    /// The proof does not match the zero-knowledge mode of the verifier,
+    /// i.e. the mask polynomial commitment or the hiding of the evaluation proof is unexpected.
+    IncorrectZkMode,
+    /// A commitment in the proof is not a point on the curve.
+    InvalidCommitment,
+    /// The proof does not include the lookup commitments, which the verifier requires
+    /// as the circuit has lookup constraints.
+    MissingLookups,
+    /// The proof is missing the evaluation of a polynomial queried by the verifier.
+    MissingEvaluation,
+    /// The proof includes evaluations that were not queried by the verifier,
+    /// i.e. more evaluations of a polynomial than there are instances in the batch.
+    EvaluationMismatch,
+    /// The sums claimed by the prover for the matrix sumchecks are degenerate, i.e. one of them is zero.
+    SumcheckFailed,
+    /// The pairing check of the polynomial commitment failed.
+    /// As the AHP sumchecks are enforced through the linear combinations opened by the polynomial commitment,
+    /// and the evaluations are bound into the Fiat-Shamir transcript, this includes tampered commitments,
+    /// tampered evaluation values, and tampered prover messages.
+    PairingFailed,
+}
+
+impl VerifyOutcome {
+    /// Returns `true` if the proof is valid.
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
+
+impl fmt::Display for VerifyOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Valid => write!(f, "The proof is valid"),
+            Self::IncorrectZkMode => write!(f, "The proof has an incorrect zero-knowledge mode"),
+            Self::InvalidCommitment => write!(f, "The proof contains a commitment that is not on the curve"),
+            Self::MissingLookups => write!(f, "The proof is missing the lookup commitments"),
+            Self::MissingEvaluation => write!(f, "The proof is missing an evaluation queried by the verifier"),
+            Self::EvaluationMismatch => write!(f, "The proof includes an evaluation not queried by the verifier"),
+            Self::SumcheckFailed => write!(f, "The proof claims a zero sum for a matrix sumcheck"),
+            Self::PairingFailed => write!(f, "The pairing check of the polynomial commitment failed"),
+        }
+    }
+}
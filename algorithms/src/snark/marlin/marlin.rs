@@ -16,18 +16,32 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{
+        Commitment,
+        Evaluations,
+        LabeledCommitment,
+        LabeledPolynomialWithBasis,
+        QuerySet,
+        Randomness,
+        SonicKZG10,
+    },
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+        metrics,
         proof,
         prover,
         witness_label,
         CircuitProvingKey,
         CircuitVerifyingKey,
+        FirstRoundJob,
+        FirstRoundSession,
         MarlinError,
         MarlinMode,
+        PreparedCircuitVerifyingKey,
         Proof,
+        ProverStage,
         UniversalSRS,
+        VerifyOutcome,
     },
     AlgebraicSponge,
     Prepare,
@@ -37,7 +51,7 @@ use crate::{
 };
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use snarkvm_curves::PairingEngine;
+use snarkvm_curves::{AffineCurve, PairingEngine};
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_r1cs::ConstraintSynthesizer;
 use snarkvm_utilities::{to_bytes_le, ToBytes};
@@ -65,6 +79,22 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
     /// Used to personalize the Fiat-Shamir RNG.
     pub const PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019";
 
+    /// Samples a universal SRS supporting circuits up to `max_degree`,
+    /// deriving all of the SRS randomness deterministically from `seed`.
+    ///
+    /// # Warning
+    ///
+    /// This method should be used *only* for reproducible tests and benchmarks, and not in production,
+    /// as anyone who knows the seed knows the SRS trapdoor and can forge proofs.
+    /// In production, one should instead use [`Self::universal_setup`].
+    pub fn universal_setup_from_seed(max_degree: usize, seed: [u8; 32]) -> Result<UniversalSRS<E>, SNARKError> {
+        let setup_time = start_timer!(|| format!("Marlin::UniversalSetupFromSeed with max_degree {max_degree}"));
+
+        let srs = SonicKZG10::<E, FS>::setup_from_seed(max_degree, seed).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
     /// Generate the index-specific (i.e., circuit-specific) prover and verifier
     /// keys. This is a trusted setup.
     ///
@@ -142,6 +172,19 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
         if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
     }
 
+    /// Ensures the committer key and the verifier key were trimmed from the same universal parameters.
+    fn check_key_compatibility(
+        committer_key: &crate::polycommit::sonic_pc::CommitterKey<E>,
+        verifier_key: &crate::polycommit::sonic_pc::VerifierKey<E>,
+    ) -> Result<(), SNARKError> {
+        let committer_metadata = committer_key.trim_metadata();
+        let verifier_metadata = verifier_key.trim_metadata();
+        match committer_metadata.is_compatible_with(&verifier_metadata) {
+            true => Ok(()),
+            false => Err(SNARKError::IncompatibleKeys(Box::new(committer_metadata), Box::new(verifier_metadata))),
+        }
+    }
+
     fn init_sponge(
         fs_parameters: &FS::Parameters,
         batch_size: usize,
@@ -196,157 +239,197 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
     }
 }
 
-impl<E: PairingEngine, FS, MM> SNARK for MarlinSNARK<E, FS, MM>
+impl<E: PairingEngine, FS, MM> MarlinSNARK<E, FS, MM>
 where
     E::Fr: PrimeField,
     E::Fq: PrimeField,
     FS: AlgebraicSponge<E::Fq, 2>,
     MM: MarlinMode,
 {
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalSetupConfig = usize;
-    type UniversalSetupParameters = UniversalSRS<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E, MM>;
-
-    fn universal_setup(max_degree: &Self::UniversalSetupConfig) -> Result<Self::UniversalSetupParameters, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {max_degree}",) });
-
-        let srs = SonicKZG10::<E, FS>::load_srs(*max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
-
-    fn setup<C: ConstraintSynthesizer<E::Fr>>(
+    /// Generates a proof, invoking `progress` as the prover completes each round of the AHP.
+    /// The callback only observes the prover, and does not affect the resulting proof.
+    pub fn prove_with_progress<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
         circuit: &C,
-        srs: &mut SRS<Self::UniversalSetupParameters>,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        match srs {
-            SRS::CircuitSpecific => Self::circuit_specific_setup(circuit),
-            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
-        }
-        .map_err(SNARKError::from)
+        progress: &dyn Fn(ProverStage),
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        Self::prove_batch_with_progress(
+            fs_parameters,
+            circuit_proving_key,
+            std::slice::from_ref(circuit),
+            &AtomicBool::new(false),
+            Some(progress),
+            zk_rng,
+        )
     }
 
-    fn prove_vk(
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate, SNARKError> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+    /// Generates a batch proof, invoking `progress` (if provided) as the prover completes each round of the AHP.
+    /// The callback only observes the prover, and does not affect the resulting proof.
+    #[allow(clippy::only_used_in_recursion)]
+    pub fn prove_batch_with_progress<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        progress: Option<&dyn Fn(ProverStage)>,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        let prover_start = std::time::Instant::now();
+        let prover_time = start_timer!(|| "Marlin::Prover");
 
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
-            lc.add(c, poly.label());
-        }
+        let mut session = Self::prover_first_round(fs_parameters, circuit_proving_key, circuits, terminator, zk_rng)?;
+        #[cfg(debug_assertions)]
+        let public_input = session.prover_state.public_inputs();
 
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
+        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
+        let labels = (session.circuit_name, session.prover_state.batch_size);
+        let (first_commitments, first_commitment_randomnesses) =
+            prover_time!(metrics::COMMIT_SECONDS, labels, "first", {
+                let first_round_oracles =
+                    Arc::get_mut(session.prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
+                SonicKZG10::<E, FS>::commit(
+                    &circuit_proving_key.committer_key,
+                    first_round_oracles.iter_for_commit(),
+                    Some(zk_rng),
+                )?
+            });
+        end_timer!(first_round_comm_time);
 
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
-            &proving_key.committer_key,
-            &[lc],
-            proving_key.circuit.iter(),
-            &commitments,
-            &query_set,
-            &proving_key.circuit_commitment_randomness.clone(),
-            &mut sponge,
+        let proof = Self::prover_remaining_rounds(
+            circuit_proving_key,
+            session,
+            first_commitments,
+            first_commitment_randomnesses,
+            terminator,
+            progress,
+            zk_rng,
         )?;
+        let elapsed_time = prover_start.elapsed();
+        println!("Proving took took {} milliseconds.", elapsed_time.as_millis());
 
-        Ok(Self::Certificate::new(certificate))
+        #[cfg(debug_assertions)]
+        if !Self::verify_batch(fs_parameters, &circuit_proving_key.circuit_verifying_key, &public_input, &proof)? {
+            println!("Invalid proof")
+        }
+        end_timer!(prover_time);
+
+        Ok(proof)
     }
 
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool, SNARKError> {
-        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
+    /// Runs the first round of the prover, and returns the paused session along with a job for each
+    /// first round polynomial (i.e. `w`, `z_a`, `z_b`, and `z_c` for each circuit, and the mask polynomial).
+    ///
+    /// The jobs may be committed to on separate machines with `commit_first_round_job`,
+    /// and the resulting commitments passed back, in order, to `resume_with_commitments`.
+    pub fn prepare_first_round_jobs<'a, C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        circuit_proving_key: &'a CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        zk_rng: &mut R,
+    ) -> Result<(FirstRoundSession<'a, E, FS, MM>, Vec<FirstRoundJob<E::Fr>>), SNARKError> {
+        let session =
+            Self::prover_first_round(fs_parameters, circuit_proving_key, circuits, &AtomicBool::new(false), zk_rng)?;
+        let first_round_oracles = session.prover_state.first_round_oracles.as_ref().unwrap();
+        let jobs = first_round_oracles.iter_for_open().cloned().map(FirstRoundJob::new).collect();
+        Ok((session, jobs))
+    }
 
-        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, point)?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+    /// Commits to the polynomial of the given first round job.
+    pub fn commit_first_round_job<R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        job: &FirstRoundJob<E::Fr>,
+        zk_rng: &mut R,
+    ) -> Result<(LabeledCommitment<Commitment<E>>, Randomness<E>), SNARKError> {
+        let (mut commitments, mut randomnesses) = SonicKZG10::<E, FS>::commit(
+            &circuit_proving_key.committer_key,
+            [LabeledPolynomialWithBasis::from(job.polynomial())],
+            Some(zk_rng),
+        )?;
+        match (commitments.pop(), randomnesses.pop()) {
+            (Some(commitment), Some(randomness)) => Ok((commitment, randomness)),
+            _ => Err(SNARKError::Message(format!("Failed to commit to first round job '{}'", job.label()))),
+        }
+    }
 
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        let mut evaluation = E::Fr::zero();
-        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
-            lc.add(c, label.as_str());
-            evaluation += c * eval;
+    /// Resumes a paused session with the externally computed first round commitments, and generates the proof.
+    ///
+    /// The commitments must be given in the same order as the jobs from `prepare_first_round_jobs`,
+    /// and each must match the label, degree bound, and hiding bound of its job.
+    pub fn resume_with_commitments<R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        session: FirstRoundSession<'_, E, FS, MM>,
+        commitments: Vec<(LabeledCommitment<Commitment<E>>, Randomness<E>)>,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        // Ensure there is a commitment for each job.
+        let expected_num_commitments = session.polynomial_info().len();
+        if commitments.len() != expected_num_commitments {
+            return Err(SNARKError::Message(format!(
+                "Expected {expected_num_commitments} first round commitments, found {}",
+                commitments.len()
+            )));
         }
 
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(info.values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+        // Ensure each commitment matches the polynomial info of its job.
+        for ((commitment, randomness), info) in commitments.iter().zip_eq(session.polynomial_info()) {
+            let label = info.label();
+            if commitment.label() != label {
+                return Err(SNARKError::Message(format!(
+                    "Expected a commitment to '{label}', found a commitment to '{}'",
+                    commitment.label()
+                )));
+            }
+            if commitment.degree_bound() != info.degree_bound() {
+                return Err(SNARKError::Message(format!("The commitment to '{label}' has an invalid degree bound")));
+            }
+            let is_valid_randomness = match info.hiding_bound() {
+                Some(hiding_bound) => randomness.is_hiding() && randomness.blinding_polynomial.degree() <= hiding_bound,
+                None => !randomness.is_hiding(),
+            };
+            if !is_valid_randomness {
+                return Err(SNARKError::Message(format!("The commitment to '{label}' has invalid randomness")));
+            }
+        }
 
-        SonicKZG10::<E, FS>::check_combinations(
-            &verifying_key.verifier_key,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
+        let (first_commitments, first_commitment_randomnesses) = commitments.into_iter().unzip();
+        Self::prover_remaining_rounds(
+            circuit_proving_key,
+            session,
+            first_commitments,
+            first_commitment_randomnesses,
+            &AtomicBool::new(false),
+            None,
+            zk_rng,
         )
-        .map_err(Into::into)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        fs_parameters: &Self::FSParameters,
-        circuit_proving_key: &CircuitProvingKey<E, MM>,
+    /// Initializes the prover, and runs the first round of the prover.
+    fn prover_first_round<'a, C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        circuit_proving_key: &'a CircuitProvingKey<E, MM>,
         circuits: &[C],
         terminator: &AtomicBool,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
-        let prover_start = std::time::Instant::now();
-        let prover_time = start_timer!(|| "Marlin::Prover");
+    ) -> Result<FirstRoundSession<'a, E, FS, MM>, SNARKError> {
         let batch_size = circuits.len();
         if batch_size == 0 {
             return Err(SNARKError::EmptyBatch);
         }
 
+        Self::check_key_compatibility(
+            &circuit_proving_key.committer_key,
+            &circuit_proving_key.circuit_verifying_key.verifier_key,
+        )?;
+
         Self::terminate(terminator)?;
 
         let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
-        let public_input = prover_state.public_inputs();
         let padded_public_input = prover_state.padded_public_inputs();
         assert_eq!(prover_state.batch_size, batch_size);
 
-        let mut sponge = Self::init_sponge(
+        let sponge = Self::init_sponge(
             fs_parameters,
             batch_size,
             &circuit_proving_key.circuit_verifying_key.circuit_commitments,
@@ -357,19 +440,45 @@ where
         // First round
 
         Self::terminate(terminator)?;
-        let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+        let circuit_name = std::any::type_name::<C>();
+        let labels = (circuit_name, batch_size);
+        let prover_state = prover_time!(
+            metrics::AHP_ROUND_SECONDS,
+            labels,
+            "first",
+            AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?
+        );
         Self::terminate(terminator)?;
 
-        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = {
-            let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
-            SonicKZG10::<E, FS>::commit(
-                &circuit_proving_key.committer_key,
-                first_round_oracles.iter_for_commit(),
-                Some(zk_rng),
-            )?
+        let first_round_oracles = prover_state.first_round_oracles.as_ref().unwrap();
+        prover_polynomials!(labels, "first", first_round_oracles.iter_for_open());
+        let polynomial_info = first_round_oracles.iter_for_open().map(|p| p.info().clone()).collect();
+
+        Ok(FirstRoundSession { prover_state, sponge, polynomial_info, circuit_name })
+    }
+
+    /// Absorbs the first round commitments, and runs the remaining rounds of the prover.
+    #[allow(clippy::too_many_arguments)]
+    fn prover_remaining_rounds<R: Rng + CryptoRng>(
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        session: FirstRoundSession<'_, E, FS, MM>,
+        first_commitments: Vec<LabeledCommitment<Commitment<E>>>,
+        first_commitment_randomnesses: Vec<Randomness<E>>,
+        terminator: &AtomicBool,
+        progress: Option<&dyn Fn(ProverStage)>,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        // Reports the completion of a prover round.
+        let report = |stage| {
+            if let Some(progress) = progress {
+                progress(stage)
+            }
         };
-        end_timer!(first_round_comm_time);
+
+        let FirstRoundSession { prover_state, mut sponge, circuit_name, .. } = session;
+        let batch_size = prover_state.batch_size;
+        let labels = (circuit_name, batch_size);
+        let public_input = prover_state.public_inputs();
 
         Self::absorb_labeled(&first_commitments, &mut sponge);
         Self::terminate(terminator)?;
@@ -377,27 +486,36 @@ where
         let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
             circuit_proving_key.circuit_verifying_key.circuit_info,
             batch_size,
+            circuit_proving_key.circuit.has_lookups(),
             &mut sponge,
         )?;
+        report(ProverStage::FirstRound);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
 
         Self::terminate(terminator)?;
-        let mut prover_state = AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state)?;
+        let mut prover_state = prover_time!(
+            metrics::AHP_ROUND_SECONDS,
+            labels,
+            "second",
+            AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state)?
+        );
         Self::terminate(terminator)?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = {
-            let second_round_oracles = Arc::get_mut(prover_state.second_round_oracles.as_mut().unwrap()).unwrap();
-            SonicKZG10::<E, FS>::commit_with_terminator(
-                &circuit_proving_key.committer_key,
-                second_round_oracles.iter().map(Into::into),
-                terminator,
-                Some(zk_rng),
-            )?
-        };
+        prover_polynomials!(labels, "second", prover_state.second_round_oracles.as_ref().unwrap().iter());
+        let (second_commitments, second_commitment_randomnesses) =
+            prover_time!(metrics::COMMIT_SECONDS, labels, "second", {
+                let second_round_oracles = Arc::get_mut(prover_state.second_round_oracles.as_mut().unwrap()).unwrap();
+                SonicKZG10::<E, FS>::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    second_round_oracles.iter().map(Into::into),
+                    terminator,
+                    Some(zk_rng),
+                )?
+            });
         end_timer!(second_round_comm_time);
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
@@ -405,23 +523,34 @@ where
 
         let (verifier_second_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        report(ProverStage::SecondRound);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Third round
 
         Self::terminate(terminator)?;
-        let (third_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng);
+        let (third_oracles, prover_state) = prover_time!(
+            metrics::AHP_ROUND_SECONDS,
+            labels,
+            "third",
+            AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)
+        );
         Self::terminate(terminator)?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            third_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        prover_polynomials!(labels, "third", third_oracles.iter());
+        let (third_commitments, third_commitment_randomnesses) = prover_time!(
+            metrics::COMMIT_SECONDS,
+            labels,
+            "third",
+            SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                third_oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?
+        );
         end_timer!(third_round_comm_time);
 
         Self::absorb_labeled(&third_commitments, &mut sponge);
@@ -429,6 +558,7 @@ where
 
         let (verifier_third_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        report(ProverStage::ThirdRound);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -436,23 +566,34 @@ where
 
         Self::terminate(terminator)?;
 
-        let (fourth_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng);
+        let (fourth_oracles, prover_state) = prover_time!(
+            metrics::AHP_ROUND_SECONDS,
+            labels,
+            "fourth",
+            AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)
+        );
         Self::terminate(terminator)?;
 
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            fourth_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        prover_polynomials!(labels, "fourth", fourth_oracles.iter());
+        let (fourth_commitments, fourth_commitment_randomnesses) = prover_time!(
+            metrics::COMMIT_SECONDS,
+            labels,
+            "fourth",
+            SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                fourth_oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?
+        );
         end_timer!(fourth_round_comm_time);
 
         Self::absorb_labeled(&fourth_commitments, &mut sponge);
 
         let (verifier_fourth_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        report(ProverStage::FourthRound);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -460,23 +601,34 @@ where
 
         Self::terminate(terminator)?;
 
-        let (prover_fifth_message, fifth_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_fifth_round(&verifier_fourth_msg, prover_state, zk_rng)?;
+        let (prover_fifth_message, fifth_oracles, prover_state) = prover_time!(
+            metrics::AHP_ROUND_SECONDS,
+            labels,
+            "fifth",
+            AHPForR1CS::<_, MM>::prover_fifth_round(&verifier_fourth_msg, prover_state, zk_rng)?
+        );
         Self::terminate(terminator)?;
 
         let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
-        let (fifth_commitments, fifth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            fifth_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        prover_polynomials!(labels, "fifth", fifth_oracles.iter());
+        let (fifth_commitments, fifth_commitment_randomnesses) = prover_time!(
+            metrics::COMMIT_SECONDS,
+            labels,
+            "fifth",
+            SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                fifth_oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?
+        );
         end_timer!(fifth_round_comm_time);
 
         Self::absorb_labeled_with_msg(&fifth_commitments, &prover_fifth_message, &mut sponge);
 
         let (verifier_fifth_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_fifth_round(verifier_state, &mut sponge)?;
+        report(ProverStage::FifthRound);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -486,21 +638,33 @@ where
 
         let first_round_oracles = Arc::clone(prover_state.first_round_oracles.as_ref().unwrap());
         let second_round_oracles = Arc::clone(prover_state.second_round_oracles.as_ref().unwrap());
-        let sixth_oracles = AHPForR1CS::<_, MM>::prover_sixth_round(&verifier_fifth_msg, prover_state, zk_rng)?;
+        let sixth_oracles = prover_time!(
+            metrics::AHP_ROUND_SECONDS,
+            labels,
+            "sixth",
+            AHPForR1CS::<_, MM>::prover_sixth_round(&verifier_fifth_msg, prover_state, zk_rng)?
+        );
         Self::terminate(terminator)?;
 
         let sixth_round_comm_time = start_timer!(|| "Committing to sixth round polys");
-        let (sixth_commitments, sixth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            sixth_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        prover_polynomials!(labels, "sixth", sixth_oracles.iter());
+        let (sixth_commitments, sixth_commitment_randomnesses) = prover_time!(
+            metrics::COMMIT_SECONDS,
+            labels,
+            "sixth",
+            SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                sixth_oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?
+        );
         end_timer!(sixth_round_comm_time);
 
         Self::absorb_labeled(&sixth_commitments, &mut sponge);
 
         let verifier_state = AHPForR1CS::<_, MM>::verifier_sixth_round(verifier_state, &mut sponge)?;
+        report(ProverStage::SixthRound);
         // --------------------------------------------------------------------
 
         Self::terminate(terminator)?;
@@ -510,7 +674,7 @@ where
             .circuit
             .iter() // 15 items
             .chain(first_round_oracles.iter_for_open()) // 4 * batch_size + (MM::ZK as usize) items
-            .chain(second_round_oracles.iter()) // 6 * batch_size + 2 items
+            .chain(second_round_oracles.iter()) // 6 * batch_size + 2 items, or 2 items without lookups
             .chain(third_oracles.iter()) // 1 item
             .chain(fourth_oracles.iter()) // 1 item
             .chain(fifth_oracles.iter()) // 3 items
@@ -626,30 +790,169 @@ where
 
         sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            &circuit_proving_key.committer_key,
-            lc_s.values(),
-            polynomials,
-            &labeled_commitments,
-            &query_set.to_set(),
-            &commitment_randomnesses,
-            &mut sponge,
-        )?;
+        let pc_proof = prover_time!(
+            metrics::OPEN_SECONDS,
+            labels,
+            "opening",
+            SonicKZG10::<E, FS>::open_combinations(
+                &circuit_proving_key.committer_key,
+                lc_s.values(),
+                polynomials,
+                &labeled_commitments,
+                &query_set.to_set(),
+                &commitment_randomnesses,
+                &mut sponge,
+            )?
+        );
 
         Self::terminate(terminator)?;
 
         let proof = Proof::<E>::new(batch_size, commitments, evaluations, prover_fifth_message, pc_proof)?;
         assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
-        let elapsed_time = prover_start.elapsed();
-        println!("Proving took took {} milliseconds.", elapsed_time.as_millis());
+        prover_proof!(labels);
+        Ok(proof)
+    }
+}
 
-        #[cfg(debug_assertions)]
-        if !Self::verify_batch(fs_parameters, &circuit_proving_key.circuit_verifying_key, &public_input, &proof)? {
-            println!("Invalid proof")
+impl<E: PairingEngine, FS, MM> SNARK for MarlinSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: MarlinMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E, MM>;
+
+    fn universal_setup(max_degree: &Self::UniversalSetupConfig) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {max_degree}",) });
+
+        let srs = SonicKZG10::<E, FS>::load_srs(*max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    fn setup<C: ConstraintSynthesizer<E::Fr>>(
+        circuit: &C,
+        srs: &mut SRS<Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific => Self::circuit_specific_setup(circuit),
+            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
         }
-        end_timer!(prover_time);
+        .map_err(SNARKError::from)
+    }
 
-        Ok(proof)
+    fn prove_vk(
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate, SNARKError> {
+        // Ensure the proving key and the verifying key share the same universal parameters.
+        Self::check_key_compatibility(&proving_key.committer_key, &verifying_key.verifier_key)?;
+
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
+            lc.add(c, poly.label());
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            &proving_key.committer_key,
+            &[lc],
+            proving_key.circuit.iter(),
+            &commitments,
+            &query_set,
+            &proving_key.circuit_commitment_randomness.clone(),
+            &mut sponge,
+        )?;
+
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool, SNARKError> {
+        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+
+        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, point)?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        let mut evaluation = E::Fr::zero();
+        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
+            lc.add(c, label.as_str());
+            evaluation += c * eval;
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(info.values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+
+        SonicKZG10::<E, FS>::check_combinations(
+            &verifying_key.verifier_key,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &Self::FSParameters,
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove_batch_with_progress(fs_parameters, circuit_proving_key, circuits, terminator, None, zk_rng)
     }
 
     fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
@@ -658,15 +961,64 @@ where
         public_inputs: &[B],
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError> {
+        Self::verify_batch_prepared_detailed(fs_parameters, prepared_verifying_key, public_inputs, proof)
+            .map(|outcome| outcome.is_valid())
+    }
+}
+
+impl<E: PairingEngine, FS, MM> MarlinSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: MarlinMode,
+{
+    /// Verifies the given batch proof against the verifying key and public inputs,
+    /// returning the reason the proof is invalid, if it is invalid.
+    ///
+    /// Unlike `SNARK::verify_batch`, which only reports whether the proof is valid,
+    /// this distinguishes the failure modes to aid in debugging.
+    pub fn verify_detailed<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        verifying_key: &CircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+        proof: &Proof<E>,
+    ) -> Result<VerifyOutcome, SNARKError> {
+        Self::verify_batch_prepared_detailed(fs_parameters, &verifying_key.prepare(), public_inputs, proof)
+    }
+
+    /// Verifies the given batch proof against the prepared verifying key and public inputs,
+    /// returning the reason the proof is invalid, if it is invalid.
+    pub fn verify_batch_prepared_detailed<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        prepared_verifying_key: &PreparedCircuitVerifyingKey<E, MM>,
+        public_inputs: &[B],
+        proof: &Proof<E>,
+    ) -> Result<VerifyOutcome, SNARKError> {
         let circuit_verifying_key = &prepared_verifying_key.orig_vk;
         if public_inputs.is_empty() {
             return Err(SNARKError::EmptyBatch);
         }
 
-        if public_inputs.len() != proof.batch_size()? {
+        if public_inputs.len() != proof.commitments.witness_commitments.len() {
             return Err(SNARKError::BatchSizeMismatch);
         }
 
+        // Ensure the proof has exactly one evaluation of each polynomial for each instance.
+        let num_instances = public_inputs.len();
+        let num_lookup_instances = if proof.has_lookups() { num_instances } else { 0 };
+        let num_evaluations = core::iter::once((proof.evaluations.z_b_evals.len(), num_instances))
+            .chain(proof.evaluations.lookup_evals().iter().map(|evals| (evals.len(), num_lookup_instances)))
+            .collect::<Vec<_>>();
+        if num_evaluations.iter().any(|(num_evals, num_expected)| num_evals < num_expected) {
+            return Ok(VerifyOutcome::MissingEvaluation);
+        }
+        if num_evaluations.iter().any(|(num_evals, num_expected)| num_evals > num_expected) {
+            return Ok(VerifyOutcome::EvaluationMismatch);
+        }
+        // Ensure the remainder of the proof is consistent with the batch size.
+        proof.batch_size()?;
+
         let comms = &proof.commitments;
         let proof_has_correct_zk_mode = if MM::ZK {
             proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
@@ -678,7 +1030,19 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(VerifyOutcome::IncorrectZkMode);
+        }
+        if !comms.iter_group_elements().all(|commitment| commitment.is_on_curve()) {
+            return Ok(VerifyOutcome::InvalidCommitment);
+        }
+        // The prover omits the lookup commitments for circuits without lookup constraints, in which case
+        // the lookup checks are skipped. A circuit with lookup constraints always requires them.
+        if !proof.has_lookups() && circuit_verifying_key.has_lookups() {
+            return Ok(VerifyOutcome::MissingLookups);
+        }
+        // Ensure the sums claimed for the matrix sumchecks are not degenerate.
+        if proof.msg.check_nonzero().is_err() {
+            return Ok(VerifyOutcome::SumcheckFailed);
         }
 
         let batch_size = public_inputs.len();
@@ -786,8 +1150,13 @@ where
         // First round
         let first_round_time = start_timer!(|| "First round");
         Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
+            circuit_verifying_key.circuit_info,
+            batch_size,
+            proof.has_lookups(),
+            &mut sponge,
+        )
+        .map_err(|error| SNARKError::from(error).with_context("Failed the first verifier round"))?;
         end_timer!(first_round_time);
         // --------------------------------------------------------------------
 
@@ -795,7 +1164,8 @@ where
         // Second round
         let second_round_time = start_timer!(|| "Second round");
         Self::absorb_labeled(&second_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)
+            .map_err(|error| SNARKError::from(error).with_context("Failed the second verifier round"))?;
         end_timer!(second_round_time);
         // --------------------------------------------------------------------
 
@@ -806,7 +1176,8 @@ where
         // This is from testnet3.3
         // Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
         let third_round_time = start_timer!(|| "Third round");
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)
+            .map_err(|error| SNARKError::from(error).with_context("Failed the third verifier round"))?;
         end_timer!(third_round_time);
         // --------------------------------------------------------------------
 
@@ -815,20 +1186,23 @@ where
         let fourth_round_time = start_timer!(|| "Fourth round");
 
         Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)
+            .map_err(|error| SNARKError::from(error).with_context("Failed the fourth verifier round"))?;
         end_timer!(fourth_round_time);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Fifth round
         Self::absorb_labeled_with_msg(&fifth_commitments, &proof.msg, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_fifth_round(verifier_state, &mut sponge)?;
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_fifth_round(verifier_state, &mut sponge)
+            .map_err(|error| SNARKError::from(error).with_context("Failed the fifth verifier round"))?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Sixth round
         Self::absorb_labeled(&sixth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_sixth_round(verifier_state, &mut sponge)?;
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_sixth_round(verifier_state, &mut sponge)
+            .map_err(|error| SNARKError::from(error).with_context("Failed the sixth verifier round"))?;
         // --------------------------------------------------------------------
 
         // Collect degree bounds for commitments. Indexed polynomials have *no*
@@ -861,8 +1235,14 @@ where
             if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
                 evaluations.insert((label, q), E::Fr::zero());
             } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
+                match proof.evaluations.get(&label) {
+                    Some(eval) => evaluations.insert((label, q), eval),
+                    None => {
+                        #[cfg(debug_assertions)]
+                        eprintln!("Failed to find the evaluation of '{label}' in the proof");
+                        return Ok(VerifyOutcome::MissingEvaluation);
+                    }
+                };
             }
         }
 
@@ -872,7 +1252,8 @@ where
             &evaluations,
             &proof.msg,
             &verifier_state,
-        )?;
+        )
+        .map_err(|error| SNARKError::from(error).with_context("Failed to construct the verifier linear combinations"))?;
         end_timer!(lc_time);
 
         let pc_time = start_timer!(|| "Checking linear combinations with PC");
@@ -884,7 +1265,8 @@ where
             &evaluations,
             &proof.pc_proof,
             &mut sponge,
-        )?;
+        )
+        .map_err(|error| SNARKError::from(error).with_context("Failed to check the linear combinations with PC"))?;
         end_timer!(pc_time);
 
         if !evaluations_are_correct {
@@ -895,7 +1277,10 @@ where
             " SonicKZG10::Check for AHP Verifier linear equations: {}",
             evaluations_are_correct & proof_has_correct_zk_mode
         ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        match evaluations_are_correct {
+            true => Ok(VerifyOutcome::Valid),
+            false => Ok(VerifyOutcome::PairingFailed),
+        }
     }
 }
 
@@ -911,7 +1296,7 @@ pub mod test {
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::Field;
     use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
-    use snarkvm_utilities::{TestRng, Uniform};
+    use snarkvm_utilities::{CanonicalSerialize, Compress, FromBytes, TestRng, Uniform};
 
     use core::ops::MulAssign;
 
@@ -984,6 +1369,442 @@ pub mod test {
             );
         }
     }
+
+    #[test]
+    fn marlin_prove_with_progress_test() {
+        let mut rng = TestRng::default();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::setup(&circ, &mut SRS::CircuitSpecific).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Collect the stages reported by the prover.
+        let stages = std::cell::RefCell::new(Vec::new());
+        let progress = |stage: ProverStage| stages.borrow_mut().push(stage);
+        let proof =
+            TestSNARK::prove_with_progress(&fs_parameters, &pk, &circ, &progress, &mut TestRng::fixed(1)).unwrap();
+        assert!(TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &proof).unwrap());
+
+        // Ensure all six rounds were reported in order.
+        assert_eq!(stages.into_inner(), vec![
+            ProverStage::FirstRound,
+            ProverStage::SecondRound,
+            ProverStage::ThirdRound,
+            ProverStage::FourthRound,
+            ProverStage::FifthRound,
+            ProverStage::SixthRound,
+        ]);
+
+        // Ensure the callback does not affect the proof.
+        let expected = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut TestRng::fixed(1)).unwrap();
+        assert_eq!(expected.to_bytes_le().unwrap(), proof.to_bytes_le().unwrap());
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn marlin_prover_metrics_test() {
+        use crate::snark::marlin::metrics;
+        use metrics_util::debugging::DebuggingRecorder;
+
+        // Install a recorder for the metrics emitted on this thread.
+        let recorder = DebuggingRecorder::per_thread();
+        let snapshotter = recorder.snapshotter();
+        recorder.install().unwrap();
+
+        let mut rng = TestRng::default();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, _) = TestSNARK::setup(&circ, &mut SRS::CircuitSpecific).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+
+        // Collect the names of the emitted metrics, and ensure each is labeled with the circuit and batch size.
+        let snapshot = snapshotter.snapshot().into_vec();
+        let mut names = std::collections::BTreeSet::new();
+        for (key, ..) in snapshot {
+            let labels = key.key().labels().map(|label| (label.key(), label.value())).collect::<Vec<_>>();
+            assert!(labels.contains(&("circuit", std::any::type_name::<Circuit<Fr>>())));
+            assert!(labels.contains(&("batch_size", "1")));
+            names.insert(key.key().name().to_string());
+        }
+
+        // Ensure the expected metrics were emitted.
+        for name in [
+            metrics::AHP_ROUND_SECONDS,
+            metrics::COMMIT_SECONDS,
+            metrics::OPEN_SECONDS,
+            metrics::NUM_POLYNOMIALS,
+            metrics::MAX_POLYNOMIAL_DEGREE,
+            metrics::NUM_PROOFS,
+        ] {
+            assert!(names.contains(name), "Missing the metric '{name}'");
+        }
+    }
+
+    #[test]
+    fn marlin_distributed_first_round_test() {
+        let mut rng = TestRng::default();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::setup(&circ, &mut SRS::CircuitSpecific).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Commits to the first round jobs on two "machines": the first commits to `w`, and the second to the rest.
+        let commit_jobs = |jobs: Vec<FirstRoundJob<Fr>>| {
+            // Ensure the jobs can be sent over the wire.
+            let jobs = jobs.iter().map(|job| FirstRoundJob::read_le(&job.to_bytes_le().unwrap()[..]).unwrap());
+            let (machine_1, machine_2): (Vec<_>, Vec<_>) = jobs.partition(|job| job.label().starts_with("w_"));
+            assert_eq!(machine_1.len(), 1);
+
+            let mut commitments = Vec::new();
+            let mut rng_1 = TestRng::fixed(1);
+            for job in &machine_1 {
+                commitments.push(TestSNARK::commit_first_round_job(&pk, job, &mut rng_1).unwrap());
+            }
+            let mut rng_2 = TestRng::fixed(2);
+            for job in &machine_2 {
+                commitments.push(TestSNARK::commit_first_round_job(&pk, job, &mut rng_2).unwrap());
+            }
+            commitments
+        };
+
+        // Ensure the distributed proof verifies.
+        let (session, jobs) = TestSNARK::prepare_first_round_jobs(&fs_parameters, &pk, &[circ], &mut rng).unwrap();
+        assert_eq!(jobs.len(), 5);
+        let labels: Vec<_> = jobs.iter().map(|job| job.label().to_string()).collect();
+        assert_eq!(labels, session.polynomial_info().iter().map(|info| info.label()).collect::<Vec<_>>());
+        let commitments = commit_jobs(jobs);
+        let proof = TestSNARK::resume_with_commitments(&pk, session, commitments, &mut rng).unwrap();
+        assert!(TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &proof).unwrap());
+
+        // Ensure commitments in the wrong order are rejected.
+        let (session, jobs) = TestSNARK::prepare_first_round_jobs(&fs_parameters, &pk, &[circ], &mut rng).unwrap();
+        let mut commitments = commit_jobs(jobs);
+        commitments.swap(0, 1);
+        assert!(TestSNARK::resume_with_commitments(&pk, session, commitments, &mut rng).is_err());
+
+        // Ensure a missing commitment is rejected.
+        let (session, jobs) = TestSNARK::prepare_first_round_jobs(&fs_parameters, &pk, &[circ], &mut rng).unwrap();
+        let mut commitments = commit_jobs(jobs);
+        commitments.pop();
+        assert!(TestSNARK::resume_with_commitments(&pk, session, commitments, &mut rng).is_err());
+
+        // Ensure a tampered commitment is caught at verification.
+        let (session, jobs) = TestSNARK::prepare_first_round_jobs(&fs_parameters, &pk, &[circ], &mut rng).unwrap();
+        let mut commitments = commit_jobs(jobs);
+        let (w_commitment, _) = &commitments[0];
+        let tampered = LabeledCommitment::new(w_commitment.label().to_string(), *commitments[1].0.commitment(), None);
+        commitments[0].0 = tampered;
+        let proof = TestSNARK::resume_with_commitments(&pk, session, commitments, &mut rng).unwrap();
+        assert!(!matches!(TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &proof), Ok(true)));
+    }
+
+    #[test]
+    fn marlin_universal_setup_from_seed_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+        let srs_bytes = srs.to_bytes_le().unwrap();
+
+        // Ensure the same seed produces an identical SRS.
+        let srs_again = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+        assert_eq!(srs_bytes, srs_again.to_bytes_le().unwrap());
+
+        // Ensure a different seed produces a different SRS.
+        let other_srs = TestSNARK::universal_setup_from_seed(max_degree, [2u8; 32]).unwrap();
+        assert_ne!(srs_bytes, other_srs.to_bytes_le().unwrap());
+
+        // Ensure the seeded SRS can be used to prove and verify.
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+        assert!(TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &proof).unwrap());
+    }
+
+    #[test]
+    fn marlin_incompatible_keys_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+        let other_srs = TestSNARK::universal_setup_from_seed(max_degree, [2u8; 32]).unwrap();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let (other_pk, other_vk) = TestSNARK::circuit_setup(&other_srs, &circ).unwrap();
+
+        // Ensure keys from the same SRS are compatible.
+        assert!(pk.committer_key.trim_metadata().is_compatible_with(&vk.verifier_key.trim_metadata()));
+        assert!(other_pk.committer_key.trim_metadata().is_compatible_with(&other_vk.verifier_key.trim_metadata()));
+
+        // Ensure keys from different SRS instances are incompatible.
+        assert!(!pk.committer_key.trim_metadata().is_compatible_with(&other_vk.verifier_key.trim_metadata()));
+
+        // Ensure compatibility requires the committer's degree bounds to be a subset of the verifier's.
+        let committer_metadata = pk.committer_key.trim_metadata();
+        let mut verifier_metadata = vk.verifier_key.trim_metadata();
+        verifier_metadata.degree_bounds = Some(vec![1, 2]);
+        let mut subset = committer_metadata.clone();
+        subset.degree_bounds = Some(vec![2]);
+        assert!(subset.is_compatible_with(&verifier_metadata));
+        let mut superset = committer_metadata.clone();
+        superset.degree_bounds = Some(vec![1, 2, 3]);
+        assert!(!superset.is_compatible_with(&verifier_metadata));
+        assert!(verifier_metadata.is_compatible_with(&subset) && !verifier_metadata.is_compatible_with(&superset));
+        verifier_metadata.degree_bounds = None;
+        assert!(!subset.is_compatible_with(&verifier_metadata));
+
+        // Ensure the metadata round-trips through its byte representation.
+        use crate::polycommit::sonic_pc::TrimMetadata;
+        let bytes = superset.to_bytes_le().unwrap();
+        assert_eq!(superset, TrimMetadata::from_bytes_le(&bytes).unwrap());
+        let bytes = vk.verifier_key.trim_metadata().to_bytes_le().unwrap();
+        assert_eq!(vk.verifier_key.trim_metadata(), TrimMetadata::from_bytes_le(&bytes).unwrap());
+
+        // Ensure proving with a mismatched committer key fails up front.
+        let mixed_pk = CircuitProvingKey { committer_key: other_pk.committer_key.clone(), ..pk.clone() };
+        let fs_parameters = FS::sample_parameters();
+        let result = TestSNARK::prove(&fs_parameters, &mixed_pk, &circ, &mut rng);
+        assert!(matches!(result, Err(SNARKError::IncompatibleKeys(..))));
+
+        // Ensure certifying a verifying key with a mismatched proving key fails up front.
+        let result = TestSNARK::prove_vk(&fs_parameters, &other_vk, &pk);
+        assert!(matches!(result, Err(SNARKError::IncompatibleKeys(..))));
+    }
+
+    #[test]
+    fn marlin_proof_serde_json_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+
+        // Ensure the JSON form exposes the proof components with hex-encoded elements.
+        let json = serde_json::to_value(&proof).unwrap();
+        assert_eq!(json["batch_size"], 1);
+        let g_1 = json["commitments"]["g_1"].as_str().unwrap();
+        assert!(hex::decode(g_1).is_ok());
+        assert!(json["evaluations"]["g_1_eval"].is_string());
+        assert!(json["msg"]["sum_a"].is_string());
+
+        // Ensure the JSON form round-trips and still verifies.
+        let candidate: Proof<Bls12_377> = serde_json::from_str(&serde_json::to_string(&proof).unwrap()).unwrap();
+        assert_eq!(proof, candidate);
+        assert!(TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &candidate).unwrap());
+
+        // Ensure a mismatched batch size is rejected.
+        let mut json = json;
+        json["batch_size"] = serde_json::json!(2);
+        assert!(serde_json::from_value::<Proof<Bls12_377>>(json).is_err());
+
+        // Ensure the binary form is unchanged from the canonical encoding.
+        let bytes = bincode::serialize(&proof).unwrap();
+        let mut expected = Vec::new();
+        proof.serialize_compressed(&mut expected).unwrap();
+        assert_eq!(&bytes[8..], &expected[..]);
+        assert_eq!(proof, bincode::deserialize::<Proof<Bls12_377>>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn marlin_proof_recompress_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, _) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+
+        // Ensure both forms deserialize to an equal proof.
+        let compressed = proof.to_compressed_bytes().unwrap();
+        let uncompressed = proof.to_uncompressed_bytes().unwrap();
+        assert!(compressed.len() < uncompressed.len());
+        assert_eq!(compressed, proof.to_bytes_le().unwrap());
+        assert_eq!(proof, Proof::<Bls12_377>::from_compressed(&compressed).unwrap());
+        assert_eq!(proof, Proof::<Bls12_377>::from_uncompressed(&uncompressed).unwrap());
+
+        // Ensure the forms are not interchangeable.
+        assert!(Proof::<Bls12_377>::from_uncompressed(&compressed).is_err());
+
+        // Ensure recompression converts between the two forms.
+        assert_eq!(uncompressed, Proof::<Bls12_377>::recompress(&compressed, Compress::Yes).unwrap());
+        assert_eq!(compressed, Proof::<Bls12_377>::recompress(&uncompressed, Compress::No).unwrap());
+    }
+
+    #[test]
+    fn marlin_proof_to_field_elements_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+
+        let sample_circuit = |rng: &mut TestRng| Circuit {
+            a: Some(Fr::rand(rng)),
+            b: Some(Fr::rand(rng)),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+        let circ = sample_circuit(&mut rng);
+        let (pk, _) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        for batch_size in 1..4 {
+            let mut expected_len = None;
+            for _ in 0..2 {
+                let circuits = (0..batch_size).map(|_| sample_circuit(&mut rng)).collect::<Vec<_>>();
+                let proof = TestSNARK::prove_batch(&fs_parameters, &pk, &circuits, &mut rng).unwrap();
+                let elements: Vec<Fq> = proof.to_field_elements().unwrap();
+
+                // Ensure the batch size is the first element.
+                assert_eq!(elements[0], Fq::from(batch_size as u128));
+                // Ensure the length is determined by the batch size alone.
+                assert_eq!(*expected_len.get_or_insert(elements.len()), elements.len());
+            }
+        }
+    }
+
+    #[test]
+    fn marlin_verify_detailed_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+
+        let verify = |proof: &Proof<Bls12_377>| TestSNARK::verify_detailed(&fs_parameters, &vk, &[[c]], proof).unwrap();
+
+        // Ensure a valid proof is reported as valid.
+        assert_eq!(verify(&proof), VerifyOutcome::Valid);
+        assert!(TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &proof).unwrap());
+
+        // Ensure a tampered evaluation value fails the pairing check, as it is bound into the transcript.
+        let mut tampered = proof.clone();
+        tampered.evaluations.g_1_eval += Fr::one();
+        assert_eq!(verify(&tampered), VerifyOutcome::PairingFailed);
+        assert!(!TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &tampered).unwrap());
+
+        // Ensure a missing evaluation is reported.
+        let mut tampered = proof.clone();
+        tampered.evaluations.z_b_evals.pop();
+        assert_eq!(verify(&tampered), VerifyOutcome::MissingEvaluation);
+        assert!(!TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &tampered).unwrap());
+
+        // Ensure an evaluation that was not queried is reported.
+        let mut tampered = proof.clone();
+        tampered.evaluations.z_b_evals.push(Fr::one());
+        assert_eq!(verify(&tampered), VerifyOutcome::EvaluationMismatch);
+        assert!(!TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &tampered).unwrap());
+
+        // Ensure a degenerate sumcheck is reported.
+        let mut tampered = proof.clone();
+        tampered.msg.sum_a = Fr::zero();
+        assert_eq!(verify(&tampered), VerifyOutcome::SumcheckFailed);
+        assert!(!TestSNARK::verify(&fs_parameters, &vk, [c].as_ref(), &tampered).unwrap());
+
+        // Ensure a tampered commitment that is not on the curve is reported.
+        let mut tampered = proof.clone();
+        tampered.commitments.g_1.0.x += Fq::one();
+        assert!(!tampered.commitments.g_1.0.is_on_curve());
+        assert_eq!(verify(&tampered), VerifyOutcome::InvalidCommitment);
+
+        // Ensure a tampered commitment that is on the curve fails the pairing check.
+        let mut tampered = proof.clone();
+        tampered.commitments.g_1 = proof.commitments.h_1;
+        assert_eq!(verify(&tampered), VerifyOutcome::PairingFailed);
+
+        // Ensure a proof with the wrong zero-knowledge mode is reported.
+        let mut tampered = proof;
+        tampered.commitments.mask_poly = None;
+        assert_eq!(verify(&tampered), VerifyOutcome::IncorrectZkMode);
+    }
+
+    #[test]
+    fn marlin_proof_without_lookups_test() {
+        let mut rng = TestRng::default();
+
+        let max_degree = 1 << 10;
+        let srs = TestSNARK::universal_setup_from_seed(max_degree, [1u8; 32]).unwrap();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let circ = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+
+        let (pk, vk) = TestSNARK::circuit_setup(&srs, &circ).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+
+        // The circuit has no lookup constraints, so the proof omits the lookup commitments and evaluations.
+        assert!(!vk.has_lookups());
+        assert!(!proof.has_lookups());
+        assert_eq!(proof.batch_size().unwrap(), 1);
+
+        // Ensure the proof round-trips through the compressed and uncompressed encodings, and JSON.
+        let compressed = proof.to_compressed_bytes().unwrap();
+        assert_eq!(proof, Proof::<Bls12_377>::from_compressed(&compressed).unwrap());
+        let uncompressed = proof.to_uncompressed_bytes().unwrap();
+        assert_eq!(proof, Proof::<Bls12_377>::from_uncompressed(&uncompressed).unwrap());
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(proof, serde_json::from_str::<Proof<Bls12_377>>(&json).unwrap());
+
+        // Ensure the verifier skips the lookup checks, and accepts the proof.
+        let verify = |proof: &Proof<Bls12_377>| TestSNARK::verify_detailed(&fs_parameters, &vk, &[[c]], proof).unwrap();
+        assert_eq!(verify(&proof), VerifyOutcome::Valid);
+
+        // Ensure lookup evaluations without the lookup commitments are rejected.
+        let mut partial = proof.clone();
+        partial.evaluations.f_evals.push(Fr::one());
+        assert!(matches!(partial.batch_size(), Err(SNARKError::BatchSizeMismatch)));
+        assert!(Proof::<Bls12_377>::from_compressed(&partial.to_compressed_bytes().unwrap()).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -998,7 +1819,7 @@ mod lookup_test {
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::Field;
     use snarkvm_r1cs::{ConstraintSystem, LinearCombination, LookupTable, SynthesisError};
-    use snarkvm_utilities::{TestRng, Uniform};
+    use snarkvm_utilities::{CanonicalSerialize, Compress, TestRng, Uniform, Validate};
 
     use core::ops::MulAssign;
 
@@ -1143,4 +1964,123 @@ mod lookup_test {
             }
         }
     }
+
+    /// Serializes the proof with the fixed layout, in which the lookup elements are always present.
+    fn to_fixed_layout_bytes(proof: &Proof<Bls12_377>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let commitments = &proof.commitments;
+        let evaluations = &proof.evaluations;
+        (proof.batch_size().unwrap() as u64).serialize_compressed(&mut bytes).unwrap();
+        for commitment in &commitments.witness_commitments {
+            commitment.serialize_compressed(&mut bytes).unwrap();
+        }
+        for commitment in &commitments.lookup_commitments {
+            commitment.serialize_compressed(&mut bytes).unwrap();
+        }
+        commitments.mask_poly.serialize_compressed(&mut bytes).unwrap();
+        for commitment in [
+            &commitments.table,
+            &commitments.delta_table_omega,
+            &commitments.g_1,
+            &commitments.h_1,
+            &commitments.g_a,
+            &commitments.g_b,
+            &commitments.g_c,
+            &commitments.h_2,
+        ] {
+            commitment.serialize_compressed(&mut bytes).unwrap();
+        }
+        for evals in [
+            &evaluations.z_b_evals,
+            &evaluations.f_evals,
+            &evaluations.s_1_evals,
+            &evaluations.s_2_evals,
+            &evaluations.z_2_evals,
+            &evaluations.delta_s_1_omega_evals,
+        ] {
+            for eval in evals {
+                eval.serialize_compressed(&mut bytes).unwrap();
+            }
+        }
+        for eval in [
+            evaluations.s_m_eval,
+            evaluations.s_l_eval,
+            evaluations.table_eval,
+            evaluations.delta_table_omega_eval,
+            evaluations.g_1_eval,
+            evaluations.g_a_eval,
+            evaluations.g_b_eval,
+            evaluations.g_c_eval,
+        ] {
+            eval.serialize_compressed(&mut bytes).unwrap();
+        }
+        proof.msg.serialize_compressed(&mut bytes).unwrap();
+        proof.pc_proof.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn marlin_proof_with_lookups_test() {
+        let mut rng = TestRng::default();
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+
+        let mut table = LookupTable::default();
+        table.fill([a + Fr::one(), b], c);
+        let circ = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+            tables: vec![table],
+            entries_to_lookup: vec![true],
+        };
+
+        let (pk, vk) = TestSNARK::setup(&circ, &mut SRS::CircuitSpecific).unwrap();
+        let fs_parameters = FS::sample_parameters();
+        let proof = TestSNARK::prove(&fs_parameters, &pk, &circ, &mut rng).unwrap();
+        assert!(vk.has_lookups());
+        assert!(proof.has_lookups());
+
+        let verify = |proof: &Proof<Bls12_377>| TestSNARK::verify_detailed(&fs_parameters, &vk, &[[c]], proof).unwrap();
+        assert_eq!(verify(&proof), VerifyOutcome::Valid);
+
+        // Ensure the lookup checks are not skipped for a circuit with lookup constraints.
+        let mut without_lookups = proof.clone();
+        without_lookups.commitments.lookup_commitments.clear();
+        without_lookups.evaluations.f_evals.clear();
+        without_lookups.evaluations.s_1_evals.clear();
+        without_lookups.evaluations.s_2_evals.clear();
+        without_lookups.evaluations.z_2_evals.clear();
+        without_lookups.evaluations.delta_s_1_omega_evals.clear();
+        assert_eq!(without_lookups.batch_size().unwrap(), 1);
+        assert_eq!(verify(&without_lookups), VerifyOutcome::MissingLookups);
+
+        // Ensure a proof with only some of the lookup elements is rejected.
+        let mut partial = proof.clone();
+        partial.evaluations.s_2_evals.clear();
+        assert!(matches!(partial.batch_size(), Err(SNARKError::BatchSizeMismatch)));
+        assert!(Proof::<Bls12_377>::from_compressed(&partial.to_compressed_bytes().unwrap()).is_err());
+
+        // Ensure a proof with the fixed layout, which does not set the layout flag, is rejected by default.
+        let fixed_layout = to_fixed_layout_bytes(&proof);
+        assert_ne!(fixed_layout, proof.to_compressed_bytes().unwrap());
+        let error = Proof::<Bls12_377>::from_compressed(&fixed_layout).unwrap_err();
+        assert!(error.to_string().contains("does not set the layout flag"), "{error}");
+
+        // Ensure the legacy path reads the fixed layout, and re-emits the original encoding.
+        let legacy = Proof::<Bls12_377>::deserialize_legacy_with_mode(&*fixed_layout, Compress::Yes, Validate::Yes);
+        assert_eq!(proof, legacy.unwrap());
+        let mut bytes = Vec::new();
+        proof.serialize_legacy_with_mode(&mut bytes, Compress::Yes).unwrap();
+        assert_eq!(fixed_layout, bytes);
+
+        // Ensure the legacy path rejects the flagged layout, and proofs without lookup elements.
+        let compressed = proof.to_compressed_bytes().unwrap();
+        assert!(Proof::<Bls12_377>::deserialize_legacy_with_mode(&*compressed, Compress::Yes, Validate::Yes).is_err());
+        assert!(without_lookups.serialize_legacy_with_mode(&mut Vec::new(), Compress::Yes).is_err());
+    }
 }
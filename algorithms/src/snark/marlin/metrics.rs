@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prover metrics, emitted through the `metrics` crate when the `metrics` feature is enabled.
+//!
+//! Each metric is labeled with the circuit name and batch size of the proof, and the prover round, where applicable.
+//! When the feature is disabled, the macros reduce to their body, and no metrics are computed.
+
+/// The time taken by an AHP prover round, in seconds.
+pub const AHP_ROUND_SECONDS: &str = "snarkvm_marlin_ahp_round_seconds";
+/// The time taken to commit to the polynomials of a prover round, in seconds.
+pub const COMMIT_SECONDS: &str = "snarkvm_marlin_commit_seconds";
+/// The time taken to open the polynomial commitments, in seconds.
+pub const OPEN_SECONDS: &str = "snarkvm_marlin_open_seconds";
+/// The number of polynomials in a prover round.
+pub const NUM_POLYNOMIALS: &str = "snarkvm_marlin_num_polynomials";
+/// The maximum degree of the polynomials in a prover round.
+pub const MAX_POLYNOMIAL_DEGREE: &str = "snarkvm_marlin_max_polynomial_degree";
+/// The number of proofs generated.
+pub const NUM_PROOFS: &str = "snarkvm_marlin_proofs_total";
+
+/// Evaluates `$body`, and records the time it took under `$name`.
+/// The `$labels` are the `(circuit name, batch size)` of the proof.
+#[cfg(feature = "metrics")]
+macro_rules! prover_time {
+    ($name:expr, $labels:expr, $round:expr, $body:expr) => {{
+        let (circuit, batch_size): (&'static str, usize) = $labels;
+        let start = std::time::Instant::now();
+        let result = $body;
+        ::metrics::histogram!(
+            $name,
+            start.elapsed().as_secs_f64(),
+            "circuit" => circuit,
+            "batch_size" => batch_size.to_string(),
+            "round" => $round
+        );
+        result
+    }};
+}
+
+#[cfg(not(feature = "metrics"))]
+macro_rules! prover_time {
+    ($name:expr, $labels:expr, $round:expr, $body:expr) => {{
+        let _ = ($name, $labels);
+        $body
+    }};
+}
+
+/// Records the number and maximum degree of the given polynomials of a prover round.
+#[cfg(feature = "metrics")]
+macro_rules! prover_polynomials {
+    ($labels:expr, $round:expr, $polynomials:expr) => {{
+        let (circuit, batch_size): (&'static str, usize) = $labels;
+        let (num_polynomials, max_degree) =
+            $polynomials.fold((0usize, 0usize), |(count, degree), p| (count + 1, degree.max(p.degree())));
+        ::metrics::gauge!(
+            $crate::snark::marlin::metrics::NUM_POLYNOMIALS,
+            num_polynomials as f64,
+            "circuit" => circuit,
+            "batch_size" => batch_size.to_string(),
+            "round" => $round
+        );
+        ::metrics::gauge!(
+            $crate::snark::marlin::metrics::MAX_POLYNOMIAL_DEGREE,
+            max_degree as f64,
+            "circuit" => circuit,
+            "batch_size" => batch_size.to_string(),
+            "round" => $round
+        );
+    }};
+}
+
+#[cfg(not(feature = "metrics"))]
+macro_rules! prover_polynomials {
+    ($labels:expr, $round:expr, $polynomials:expr) => {{
+        let _ = $labels;
+    }};
+}
+
+/// Increments the number of proofs generated.
+#[cfg(feature = "metrics")]
+macro_rules! prover_proof {
+    ($labels:expr) => {{
+        let (circuit, batch_size): (&'static str, usize) = $labels;
+        ::metrics::counter!(
+            $crate::snark::marlin::metrics::NUM_PROOFS,
+            1,
+            "circuit" => circuit,
+            "batch_size" => batch_size.to_string()
+        );
+    }};
+}
+
+#[cfg(not(feature = "metrics"))]
+macro_rules! prover_proof {
+    ($labels:expr) => {{
+        let _ = $labels;
+    }};
+}
@@ -37,6 +37,10 @@ pub use data_structures::*;
 mod errors;
 pub use errors::*;
 
+/// Prover metrics, behind the `metrics` feature.
+#[macro_use]
+pub mod metrics;
+
 /// Implements the base Marlin zkSNARK proof system.
 mod marlin;
 pub use marlin::*;
@@ -45,5 +49,9 @@ pub use marlin::*;
 mod mode;
 pub use mode::*;
 
+/// Specifies the Marlin prover stages.
+mod stage;
+pub use stage::*;
+
 #[cfg(test)]
 pub mod tests;
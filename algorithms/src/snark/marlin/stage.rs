@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The rounds of the AHP completed by the Marlin prover, reported in order to a progress callback.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProverStage {
+    /// The prover has committed to the first round oracles.
+    FirstRound,
+    /// The prover has committed to the second round oracles.
+    SecondRound,
+    /// The prover has committed to the third round oracles.
+    ThirdRound,
+    /// The prover has committed to the fourth round oracles.
+    FourthRound,
+    /// The prover has committed to the fifth round oracles.
+    FifthRound,
+    /// The prover has committed to the sixth round oracles.
+    SixthRound,
+}
@@ -295,9 +295,17 @@ mod marlin_hiding {
     use super::*;
     use crate::{
         crypto_hash::PoseidonSponge,
-        snark::marlin::{ahp::AHPForR1CS, CircuitVerifyingKey, MarlinHidingMode, MarlinSNARK},
+        snark::marlin::{
+            ahp::{AHPError, AHPForR1CS},
+            CircuitVerifyingKey,
+            MarlinHidingMode,
+            MarlinSNARK,
+            Proof,
+        },
+        SNARKError,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::Zero;
     use snarkvm_utilities::{
         rand::{TestRng, Uniform},
         FromBytes,
@@ -387,6 +395,166 @@ mod marlin_hiding {
         assert_eq!(index_vk, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
 
+    #[test]
+    fn test_verifying_key_id() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+
+        let (a, b) = (Some(Fr::rand(rng)), Some(Fr::rand(rng)));
+        let circuit = Circuit { a, b, num_constraints: 100, num_variables: 25 };
+        let (_index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure equal verifying keys have the same ID.
+        let (_index_pk, index_vk_again) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        assert_eq!(index_vk, index_vk_again);
+        assert_eq!(index_vk.id().unwrap(), index_vk_again.id().unwrap());
+
+        // Ensure (de)serialization preserves the ID.
+        let recovered = CircuitVerifyingKey::read_le(&index_vk.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(index_vk.id().unwrap(), recovered.id().unwrap());
+        let recovered: CircuitVerifyingKey<Bls12_377, MarlinHidingMode> =
+            serde_json::from_str(&serde_json::to_string(&index_vk).unwrap()).unwrap();
+        assert_eq!(index_vk.id().unwrap(), recovered.id().unwrap());
+
+        // Ensure a different circuit has a different ID.
+        let circuit = Circuit { a, b, num_constraints: 25, num_variables: 100 };
+        let (_index_pk, other_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        assert_ne!(index_vk.id().unwrap(), other_vk.id().unwrap());
+    }
+
+    #[test]
+    fn test_commitments_iter_group_elements() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, _c, _d) = setup_test(100, 25);
+        let (index_pk, _index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&fs_parameters, &index_pk, &circuit, rng).unwrap();
+
+        // Expect 4 witness commitments per instance, the mask polynomial, and 8 named commitments.
+        // The circuit has no lookup constraints, so the proof has no lookup commitments.
+        let batch_size = proof.batch_size().unwrap();
+        let commitments = &proof.commitments;
+        assert!(!proof.has_lookups());
+        assert_eq!(commitments.iter_group_elements().count(), 4 * batch_size + 1 + 8);
+
+        // Ensure the order is as documented.
+        let elements = commitments.iter_group_elements().collect::<Vec<_>>();
+        assert_eq!(elements[0], &commitments.witness_commitments[0].w.0);
+        assert_eq!(elements[4 * batch_size], &commitments.mask_poly.unwrap().0);
+        assert_eq!(*elements.last().unwrap(), &commitments.h_2.0);
+    }
+
+    #[test]
+    fn test_proof_fifth_message() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, c, d) = setup_test(100, 25);
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(MarlinInst::verify(&fs_parameters, &index_vk, [c, d], &proof).unwrap());
+
+        // Ensure the accessor matches the prover message in the proof.
+        let message = proof.fifth_message();
+        assert_eq!(message, &proof.msg);
+        assert!(message.check_nonzero().is_ok());
+
+        // Ensure the accessor matches the prover message after (de)serialization.
+        let recovered = Proof::<Bls12_377>::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+        assert_eq!(message, recovered.fifth_message());
+
+        // Ensure a zero sum is rejected.
+        let mut message = message.clone();
+        message.sum_b = Fr::zero();
+        assert!(message.check_nonzero().is_err());
+    }
+
+    #[test]
+    fn test_verify_error_has_context() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, c, d) = setup_test(100, 25);
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let mut proof = MarlinInst::prove(&fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(MarlinInst::verify(&fs_parameters, &index_vk, [c, d], &proof).unwrap());
+
+        // Remove an evaluation from the proof, and ensure the proof is rejected rather than erroring.
+        proof.evaluations.z_b_evals.clear();
+        assert!(!MarlinInst::verify(&fs_parameters, &index_vk, [c, d], &proof).unwrap());
+
+        // Ensure the context prefixes the underlying error.
+        let error = SNARKError::from(AHPError::MissingEval("f_00000000".to_string()))
+            .with_context("Failed to construct the verifier linear combinations");
+        assert!(matches!(error, SNARKError::Custom(_)));
+        let message = error.to_string();
+        assert!(message.starts_with("Failed to construct the verifier linear combinations: "), "{message}");
+        assert!(message.contains("MissingEval"), "{message}");
+
+        // Ensure the context is not applied to a terminated error.
+        assert!(matches!(SNARKError::Terminated.with_context("context"), SNARKError::Terminated));
+    }
+
+    #[test]
+    fn test_pc_proof_introspection() {
+        use snarkvm_utilities::serialize::{CanonicalSerialize, Compress};
+        use std::collections::BTreeSet;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, c, d) = setup_test(100, 25);
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(MarlinInst::verify(&fs_parameters, &index_vk, [c, d], &proof).unwrap());
+
+        // Construct the query set of the verifier.
+        let sponge = &mut FS::new_with_parameters(&fs_parameters);
+        type Verifier = AHPForR1CS<Fr, MarlinHidingMode>;
+        let (_, state) = Verifier::verifier_first_round(index_vk.circuit_info, 1, proof.has_lookups(), sponge).unwrap();
+        let (_, state) = Verifier::verifier_second_round(state, sponge).unwrap();
+        let (_, state) = Verifier::verifier_third_round(state, sponge).unwrap();
+        let (_, state) = Verifier::verifier_fourth_round(state, sponge).unwrap();
+        let (_, state) = Verifier::verifier_fifth_round(state, sponge).unwrap();
+        let state = Verifier::verifier_sixth_round(state, sponge).unwrap();
+        let (query_set, _) = Verifier::verifier_query_set(state);
+        let query_set = query_set.to_set();
+        let query_points = query_set.iter().map(|(_, (point_name, _))| point_name).collect::<BTreeSet<_>>();
+
+        // Ensure there is one opening witness per query point.
+        let pc_proof = &proof.pc_proof;
+        assert_eq!(pc_proof.proof_points().len(), query_points.len());
+        assert_eq!(pc_proof.proof.len(), query_points.len());
+        // Ensure the evaluations are carried in the Marlin proof, rather than the batch proof.
+        assert_eq!(pc_proof.num_evaluations(), 0);
+        assert!(pc_proof.randomness_is_hiding());
+
+        // Ensure the size summary accounts for the full serialized size.
+        let summary = pc_proof.serialized_size_summary();
+        assert_eq!(summary.total(), pc_proof.serialized_size(Compress::Yes));
+        assert!(summary.opening_points > 0);
+
+        // Ensure the debug output is summarized.
+        let debug = format!("{pc_proof:?}");
+        assert!(debug.contains(&format!("num_proof_points: {}", query_points.len())), "{debug}");
+        assert!(debug.contains("num_evaluations: 0"), "{debug}");
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
@@ -68,6 +68,18 @@ pub trait AlgebraicSponge<F: PrimeField, const RATE: usize>: Clone + Debug {
         self.absorb_native_field_elements(&elements);
     }
 
+    /// Takes in bytes, packed canonically into field elements.
+    /// The length of the bytes is absorbed first, followed by the bytes in little-endian chunks
+    /// that each fit in the data bits of a field element, so distinct inputs never share an encoding.
+    fn absorb_bytes_canonical(&mut self, bytes: &[u8]) {
+        let chunk_size = F::size_in_data_bits() / 8;
+        let elements = core::iter::once(F::from(bytes.len() as u64))
+            .chain(bytes.chunks(chunk_size).map(F::from_bytes_le_mod_order))
+            .collect::<SmallVec<[F; 10]>>();
+
+        self.absorb_native_field_elements(&elements);
+    }
+
     /// Takes in field elements.
     fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]>;
 
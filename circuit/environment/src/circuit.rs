@@ -15,14 +15,17 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    helpers::{Constraint, LookupConstraint},
+    helpers::{Constraint, ConstraintLimit, ConstraintLimitExceeded, LookupConstraint},
     Mode,
     *,
 };
 
 use snarkvm_r1cs::{LookupTable, SynthesisError};
 
-use core::{cell::RefCell, fmt};
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+};
 use std::rc::Rc;
 
 type Field = <console::Testnet3 as console::Environment>::Field;
@@ -32,6 +35,26 @@ thread_local! {
     pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
+    pub(super) static CONSTRAINT_LIMIT: Cell<ConstraintLimit> = Cell::new(ConstraintLimit::unlimited());
+    pub(super) static CONSTRAINT_LIMIT_EXCEEDED: RefCell<Option<ConstraintLimitExceeded>> = RefCell::new(None);
+}
+
+/// Records a `ConstraintLimitExceeded` error, if the given circuit exceeds the constraint limit.
+/// Only the first error is recorded, until the circuit is reset or ejected.
+fn check_constraint_limit(circuit: &R1CS<Field>) {
+    let limit = CONSTRAINT_LIMIT.with(|limit| limit.get());
+    let num_constraints = circuit.num_constraints() + circuit.num_lookup_constraints();
+    let num_variables = circuit.num_public() + circuit.num_private();
+    if let Err(error) = limit.check(num_constraints, num_variables, || circuit.scope()) {
+        CONSTRAINT_LIMIT_EXCEEDED.with(|exceeded| {
+            exceeded.borrow_mut().get_or_insert(error);
+        });
+    }
+}
+
+/// Clears the recorded `ConstraintLimitExceeded` error, if any.
+fn clear_constraint_limit_exceeded() {
+    CONSTRAINT_LIMIT_EXCEEDED.with(|exceeded| *exceeded.borrow_mut() = None);
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -69,10 +92,15 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| match mode {
-                    Mode::Constant => (**circuit).borrow_mut().new_constant(value),
-                    Mode::Public => (**circuit).borrow_mut().new_public(value),
-                    Mode::Private => (**circuit).borrow_mut().new_private(value),
+                CIRCUIT.with(|circuit| {
+                    let variable = match mode {
+                        Mode::Constant => (**circuit).borrow_mut().new_constant(value),
+                        Mode::Public => (**circuit).borrow_mut().new_public(value),
+                        Mode::Private => (**circuit).borrow_mut().new_private(value),
+                    };
+                    // Ensure the circuit is within the constraint limit.
+                    check_constraint_limit(&(**circuit).borrow());
+                    variable
                 })
             } else {
                 Self::halt("Tried to initialize a new variable in witness mode")
@@ -188,7 +216,9 @@ impl Environment for Circuit {
                             // Construct the constraint object.
                             let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
                             // Append the constraint.
-                            (**circuit).borrow_mut().enforce(constraint)
+                            (**circuit).borrow_mut().enforce(constraint);
+                            // Ensure the circuit is within the constraint limit.
+                            check_constraint_limit(&(**circuit).borrow());
                         }
                     }
                 });
@@ -220,6 +250,8 @@ impl Environment for Circuit {
                         LookupConstraint((**circuit).borrow().scope(), a(z.clone()), b(z.clone()), c(z), table_index);
                     // Append the constraint.
                     (**circuit).borrow_mut().enforce_lookup(constraint);
+                    // Ensure the circuit is within the constraint limit.
+                    check_constraint_limit(&(**circuit).borrow());
                 });
             }
         });
@@ -286,6 +318,22 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_gates_in_scope())
     }
 
+    /// Returns the constraint limit of the current environment.
+    fn constraint_limit() -> ConstraintLimit {
+        CONSTRAINT_LIMIT.with(|limit| limit.get())
+    }
+
+    /// Sets the constraint limit of the current environment.
+    fn set_constraint_limit(limit: ConstraintLimit) {
+        CONSTRAINT_LIMIT.with(|constraint_limit| constraint_limit.set(limit));
+        clear_constraint_limit_exceeded();
+    }
+
+    /// Returns the error recorded when synthesis first exceeded the constraint limit, if any.
+    fn constraint_limit_exceeded() -> Option<ConstraintLimitExceeded> {
+        CONSTRAINT_LIMIT_EXCEEDED.with(|exceeded| exceeded.borrow().clone())
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -297,6 +345,7 @@ impl Environment for Circuit {
     ///  Circuits should not have easy access to this during synthesis.
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
+        clear_constraint_limit_exceeded();
         CIRCUIT.with(|circuit| {
             // Ensure the circuit is empty before injecting.
             assert_eq!(0, (**circuit).borrow().num_constants());
@@ -317,6 +366,7 @@ impl Environment for Circuit {
     ///  Circuits should not have easy access to this during synthesis.
     /// Returns the R1CS circuit, resetting the circuit.
     fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
+        clear_constraint_limit_exceeded();
         CIRCUIT.with(|circuit| {
             // Eject the R1CS instance.
             let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
@@ -334,6 +384,7 @@ impl Environment for Circuit {
     ///  Circuits should not have easy access to this during synthesis.
     /// Returns the R1CS assignment of the circuit, resetting the circuit.
     fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field> {
+        clear_constraint_limit_exceeded();
         CIRCUIT.with(|circuit| {
             // Eject the R1CS instance.
             let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
@@ -348,6 +399,7 @@ impl Environment for Circuit {
 
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
+        clear_constraint_limit_exceeded();
         CIRCUIT.with(|circuit| {
             *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
             assert_eq!(0, (**circuit).borrow().num_constants());
@@ -366,6 +418,7 @@ impl fmt::Display for Circuit {
 
 #[cfg(test)]
 mod tests {
+    use crate::{ConstraintLimit, ConstraintLimitExceeded};
     use snarkvm_circuit::prelude::*;
 
     /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
@@ -399,6 +452,35 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_constraint_limit() {
+        // Synthesize the example circuit just under the limit.
+        Circuit::reset();
+        Circuit::set_constraint_limit(ConstraintLimit::new(64, 131));
+        let _candidate = create_example_circuit::<Circuit>();
+        assert_eq!(None, Circuit::constraint_limit_exceeded());
+        Circuit::reset();
+
+        // Synthesize the example circuit just over the constraint limit.
+        Circuit::set_constraint_limit(ConstraintLimit::new(63, u64::MAX));
+        Circuit::scope("outer", || Circuit::scope("inner", || create_example_circuit::<Circuit>()));
+        let error = Circuit::constraint_limit_exceeded().unwrap();
+        assert_eq!(ConstraintLimitExceeded::Constraints { limit: 63, scope: "outer.inner".to_string() }, error);
+        // Ensure the error is cleared when the circuit is reset.
+        Circuit::reset();
+        assert_eq!(None, Circuit::constraint_limit_exceeded());
+
+        // Synthesize the example circuit just over the variable limit.
+        Circuit::set_constraint_limit(ConstraintLimit::new(u64::MAX, 130));
+        let _candidate = create_example_circuit::<Circuit>();
+        let error = Circuit::constraint_limit_exceeded().unwrap();
+        assert!(matches!(error, ConstraintLimitExceeded::Variables { limit: 130, .. }));
+
+        // Restore the environment.
+        Circuit::set_constraint_limit(ConstraintLimit::unlimited());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
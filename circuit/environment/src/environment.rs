@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{
+    witness_mode,
+    Assignment,
+    ConstraintLimit,
+    ConstraintLimitExceeded,
+    Inject,
+    LinearCombination,
+    Mode,
+    Variable,
+    R1CS,
+};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 use snarkvm_r1cs::{LookupTable, SynthesisError};
@@ -177,6 +187,18 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         )
     }
 
+    /// Returns the constraint limit of the current environment.
+    fn constraint_limit() -> ConstraintLimit;
+
+    /// Sets the constraint limit of the current environment, clearing any recorded `ConstraintLimitExceeded` error.
+    /// Synthesis that exceeds the limit records a `ConstraintLimitExceeded` error, see `constraint_limit_exceeded`.
+    /// Note: The limit persists across calls to `reset`.
+    fn set_constraint_limit(limit: ConstraintLimit);
+
+    /// Returns the error recorded when synthesis first exceeded the constraint limit, if any.
+    /// Note: The error is cleared when the circuit is reset or ejected.
+    fn constraint_limit_exceeded() -> Option<ConstraintLimitExceeded>;
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         <Self::Network as console::Environment>::halt(message)
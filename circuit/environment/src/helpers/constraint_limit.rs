@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::Scope;
+
+use core::fmt;
+
+/// A budget on the number of constraints and variables that may be synthesized in a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConstraintLimit {
+    /// The maximum number of constraints (including lookup constraints), if any.
+    max_constraints: Option<u64>,
+    /// The maximum number of public and private variables, if any.
+    max_variables: Option<u64>,
+}
+
+impl ConstraintLimit {
+    /// Initializes a new constraint limit with the given maximum number of constraints and variables.
+    pub const fn new(max_constraints: u64, max_variables: u64) -> Self {
+        Self { max_constraints: Some(max_constraints), max_variables: Some(max_variables) }
+    }
+
+    /// Initializes a constraint limit that does not restrict synthesis.
+    pub const fn unlimited() -> Self {
+        Self { max_constraints: None, max_variables: None }
+    }
+
+    /// Returns the maximum number of constraints, if any.
+    pub const fn max_constraints(&self) -> Option<u64> {
+        self.max_constraints
+    }
+
+    /// Returns the maximum number of variables, if any.
+    pub const fn max_variables(&self) -> Option<u64> {
+        self.max_variables
+    }
+
+    /// Ensures the given number of constraints and variables are within the limit.
+    /// The `scope` is only computed when the limit is exceeded.
+    #[inline]
+    pub fn check<S: FnOnce() -> Scope>(
+        &self,
+        num_constraints: u64,
+        num_variables: u64,
+        scope: S,
+    ) -> Result<(), ConstraintLimitExceeded> {
+        match (self.max_constraints, self.max_variables) {
+            (Some(limit), _) if num_constraints > limit => {
+                Err(ConstraintLimitExceeded::Constraints { limit, scope: scope() })
+            }
+            (_, Some(limit)) if num_variables > limit => {
+                Err(ConstraintLimitExceeded::Variables { limit, scope: scope() })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The error raised when synthesis exceeds the constraint limit of the environment.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConstraintLimitExceeded {
+    /// The number of constraints exceeded the limit in the given scope.
+    Constraints { limit: u64, scope: Scope },
+    /// The number of variables exceeded the limit in the given scope.
+    Variables { limit: u64, scope: Scope },
+}
+
+impl ConstraintLimitExceeded {
+    /// Returns the scope in which the limit was exceeded.
+    pub fn scope(&self) -> &Scope {
+        match self {
+            Self::Constraints { scope, .. } | Self::Variables { scope, .. } => scope,
+        }
+    }
+}
+
+impl fmt::Display for ConstraintLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (kind, limit, scope) = match self {
+            Self::Constraints { limit, scope } => ("constraints", limit, scope),
+            Self::Variables { limit, scope } => ("variables", limit, scope),
+        };
+        match scope.is_empty() {
+            true => write!(f, "Circuit synthesis exceeded the limit of {limit} {kind}"),
+            false => write!(f, "Circuit synthesis exceeded the limit of {limit} {kind} in scope '{scope}'"),
+        }
+    }
+}
+
+impl std::error::Error for ConstraintLimitExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constraint_limit_check() {
+        let limit = ConstraintLimit::new(10, 20);
+        assert!(limit.check(10, 20, String::new).is_ok());
+        assert_eq!(
+            Err(ConstraintLimitExceeded::Constraints { limit: 10, scope: "a.b".to_string() }),
+            limit.check(11, 20, || "a.b".to_string())
+        );
+        assert_eq!(
+            Err(ConstraintLimitExceeded::Variables { limit: 20, scope: "a".to_string() }),
+            limit.check(10, 21, || "a".to_string())
+        );
+        assert!(ConstraintLimit::unlimited().check(u64::MAX, u64::MAX, String::new).is_ok());
+        assert_eq!(ConstraintLimit::default(), ConstraintLimit::unlimited());
+    }
+}
@@ -16,6 +16,7 @@
 
 use crate::*;
 use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::LookupTable;
 
 #[derive(Debug, Default)]
 pub(crate) struct Counter<F: PrimeField> {
@@ -110,10 +111,10 @@ impl<F: PrimeField> Counter<F> {
         self.lookup_constraints.push(constraint);
     }
 
-    /// Returns `true` if all constraints in the scope are satisfied.
-    pub(crate) fn is_satisfied_in_scope(&self) -> bool {
+    /// Returns `true` if all constraints in the scope are satisfied, using the given lookup tables.
+    pub(crate) fn is_satisfied_in_scope(&self, tables: &[LookupTable<F>]) -> bool {
         let constraints_satisfied = self.constraints.iter().all(|constraint| constraint.is_satisfied());
-        let lookups_satisfied = self.lookup_constraints.iter().all(|constraint| constraint.is_satisfied());
+        let lookups_satisfied = self.lookup_constraints.iter().all(|constraint| constraint.is_satisfied(tables));
         constraints_satisfied && lookups_satisfied
     }
 
@@ -16,6 +16,7 @@
 
 use crate::{prelude::*, *};
 use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::LookupTable;
 
 #[derive(Clone, Debug)]
 pub(crate) struct LookupConstraint<F: PrimeField>(
@@ -33,22 +34,20 @@ impl<F: PrimeField> LookupConstraint<F> {
         1 + a.num_additions() + b.num_additions() + c.num_additions()
     }
 
-    /// Returns `true` if the constraint is satisfied.
-    pub(crate) fn is_satisfied(&self) -> bool {
+    /// Returns `true` if the constraint is satisfied, i.e. if `(a, b) => c` is an entry of the given lookup table.
+    pub(crate) fn is_satisfied(&self, tables: &[LookupTable<F>]) -> bool {
         let (scope, a, b, c, table_index) = (&self.0, &self.1, &self.2, &self.3, &self.4);
-        // TODO: lookup values in lookup table.
-        // let a = a.value();
-        // let b = b.value();
-        // let c = c.value();
+        let a = a.value();
+        let b = b.value();
+        let c = c.value();
 
-        // match a * b == c {
-        //     true => true,
-        //     false => {
-        //         eprintln!("Failed constraint at {scope}:\n\t({a} * {b}) != {c}");
-        //         false
-        //     }
-        // }
-        true
+        match tables.get(*table_index).and_then(|table| table.lookup(&[a, b])) {
+            Some(value) if *value == c => true,
+            _ => {
+                eprintln!("Failed lookup constraint at {scope}:\n\t({a}, {b}) => {c} is not in table {table_index}");
+                false
+            }
+        }
     }
 
     /// Returns a reference to the terms `(a, b, c)`.
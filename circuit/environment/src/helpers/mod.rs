@@ -23,6 +23,9 @@ pub use circuit_type::*;
 pub(crate) mod constraint;
 pub(crate) use constraint::*;
 
+pub mod constraint_limit;
+pub use constraint_limit::*;
+
 pub(crate) mod lookup_constraint;
 pub(crate) use lookup_constraint::*;
 
@@ -106,12 +106,14 @@ impl<F: PrimeField> R1CS<F> {
 
     /// Returns `true` if all constraints in the environment are satisfied.
     pub(crate) fn is_satisfied(&self) -> bool {
-        self.constraints.iter().all(|constraint| constraint.is_satisfied())
+        let constraints_satisfied = self.constraints.iter().all(|constraint| constraint.is_satisfied());
+        let lookups_satisfied = self.lookup_constraints.iter().all(|constraint| constraint.is_satisfied(&self.tables));
+        constraints_satisfied && lookups_satisfied
     }
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
-        self.counter.is_satisfied_in_scope()
+        self.counter.is_satisfied_in_scope(&self.tables)
     }
 
     /// Returns the current scope.
@@ -34,7 +34,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, Circuit, R1CS},
+    environment::{prelude::*, Assignment, Circuit, ConstraintLimit, ConstraintLimitExceeded, R1CS},
     Boolean,
     Field,
     Group,
@@ -396,6 +396,21 @@ impl Environment for AleoV0 {
         E::num_gates_in_scope()
     }
 
+    /// Returns the constraint limit of the current environment.
+    fn constraint_limit() -> ConstraintLimit {
+        E::constraint_limit()
+    }
+
+    /// Sets the constraint limit of the current environment.
+    fn set_constraint_limit(limit: ConstraintLimit) {
+        E::set_constraint_limit(limit)
+    }
+
+    /// Returns the error recorded when synthesis first exceeded the constraint limit, if any.
+    fn constraint_limit_exceeded() -> Option<ConstraintLimitExceeded> {
+        E::constraint_limit_exceeded()
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         E::halt(message)
@@ -432,6 +447,7 @@ impl Display for AleoV0 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::prelude::{TestRng, Uniform};
     use snarkvm_circuit_types::Field;
 
     type CurrentAleo = AleoV0;
@@ -482,4 +498,25 @@ mod tests {
             assert_eq!(0, CurrentAleo::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_hash_to_group_psd4() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_inputs in 1..8 {
+                let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+
+                // Compute the expected hash to group.
+                let expected = <console::Testnet3 as console::Network>::hash_to_group_psd4(&input).unwrap();
+
+                // Compute the hash to group in the circuit.
+                let circuit_input: Vec<Field<CurrentAleo>> = Inject::new(mode, input);
+                let candidate = CurrentAleo::hash_to_group_psd4(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(CurrentAleo::is_satisfied());
+                CurrentAleo::reset();
+            }
+        }
+    }
 }
@@ -90,6 +90,33 @@ impl<E: Environment> FromBits for Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Initializes a new base field element from a list of **little-endian** bits, rejecting non-canonical encodings.
+    ///   - If `bits_le` is longer than `E::BaseField::size_in_bits()`, the excess bits are enforced to be `0`s.
+    ///   - If `bits_le` is longer than `E::BaseField::size_in_data_bits()`, it is enforced to be below the modulus.
+    ///
+    /// This matches `Field::from_bits_le_strict` in console, which fails whenever this circuit is unsatisfied.
+    pub fn from_bits_le_strict(bits_le: &[Boolean<E>]) -> Self {
+        <Self as FromBits>::from_bits_le(bits_le)
+    }
+
+    /// Initializes a new base field element from a list of **little-endian** bits of any length,
+    /// by reducing the value of the bits modulo the base field modulus.
+    ///
+    /// This matches `Field::from_bits_le_mod` in console, and does not incur any constraints.
+    pub fn from_bits_le_mod(bits_le: &[Boolean<E>]) -> Self {
+        // Reconstruct the bits as a linear combination, with coefficients reduced modulo the base field.
+        // `output` := (2^i * b_i + ... + 2^0 * b_0)
+        let mut output = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in bits_le {
+            output += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+        output
+    }
+}
+
 impl<E: Environment> Metrics<dyn FromBits<Boolean = Boolean<E>>> for Field<E> {
     type Case = Vec<Mode>;
 
@@ -196,6 +223,68 @@ mod tests {
         }
     }
 
+    /// Checks that console and circuit agree on the strict and reduced interpretations of the given bits.
+    fn check_console_and_circuit_agree(mode: Mode, bits_le: &[bool]) {
+        type ConsoleField = console::Field<<Circuit as Environment>::Network>;
+
+        // Injects the given bits into the circuit.
+        let inject_bits = || bits_le.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+
+        let given_bits = inject_bits();
+        Circuit::scope(format!("{mode} mod {}", bits_le.len()), || {
+            let candidate = Field::<Circuit>::from_bits_le_mod(&given_bits);
+            assert_eq!(ConsoleField::from_bits_le_mod(bits_le), candidate.eject_value());
+            assert_eq!(0, Circuit::num_constraints_in_scope());
+        });
+        Circuit::reset();
+
+        let given_bits = inject_bits();
+        Circuit::scope(format!("{mode} strict {}", bits_le.len()), || {
+            let candidate = Field::<Circuit>::from_bits_le_strict(&given_bits);
+            match ConsoleField::from_bits_le_strict(bits_le) {
+                Ok(expected) => {
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                }
+                Err(_) => assert!(!Circuit::is_satisfied_in_scope()),
+            }
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_console_and_circuit_agree() {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = console::Field::<<Circuit as Environment>::Network>::size_in_bits();
+
+        // Retrieve the bits of `MODULUS - 1`, and compute the bits of `MODULUS` and `MODULUS + 1`.
+        let increment = |bits_le: &[bool]| {
+            let mut bits_le = bits_le.to_vec();
+            let position = bits_le.iter().position(|bit| !bit).unwrap();
+            bits_le[..position].iter_mut().for_each(|bit| *bit = false);
+            bits_le[position] = true;
+            bits_le
+        };
+        let modulus_minus_one = (-console::Field::<<Circuit as Environment>::Network>::one()).to_bits_le();
+        let modulus = increment(&modulus_minus_one);
+        let modulus_plus_one = increment(&modulus);
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Check the modulus boundary.
+            for bits_le in [&modulus_minus_one, &modulus, &modulus_plus_one, &vec![true; size_in_bits]] {
+                check_console_and_circuit_agree(mode, bits_le);
+            }
+
+            // Check random bit patterns, with and without excess bits.
+            for i in 0..ITERATIONS {
+                let num_bits = size_in_bits + (i as usize % 3);
+                let bits_le = (0..num_bits).map(|_| Uniform::rand(&mut rng)).collect::<Vec<bool>>();
+                check_console_and_circuit_agree(mode, &bits_le);
+            }
+        }
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 0, 0, 0, 0);
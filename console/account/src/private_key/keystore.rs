@@ -0,0 +1,385 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Address;
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm,
+    Nonce,
+};
+
+/// The current version of the keystore format.
+const KEYSTORE_VERSION: u16 = 1;
+/// The name of the key derivation function.
+const KEYSTORE_KDF: &str = "scrypt";
+/// The name of the cipher.
+const KEYSTORE_CIPHER: &str = "aes-256-gcm";
+/// The number of bytes in the salt.
+const SALT_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in the nonce.
+const NONCE_SIZE_IN_BYTES: usize = 12;
+/// The number of bytes in the derived key.
+const KEY_SIZE_IN_BYTES: usize = 32;
+/// The maximum scrypt cost parameter (log2 of N) that is accepted when decrypting.
+const MAX_LOG_N: u8 = 20;
+/// The maximum scrypt memory, `128 * r * 2^log_n` bytes, that is accepted when decrypting (1 GiB).
+const MAX_MEMORY_IN_BYTES: u128 = 1 << 30;
+/// The maximum scrypt work, `p * r * 2^log_n` block mixes, that is accepted when decrypting.
+const MAX_WORK: u128 = 1 << 23;
+
+/// The scrypt parameters of a keystore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreParams {
+    /// The log2 of the scrypt CPU/memory cost parameter `N`.
+    pub log_n: u8,
+    /// The scrypt block size parameter.
+    pub r: u32,
+    /// The scrypt parallelization parameter.
+    pub p: u32,
+}
+
+impl Default for KeystoreParams {
+    /// Returns the recommended scrypt parameters for interactive use.
+    fn default() -> Self {
+        Self { log_n: 15, r: 8, p: 1 }
+    }
+}
+
+/// The key derivation function of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreKdf {
+    /// The name of the key derivation function.
+    name: String,
+    /// The scrypt parameters.
+    #[serde(flatten)]
+    params: KeystoreParams,
+    /// The hex-encoded salt.
+    salt: String,
+}
+
+/// An encrypted private key, stored as a versioned JSON envelope.
+///
+/// The account seed is encrypted with AES-256-GCM, under a key derived from the passphrase with scrypt.
+/// The address is authenticated as associated data, so that it cannot be swapped without detection.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    /// The version of the keystore format.
+    version: u16,
+    /// The address of the encrypted private key.
+    address: String,
+    /// The key derivation function.
+    kdf: KeystoreKdf,
+    /// The name of the cipher.
+    cipher: String,
+    /// The hex-encoded nonce.
+    nonce: String,
+    /// The hex-encoded ciphertext of the account seed, including the authentication tag.
+    ciphertext: String,
+}
+
+impl Keystore {
+    /// Returns the version of the keystore format.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the address of the encrypted private key.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns the scrypt parameters.
+    pub const fn params(&self) -> &KeystoreParams {
+        &self.kdf.params
+    }
+}
+
+impl FromStr for Keystore {
+    type Err = Error;
+
+    /// Initializes the keystore from a JSON-string.
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(keystore)?)
+    }
+}
+
+impl Display for Keystore {
+    /// Displays the keystore as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Encrypts the private key into a keystore with the given passphrase, using the default scrypt parameters.
+    pub fn to_keystore<R: Rng + CryptoRng>(&self, passphrase: &str, rng: &mut R) -> Result<Keystore> {
+        self.to_keystore_with_params(passphrase, KeystoreParams::default(), rng)
+    }
+
+    /// Encrypts the private key into a keystore with the given passphrase and scrypt parameters.
+    pub fn to_keystore_with_params<R: Rng + CryptoRng>(
+        &self,
+        passphrase: &str,
+        params: KeystoreParams,
+        rng: &mut R,
+    ) -> Result<Keystore> {
+        // Ensure the scrypt cost is bounded, so that the keystore can be decrypted.
+        check_params(&params)?;
+
+        // Sample a random salt and nonce.
+        let salt: [u8; SALT_SIZE_IN_BYTES] = rng.gen();
+        let nonce: [u8; NONCE_SIZE_IN_BYTES] = rng.gen();
+        // Compute the address.
+        let address = Address::try_from(self)?.to_string();
+
+        // Derive the encryption key from the passphrase.
+        let cipher = derive_cipher(passphrase, &params, &salt)?;
+        // Encrypt the account seed, authenticating the address.
+        let seed = self.seed.to_bytes_le()?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &seed, aad: address.as_bytes() })
+            .map_err(|_| anyhow!("Failed to encrypt the private key"))?;
+
+        Ok(Keystore {
+            version: KEYSTORE_VERSION,
+            address,
+            kdf: KeystoreKdf { name: KEYSTORE_KDF.to_string(), params, salt: hex::encode(salt) },
+            cipher: KEYSTORE_CIPHER.to_string(),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypts the private key from the given keystore with the given passphrase.
+    ///
+    /// A wrong passphrase and a tampered keystore fail with the same error, after the same amount of work.
+    pub fn from_keystore(keystore: &Keystore, passphrase: &str) -> Result<Self> {
+        // Ensure the keystore format is supported.
+        ensure!(keystore.version == KEYSTORE_VERSION, "Unsupported keystore version '{}'", keystore.version);
+        ensure!(keystore.kdf.name == KEYSTORE_KDF, "Unsupported keystore KDF '{}'", keystore.kdf.name);
+        ensure!(keystore.cipher == KEYSTORE_CIPHER, "Unsupported keystore cipher '{}'", keystore.cipher);
+        // Ensure the scrypt cost is bounded, to avoid exhausting memory or time on a malicious keystore.
+        check_params(&keystore.kdf.params)?;
+
+        // Decode the salt, nonce, and ciphertext.
+        let salt = hex::decode(&keystore.kdf.salt)?;
+        let nonce = hex::decode(&keystore.nonce)?;
+        let ciphertext = hex::decode(&keystore.ciphertext)?;
+        ensure!(salt.len() == SALT_SIZE_IN_BYTES, "Invalid keystore salt length: {}", salt.len());
+        ensure!(nonce.len() == NONCE_SIZE_IN_BYTES, "Invalid keystore nonce length: {}", nonce.len());
+
+        // Derive the encryption key from the passphrase.
+        let cipher = derive_cipher(passphrase, &keystore.kdf.params, &salt)?;
+        // Decrypt the account seed, authenticating the address.
+        // Note: The authentication tag is checked in constant time, and both a wrong passphrase
+        // and a tampered ciphertext are reported with the same error.
+        let seed = cipher
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: keystore.address.as_bytes() })
+            .map_err(|_| anyhow!("Failed to decrypt the keystore: invalid passphrase or corrupted keystore"))?;
+
+        // Recover the private key.
+        let private_key = Self::try_from(Field::<N>::from_bytes_le(&seed)?)?;
+        // Ensure the private key matches the address.
+        ensure!(
+            Address::try_from(&private_key)?.to_string() == keystore.address,
+            "The keystore does not match its address '{}'",
+            keystore.address
+        );
+        Ok(private_key)
+    }
+}
+
+/// Ensures the scrypt parameters are within the cost, memory, and work bounds.
+fn check_params(params: &KeystoreParams) -> Result<()> {
+    // Ensure the cost parameter is bounded.
+    ensure!(
+        params.log_n <= MAX_LOG_N,
+        "The keystore scrypt cost (log_n = {}) exceeds the maximum of {MAX_LOG_N}",
+        params.log_n
+    );
+    // Ensure the memory is bounded.
+    let memory = 128 * params.r as u128 * (1u128 << params.log_n);
+    ensure!(
+        memory <= MAX_MEMORY_IN_BYTES,
+        "The keystore scrypt memory ({memory} bytes) exceeds the maximum of {MAX_MEMORY_IN_BYTES} bytes"
+    );
+    // Ensure the work is bounded.
+    let work = params.p as u128 * params.r as u128 * (1u128 << params.log_n);
+    ensure!(work <= MAX_WORK, "The keystore scrypt work ({work}) exceeds the maximum of {MAX_WORK}");
+    Ok(())
+}
+
+/// Returns the AES-256-GCM cipher, keyed with the scrypt derivation of the passphrase.
+fn derive_cipher(passphrase: &str, params: &KeystoreParams, salt: &[u8]) -> Result<Aes256Gcm> {
+    // Initialize the scrypt parameters.
+    let params = scrypt::Params::new(params.log_n, params.r, params.p, KEY_SIZE_IN_BYTES)
+        .map_err(|error| anyhow!("Invalid keystore scrypt parameters: {error}"))?;
+    // Derive the key.
+    let mut key = [0u8; KEY_SIZE_IN_BYTES];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+        .map_err(|error| anyhow!("Failed to derive the keystore key: {error}"))?;
+    // Initialize the cipher.
+    Aes256Gcm::new_from_slice(&key).map_err(|_| anyhow!("Invalid keystore key length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// The passphrase of the test vector.
+    const PASSPHRASE: &str = "correct horse battery staple";
+    /// The private key of the test vector.
+    const PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    /// The keystore of the test vector, computed independently of this implementation.
+    const KEYSTORE: &str = concat!(
+        r#"{"version":1,"address":"aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf","#,
+        r#""kdf":{"name":"scrypt","log_n":10,"r":8,"p":1,"#,
+        r#""salt":"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"},"#,
+        r#""cipher":"aes-256-gcm","nonce":"6465666768696a6b6c6d6e6f","#,
+        r#""ciphertext":"a7c7b44e58c4fbe97c267d97c4eef3cdbdeeb83b82af401e9906685a8e8b4312"#,
+        r#"d1429b6769999c6e3e6b5f5e1063ac3e"}"#
+    );
+
+    /// Cheap scrypt parameters, to keep the tests fast.
+    const TEST_PARAMS: KeystoreParams = KeystoreParams { log_n: 10, r: 8, p: 1 };
+
+    #[test]
+    fn test_keystore_test_vector() -> Result<()> {
+        let keystore = Keystore::from_str(KEYSTORE)?;
+        assert_eq!(keystore.version(), 1);
+        assert_eq!(keystore.params(), &TEST_PARAMS);
+
+        // Ensure the test vector decrypts to the expected private key.
+        let private_key = PrivateKey::<CurrentNetwork>::from_keystore(&keystore, PASSPHRASE)?;
+        assert_eq!(private_key, PrivateKey::from_str(PRIVATE_KEY)?);
+        // Ensure the test vector serializes back to the same JSON.
+        assert_eq!(keystore.to_string(), KEYSTORE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..3 {
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let keystore = expected.to_keystore_with_params(PASSPHRASE, TEST_PARAMS, &mut rng)?;
+            assert_eq!(keystore.address(), Address::try_from(&expected)?.to_string());
+
+            // Ensure the keystore round-trips directly, and across serialization.
+            assert_eq!(expected, PrivateKey::from_keystore(&keystore, PASSPHRASE)?);
+            let candidate = Keystore::from_str(&keystore.to_string())?;
+            assert_eq!(keystore, candidate);
+            assert_eq!(expected, PrivateKey::from_keystore(&candidate, PASSPHRASE)?);
+            let candidate: Keystore = serde_json::from_value(serde_json::to_value(&keystore)?)?;
+            assert_eq!(expected, PrivateKey::from_keystore(&candidate, PASSPHRASE)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_failures() -> Result<()> {
+        let keystore = Keystore::from_str(KEYSTORE)?;
+        let decrypt = |keystore: &Keystore, passphrase: &str| {
+            PrivateKey::<CurrentNetwork>::from_keystore(keystore, passphrase).unwrap_err().to_string()
+        };
+
+        // Ensure a wrong passphrase fails.
+        let wrong_passphrase = decrypt(&keystore, "wrong horse battery staple");
+        assert!(wrong_passphrase.contains("invalid passphrase or corrupted keystore"));
+
+        // Ensure a tampered ciphertext fails with the same error.
+        let mut tampered = keystore.clone();
+        let mut ciphertext = hex::decode(&tampered.ciphertext)?;
+        ciphertext[0] ^= 1;
+        tampered.ciphertext = hex::encode(ciphertext);
+        assert_eq!(decrypt(&tampered, PASSPHRASE), wrong_passphrase);
+
+        // Ensure a swapped address fails with the same error.
+        let mut tampered = keystore.clone();
+        tampered.address = "aleo1abcdefghijklmnopqrstuvwxyz".to_string();
+        assert_eq!(decrypt(&tampered, PASSPHRASE), wrong_passphrase);
+
+        // Ensure a tampered salt fails with the same error.
+        let mut tampered = keystore.clone();
+        tampered.kdf.salt = hex::encode([0u8; SALT_SIZE_IN_BYTES]);
+        assert_eq!(decrypt(&tampered, PASSPHRASE), wrong_passphrase);
+
+        // Ensure an unsupported version and an excessive cost are rejected.
+        let mut tampered = keystore.clone();
+        tampered.version = 2;
+        assert!(decrypt(&tampered, PASSPHRASE).contains("Unsupported keystore version"));
+        let mut tampered = keystore;
+        tampered.kdf.params.log_n = MAX_LOG_N + 1;
+        assert!(decrypt(&tampered, PASSPHRASE).contains("exceeds the maximum"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_rejects_excessive_memory() -> Result<()> {
+        let mut rng = TestRng::default();
+        let keystore = Keystore::from_str(KEYSTORE)?;
+
+        // Ensure the largest block size within the memory bound is accepted.
+        let r = (MAX_MEMORY_IN_BYTES / (128 << TEST_PARAMS.log_n)) as u32;
+        assert!(check_params(&KeystoreParams { r, ..TEST_PARAMS }).is_ok());
+
+        // Ensure a block size beyond the memory bound is rejected, before any key is derived.
+        let mut tampered = keystore;
+        tampered.kdf.params.r = r + 1;
+        let error = PrivateKey::<CurrentNetwork>::from_keystore(&tampered, PASSPHRASE).unwrap_err();
+        assert!(error.to_string().contains("scrypt memory"), "Unexpected error: {error}");
+        tampered.kdf.params.r = u32::MAX;
+        let error = PrivateKey::<CurrentNetwork>::from_keystore(&tampered, PASSPHRASE).unwrap_err();
+        assert!(error.to_string().contains("scrypt memory"), "Unexpected error: {error}");
+
+        // Ensure such a keystore cannot be created.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let params = KeystoreParams { r: r + 1, ..TEST_PARAMS };
+        assert!(private_key.to_keystore_with_params(PASSPHRASE, params, &mut rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_rejects_excessive_work() -> Result<()> {
+        let mut rng = TestRng::default();
+        let keystore = Keystore::from_str(KEYSTORE)?;
+
+        // Ensure the largest parallelization within the work bound is accepted.
+        let p = (MAX_WORK / (TEST_PARAMS.r as u128 * (1 << TEST_PARAMS.log_n))) as u32;
+        assert!(check_params(&KeystoreParams { p, ..TEST_PARAMS }).is_ok());
+
+        // Ensure a parallelization beyond the work bound is rejected, before any key is derived.
+        let mut tampered = keystore;
+        tampered.kdf.params.p = p + 1;
+        let error = PrivateKey::<CurrentNetwork>::from_keystore(&tampered, PASSPHRASE).unwrap_err();
+        assert!(error.to_string().contains("scrypt work"), "Unexpected error: {error}");
+        tampered.kdf.params.p = u32::MAX;
+        let error = PrivateKey::<CurrentNetwork>::from_keystore(&tampered, PASSPHRASE).unwrap_err();
+        assert!(error.to_string().contains("scrypt work"), "Unexpected error: {error}");
+
+        // Ensure such a keystore cannot be created.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let params = KeystoreParams { p: p + 1, ..TEST_PARAMS };
+        assert!(private_key.to_keystore_with_params(PASSPHRASE, params, &mut rng).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The domain prefix for BHP instances used as a hash function.
+const HASH_DOMAIN_PREFIX: &str = "H";
+/// The domain prefix for BHP instances used as a commitment scheme.
+const COMMITMENT_DOMAIN_PREFIX: &str = "C";
+
+/// A BHP instance that may only be used as a hash function.
+///
+/// The given domain is prefixed with a hash-specific tag, so that a `BHPHash` and a
+/// `BHPCommitment` set up with the same domain never share bases.
+#[derive(Clone)]
+pub struct BHPHash<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(BHP<E, NUM_WINDOWS, WINDOW_SIZE>);
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHash<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of the BHP hash function with the given domain.
+    pub fn setup(domain: &str) -> Result<Self> {
+        Ok(Self(BHP::setup(&format!("{HASH_DOMAIN_PREFIX}{domain}"))?))
+    }
+
+    /// Returns the underlying BHP instance.
+    pub fn bhp(&self) -> &BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+        &self.0
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHPHash<E, NUM_WINDOWS, WINDOW_SIZE> {
+    type Input = bool;
+    type Output = Field<E>;
+
+    /// Returns the BHP hash of the given input as a field element.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        self.0.hash(input)
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed
+    for BHPHash<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Input = bool;
+    type Output = Group<E>;
+
+    /// Returns the BHP hash of the given input as an affine group element.
+    fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        self.0.hash_uncompressed(input)
+    }
+}
+
+/// A BHP instance that may only be used as a commitment scheme.
+///
+/// The given domain is prefixed with a commitment-specific tag, so that a `BHPCommitment` and a
+/// `BHPHash` set up with the same domain never share bases.
+#[derive(Clone)]
+pub struct BHPCommitment<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+    BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+);
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPCommitment<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Initializes a new instance of the BHP commitment scheme with the given domain.
+    pub fn setup(domain: &str) -> Result<Self> {
+        Ok(Self(BHP::setup(&format!("{COMMITMENT_DOMAIN_PREFIX}{domain}"))?))
+    }
+
+    /// Returns the underlying BHP instance.
+    pub fn bhp(&self) -> &BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+        &self.0
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Commit
+    for BHPCommitment<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Input = bool;
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the BHP commitment of the given input and randomizer as a field element.
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        self.0.commit(input, randomizer)
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CommitUncompressed
+    for BHPCommitment<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Input = bool;
+    type Output = Group<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the BHP commitment of the given input and randomizer as an affine group element.
+    fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        self.0.commit_uncompressed(input, randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_hash_and_commitment_are_domain_separated() -> Result<()> {
+        let hasher = BHPHash::<CurrentEnvironment, 3, 57>::setup("BHPTest")?;
+        let committer = BHPCommitment::<CurrentEnvironment, 3, 57>::setup("BHPTest")?;
+
+        // Ensure the two instances do not share bases.
+        assert_ne!(hasher.bhp().bases(), committer.bhp().bases());
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let input = (0..hasher.bhp().window_size()).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the hashes differ for identical input.
+            assert_ne!(hasher.hash(&input)?, committer.bhp().hash(&input)?);
+            assert_ne!(hasher.hash_uncompressed(&input)?, committer.bhp().hash_uncompressed(&input)?);
+
+            // Ensure the commitments differ for identical input and randomizer.
+            let randomizer = Uniform::rand(&mut rng);
+            assert_ne!(hasher.bhp().commit(&input, &randomizer)?, committer.commit(&input, &randomizer)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrappers_match_prefixed_bhp() -> Result<()> {
+        let hasher = BHPHash::<CurrentEnvironment, 3, 57>::setup("BHPTest")?;
+        let committer = BHPCommitment::<CurrentEnvironment, 3, 57>::setup("BHPTest")?;
+        let expected_hasher = BHP256::<CurrentEnvironment>::setup("HBHPTest")?;
+        let expected_committer = BHP256::<CurrentEnvironment>::setup("CBHPTest")?;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let input = (0..hasher.bhp().window_size()).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let randomizer = Uniform::rand(&mut rng);
+
+            assert_eq!(expected_hasher.hash(&input)?, hasher.hash(&input)?);
+            assert_eq!(expected_committer.commit(&input, &randomizer)?, committer.commit(&input, &randomizer)?);
+        }
+        Ok(())
+    }
+}
@@ -25,3 +25,32 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHP<
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_hash_bits_matches_packed_bytes() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a value, and hash its bits directly.
+            let value = Field::<CurrentEnvironment>::rand(&mut rng);
+            let candidate = bhp.hash(&value.to_bits_le())?;
+
+            // Ensure the hash matches the hash of the bits of its packed (byte-aligned) representation.
+            let bytes = value.to_bytes_le()?;
+            let bits = bytes.to_bits_le();
+            assert_eq!(candidate, bhp.hash(&bits[..Field::<CurrentEnvironment>::size_in_bits()])?);
+        }
+        Ok(())
+    }
+}
@@ -19,6 +19,8 @@ use hasher::BHPHasher;
 
 mod commit;
 mod commit_uncompressed;
+mod domain;
+pub use domain::*;
 mod hash;
 mod hash_uncompressed;
 
@@ -22,7 +22,7 @@
 pub use snarkvm_console_types::prelude::*;
 
 pub mod bhp;
-pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
+pub use bhp::{BHPCommitment, BHPHash, BHP, BHP1024, BHP256, BHP512, BHP768};
 
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
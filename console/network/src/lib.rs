@@ -106,6 +106,9 @@ pub trait Network:
     const MAX_PROVER_SOLUTIONS: usize = 1 << 20; // 1,048,576 prover solutions
     /// The number of blocks per epoch (1 hour).
     const NUM_BLOCKS_PER_EPOCH: u32 = 1 << 8; // 256 blocks == ~1 hour
+    /// The block height at which the version bits in the block metadata are activated.
+    /// Below this height, the version bits must be zero, and are excluded from the metadata hash.
+    const VERSION_BITS_ACTIVATION_HEIGHT: u32 = 1 << 22; // 4,194,304 blocks
 
     /// The maximum recursive depth of a value and/or entry.
     /// Note: This value must be strictly less than u8::MAX.
@@ -130,6 +133,11 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 8;
 
+    /// The maximum number of constraints in a deployed function circuit.
+    const MAX_DEPLOYMENT_CONSTRAINTS: u64 = 1 << 20; // 1,048,576 constraints
+    /// The maximum number of variables in a deployed function circuit.
+    const MAX_DEPLOYMENT_VARIABLES: u64 = 1 << 21; // 2,097,152 variables
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
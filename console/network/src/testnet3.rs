@@ -395,4 +395,45 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_hash_to_group_psd4() {
+        use snarkvm_console_algorithms::Elligator2;
+
+        // Ensure an empty input fails.
+        assert!(CurrentNetwork::hash_to_group_psd4(&[]).is_err());
+
+        let rng = &mut TestRng::default();
+
+        for num_inputs in 1..8 {
+            let input = (0..num_inputs).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+
+            // Compute the hash to group.
+            let candidate = CurrentNetwork::hash_to_group_psd4(&input).unwrap();
+            assert!((*candidate).to_affine().is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(candidate, CurrentNetwork::hash_to_group_psd4(&input).unwrap());
+
+            // Ensure the construction is `Elligator2(h0) + Elligator2(h1)`, where `(h0, h1) = HashMany(input, 2)`.
+            let hashes = CurrentNetwork::hash_many_psd4(&input, 2);
+            let (h0, _) = Elligator2::<CurrentNetwork>::encode(&hashes[0]).unwrap();
+            let (h1, _) = Elligator2::<CurrentNetwork>::encode(&hashes[1]).unwrap();
+            assert_eq!(candidate, h0 + h1);
+        }
+    }
+
+    #[test]
+    fn test_hash_to_group_psd4_known_answers() -> Result<()> {
+        // Note: These vectors freeze the construction, as programs depend on the outputs of `hash_to_group.psd4`.
+        let vectors: [(&[u64], &str); 4] = [
+            (&[0], "1340569405774666880086280831583736121173645508947368710913857043772472114471group"),
+            (&[1], "5445189525635801907743074992293780716527905878586435453771124347933535666196group"),
+            (&[1, 2], "7114902163628037038022569099986788048136932105898249210335438158401342912026group"),
+            (&[1, 2, 3, 4, 5], "3133797318836858055878652768235072592010632788799047007296726541257057727460group"),
+        ];
+        for (input, expected) in vectors {
+            let input = input.iter().map(|value| Field::from_u64(*value)).collect::<Vec<_>>();
+            assert_eq!(CurrentNetwork::hash_to_group_psd4(&input)?, Group::from_str(expected)?);
+        }
+        Ok(())
+    }
 }
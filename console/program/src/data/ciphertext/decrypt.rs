@@ -20,7 +20,7 @@ impl<N: Network> Ciphertext<N> {
     /// Decrypts `self` into plaintext using the given account view key & nonce.
     pub fn decrypt(&self, view_key: ViewKey<N>, nonce: Group<N>) -> Result<Plaintext<N>> {
         // Compute the plaintext view key.
-        let plaintext_view_key = (nonce * *view_key).to_x_coordinate();
+        let plaintext_view_key = nonce.mul_constant_time(&view_key).to_x_coordinate();
         // Decrypt the record.
         self.decrypt_symmetric(plaintext_view_key)
     }
@@ -19,6 +19,7 @@ mod decrypt;
 mod equal;
 mod from_bits;
 mod from_fields;
+mod num_fields;
 mod num_randomizers;
 mod parse;
 mod serialize;
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{LiteralType, PlaintextType};
+use snarkvm_console_types::{Address, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+
+impl<N: Network> Ciphertext<N> {
+    /// Returns the number of field elements in the ciphertext.
+    pub fn num_fields(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Ensures the number of field elements in the ciphertext matches the given plaintext type.
+    ///
+    /// Literals (other than strings) have a fixed size, and are checked exactly. As the size of a string or
+    /// a struct depends on its contents or its definition, these are only checked to be within the allowed size.
+    pub fn validate_against_type(&self, plaintext_type: &PlaintextType<N>) -> Result<()> {
+        let num_fields = self.num_fields();
+        // Ensure the ciphertext is not empty, and does not exceed the maximum allowed size.
+        ensure!(
+            num_fields > 0 && num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize,
+            "Ciphertext has {num_fields} field elements, expected between 1 and {}",
+            N::MAX_DATA_SIZE_IN_FIELDS
+        );
+
+        match plaintext_type {
+            PlaintextType::Literal(LiteralType::String) => {
+                // Compute the number of field elements for the smallest and largest strings.
+                let min_fields = num_literal_fields::<N>(0);
+                let max_fields = num_literal_fields::<N>(N::MAX_STRING_BYTES as usize * 8);
+                ensure!(
+                    (min_fields..=max_fields).contains(&num_fields),
+                    "Ciphertext has {num_fields} field elements, which is invalid for type '{plaintext_type}'"
+                );
+            }
+            PlaintextType::Literal(literal_type) => {
+                // Compute the number of field elements for the literal.
+                let expected = num_literal_fields::<N>(literal_size_in_bits::<N>(literal_type));
+                ensure!(
+                    num_fields == expected,
+                    "Ciphertext has {num_fields} field elements, expected {expected} for type '{plaintext_type}'"
+                );
+            }
+            PlaintextType::Struct(..) => (),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of field elements needed to encode a literal plaintext with the given size in bits.
+fn num_literal_fields<N: Network>(size_in_bits: usize) -> usize {
+    // The encoding consists of the variant (2 bits), the literal variant (8 bits), the literal size (16 bits),
+    // the literal, and the terminus bit.
+    let num_bits = 2 + 8 + 16 + size_in_bits + 1;
+    // Compute the number of field elements, rounding up.
+    (num_bits + Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits()
+}
+
+/// Returns the size in bits of a literal of the given (fixed-size) type.
+fn literal_size_in_bits<N: Network>(literal_type: &LiteralType) -> usize {
+    match literal_type {
+        LiteralType::Address => Address::<N>::size_in_bits(),
+        LiteralType::Boolean => Boolean::<N>::size_in_bits(),
+        LiteralType::Field => Field::<N>::size_in_bits(),
+        LiteralType::Group => Group::<N>::size_in_bits(),
+        LiteralType::I8 => I8::<N>::size_in_bits(),
+        LiteralType::I16 => I16::<N>::size_in_bits(),
+        LiteralType::I32 => I32::<N>::size_in_bits(),
+        LiteralType::I64 => I64::<N>::size_in_bits(),
+        LiteralType::I128 => I128::<N>::size_in_bits(),
+        LiteralType::U8 => U8::<N>::size_in_bits(),
+        LiteralType::U16 => U16::<N>::size_in_bits(),
+        LiteralType::U32 => U32::<N>::size_in_bits(),
+        LiteralType::U64 => U64::<N>::size_in_bits(),
+        LiteralType::U128 => U128::<N>::size_in_bits(),
+        LiteralType::Scalar => Scalar::<N>::size_in_bits(),
+        LiteralType::String => N::MAX_STRING_BYTES as usize * 8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_validate_against_type(literal: Literal<CurrentNetwork>, rng: &mut TestRng) -> Result<()> {
+        let plaintext_type = PlaintextType::Literal(literal.to_type());
+
+        // Encrypt the plaintext.
+        let plaintext = Plaintext::from(literal);
+        let ciphertext = plaintext.encrypt_symmetric(Uniform::rand(rng))?;
+        assert_eq!(ciphertext.num_fields(), plaintext.to_fields()?.len());
+
+        // Ensure the ciphertext matches its type.
+        ciphertext.validate_against_type(&plaintext_type)?;
+
+        // Ensure a truncated ciphertext does not match its type.
+        let truncated = Ciphertext::<CurrentNetwork>(ciphertext[..ciphertext.num_fields() - 1].to_vec());
+        assert!(truncated.validate_against_type(&plaintext_type).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_against_type() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            check_validate_against_type(Literal::U64(U64::rand(&mut rng)), &mut rng)?;
+            check_validate_against_type(Literal::Field(Field::rand(&mut rng)), &mut rng)?;
+            check_validate_against_type(Literal::Group(Group::rand(&mut rng)), &mut rng)?;
+            check_validate_against_type(Literal::Boolean(Boolean::rand(&mut rng)), &mut rng)?;
+        }
+
+        // Ensure a ciphertext does not match a literal type of a different size.
+        let plaintext = Plaintext::from(Literal::Field(Field::<CurrentNetwork>::rand(&mut rng)));
+        let ciphertext = plaintext.encrypt_symmetric(Uniform::rand(&mut rng))?;
+        assert!(ciphertext.validate_against_type(&PlaintextType::Literal(LiteralType::U8)).is_err());
+        Ok(())
+    }
+}
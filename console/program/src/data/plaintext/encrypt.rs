@@ -20,11 +20,26 @@ impl<N: Network> Plaintext<N> {
     /// Encrypts `self` to the given address under the given randomizer.
     pub fn encrypt(&self, address: &Address<N>, randomizer: Scalar<N>) -> Result<Ciphertext<N>> {
         // Compute the plaintext view key.
-        let plaintext_view_key = (**address * randomizer).to_x_coordinate();
+        let plaintext_view_key = address.mul_constant_time(&randomizer).to_x_coordinate();
         // Encrypt the plaintext.
         self.encrypt_symmetric(plaintext_view_key)
     }
 
+    /// Encrypts `self` to the given address under a randomizer derived deterministically from the given seed.
+    ///
+    /// This is intended for reproducible tests. Reusing a seed reuses the randomizer, so use `encrypt` otherwise.
+    pub fn encrypt_with_seed(&self, address: &Address<N>, seed: [u8; 32]) -> Result<Ciphertext<N>> {
+        // Pack the seed into field elements, following the domain separator.
+        let mut preimage = vec![Field::new_domain_separator("AleoEncryptionSeed0")];
+        for chunk in seed.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+            preimage.push(Field::from_bits_le(chunk)?);
+        }
+        // Derive the randomizer from the seed.
+        let randomizer = N::hash_to_scalar_psd4(&preimage)?;
+        // Encrypt the plaintext.
+        self.encrypt(address, randomizer)
+    }
+
     /// Encrypts `self` under the given plaintext view key.
     pub fn encrypt_symmetric(&self, plaintext_view_key: Field<N>) -> Result<Ciphertext<N>> {
         // Determine the number of randomizers needed to encrypt the plaintext.
@@ -48,3 +63,44 @@ impl<N: Network> Plaintext<N> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::{PrivateKey, ViewKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_encrypt_with_seed() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample an address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: 2field } }")?;
+        let seed = [7u8; 32];
+
+        // Ensure the same seed produces the same ciphertext.
+        let ciphertext = plaintext.encrypt_with_seed(&address, seed)?;
+        assert_eq!(ciphertext, plaintext.encrypt_with_seed(&address, seed)?);
+
+        // Ensure a different seed produces a different ciphertext.
+        let mut other_seed = seed;
+        other_seed[0] ^= 1;
+        assert_ne!(ciphertext, plaintext.encrypt_with_seed(&address, other_seed)?);
+
+        // Ensure the ciphertext decrypts with the derived randomizer.
+        let mut preimage = vec![Field::new_domain_separator("AleoEncryptionSeed0")];
+        for chunk in seed.to_bits_le().chunks(Field::<CurrentNetwork>::size_in_data_bits()) {
+            preimage.push(Field::from_bits_le(chunk)?);
+        }
+        let randomizer = CurrentNetwork::hash_to_scalar_psd4(&preimage)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        assert_eq!(plaintext, ciphertext.decrypt(view_key, nonce)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of field elements in the frame header, i.e. `(length, type tag)`.
+const FRAME_HEADER_SIZE: usize = 2;
+
+impl<N: Network> Plaintext<N> {
+    /// The type tag of a literal plaintext.
+    const LITERAL_TAG: u8 = 0;
+    /// The type tag of a struct plaintext.
+    const STRUCT_TAG: u8 = 1;
+
+    /// Returns this plaintext as a list of field elements, prefixed by its length and type tag.
+    /// The framed encoding is self-delimiting, so multiple plaintexts may be concatenated in one list.
+    ///
+    /// Note: This encoding is not used in consensus; see `Plaintext::to_fields` for the unframed encoding.
+    pub fn to_fields_framed(&self) -> Result<Vec<Field<N>>> {
+        // Encode the plaintext as field elements.
+        let fields = self.to_fields()?;
+        // Construct the length, as the number of field elements in the plaintext.
+        let length = match u32::try_from(fields.len()) {
+            Ok(length) => U32::<N>::new(length),
+            Err(_) => bail!("Plaintext exceeds maximum allowed size"),
+        };
+        // Construct the type tag.
+        let tag = match self {
+            Self::Literal(..) => Field::from_u8(Self::LITERAL_TAG),
+            Self::Struct(..) => Field::from_u8(Self::STRUCT_TAG),
+        };
+        // Prepend the length and type tag to the field elements.
+        Ok([length.to_field()?, tag].into_iter().chain(fields).collect())
+    }
+
+    /// Initializes a plaintext from the framed encoding at the start of the given list of field elements,
+    /// returning the plaintext and the number of field elements consumed.
+    pub fn from_fields_framed(fields: &[Field<N>]) -> Result<(Self, usize)> {
+        // Ensure the frame header is present.
+        ensure!(fields.len() >= FRAME_HEADER_SIZE, "Framed plaintext is missing its header");
+        // Recover the length.
+        let length = *U32::<N>::from_field(&fields[0])? as usize;
+        // Ensure the length is within the maximum allowed size.
+        ensure!(length <= N::MAX_DATA_SIZE_IN_FIELDS as usize, "Plaintext exceeds maximum allowed size");
+        // Ensure the field elements are not truncated.
+        let num_consumed = FRAME_HEADER_SIZE + length;
+        ensure!(
+            fields.len() >= num_consumed,
+            "Framed plaintext is truncated: expected {length} field elements, found {}",
+            fields.len() - FRAME_HEADER_SIZE
+        );
+
+        // Recover the plaintext.
+        let plaintext = Self::from_fields(&fields[FRAME_HEADER_SIZE..num_consumed])?;
+        // Ensure the type tag matches the plaintext.
+        let tag = match plaintext {
+            Self::Literal(..) => Field::from_u8(Self::LITERAL_TAG),
+            Self::Struct(..) => Field::from_u8(Self::STRUCT_TAG),
+        };
+        ensure!(fields[1] == tag, "Framed plaintext has a mismatching type tag");
+
+        Ok((plaintext, num_consumed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a sequence of mixed literal and struct plaintexts.
+    fn sample_plaintexts() -> Vec<Plaintext<CurrentNetwork>> {
+        [
+            "5u8",
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 100u64 }",
+            "true",
+            "{ foo: { bar: 1field, baz: 2u32 }, qux: 0group }",
+            "\"hello world\"",
+            "{ a: 1i128, b: { c: 0scalar } }",
+        ]
+        .iter()
+        .map(|string| Plaintext::from_str(string).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_framed_round_trip() -> Result<()> {
+        for expected in sample_plaintexts() {
+            let fields = expected.to_fields_framed()?;
+            // Ensure the frame header prefixes the unframed encoding.
+            assert_eq!(fields[FRAME_HEADER_SIZE..], expected.to_fields()?[..]);
+
+            let (candidate, num_consumed) = Plaintext::from_fields_framed(&fields)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(fields.len(), num_consumed);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_framed_sequence() -> Result<()> {
+        let expected = sample_plaintexts();
+
+        // Concatenate the framed encodings of all of the plaintexts.
+        let mut fields = vec![];
+        for plaintext in &expected {
+            fields.extend(plaintext.to_fields_framed()?);
+        }
+
+        // Stream the plaintexts back out of the field elements.
+        let mut candidates = vec![];
+        let mut offset = 0;
+        while offset < fields.len() {
+            let (candidate, num_consumed) = Plaintext::from_fields_framed(&fields[offset..])?;
+            candidates.push(candidate);
+            offset += num_consumed;
+        }
+        assert_eq!(expected, candidates);
+        assert_eq!(fields.len(), offset);
+        Ok(())
+    }
+
+    #[test]
+    fn test_framed_truncation() -> Result<()> {
+        for plaintext in sample_plaintexts() {
+            let fields = plaintext.to_fields_framed()?;
+            // Ensure every truncation of the framed encoding fails.
+            for num_fields in 0..fields.len() {
+                assert!(Plaintext::<CurrentNetwork>::from_fields_framed(&fields[..num_fields]).is_err());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_framed_mismatching_tag() -> Result<()> {
+        for plaintext in sample_plaintexts() {
+            let mut fields = plaintext.to_fields_framed()?;
+            // Flip the type tag.
+            fields[1] = match fields[1].is_zero() {
+                true => Field::one(),
+                false => Field::zero(),
+            };
+            assert!(Plaintext::<CurrentNetwork>::from_fields_framed(&fields).is_err());
+        }
+        Ok(())
+    }
+}
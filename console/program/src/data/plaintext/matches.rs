@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{PlaintextType, Struct};
+
+impl<N: Network> Plaintext<N> {
+    /// Checks that the plaintext matches the layout of the given plaintext type, using the given struct definitions.
+    ///
+    /// On a mismatch, the error names the path to the mismatched member,
+    /// e.g. `token.metadata.uri: expected u128, found field`.
+    pub fn matches(
+        &self,
+        plaintext_type: &PlaintextType<N>,
+        structs: &IndexMap<Identifier<N>, Struct<N>>,
+    ) -> Result<()> {
+        self.matches_at_depth(plaintext_type, structs, 0)
+    }
+
+    /// Checks that the plaintext matches the layout of the given plaintext type, starting from the given depth.
+    /// This is used for plaintexts that are nested in another value, such as a record entry.
+    ///
+    /// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
+    pub fn matches_at_depth(
+        &self,
+        plaintext_type: &PlaintextType<N>,
+        structs: &IndexMap<Identifier<N>, Struct<N>>,
+        depth: usize,
+    ) -> Result<()> {
+        // Initialize the path with the struct name, if the plaintext type is a struct.
+        let path = match plaintext_type {
+            PlaintextType::Literal(..) => String::new(),
+            PlaintextType::Struct(struct_name) => struct_name.to_string(),
+        };
+        self.matches_internal(plaintext_type, structs, &path, depth)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Checks that the plaintext matches the layout of the given plaintext type.
+    ///
+    /// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
+    fn matches_internal(
+        &self,
+        plaintext_type: &PlaintextType<N>,
+        structs: &IndexMap<Identifier<N>, Struct<N>>,
+        path: &str,
+        depth: usize,
+    ) -> Result<()> {
+        // Prefix errors with the path to the current member, if there is one.
+        let prefix = match path.is_empty() {
+            true => String::new(),
+            false => format!("{path}: "),
+        };
+
+        // If the depth exceeds the maximum depth, then the plaintext type is invalid.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "{prefix}exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => match self {
+                // If the plaintext is a literal, it must match the literal type.
+                Self::Literal(literal, ..) => {
+                    let found = literal.to_type();
+                    match found == *literal_type {
+                        true => Ok(()),
+                        false => bail!("{prefix}expected {literal_type}, found {found}"),
+                    }
+                }
+                // If the plaintext is a struct, this is a mismatch.
+                Self::Struct(..) => bail!("{prefix}expected {literal_type}, found struct"),
+            },
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct definition.
+                let struct_ = match structs.get(struct_name) {
+                    Some(struct_) => struct_,
+                    None => bail!("{prefix}struct '{struct_name}' is not defined"),
+                };
+
+                // Ensure the struct name matches.
+                if struct_.name() != struct_name {
+                    bail!("{prefix}expected struct '{struct_name}', found struct '{}'", struct_.name())
+                }
+
+                // Retrieve the struct members.
+                let members = match self {
+                    Self::Literal(literal, ..) => bail!("{prefix}expected {struct_name}, found {}", literal.to_type()),
+                    Self::Struct(members, ..) => members,
+                };
+
+                // Ensure the number of struct members does not exceed the maximum.
+                let num_members = members.len();
+                ensure!(num_members <= N::MAX_DATA_ENTRIES, "{prefix}cannot exceed {} members", N::MAX_DATA_ENTRIES);
+
+                // Ensure the number of struct members match.
+                let expected_num_members = struct_.members().len();
+                if expected_num_members != num_members {
+                    bail!("{prefix}expected {expected_num_members} members, found {num_members} members")
+                }
+
+                // Ensure the struct members match, in the same order.
+                for (i, ((expected_name, expected_type), (member_name, member))) in
+                    struct_.members().iter().zip_eq(members.iter()).enumerate()
+                {
+                    // Ensure the member name matches.
+                    if expected_name != member_name {
+                        bail!("{prefix}expected member '{i}' to be '{expected_name}', found '{member_name}'")
+                    }
+                    // Ensure the member plaintext matches (recursive call).
+                    member.matches_internal(expected_type, structs, &format!("{path}.{member_name}"), depth + 1)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_structs() -> Result<IndexMap<Identifier<CurrentNetwork>, Struct<CurrentNetwork>>> {
+        let structs = [
+            "struct token:\n    amount as u64;\n    metadata as metadata;",
+            "struct metadata:\n    name as field;\n    link as link;",
+            "struct link:\n    uri as u128;\n    version as u8;",
+        ];
+        let mut map = IndexMap::new();
+        for string in structs {
+            let struct_ = Struct::<CurrentNetwork>::from_str(string)?;
+            map.insert(*struct_.name(), struct_);
+        }
+        Ok(map)
+    }
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let structs = sample_structs()?;
+        let token = PlaintextType::from_str("token")?;
+
+        // Ensure a well-formed plaintext matches.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ amount: 5u64, metadata: { name: 1field, link: { uri: 7u128, version: 1u8 } } }",
+        )?;
+        plaintext.matches(&token, &structs)?;
+
+        // Ensure a literal matches its own type only.
+        let literal = Plaintext::<CurrentNetwork>::from_str("5u64")?;
+        literal.matches(&PlaintextType::from_str("u64")?, &structs)?;
+        let error = literal.matches(&PlaintextType::from_str("u8")?, &structs).unwrap_err();
+        assert_eq!(error.to_string(), "expected u8, found u64");
+
+        // Ensure a mismatch at depth 3 reports the path to the member.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ amount: 5u64, metadata: { name: 1field, link: { uri: 7field, version: 1u8 } } }",
+        )?;
+        let error = plaintext.matches(&token, &structs).unwrap_err();
+        assert_eq!(error.to_string(), "token.metadata.link.uri: expected u128, found field");
+
+        // Ensure a misnamed member at depth 3 reports the path to its struct.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ amount: 5u64, metadata: { name: 1field, link: { url: 7u128, version: 1u8 } } }",
+        )?;
+        let error = plaintext.matches(&token, &structs).unwrap_err();
+        assert_eq!(error.to_string(), "token.metadata.link: expected member '0' to be 'uri', found 'url'");
+
+        // Ensure a missing member is rejected.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ amount: 5u64, metadata: { name: 1field, link: { uri: 7u128 } } }",
+        )?;
+        let error = plaintext.matches(&token, &structs).unwrap_err();
+        assert_eq!(error.to_string(), "token.metadata.link: expected 2 members, found 1 members");
+
+        // Ensure a literal in place of a struct is rejected.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ amount: 5u64, metadata: 1field }")?;
+        let error = plaintext.matches(&token, &structs).unwrap_err();
+        assert_eq!(error.to_string(), "token.metadata: expected metadata, found field");
+
+        // Ensure an undefined struct is rejected.
+        assert!(plaintext.matches(&PlaintextType::from_str("unknown")?, &structs).is_err());
+        Ok(())
+    }
+}
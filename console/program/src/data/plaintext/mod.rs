@@ -18,8 +18,10 @@ mod bytes;
 mod encrypt;
 mod equal;
 mod find;
+mod framed;
 mod from_bits;
 mod from_fields;
+mod matches;
 mod num_randomizers;
 mod parse;
 mod serialize;
@@ -20,7 +20,7 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
         // Compute the record view key.
-        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        let record_view_key = self.nonce.mul_constant_time(view_key).to_x_coordinate();
         // Decrypt the record.
         self.decrypt_symmetric(&record_view_key)
     }
@@ -35,40 +35,36 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         self.decrypt_with_randomizers(&randomizers)
     }
 
+    /// Decrypts `self` into plaintext using the given record view key,
+    /// such as one recovered from a `ViewKeyCapsule`.
+    pub fn decrypt_with_record_view_key(&self, record_view_key: &Field<N>) -> Result<Record<N, Plaintext<N>>> {
+        self.decrypt_symmetric(record_view_key)
+    }
+
     /// Decrypts `self` into plaintext using the given randomizers.
     fn decrypt_with_randomizers(&self, randomizers: &[Field<N>]) -> Result<Record<N, Plaintext<N>>> {
-        // Initialize an index to keep track of the randomizer index.
-        let mut index: usize = 0;
+        // Retrieve the randomizer layout of the record.
+        let (offsets, num_randomizers) = self.randomizer_layout()?;
+        // Ensure the number of randomizers matches the layout.
+        ensure!(randomizers.len() == num_randomizers as usize, "Incorrect number of randomizers for the record");
 
         // Decrypt the owner.
-        let owner = match self.owner.is_public() {
-            true => self.owner.decrypt_with_randomizer(&[])?,
-            false => self.owner.decrypt_with_randomizer(&[randomizers[index]])?,
+        let owner = match self.owner_randomizer_index() {
+            None => self.owner.decrypt_with_randomizer(&[])?,
+            Some(index) => self.owner.decrypt_with_randomizer(&[randomizers[index]])?,
         };
 
-        // Increment the index if the owner is private.
-        if owner.is_private() {
-            index += 1;
-        }
-
         // Decrypt the gates.
-        let gates = match self.gates.is_public() {
-            true => self.gates.decrypt_with_randomizer(&[])?,
-            false => self.gates.decrypt_with_randomizer(&[randomizers[index]])?,
+        let gates = match self.gates_randomizer_index() {
+            None => self.gates.decrypt_with_randomizer(&[])?,
+            Some(index) => self.gates.decrypt_with_randomizer(&[randomizers[index]])?,
         };
 
-        // Increment the index if the gates is private.
-        if gates.is_private() {
-            index += 1;
-        }
-
         // Decrypt the program data.
         let mut decrypted_data = IndexMap::with_capacity(self.data.len());
-        for (id, entry, num_randomizers) in self.data.iter().map(|(id, entry)| (id, entry, entry.num_randomizers())) {
-            // Retrieve the result for `num_randomizers`.
-            let num_randomizers = num_randomizers? as usize;
+        for ((id, entry), (_, start, count)) in self.data.iter().zip_eq(offsets) {
             // Retrieve the randomizers for this entry.
-            let randomizers = &randomizers[index..index + num_randomizers];
+            let randomizers = &randomizers[start as usize..(start + count) as usize];
             // Decrypt the entry.
             let entry = match entry {
                 // Constant entries do not need to be decrypted.
@@ -88,8 +84,6 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             if decrypted_data.insert(*id, entry).is_some() {
                 bail!("Duplicate identifier in record: {}", id);
             }
-            // Increment the index.
-            index += num_randomizers;
         }
 
         // Return the decrypted record.
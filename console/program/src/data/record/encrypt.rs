@@ -22,7 +22,7 @@ impl<N: Network> Record<N, Plaintext<N>> {
         // Ensure the randomizer corresponds to the record nonce.
         if self.nonce == N::g_scalar_multiply(&randomizer) {
             // Compute the record view key.
-            let record_view_key = (**self.owner * randomizer).to_x_coordinate();
+            let record_view_key = self.owner.mul_constant_time(&randomizer).to_x_coordinate();
             // Encrypt the record.
             self.encrypt_symmetric(&record_view_key)
         } else {
@@ -42,38 +42,28 @@ impl<N: Network> Record<N, Plaintext<N>> {
 
     /// Encrypts `self` under the given randomizers.
     fn encrypt_with_randomizers(&self, randomizers: &[Field<N>]) -> Result<Record<N, Ciphertext<N>>> {
-        // Initialize an index to keep track of the randomizer index.
-        let mut index: usize = 0;
+        // Retrieve the randomizer layout of the record.
+        let (offsets, num_randomizers) = self.randomizer_layout()?;
+        // Ensure the number of randomizers matches the layout.
+        ensure!(randomizers.len() == num_randomizers as usize, "Incorrect number of randomizers for the record");
 
         // Encrypt the owner.
-        let owner = match self.owner.is_public() {
-            true => self.owner.encrypt_with_randomizer(&[])?,
-            false => self.owner.encrypt_with_randomizer(&[randomizers[index]])?,
+        let owner = match self.owner_randomizer_index() {
+            None => self.owner.encrypt_with_randomizer(&[])?,
+            Some(index) => self.owner.encrypt_with_randomizer(&[randomizers[index]])?,
         };
 
-        // Increment the index if the owner is private.
-        if owner.is_private() {
-            index += 1;
-        }
-
         // Encrypt the gates.
-        let gates = match self.gates.is_public() {
-            true => self.gates.encrypt_with_randomizer(&[])?,
-            false => self.gates.encrypt_with_randomizer(&[randomizers[index]])?,
+        let gates = match self.gates_randomizer_index() {
+            None => self.gates.encrypt_with_randomizer(&[])?,
+            Some(index) => self.gates.encrypt_with_randomizer(&[randomizers[index]])?,
         };
 
-        // Increment the index if the gates is private.
-        if gates.is_private() {
-            index += 1;
-        }
-
         // Encrypt the data.
         let mut encrypted_data = IndexMap::with_capacity(self.data.len());
-        for (id, entry, num_randomizers) in self.data.iter().map(|(id, entry)| (id, entry, entry.num_randomizers())) {
-            // Retrieve the result for `num_randomizers`.
-            let num_randomizers = num_randomizers? as usize;
+        for ((id, entry), (_, start, count)) in self.data.iter().zip_eq(offsets) {
             // Retrieve the randomizers for this entry.
-            let randomizers = &randomizers[index..index + num_randomizers];
+            let randomizers = &randomizers[start as usize..(start + count) as usize];
             // Encrypt the entry.
             let entry = match entry {
                 // Constant entries do not need to be encrypted.
@@ -94,8 +84,6 @@ impl<N: Network> Record<N, Plaintext<N>> {
             if encrypted_data.insert(*id, entry).is_some() {
                 bail!("Duplicate identifier in record: {}", id);
             }
-            // Increment the index.
-            index += num_randomizers;
         }
 
         // Return the encrypted record.
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns a capsule that encrypts the record view key of `self` to the given auditor address,
+    /// allowing the auditor to decrypt `self` without the owner's account view key.
+    pub fn encrypt_view_key_for<R: Rng + CryptoRng>(
+        &self,
+        owner_view_key: &ViewKey<N>,
+        auditor_address: &Address<N>,
+        rng: &mut R,
+    ) -> Result<ViewKeyCapsule<N>> {
+        // Ensure the view key belongs to the owner of the record.
+        ensure!(self.is_owner(owner_view_key), "The given view key does not belong to the record owner");
+        // Compute the record view key.
+        let record_view_key = self.nonce.mul_constant_time(owner_view_key).to_x_coordinate();
+        // Encrypt the record view key to the auditor.
+        ViewKeyCapsule::encrypt(&record_view_key, auditor_address, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Samples a record ciphertext, owned by the given address.
+    fn sample_record(
+        address: Address<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<(Record<CurrentNetwork, Plaintext<CurrentNetwork>>, Record<CurrentNetwork, Ciphertext<CurrentNetwork>>)>
+    {
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner: Owner::Private(Plaintext::from(Literal::Address(address))),
+            gates: Balance::Private(Plaintext::from(Literal::U64(U64::new(u64::rand(rng) >> 12)))),
+            data: IndexMap::from_iter(vec![(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        let ciphertext = record.encrypt(randomizer)?;
+        Ok((record, ciphertext))
+    }
+
+    #[test]
+    fn test_encrypt_view_key_for() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the owner and the auditor.
+            let owner_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let owner_view_key = ViewKey::try_from(&owner_private_key)?;
+            let owner_address = Address::try_from(&owner_private_key)?;
+            let auditor_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let auditor_address = auditor_view_key.to_address();
+
+            // Sample two records for the owner.
+            let (record, ciphertext) = sample_record(owner_address, &mut rng)?;
+            let (other_record, other_ciphertext) = sample_record(owner_address, &mut rng)?;
+
+            // Ensure the auditor decrypts the intended record.
+            let capsule = ciphertext.encrypt_view_key_for(&owner_view_key, &auditor_address, &mut rng)?;
+            let record_view_key = capsule.decrypt(&auditor_view_key)?;
+            assert_eq!(record, ciphertext.decrypt_with_record_view_key(&record_view_key)?);
+
+            // Ensure the auditor cannot decrypt a different record.
+            if let Ok(candidate) = other_ciphertext.decrypt_with_record_view_key(&record_view_key) {
+                assert_ne!(other_record, candidate);
+            }
+            assert_eq!(other_record, other_ciphertext.decrypt(&owner_view_key)?);
+
+            // Ensure only the owner may create a capsule.
+            assert!(ciphertext.encrypt_view_key_for(&auditor_view_key, &auditor_address, &mut rng).is_err());
+        }
+        Ok(())
+    }
+}
@@ -20,6 +20,9 @@ mod find;
 mod num_randomizers;
 mod parse;
 mod to_bits;
+mod visibility;
+
+pub use visibility::EntryVisibility;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext};
 use snarkvm_console_network::Network;
@@ -17,8 +17,8 @@
 use super::*;
 
 impl<N: Network, Private: Visibility> Entry<N, Private> {
-    /// Returns the number of field elements to encode `self`.
-    pub(crate) fn num_randomizers(&self) -> Result<u16> {
+    /// Returns the number of field elements to encode `self`, which is the number of randomizers it consumes.
+    pub fn num_randomizers(&self) -> Result<u16> {
         match self {
             // Constant and public entries do not need to be encrypted.
             Self::Constant(..) | Self::Public(..) => Ok(0u16),
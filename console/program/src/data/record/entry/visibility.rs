@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The visibility of an entry stored in program data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntryVisibility {
+    /// A constant entry.
+    Constant,
+    /// A publicly-visible entry.
+    Public,
+    /// A private entry.
+    Private,
+}
+
+impl Display for EntryVisibility {
+    /// Prints the entry visibility as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Constant => write!(f, "constant"),
+            Self::Public => write!(f, "public"),
+            Self::Private => write!(f, "private"),
+        }
+    }
+}
+
+impl<N: Network, Private: Visibility> Entry<N, Private> {
+    /// Returns the visibility of the entry.
+    pub const fn visibility(&self) -> EntryVisibility {
+        match self {
+            Self::Constant(..) => EntryVisibility::Constant,
+            Self::Public(..) => EntryVisibility::Public,
+            Self::Private(..) => EntryVisibility::Private,
+        }
+    }
+}
+
+impl<N: Network> Entry<N, Plaintext<N>> {
+    /// Returns a copy of the entry with the given visibility.
+    ///
+    /// Public and private entries may be switched freely between public and private.
+    /// Constant entries cannot be demoted, and public or private entries cannot be promoted to constant.
+    pub fn with_visibility(&self, visibility: EntryVisibility) -> Result<Self> {
+        // Retrieve the plaintext.
+        let plaintext = match self {
+            Self::Constant(plaintext) | Self::Public(plaintext) | Self::Private(plaintext) => plaintext.clone(),
+        };
+        // Construct the entry with the given visibility.
+        match (self.visibility(), visibility) {
+            (EntryVisibility::Constant, EntryVisibility::Constant) => Ok(Self::Constant(plaintext)),
+            (EntryVisibility::Constant, _) => bail!("Illegal operation: A constant entry cannot be made {visibility}"),
+            (current, EntryVisibility::Constant) => bail!("Illegal operation: A {current} entry cannot be made constant"),
+            (_, EntryVisibility::Public) => Ok(Self::Public(plaintext)),
+            (_, EntryVisibility::Private) => Ok(Self::Private(plaintext)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_with_visibility() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ foo: 5u8, bar: { baz: 6field } }")?;
+        let constant = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Constant(plaintext.clone());
+        let public = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Public(plaintext.clone());
+        let private = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Private(plaintext);
+
+        // Ensure public and private entries can be switched between public and private.
+        for entry in [&public, &private] {
+            assert_eq!(entry.with_visibility(EntryVisibility::Public)?, public);
+            assert_eq!(entry.with_visibility(EntryVisibility::Private)?, private);
+            // Ensure the round trip returns the original entry.
+            for visibility in [EntryVisibility::Public, EntryVisibility::Private] {
+                let candidate = entry.with_visibility(visibility)?;
+                assert_eq!(candidate.visibility(), visibility);
+                assert_eq!(&candidate.with_visibility(entry.visibility())?, entry);
+            }
+            // Ensure public and private entries cannot be promoted to constant.
+            assert!(entry.with_visibility(EntryVisibility::Constant).is_err());
+        }
+
+        // Ensure constant entries cannot be demoted.
+        assert_eq!(constant.with_visibility(EntryVisibility::Constant)?, constant);
+        assert!(constant.with_visibility(EntryVisibility::Public).is_err());
+        assert!(constant.with_visibility(EntryVisibility::Private).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_visibility_to_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let plaintext = Plaintext::<CurrentNetwork>::from(Literal::Field(Uniform::rand(&mut rng)));
+            let public = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::Public(plaintext.clone());
+            let private = public.with_visibility(EntryVisibility::Private)?;
+
+            // Ensure only the visibility bits change.
+            let public_bits = public.to_bits_le();
+            let private_bits = private.to_bits_le();
+            assert_eq!(public_bits.len(), private_bits.len());
+            assert_ne!(public_bits[..2], private_bits[..2]);
+            assert_eq!(public_bits[2..], private_bits[2..]);
+            assert_eq!(private_bits, Entry::Private(plaintext).to_bits_le());
+        }
+        Ok(())
+    }
+}
@@ -19,3 +19,6 @@ pub use balance::*;
 
 mod owner;
 pub use owner::*;
+
+mod view_key_capsule;
+pub use view_key_capsule::*;
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_console_account::ViewKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+use snarkvm_utilities::DeserializeExt;
+
+/// A record view key encrypted to an address, allowing the holder of the corresponding
+/// view key (e.g. an auditor) to decrypt a single record without the owner's account view key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ViewKeyCapsule<N: Network> {
+    /// The ephemeral public key, `G^r`.
+    nonce: Group<N>,
+    /// The record view key, masked with the shared secret.
+    ciphertext: Field<N>,
+}
+
+impl<N: Network> ViewKeyCapsule<N> {
+    /// Encrypts the given record view key to the given address.
+    pub fn encrypt<R: Rng + CryptoRng>(record_view_key: &Field<N>, address: &Address<N>, rng: &mut R) -> Result<Self> {
+        // Sample an ephemeral randomizer.
+        let randomizer = Scalar::rand(rng);
+        // Compute the ephemeral public key.
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Compute the shared secret.
+        let shared_secret = (**address).mul_constant_time(&randomizer).to_x_coordinate();
+        // Mask the record view key.
+        let ciphertext = *record_view_key + Self::mask(shared_secret)?;
+        Ok(Self { nonce, ciphertext })
+    }
+
+    /// Decrypts the record view key using the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Field<N>> {
+        // Compute the shared secret.
+        let shared_secret = self.nonce.mul_constant_time(view_key).to_x_coordinate();
+        // Unmask the record view key.
+        Ok(self.ciphertext - Self::mask(shared_secret)?)
+    }
+
+    /// Returns the ephemeral public key.
+    pub const fn nonce(&self) -> Group<N> {
+        self.nonce
+    }
+
+    /// Returns the masked record view key.
+    pub const fn ciphertext(&self) -> Field<N> {
+        self.ciphertext
+    }
+
+    /// Returns the mask for the given shared secret.
+    fn mask(shared_secret: Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[N::encryption_domain(), shared_secret])
+    }
+}
+
+impl<N: Network> FromBytes for ViewKeyCapsule<N> {
+    /// Reads the capsule from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the nonce.
+        let nonce = FromBytes::read_le(&mut reader)?;
+        // Read the ciphertext.
+        let ciphertext = FromBytes::read_le(&mut reader)?;
+        // Return the capsule.
+        Ok(Self { nonce, ciphertext })
+    }
+}
+
+impl<N: Network> ToBytes for ViewKeyCapsule<N> {
+    /// Writes the capsule to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the ciphertext.
+        self.ciphertext.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for ViewKeyCapsule<N> {
+    /// Serializes the capsule into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut capsule = serializer.serialize_struct("ViewKeyCapsule", 2)?;
+                capsule.serialize_field("nonce", &self.nonce)?;
+                capsule.serialize_field("ciphertext", &self.ciphertext)?;
+                capsule.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ViewKeyCapsule<N> {
+    /// Deserializes the capsule from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the capsule from a string into a value.
+                let mut capsule = serde_json::Value::deserialize(deserializer)?;
+                // Recover the capsule.
+                Ok(Self {
+                    // Retrieve the nonce.
+                    nonce: DeserializeExt::take_from_value::<D>(&mut capsule, "nonce")?,
+                    // Retrieve the ciphertext.
+                    ciphertext: DeserializeExt::take_from_value::<D>(&mut capsule, "ciphertext")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "view key capsule"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    type Sample = (ViewKeyCapsule<CurrentNetwork>, ViewKey<CurrentNetwork>, Field<CurrentNetwork>);
+
+    fn sample_capsule(rng: &mut TestRng) -> Result<Sample> {
+        let view_key = ViewKey::try_from(PrivateKey::new(rng)?)?;
+        let record_view_key = Field::rand(rng);
+        let capsule = ViewKeyCapsule::encrypt(&record_view_key, &view_key.to_address(), rng)?;
+        Ok((capsule, view_key, record_view_key))
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (capsule, view_key, record_view_key) = sample_capsule(&mut rng)?;
+            assert_eq!(record_view_key, capsule.decrypt(&view_key)?);
+
+            // Ensure a different view key does not recover the record view key.
+            let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert_ne!(record_view_key, capsule.decrypt(&other_view_key)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let (expected, ..) = sample_capsule(&mut rng)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ViewKeyCapsule::read_le(&expected_bytes[..])?);
+            assert!(ViewKeyCapsule::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (expected, ..) = sample_capsule(&mut rng)?;
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        // Deserialize
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (expected, ..) = sample_capsule(&mut rng)?;
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, ViewKeyCapsule::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        Ok(())
+    }
+}
@@ -40,7 +40,7 @@ impl<N: Network> Record<N, Ciphertext<N>> {
             // If the owner is private, decrypt the owner to check if it matches the address.
             Owner::Private(ciphertext) => {
                 // Compute the record view key.
-                let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+                let record_view_key = self.nonce.mul_constant_time(view_key).to_x_coordinate();
                 // Compute the 0th randomizer.
                 let randomizer = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1);
                 // Decrypt the owner.
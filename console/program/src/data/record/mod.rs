@@ -15,29 +15,32 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod entry;
-pub use entry::Entry;
+pub use entry::{Entry, EntryVisibility};
 
 mod helpers;
-pub use helpers::{Balance, Owner};
+pub use helpers::{Balance, Owner, ViewKeyCapsule};
 
 mod bytes;
 mod decrypt;
 mod encrypt;
+mod encrypt_view_key;
 mod equal;
 mod find;
 mod is_owner;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod reveal;
 mod serial_number;
 mod serialize;
 mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod to_public_view;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
-use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_account::{Address, GraphKey, PrivateKey, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar, U64};
 
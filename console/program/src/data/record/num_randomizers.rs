@@ -19,30 +19,141 @@ use super::*;
 impl<N: Network, Private: Visibility> Record<N, Private> {
     /// Returns the number of field elements to encode `self`.
     pub(crate) fn num_randomizers(&self) -> Result<u16> {
-        // Initialize an tracker for the number of randomizers.
-        let mut num_randomizers: u16 = 0;
+        self.randomizer_layout().map(|(_, num_randomizers)| num_randomizers)
+    }
 
-        // If the owner is private, increment the number of randomizers by 1.
-        if self.owner.is_private() {
-            num_randomizers += 1;
-        }
+    /// Returns the `(entry name, start, count)` of the randomizers for each data entry, in order.
+    /// The randomizers for the owner and gates precede the data entries.
+    pub fn randomizer_offsets(&self) -> Result<Vec<(Identifier<N>, u16, u16)>> {
+        self.randomizer_layout().map(|(offsets, _)| offsets)
+    }
 
-        // If the gates is private, increment the number of randomizers by 1.
-        if self.gates.is_private() {
-            num_randomizers += 1;
-        }
+    /// Returns the index of the randomizer for the owner, if the owner is private.
+    pub(crate) fn owner_randomizer_index(&self) -> Option<usize> {
+        self.owner.is_private().then_some(0)
+    }
+
+    /// Returns the index of the randomizer for the gates, if the gates is private.
+    pub(crate) fn gates_randomizer_index(&self) -> Option<usize> {
+        self.gates.is_private().then_some(self.owner.is_private() as usize)
+    }
 
-        // Increment the number of randomizers by the number of data randomizers.
-        for (_, entry) in self.data.iter() {
+    /// Returns the randomizer offsets of the data entries, and the total number of randomizers.
+    /// This layout is shared by encryption and decryption:
+    ///   1. If the owner is private, it consumes 1 randomizer.
+    ///   2. If the gates is private, it consumes 1 randomizer.
+    ///   3. Each data entry, in order, consumes one randomizer per field element if private, and none otherwise.
+    pub(crate) fn randomizer_layout(&self) -> Result<(Vec<(Identifier<N>, u16, u16)>, u16)> {
+        // Initialize a tracker for the number of randomizers, starting after the owner and gates.
+        let mut num_randomizers = self.owner.is_private() as u16 + self.gates.is_private() as u16;
+
+        // Compute the offsets of the data entries.
+        let mut offsets = Vec::with_capacity(self.data.len());
+        for (identifier, entry) in self.data.iter() {
+            let count = entry.num_randomizers()?;
+            offsets.push((*identifier, num_randomizers, count));
             num_randomizers = num_randomizers
-                .checked_add(entry.num_randomizers()?)
+                .checked_add(count)
                 .ok_or_else(|| anyhow!("Number of randomizers exceeds maximum allowed size."))?;
         }
 
         // Ensure the number of randomizers does not exceed the maximum allowed size.
         match num_randomizers as u32 <= N::MAX_DATA_SIZE_IN_FIELDS {
-            true => Ok(num_randomizers),
+            true => Ok((offsets, num_randomizers)),
             false => bail!("Number of randomizers exceeds the maximum allowed size."),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_randomizer_offsets(record: &str) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an owner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = record.replace("{address}", &address.to_string()).replace("{nonce}", &nonce.to_string());
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&record)?;
+
+        // Ensure the offsets are contiguous, start after the owner and gates, and sum to the total.
+        let offsets = record.randomizer_offsets()?;
+        let mut expected_start = record.owner.is_private() as u16 + record.gates.is_private() as u16;
+        for ((identifier, entry), (name, start, count)) in record.data.iter().zip_eq(&offsets) {
+            assert_eq!(identifier, name);
+            assert_eq!(expected_start, *start);
+            assert_eq!(entry.num_randomizers()?, *count);
+            expected_start += count;
+        }
+        assert_eq!(expected_start, record.num_randomizers()?);
+
+        // Ensure the ciphertext has the same layout as the plaintext.
+        let ciphertext = record.encrypt(randomizer)?;
+        assert_eq!(offsets, ciphertext.randomizer_offsets()?);
+        assert_eq!(record.num_randomizers()?, ciphertext.num_randomizers()?);
+        for (entry, ciphertext_entry) in record.data.values().zip_eq(ciphertext.data.values()) {
+            assert_eq!(entry.num_randomizers()?, ciphertext_entry.num_randomizers()?);
+        }
+
+        // Ensure the ciphertext decrypts to the record.
+        assert_eq!(record, ciphertext.decrypt(&view_key)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_randomizer_offsets_without_entries() -> Result<()> {
+        check_randomizer_offsets("{ owner: {address}.public, gates: 5u64.public, _nonce: {nonce}.public }")?;
+        check_randomizer_offsets("{ owner: {address}.private, gates: 5u64.private, _nonce: {nonce}.public }")
+    }
+
+    #[test]
+    fn test_randomizer_offsets_with_mixed_entries() -> Result<()> {
+        check_randomizer_offsets(
+            r"{
+  owner: {address}.private,
+  gates: 5u64.public,
+  a: 1u8.constant,
+  b: 2field.private,
+  c: 3u32.public,
+  d: 4scalar.private,
+  e: 5u128.constant,
+  _nonce: {nonce}.public
+}",
+        )
+    }
+
+    #[test]
+    fn test_randomizer_offsets_with_nested_structs() -> Result<()> {
+        check_randomizer_offsets(
+            r"{
+  owner: {address}.public,
+  gates: 5u64.private,
+  a: 1u8.public,
+  b: {
+    c: 2field.private,
+    d: {
+      e: 3field.private,
+      f: 4field.private,
+      g: 5field.private
+    }
+  },
+  h: {
+    i: 6u8.constant
+  },
+  j: 7scalar.private,
+  _nonce: {nonce}.public
+}",
+        )
+    }
+}
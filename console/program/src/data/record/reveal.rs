@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns a copy of the record, with the given data entries switched to public.
+    /// The remaining entries, the owner, and the gates are left untouched.
+    /// Note: As the visibility of each entry is encoded in `to_bits_le`, the record commitment changes.
+    pub fn reveal(&self, entry_names: &[Identifier<N>]) -> Result<Record<N, Plaintext<N>>> {
+        // Ensure each entry name exists in the record data.
+        for entry_name in entry_names {
+            ensure!(self.data.contains_key(entry_name), "Record entry `{entry_name}` not found.");
+        }
+
+        // Switch the given entries to public.
+        let data = self
+            .data
+            .iter()
+            .map(|(name, entry)| match entry_names.contains(name) {
+                true => Ok((*name, entry.with_visibility(EntryVisibility::Public)?)),
+                false => Ok((*name, entry.clone())),
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Return the record.
+        Self::from_plaintext(self.owner.clone(), self.gates.clone(), data, self.nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_reveal() -> Result<()> {
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 99u64.private, foo: 5u8.private, bar: 6u8.public, baz: 7u8.private, qux: 8u8.constant, _nonce: 0group.public }",
+        )?;
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 99u64.private, foo: 5u8.public, bar: 6u8.public, baz: 7u8.private, qux: 8u8.constant, _nonce: 0group.public }",
+        )?;
+
+        // Reveal the entries.
+        let names = [Identifier::from_str("foo")?, Identifier::from_str("bar")?];
+        let candidate = record.reveal(&names)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure the commitment changes deterministically.
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+        let candidate_commitment = candidate.to_commitment(&program_id, &record_name)?;
+        assert_ne!(commitment, candidate_commitment);
+        assert_eq!(candidate_commitment, record.reveal(&names)?.to_commitment(&program_id, &record_name)?);
+        assert_eq!(candidate_commitment, expected.to_commitment(&program_id, &record_name)?);
+
+        // Ensure revealing no entries leaves the record unchanged.
+        assert_eq!(record, record.reveal(&[])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reveal_fails() -> Result<()> {
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 99u64.private, foo: 5u8.private, qux: 8u8.constant, _nonce: 0group.public }",
+        )?;
+
+        // Ensure a constant entry cannot be revealed.
+        assert!(record.reveal(&[Identifier::from_str("qux")?]).is_err());
+        // Ensure a missing entry cannot be revealed.
+        assert!(record.reveal(&[Identifier::from_str("missing")?]).is_err());
+        Ok(())
+    }
+}
@@ -22,7 +22,7 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         // Compute the generator `H` as `HashToGroup(commitment)`.
         let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
         // Compute `gamma` as `sk_sig * H`.
-        let gamma = h * private_key.sk_sig();
+        let gamma = h.mul_constant_time(&private_key.sk_sig());
         // Compute the serial number from `gamma`.
         Self::serial_number_from_gamma(&gamma, commitment)
     }
@@ -35,3 +35,57 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
 }
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the serial number of the record, given the private key of the record owner and the record commitment.
+    ///
+    /// The serial number is `Commit(serial_number_domain || commitment, HashToScalar(COFACTOR * gamma))`,
+    /// where `gamma := sk_sig * HashToGroup(serial_number_domain || commitment)`. It is the input ID
+    /// of this record in any transition that spends it, and may be used to check if the record is spent.
+    pub fn to_serial_number(&self, private_key: &PrivateKey<N>, commitment: Field<N>) -> Result<Field<N>> {
+        // Ensure the private key corresponds to the record owner.
+        ensure!(
+            *self.owner == Address::try_from(private_key)?,
+            "Failed to compute the serial number: the private key does not own the record"
+        );
+        // Compute the serial number.
+        Self::serial_number(*private_key, commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentRecord = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+    #[test]
+    fn test_to_serial_number_and_tag() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the record owner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let graph_key = GraphKey::try_from(&view_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sample a record and its commitment.
+        let record = CurrentRecord::from_str(&format!(
+            "{{ owner: {address}.private, gates: 5u64.private, _nonce: {}.public }}",
+            Group::<CurrentNetwork>::rand(rng)
+        ))?;
+        let commitment = Field::rand(rng);
+
+        // Ensure the serial number and tag match the helper methods.
+        let serial_number = record.to_serial_number(&private_key, commitment)?;
+        assert_eq!(serial_number, CurrentRecord::serial_number(private_key, commitment)?);
+        assert_eq!(record.to_tag(&graph_key, commitment)?, CurrentRecord::tag(graph_key.sk_tag(), commitment)?);
+
+        // Ensure a private key that does not own the record fails.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(record.to_serial_number(&other_private_key, commitment).is_err());
+        Ok(())
+    }
+}
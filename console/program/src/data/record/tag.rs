@@ -23,3 +23,14 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         N::hash_psd2(&[sk_tag, commitment])
     }
 }
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the tag of the record, given the graph key of the record owner and the record commitment.
+    ///
+    /// The graph key is derived from the view key as `sk_tag := Hash(graph_key_domain || view_key || 0)`,
+    /// so the tag may be computed without the private key. The tag is `Hash(sk_tag || commitment)`,
+    /// and matches the tag of this record in any transition that spends it.
+    pub fn to_tag(&self, graph_key: &GraphKey<N>, commitment: Field<N>) -> Result<Field<N>> {
+        Self::tag(graph_key.sk_tag(), commitment)
+    }
+}
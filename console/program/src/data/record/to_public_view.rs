@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns a copy of the record with every `private` entry rewritten as `public`, keeping the data unchanged.
+    ///
+    /// This is a display transform **only**, i.e. for explorers rendering a record whose owner has shared a view key.
+    /// The resulting record has a different commitment from the original, and must **not** be used for on-chain data.
+    pub fn to_public_view(&self) -> Record<N, Plaintext<N>> {
+        // Reveal the owner.
+        let owner = Owner::Public(**self.owner());
+        // Reveal the gates.
+        let gates = Balance::Public(**self.gates());
+        // Reveal the data.
+        let data = self
+            .data
+            .iter()
+            .map(|(identifier, entry)| {
+                let entry = match entry {
+                    Entry::Private(plaintext) => Entry::Public(plaintext.clone()),
+                    entry => entry.clone(),
+                };
+                (*identifier, entry)
+            })
+            .collect();
+
+        Record { owner, gates, data, nonce: self.nonce }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_public_view() -> Result<()> {
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            r"{
+    owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+    gates: 5u64.private,
+    token_amount: 100u64.private,
+    expiry: 42u32.public,
+    decimals: 6u8.constant,
+    _nonce: 0group.public
+}",
+        )?;
+
+        let view = record.to_public_view();
+        // Ensure the owner, gates, and entries are no longer private.
+        assert!(view.owner().is_public());
+        assert!(view.gates().is_public());
+        assert!(view.data().values().all(|entry| !matches!(entry, Entry::Private(..))));
+        // Ensure the constant entry remains constant.
+        assert!(matches!(view.data().get(&Identifier::from_str("decimals")?), Some(Entry::Constant(..))));
+
+        // Ensure the payloads are unchanged.
+        assert_eq!(**view.owner(), **record.owner());
+        assert_eq!(**view.gates(), **record.gates());
+        assert_eq!(view.nonce(), record.nonce());
+        for ((name, entry), (candidate_name, candidate)) in record.data().iter().zip_eq(view.data()) {
+            assert_eq!(name, candidate_name);
+            let (Entry::Constant(expected) | Entry::Public(expected) | Entry::Private(expected)) = entry;
+            let (Entry::Constant(candidate) | Entry::Public(candidate) | Entry::Private(candidate)) = candidate;
+            assert_eq!(expected, candidate);
+        }
+        Ok(())
+    }
+}
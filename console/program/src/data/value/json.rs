@@ -0,0 +1,428 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Balance, EntryType, Literal, Owner, PlaintextType, RecordType, Struct, ValueType};
+use snarkvm_console_types::{Address, Group, U64};
+
+use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+const CONSTANT: &str = ".constant";
+const PUBLIC: &str = ".public";
+const PRIVATE: &str = ".private";
+
+impl<N: Network> Value<N> {
+    /// Returns the value as canonical JSON.
+    ///
+    /// Structs are mapped to JSON objects, and literals are mapped to typed strings (e.g. `"5u64"`).
+    /// Records are mapped to JSON objects whose literals retain their visibility (e.g. `"5u64.private"`).
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Plaintext(plaintext) => plaintext_to_json(plaintext, ""),
+            Self::Record(record) => {
+                let mut object = JsonMap::new();
+                // Insert the owner.
+                let owner = match record.owner() {
+                    Owner::Public(owner) => JsonValue::String(format!("{owner}{PUBLIC}")),
+                    Owner::Private(owner) => plaintext_to_json(owner, PRIVATE),
+                };
+                object.insert("owner".to_string(), owner);
+                // Insert the gates.
+                let gates = match record.gates() {
+                    Balance::Public(gates) => JsonValue::String(format!("{gates}{PUBLIC}")),
+                    Balance::Private(gates) => plaintext_to_json(gates, PRIVATE),
+                };
+                object.insert("gates".to_string(), gates);
+                // Insert the entries.
+                for (identifier, entry) in record.data() {
+                    let entry = match entry {
+                        Entry::Constant(plaintext) => plaintext_to_json(plaintext, CONSTANT),
+                        Entry::Public(plaintext) => plaintext_to_json(plaintext, PUBLIC),
+                        Entry::Private(plaintext) => plaintext_to_json(plaintext, PRIVATE),
+                    };
+                    object.insert(identifier.to_string(), entry);
+                }
+                // Insert the nonce.
+                object.insert("_nonce".to_string(), JsonValue::String(format!("{}{PUBLIC}", record.nonce())));
+                JsonValue::Object(object)
+            }
+        }
+    }
+
+    /// Returns the value from its canonical JSON, ensuring it matches the expected value type.
+    ///
+    /// Structs and records are parsed against their definitions in `structs` and `records`,
+    /// so every member is checked against its declared type. For an external record,
+    /// the definitions must be those of the program that declares the record.
+    pub fn from_json(
+        json: &JsonValue,
+        expected_type: &ValueType<N>,
+        structs: &IndexMap<Identifier<N>, Struct<N>>,
+        records: &IndexMap<Identifier<N>, RecordType<N>>,
+    ) -> Result<Self> {
+        match expected_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => {
+                Ok(Self::Plaintext(plaintext_from_json(json, plaintext_type, structs, "", "", 0)?))
+            }
+            ValueType::Record(record_name) => Ok(Self::Record(record_from_json(json, record_name, structs, records)?)),
+            ValueType::ExternalRecord(locator) => {
+                Ok(Self::Record(record_from_json(json, locator.resource(), structs, records)?))
+            }
+        }
+    }
+}
+
+/// Returns the given plaintext as JSON, appending the given suffix to each literal.
+fn plaintext_to_json<N: Network>(plaintext: &Plaintext<N>, suffix: &str) -> JsonValue {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => JsonValue::String(format!("{literal}{suffix}")),
+        Plaintext::Struct(members, ..) => JsonValue::Object(
+            members
+                .iter()
+                .map(|(identifier, member)| (identifier.to_string(), plaintext_to_json(member, suffix)))
+                .collect(),
+        ),
+    }
+}
+
+/// Returns the plaintext of the given type from the given JSON, ensuring each literal ends with the given suffix.
+/// The path names the current member in errors, e.g. `message.b.c`.
+///
+/// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
+fn plaintext_from_json<N: Network>(
+    json: &JsonValue,
+    plaintext_type: &PlaintextType<N>,
+    structs: &IndexMap<Identifier<N>, Struct<N>>,
+    suffix: &str,
+    path: &str,
+    depth: usize,
+) -> Result<Plaintext<N>> {
+    // Prefix errors with the path to the current member, if there is one.
+    let at = match path.is_empty() {
+        true => String::new(),
+        false => format!(" at '{path}'"),
+    };
+    // Ensure the depth is within `N::MAX_DATA_DEPTH`.
+    ensure!(depth <= N::MAX_DATA_DEPTH, "Exceeded the maximum depth of {}{at}", N::MAX_DATA_DEPTH);
+
+    match (plaintext_type, json) {
+        (PlaintextType::Literal(literal_type), JsonValue::String(string)) => {
+            let literal = match string.strip_suffix(suffix) {
+                Some(literal) => Literal::from_str(literal)?,
+                None => bail!("Expected a literal ending in '{suffix}'{at}, found '{string}'"),
+            };
+            // Ensure the literal matches the declared literal type.
+            let found = literal.to_type();
+            ensure!(found == *literal_type, "Type mismatch{at}: expected '{literal_type}', found '{found}'");
+            Ok(Plaintext::from(literal))
+        }
+        (PlaintextType::Struct(struct_name), JsonValue::Object(object)) => {
+            // Retrieve the struct definition.
+            let struct_ = match structs.get(struct_name) {
+                Some(struct_) => struct_,
+                None => bail!("Struct '{struct_name}' is not defined{at}"),
+            };
+            // Ensure the number of members is within `N::MAX_DATA_ENTRIES`.
+            ensure!(object.len() <= N::MAX_DATA_ENTRIES, "Found a struct that exceeds size ({})", object.len());
+            // Ensure the number of members matches the definition.
+            let num_members = struct_.members().len();
+            ensure!(
+                object.len() == num_members,
+                "Type mismatch{at}: expected {num_members} members in '{struct_name}', found {}",
+                object.len()
+            );
+            // Parse the members, in the order they are declared.
+            let members = struct_
+                .members()
+                .iter()
+                .map(|(member_name, member_type)| {
+                    let path = match path.is_empty() {
+                        true => format!("{struct_name}.{member_name}"),
+                        false => format!("{path}.{member_name}"),
+                    };
+                    let member = match object.get(&member_name.to_string()) {
+                        Some(member) => member,
+                        None => bail!("Missing member '{path}'"),
+                    };
+                    Ok((*member_name, plaintext_from_json(member, member_type, structs, suffix, &path, depth + 1)?))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?;
+            Ok(Plaintext::Struct(members, OnceCell::new()))
+        }
+        (plaintext_type, json) => bail!("Type mismatch{at}: expected '{plaintext_type}', found '{json}'"),
+    }
+}
+
+/// Returns the record of the given record type from the given JSON.
+fn record_from_json<N: Network>(
+    json: &JsonValue,
+    record_name: &Identifier<N>,
+    structs: &IndexMap<Identifier<N>, Struct<N>>,
+    records: &IndexMap<Identifier<N>, RecordType<N>>,
+) -> Result<Record<N, Plaintext<N>>> {
+    // Retrieve the record definition.
+    let record_type = match records.get(record_name) {
+        Some(record_type) => record_type,
+        None => bail!("Record '{record_name}' is not defined"),
+    };
+    let object = match json {
+        JsonValue::Object(object) => object,
+        _ => bail!("Type mismatch: expected a record as a JSON object, found '{json}'"),
+    };
+    // Retrieves the given member as a string.
+    let get_string = |name: &str| match object.get(name) {
+        Some(JsonValue::String(string)) => Ok(string.as_str()),
+        _ => bail!("Expected the record '{name}' to be a JSON string"),
+    };
+
+    // Parse the owner, with its declared visibility.
+    let owner = get_string("owner")?;
+    let owner = match (record_type.owner().is_public(), owner.strip_suffix(PUBLIC), owner.strip_suffix(PRIVATE)) {
+        (true, Some(owner), _) => Owner::Public(Address::from_str(owner)?),
+        (false, _, Some(owner)) => {
+            Owner::Private(Plaintext::from(Literal::Address(Address::from_str(owner)?)))
+        }
+        _ => bail!("Type mismatch: expected the record owner to be {}, found '{owner}'", record_type.owner()),
+    };
+    // Parse the gates, with their declared visibility.
+    let gates = get_string("gates")?;
+    let gates = match (record_type.gates().is_public(), gates.strip_suffix(PUBLIC), gates.strip_suffix(PRIVATE)) {
+        (true, Some(gates), _) => Balance::Public(U64::from_str(gates)?),
+        (false, _, Some(gates)) => {
+            Balance::Private(Plaintext::from(Literal::U64(U64::from_str(gates)?)))
+        }
+        _ => bail!("Type mismatch: expected the record gates to be {}, found '{gates}'", record_type.gates()),
+    };
+    // Parse the nonce.
+    let nonce = get_string("_nonce")?;
+    let nonce = match nonce.strip_suffix(PUBLIC) {
+        Some(nonce) => Group::from_str(nonce)?,
+        None => bail!("Expected the record nonce to be public, found '{nonce}'"),
+    };
+
+    // Ensure the number of entries matches the definition.
+    let num_entries = record_type.entries().len();
+    let found = object.keys().filter(|name| !["owner", "gates", "_nonce"].contains(&name.as_str())).count();
+    ensure!(num_entries == found, "Type mismatch: expected {num_entries} entries in '{record_name}', found {found}");
+    // Parse the entries, in the order they are declared, with their declared visibility.
+    let data = record_type
+        .entries()
+        .iter()
+        .map(|(entry_name, entry_type)| {
+            let path = format!("{record_name}.{entry_name}");
+            let entry = match object.get(&entry_name.to_string()) {
+                Some(entry) => entry,
+                None => bail!("Missing entry '{path}'"),
+            };
+            let entry = match entry_type {
+                EntryType::Constant(plaintext_type) => {
+                    Entry::Constant(plaintext_from_json(entry, plaintext_type, structs, CONSTANT, &path, 1)?)
+                }
+                EntryType::Public(plaintext_type) => {
+                    Entry::Public(plaintext_from_json(entry, plaintext_type, structs, PUBLIC, &path, 1)?)
+                }
+                EntryType::Private(plaintext_type) => {
+                    Entry::Private(plaintext_from_json(entry, plaintext_type, structs, PRIVATE, &path, 1)?)
+                }
+            };
+            Ok((*entry_name, entry))
+        })
+        .collect::<Result<IndexMap<_, _>>>()?;
+
+    Record::<N, Plaintext<N>>::from_plaintext(owner, gates, data, nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LiteralType;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the struct definitions used in the tests.
+    fn sample_structs() -> IndexMap<Identifier<CurrentNetwork>, Struct<CurrentNetwork>> {
+        [
+            "struct leaf:\n    e as field;\n    f as i64;",
+            "struct node:\n    c as boolean;\n    d as leaf;",
+            "struct message:\n    a as u8;\n    b as node;\n    g as address;",
+            "struct pair:\n    c as field;\n    d as u32;",
+        ]
+        .iter()
+        .map(|string| {
+            let struct_ = Struct::<CurrentNetwork>::from_str(string).unwrap();
+            (*struct_.name(), struct_)
+        })
+        .collect()
+    }
+
+    /// Returns the record definitions used in the tests.
+    fn sample_records() -> IndexMap<Identifier<CurrentNetwork>, RecordType<CurrentNetwork>> {
+        [
+            "record token:\n    owner as address.private;\n    gates as u64.public;\n    a as u8.constant;\n    \
+             b as pair.public;\n    e as i16.private;",
+            "record ticket:\n    owner as address.public;\n    gates as u64.private;\n    a as pair.public;",
+        ]
+        .iter()
+        .map(|string| {
+            let record_type = RecordType::<CurrentNetwork>::from_str(string).unwrap();
+            (*record_type.name(), record_type)
+        })
+        .collect()
+    }
+
+    /// Returns the value from the given JSON, using the sample definitions.
+    fn from_json(json: &JsonValue, expected_type: &str) -> Result<Value<CurrentNetwork>> {
+        Value::from_json(json, &ValueType::from_str(expected_type)?, &sample_structs(), &sample_records())
+    }
+
+    fn check_json(expected: Value<CurrentNetwork>, expected_type: &str) -> Result<()> {
+        // Check the JSON round trip.
+        let json = expected.to_json();
+        let candidate = from_json(&json, expected_type)?;
+        assert_eq!(expected, candidate);
+        // Check the JSON string round trip.
+        let json = serde_json::from_str(&serde_json::to_string(&json)?)?;
+        assert_eq!(expected, from_json(&json, expected_type)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_literals() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for literal_type in [
+            LiteralType::Address,
+            LiteralType::Boolean,
+            LiteralType::Field,
+            LiteralType::Group,
+            LiteralType::I8,
+            LiteralType::I16,
+            LiteralType::I32,
+            LiteralType::I64,
+            LiteralType::I128,
+            LiteralType::U8,
+            LiteralType::U16,
+            LiteralType::U32,
+            LiteralType::U64,
+            LiteralType::U128,
+            LiteralType::Scalar,
+        ] {
+            let literal = Literal::<CurrentNetwork>::sample(literal_type, &mut rng);
+            // Ensure the literal is a typed string.
+            let value = Value::Plaintext(Plaintext::from(&literal));
+            assert_eq!(JsonValue::String(literal.to_string()), value.to_json());
+            check_json(value, &format!("{literal_type}.public"))?;
+        }
+
+        // Check a string literal.
+        let value = Value::from_str("\"hello world\"")?;
+        check_json(value, "string.private")
+    }
+
+    #[test]
+    fn test_nested_structs() -> Result<()> {
+        let expected = Value::<CurrentNetwork>::from_str(
+            "{ a: 1u8, b: { c: true, d: { e: 5field, f: -2i64 } }, g: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah }",
+        )?;
+        let json = expected.to_json();
+        assert_eq!(json["a"], "1u8");
+        assert_eq!(json["b"]["d"]["f"], "-2i64");
+        check_json(expected, "message.private")
+    }
+
+    #[test]
+    fn test_records() -> Result<()> {
+        let expected = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.public, a: 1u8.constant, b: { c: 2field.public, d: 3u32.public }, e: 4i16.private, _nonce: 0group.public }",
+        )?;
+        let json = expected.to_json();
+        assert_eq!(json["gates"], "5u64.public");
+        assert_eq!(json["b"]["c"], "2field.public");
+        check_json(expected.clone(), "token.record")?;
+        check_json(expected, "credits.aleo/token.record")?;
+
+        // Ensure an entry with mixed visibilities is rejected.
+        let mut json = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, gates: 5u64.private, a: { c: 1field.public, d: 2u32.public }, _nonce: 0group.public }",
+        )?
+        .to_json();
+        assert!(from_json(&json, "ticket.record").is_ok());
+        json["a"]["d"] = JsonValue::String("2u32.private".to_string());
+        assert!(from_json(&json, "ticket.record").is_err());
+
+        // Ensure a record with the wrong owner visibility is rejected.
+        assert!(from_json(&json, "token.record").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_mismatch() -> Result<()> {
+        // Ensure a `u64` is rejected for a `field`.
+        let json = Value::<CurrentNetwork>::from_str("5u64")?.to_json();
+        let error = from_json(&json, "field.private").unwrap_err();
+        assert!(error.to_string().contains("Type mismatch"), "Unexpected error: {error}");
+
+        // Ensure a literal is rejected for a struct, and vice versa.
+        assert!(from_json(&json, "message.private").is_err());
+        let json = Value::<CurrentNetwork>::from_str("{ c: 5field, d: 6u32 }")?.to_json();
+        assert!(from_json(&json, "u64.private").is_err());
+        assert!(from_json(&json, "pair.private").is_ok());
+
+        // Ensure a plaintext is rejected for a record.
+        assert!(from_json(&json, "token.record").is_err());
+
+        // Ensure an undefined struct or record is rejected.
+        assert!(from_json(&json, "unknown.private").is_err());
+        assert!(from_json(&json, "unknown.record").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_member_type_mismatch() -> Result<()> {
+        // Ensure a nested struct member with the wrong type is rejected, naming its path.
+        let mut json = Value::<CurrentNetwork>::from_str(
+            "{ a: 1u8, b: { c: true, d: { e: 5field, f: -2i64 } }, g: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah }",
+        )?
+        .to_json();
+        json["b"]["d"]["f"] = JsonValue::String("-2i32".to_string());
+        let error = from_json(&json, "message.private").unwrap_err().to_string();
+        assert!(error.contains("message.b.d.f"), "Unexpected error: {error}");
+        assert!(error.contains("expected 'i64', found 'i32'"), "Unexpected error: {error}");
+
+        // Ensure a missing or extra member is rejected.
+        let json = Value::<CurrentNetwork>::from_str("{ c: 5field }")?.to_json();
+        assert!(from_json(&json, "pair.private").is_err());
+        let json = Value::<CurrentNetwork>::from_str("{ c: 5field, d: 6u32, e: 7u32 }")?.to_json();
+        assert!(from_json(&json, "pair.private").is_err());
+        let json = Value::<CurrentNetwork>::from_str("{ c: 5field, e: 6u32 }")?.to_json();
+        assert!(from_json(&json, "pair.private").is_err());
+
+        // Ensure a record entry with the wrong type is rejected, naming its path.
+        let mut json = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.public, a: 1u8.constant, b: { c: 2field.public, d: 3u32.public }, e: 4i16.private, _nonce: 0group.public }",
+        )?
+        .to_json();
+        json["b"]["d"] = JsonValue::String("3u64.public".to_string());
+        let error = from_json(&json, "token.record").unwrap_err().to_string();
+        assert!(error.contains("token.b.d"), "Unexpected error: {error}");
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@
 mod bytes;
 mod equal;
 mod find;
+mod json;
 mod parse;
 mod serialize;
 mod to_bits;
@@ -34,6 +35,40 @@ pub enum Value<N: Network> {
     Record(Record<N, Plaintext<N>>),
 }
 
+impl<N: Network> Value<N> {
+    /// Returns the plaintext, if the value is a plaintext.
+    pub const fn as_plaintext(&self) -> Option<&Plaintext<N>> {
+        match self {
+            Self::Plaintext(plaintext) => Some(plaintext),
+            Self::Record(..) => None,
+        }
+    }
+
+    /// Returns the record, if the value is a record.
+    pub const fn as_record(&self) -> Option<&Record<N, Plaintext<N>>> {
+        match self {
+            Self::Plaintext(..) => None,
+            Self::Record(record) => Some(record),
+        }
+    }
+
+    /// Returns the plaintext, or an error if the value is a record.
+    pub fn into_plaintext(self) -> Result<Plaintext<N>> {
+        match self {
+            Self::Plaintext(plaintext) => Ok(plaintext),
+            Self::Record(..) => bail!("Expected a plaintext value, found a record"),
+        }
+    }
+
+    /// Returns the record, or an error if the value is a plaintext.
+    pub fn into_record(self) -> Result<Record<N, Plaintext<N>>> {
+        match self {
+            Self::Plaintext(..) => bail!("Expected a record value, found a plaintext"),
+            Self::Record(record) => Ok(record),
+        }
+    }
+}
+
 impl<N: Network> From<&Value<N>> for Value<N> {
     /// Returns a clone of the value.
     fn from(value: &Value<N>) -> Self {
@@ -76,3 +111,33 @@ impl<N: Network> TryFrom<&str> for Value<N> {
         Self::from_str(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_accessors() -> Result<()> {
+        // Construct a plaintext value.
+        let plaintext = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 5u64 }";
+        let value = Value::<CurrentNetwork>::from_str(plaintext)?;
+        let expected = Plaintext::from_str(plaintext)?;
+        assert_eq!(value.as_plaintext(), Some(&expected));
+        assert!(value.as_record().is_none());
+        assert!(value.clone().into_record().is_err());
+        assert_eq!(value.into_plaintext()?, expected);
+
+        // Construct a record value.
+        let record = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, gates: 5u64.private, _nonce: 0group.public }";
+        let value = Value::<CurrentNetwork>::from_str(record)?;
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(record)?;
+        assert_eq!(value.as_record(), Some(&expected));
+        assert!(value.as_plaintext().is_none());
+        assert!(value.clone().into_plaintext().is_err());
+        assert_eq!(value.into_record()?, expected);
+        Ok(())
+    }
+}
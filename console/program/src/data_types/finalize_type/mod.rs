@@ -18,7 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{Identifier, Locator, PlaintextType};
+use crate::{Identifier, Locator, PlaintextType, ValueType};
 use snarkvm_console_network::prelude::*;
 
 use enum_index::EnumIndex;
@@ -32,3 +32,54 @@ pub enum FinalizeType<N: Network> {
     /// An external record type inherits its visibility from its record definition.
     ExternalRecord(Locator<N>),
 }
+
+impl<N: Network> TryFrom<ValueType<N>> for FinalizeType<N> {
+    type Error = Error;
+
+    /// Converts a value type to a finalize type.
+    fn try_from(value: ValueType<N>) -> Result<Self> {
+        match value {
+            ValueType::Public(plaintext_type) => Ok(Self::Public(plaintext_type)),
+            ValueType::Record(record_name) => Ok(Self::Record(record_name)),
+            ValueType::ExternalRecord(locator) => Ok(Self::ExternalRecord(locator)),
+            ValueType::Constant(..) | ValueType::Private(..) => {
+                bail!("Value type '{value}' is not a valid finalize type, as finalize types must be public")
+            }
+        }
+    }
+}
+
+impl<N: Network> TryFrom<&ValueType<N>> for FinalizeType<N> {
+    type Error = Error;
+
+    /// Converts a value type to a finalize type.
+    fn try_from(value: &ValueType<N>) -> Result<Self> {
+        Self::try_from(*value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_value_type_interconversion() -> Result<()> {
+        // Ensure the mappable types convert in both directions.
+        for string in ["u64.public", "token.record", "credits.aleo/credits.record"] {
+            let value_type = ValueType::<CurrentNetwork>::from_str(string)?;
+            let finalize_type = FinalizeType::try_from(&value_type)?;
+            assert_eq!(finalize_type, FinalizeType::from_str(string)?);
+            assert_eq!(value_type, ValueType::from(finalize_type));
+        }
+
+        // Ensure the constant and private types do not convert.
+        for string in ["u64.constant", "u64.private", "foo.constant", "foo.private"] {
+            let value_type = ValueType::<CurrentNetwork>::from_str(string)?;
+            assert!(FinalizeType::try_from(&value_type).is_err());
+        }
+        Ok(())
+    }
+}
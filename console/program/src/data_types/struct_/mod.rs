@@ -17,9 +17,11 @@
 mod bytes;
 mod parse;
 mod serialize;
+mod to_type_hash;
 
 use crate::{Identifier, PlaintextType};
 use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
 
 use indexmap::IndexMap;
 
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Struct<N> {
+    /// Returns the type hash of the struct, as a Poseidon hash over the member names and plaintext types, in order.
+    /// The struct name is not included, and member structs are hashed by name.
+    pub fn to_type_hash(&self) -> Result<Field<N>> {
+        // Initialize the preimage with the domain separator and the number of members.
+        let mut preimage =
+            vec![Field::new_domain_separator("AleoStructTypeHash0"), Field::from_u64(self.members.len() as u64)];
+        for (name, plaintext_type) in &self.members {
+            // Append the member name.
+            preimage.push(name.to_field()?);
+            // Append the length of the plaintext type in bytes, followed by the plaintext type.
+            let bytes = plaintext_type.to_bytes_le()?;
+            preimage.push(Field::from_u64(bytes.len() as u64));
+            for chunk in bytes.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+                preimage.push(Field::from_bits_le(chunk)?);
+            }
+        }
+        // Compute the type hash.
+        N::hash_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_type_hash() -> Result<()> {
+        let expected = Struct::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    second as u8;")?;
+        let expected_hash = expected.to_type_hash()?;
+
+        // Ensure the struct name does not affect the type hash.
+        let renamed = Struct::<CurrentNetwork>::from_str("struct token:\n    first as field;\n    second as u8;")?;
+        assert_eq!(expected_hash, renamed.to_type_hash()?);
+
+        // Ensure a different member type changes the type hash.
+        let candidate = Struct::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    second as u16;")?;
+        assert_ne!(expected_hash, candidate.to_type_hash()?);
+
+        // Ensure a different member name changes the type hash.
+        let candidate = Struct::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    third as u8;")?;
+        assert_ne!(expected_hash, candidate.to_type_hash()?);
+
+        // Ensure a different member order changes the type hash.
+        let candidate = Struct::<CurrentNetwork>::from_str("struct message:\n    second as u8;\n    first as field;")?;
+        assert_ne!(expected_hash, candidate.to_type_hash()?);
+        Ok(())
+    }
+}
@@ -18,7 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{EntryType, Identifier, Locator, PlaintextType};
+use crate::{EntryType, FinalizeType, Identifier, Locator, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
 use enum_index::EnumIndex;
@@ -46,3 +46,21 @@ impl<N: Network> From<EntryType<N>> for ValueType<N> {
         }
     }
 }
+
+impl<N: Network> From<FinalizeType<N>> for ValueType<N> {
+    /// Converts a finalize type to a value type.
+    fn from(finalize: FinalizeType<N>) -> Self {
+        match finalize {
+            FinalizeType::Public(plaintext_type) => ValueType::Public(plaintext_type),
+            FinalizeType::Record(record_name) => ValueType::Record(record_name),
+            FinalizeType::ExternalRecord(locator) => ValueType::ExternalRecord(locator),
+        }
+    }
+}
+
+impl<N: Network> From<&FinalizeType<N>> for ValueType<N> {
+    /// Converts a finalize type to a value type.
+    fn from(finalize: &FinalizeType<N>) -> Self {
+        Self::from(*finalize)
+    }
+}
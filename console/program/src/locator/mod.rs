@@ -33,7 +33,13 @@ pub struct Locator<N: Network> {
 }
 
 impl<N: Network> Locator<N> {
-    /// Returns the program ID.
+    /// Initializes a new locator from the given program ID and resource.
+    #[inline]
+    pub const fn new(id: ProgramID<N>, resource: Identifier<N>) -> Self {
+        Self { id, resource }
+    }
+
+    /// Returns the program ID, i.e. `howard.aleo` in `howard.aleo/notify`.
     #[inline]
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.id
@@ -51,9 +57,63 @@ impl<N: Network> Locator<N> {
         self.id.network()
     }
 
-    /// Returns the resource name.
+    /// Returns the resource name, i.e. `notify` in `howard.aleo/notify`.
+    /// The resource is the name of a function, closure, record, struct, or mapping in the program.
     #[inline]
     pub const fn resource(&self) -> &Identifier<N> {
         &self.resource
     }
 }
+
+impl<N: Network> Ord for Locator<N> {
+    /// Ordering is determined by the program ID first, then the resource name second.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.id == other.id {
+            true => self.resource.to_string().cmp(&other.resource.to_string()),
+            false => self.id.cmp(&other.id),
+        }
+    }
+}
+
+impl<N: Network> PartialOrd for Locator<N> {
+    /// Ordering is determined by the program ID first, then the resource name second.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::BTreeMap;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_locator_ord() -> Result<()> {
+        let locators = ["bar.aleo/transfer", "foo.aleo/mint", "foo.aleo/burn", "bar.aleo/approve"]
+            .iter()
+            .map(|locator| Locator::<CurrentNetwork>::from_str(locator))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure locators are ordered by the program ID, then the resource.
+        let mut sorted = locators.clone();
+        sorted.sort();
+        let expected = ["bar.aleo/approve", "bar.aleo/transfer", "foo.aleo/burn", "foo.aleo/mint"];
+        assert_eq!(sorted.iter().map(ToString::to_string).collect::<Vec<_>>(), expected);
+
+        // Ensure a map keyed by locators iterates in the same order, regardless of the insertion order.
+        let forward = locators.iter().map(|locator| (*locator, ())).collect::<BTreeMap<_, _>>();
+        let reverse = locators.iter().rev().map(|locator| (*locator, ())).collect::<BTreeMap<_, _>>();
+        assert!(forward.keys().eq(reverse.keys()));
+        assert!(forward.keys().eq(sorted.iter()));
+
+        // Ensure the ordering is consistent with equality.
+        for (a, b) in locators.iter().zip(sorted.iter()) {
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+        }
+        Ok(())
+    }
+}
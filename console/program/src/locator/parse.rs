@@ -29,21 +29,46 @@ impl<N: Network> Parser for Locator<N> {
     }
 }
 
+impl<N: Network> Locator<N> {
+    /// Parses a string into a locator, resolving references to the given current program.
+    /// In addition to the `{program_id}/{resource}` form, this accepts the `self/{resource}` form
+    /// and a bare `{resource}`, which both refer to the resource in the current program.
+    pub fn parse_with_default(string: &str, current_program: &ProgramID<N>) -> Result<Self> {
+        match string.split_once('/') {
+            // Resolve `self/{resource}` against the current program.
+            Some(("self", resource)) => Ok(Self::new(*current_program, Self::parse_resource(string, resource)?)),
+            // Parse `{program_id}/{resource}`.
+            Some(..) => Self::from_str(string),
+            // Resolve `{resource}` against the current program.
+            None => Ok(Self::new(*current_program, Self::parse_resource(string, string)?)),
+        }
+    }
+
+    /// Parses the resource of the given locator string.
+    fn parse_resource(string: &str, resource: &str) -> Result<Identifier<N>> {
+        Identifier::from_str(resource)
+            .map_err(|error| anyhow!("Invalid resource '{resource}' in locator '{string}': {error}"))
+    }
+}
+
 impl<N: Network> FromStr for Locator<N> {
     type Err = Error;
 
     /// Parses a string into a locator.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
-            Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
-                // Return the object.
-                Ok(object)
-            }
-            Err(error) => bail!("Failed to parse string. {error}"),
-        }
+        // Split the string into the program ID and the resource.
+        let (id, resource) = match string.split_once('/') {
+            Some((id, resource)) => (id, resource),
+            None => bail!("Invalid locator '{string}': expected the form '{{program_id}}/{{resource}}'"),
+        };
+        // Parse the program ID.
+        let id = ProgramID::from_str(id)
+            .map_err(|error| anyhow!("Invalid program ID '{id}' in locator '{string}': {error}"))?;
+        // Parse the resource.
+        let resource = Self::parse_resource(string, resource)?;
+        // Return the locator.
+        Ok(Self { id, resource })
     }
 }
 
@@ -81,6 +106,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_errors() {
+        let error = |string: &str| Locator::<CurrentNetwork>::from_str(string).unwrap_err().to_string();
+
+        assert!(error("foo.aleo").contains("expected the form"));
+        assert!(error("foo/compute").contains("Invalid program ID 'foo'"));
+        assert!(error("1foo.aleo/compute").contains("Invalid program ID '1foo.aleo'"));
+        assert!(error("foo.aleo/1compute").contains("Invalid resource '1compute'"));
+        assert!(error("foo.aleo/compute/again").contains("Invalid resource 'compute/again'"));
+        assert!(error("foo.aleo/").contains("Invalid resource ''"));
+    }
+
+    #[test]
+    fn test_parse_with_default() -> Result<()> {
+        let current = ProgramID::<CurrentNetwork>::from_str("current.aleo")?;
+        let expected = Locator::new(current, Identifier::from_str("transfer")?);
+
+        // Ensure the `self/{resource}` and `{resource}` forms resolve to the current program.
+        assert_eq!(Locator::parse_with_default("self/transfer", &current)?, expected);
+        assert_eq!(Locator::parse_with_default("transfer", &current)?, expected);
+        assert_eq!(Locator::parse_with_default("current.aleo/transfer", &current)?, expected);
+
+        // Ensure the `{program_id}/{resource}` form is not resolved to the current program.
+        let locator = Locator::parse_with_default("other.aleo/transfer", &current)?;
+        assert_eq!(locator.program_id(), &ProgramID::from_str("other.aleo")?);
+        assert_eq!(locator.resource(), &Identifier::from_str("transfer")?);
+
+        // Ensure invalid forms are rejected with the invalid component.
+        let error = |string: &str| Locator::parse_with_default(string, &current).unwrap_err().to_string();
+        assert!(error("self/1transfer").contains("Invalid resource '1transfer'"));
+        assert!(error("1transfer").contains("Invalid resource '1transfer'"));
+        assert!(error("self.aleo.x/transfer").contains("Invalid program ID 'self.aleo.x'"));
+        assert!(error("other/transfer").contains("Invalid program ID 'other'"));
+        Ok(())
+    }
+
     #[test]
     fn test_import_display() -> Result<()> {
         let id = Locator::<CurrentNetwork>::from_str("foo.aleo/compute")?;
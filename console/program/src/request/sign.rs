@@ -64,7 +64,7 @@ impl<N: Network> Request<N> {
         // Derive the caller from the compute key.
         let caller = Address::try_from(compute_key)?;
         // Compute the transition view key `tvk` as `r * caller`.
-        let tvk = (*caller * r).to_x_coordinate();
+        let tvk = caller.mul_constant_time(&r).to_x_coordinate();
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
         let tcm = N::hash_psd2(&[tvk])?;
 
@@ -177,9 +177,9 @@ impl<N: Network> Request<N> {
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
                     // Compute `h_r` as `r * H`.
-                    let h_r = h * r;
+                    let h_r = h.mul_constant_time(&r);
                     // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
+                    let gamma = h.mul_constant_time(&sk_sig);
 
                     // Compute the `serial_number` from `gamma`.
                     let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
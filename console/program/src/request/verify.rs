@@ -178,7 +178,7 @@ impl<N: Network> Request<N> {
                         // Compute the generator `H` as `HashToGroup(commitment)`.
                         let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
                         // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
-                        let h_r = (*gamma * challenge) + (h * response);
+                        let h_r = gamma.mul_vartime(&challenge) + h.mul_vartime(&response);
 
                         // Compute the tag as `Hash(sk_tag || commitment)`.
                         let candidate_tag = N::hash_psd2(&[self.sk_tag, *commitment])?;
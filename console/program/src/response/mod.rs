@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod verify;
+
 use crate::{Identifier, ProgramID, Register, Value, ValueType};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
@@ -71,120 +73,17 @@ impl<N: Network> Response<N> {
             .zip_eq(output_operands)
             .enumerate()
             .map(|(index, ((output, output_type), output_register))| {
-                match output_type {
-                    // For a constant output, compute the hash (using `tcm`) of the output.
-                    ValueType::Constant(..) => {
-                        // Ensure the output is a plaintext.
-                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = vec![function_id];
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Constant(output_hash))
-                    }
-                    // For a public output, compute the hash (using `tcm`) of the output.
-                    ValueType::Public(..) => {
-                        // Ensure the output is a plaintext.
-                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tcm || index)`.
-                        let mut preimage = vec![function_id];
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tcm);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Public(output_hash))
-                    }
-                    // For a private output, compute the ciphertext (using `tvk`) and hash the ciphertext.
-                    ValueType::Private(..) => {
-                        // Ensure the output is a plaintext.
-                        ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Compute the output view key as `Hash(function ID || tvk || index)`.
-                        let output_view_key = N::hash_psd4(&[function_id, *tvk, index])?;
-                        // Compute the ciphertext.
-                        let ciphertext = match &output {
-                            Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(output_view_key)?,
-                            // Ensure the output is a plaintext.
-                            Value::Record(..) => bail!("Expected a plaintext output, found a record output"),
-                        };
-                        // Hash the ciphertext to a field element.
-                        let output_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
-                        // Return the output ID.
-                        Ok(OutputID::Private(output_hash))
-                    }
-                    // For a record output, compute the record commitment, and encrypt the record (using `tvk`).
-                    ValueType::Record(record_name) => {
-                        // Retrieve the record.
-                        let record = match &output {
-                            Value::Record(record) => record,
-                            // Ensure the input is a record.
-                            Value::Plaintext(..) => bail!("Expected a record output, found a plaintext output"),
-                        };
-
-                        // Retrieve the output register.
-                        let output_register = match output_register {
-                            Some(output_register) => output_register,
-                            None => bail!("Expected a register to be paired with a record output"),
-                        };
-
-                        // Compute the record commitment.
-                        let commitment = record.to_commitment(program_id, record_name)?;
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u64(output_register.locator());
-                        // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                        let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
-
-                        // Encrypt the record, using the randomizer.
-                        let encrypted_record = record.encrypt(randomizer)?;
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
-
-                        // Return the output ID.
-                        Ok(OutputID::Record(commitment, checksum))
-                    }
-                    // For a locator output, compute the hash (using `tvk`) of the output.
-                    ValueType::ExternalRecord(..) => {
-                        // Ensure the output is a record.
-                        ensure!(matches!(output, Value::Record(..)), "Expected a record output");
-
-                        // Construct the (console) output index as a field element.
-                        let index = Field::from_u16(
-                            u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
-                        );
-                        // Construct the preimage as `(function ID || output || tvk || index)`.
-                        let mut preimage = vec![function_id];
-                        preimage.extend(output.to_fields()?);
-                        preimage.push(*tvk);
-                        preimage.push(index);
-                        // Hash the output to a field element.
-                        let output_hash = N::hash_psd8(&preimage)?;
-
-                        // Return the output ID.
-                        Ok(OutputID::ExternalRecord(output_hash))
-                    }
-                }
+                Self::compute_output_id(
+                    &function_id,
+                    program_id,
+                    num_inputs,
+                    tvk,
+                    tcm,
+                    index,
+                    output,
+                    output_type,
+                    output_register.as_ref(),
+                )
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -200,4 +99,132 @@ impl<N: Network> Response<N> {
     pub fn outputs(&self) -> &[Value<N>] {
         &self.outputs
     }
+
+    /// Returns the output ID for the given output, at the given (output) index.
+    fn compute_output_id(
+        function_id: &Field<N>,
+        program_id: &ProgramID<N>,
+        num_inputs: usize,
+        tvk: &Field<N>,
+        tcm: &Field<N>,
+        index: usize,
+        output: &Value<N>,
+        output_type: &ValueType<N>,
+        output_register: Option<&Register<N>>,
+    ) -> Result<OutputID<N>> {
+        match output_type {
+            // For a constant output, compute the hash (using `tcm`) of the output.
+            ValueType::Constant(..) => {
+                // Ensure the output is a plaintext.
+                ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+
+                // Construct the (console) output index as a field element.
+                let index = Field::from_u16(
+                    u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                );
+                // Construct the preimage as `(function ID || output || tcm || index)`.
+                let mut preimage = vec![*function_id];
+                preimage.extend(output.to_fields()?);
+                preimage.push(*tcm);
+                preimage.push(index);
+                // Hash the output to a field element.
+                let output_hash = N::hash_psd8(&preimage)?;
+
+                // Return the output ID.
+                Ok(OutputID::Constant(output_hash))
+            }
+            // For a public output, compute the hash (using `tcm`) of the output.
+            ValueType::Public(..) => {
+                // Ensure the output is a plaintext.
+                ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+
+                // Construct the (console) output index as a field element.
+                let index = Field::from_u16(
+                    u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                );
+                // Construct the preimage as `(function ID || output || tcm || index)`.
+                let mut preimage = vec![*function_id];
+                preimage.extend(output.to_fields()?);
+                preimage.push(*tcm);
+                preimage.push(index);
+                // Hash the output to a field element.
+                let output_hash = N::hash_psd8(&preimage)?;
+
+                // Return the output ID.
+                Ok(OutputID::Public(output_hash))
+            }
+            // For a private output, compute the ciphertext (using `tvk`) and hash the ciphertext.
+            ValueType::Private(..) => {
+                // Ensure the output is a plaintext.
+                ensure!(matches!(output, Value::Plaintext(..)), "Expected a plaintext output");
+                // Construct the (console) output index as a field element.
+                let index = Field::from_u16(
+                    u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                );
+                // Compute the output view key as `Hash(function ID || tvk || index)`.
+                let output_view_key = N::hash_psd4(&[*function_id, *tvk, index])?;
+                // Compute the ciphertext.
+                let ciphertext = match output {
+                    Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(output_view_key)?,
+                    // Ensure the output is a plaintext.
+                    Value::Record(..) => bail!("Expected a plaintext output, found a record output"),
+                };
+                // Hash the ciphertext to a field element.
+                let output_hash = N::hash_psd8(&ciphertext.to_fields()?)?;
+                // Return the output ID.
+                Ok(OutputID::Private(output_hash))
+            }
+            // For a record output, compute the record commitment, and encrypt the record (using `tvk`).
+            ValueType::Record(record_name) => {
+                // Retrieve the record.
+                let record = match output {
+                    Value::Record(record) => record,
+                    // Ensure the input is a record.
+                    Value::Plaintext(..) => bail!("Expected a record output, found a plaintext output"),
+                };
+
+                // Retrieve the output register.
+                let output_register = match output_register {
+                    Some(output_register) => output_register,
+                    None => bail!("Expected a register to be paired with a record output"),
+                };
+
+                // Compute the record commitment.
+                let commitment = record.to_commitment(program_id, record_name)?;
+
+                // Construct the (console) output index as a field element.
+                let index = Field::from_u64(output_register.locator());
+                // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+                let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
+
+                // Encrypt the record, using the randomizer.
+                let encrypted_record = record.encrypt(randomizer)?;
+                // Compute the record checksum, as the hash of the encrypted record.
+                let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
+
+                // Return the output ID.
+                Ok(OutputID::Record(commitment, checksum))
+            }
+            // For a locator output, compute the hash (using `tvk`) of the output.
+            ValueType::ExternalRecord(..) => {
+                // Ensure the output is a record.
+                ensure!(matches!(output, Value::Record(..)), "Expected a record output");
+
+                // Construct the (console) output index as a field element.
+                let index = Field::from_u16(
+                    u16::try_from(num_inputs + index).or_halt_with::<N>("Output index exceeds u16"),
+                );
+                // Construct the preimage as `(function ID || output || tvk || index)`.
+                let mut preimage = vec![*function_id];
+                preimage.extend(output.to_fields()?);
+                preimage.push(*tvk);
+                preimage.push(index);
+                // Hash the output to a field element.
+                let output_hash = N::hash_psd8(&preimage)?;
+
+                // Return the output ID.
+                Ok(OutputID::ExternalRecord(output_hash))
+            }
+        }
+    }
 }
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Response<N> {
+    /// Checks that the outputs in the response are consistent with the given output IDs,
+    /// by recomputing the output ID of each output, without re-executing the function.
+    ///
+    /// Note: This method does **not** check that the outputs match their declared output types.
+    pub fn verify(
+        &self,
+        network_id: &U16<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        num_inputs: usize,
+        tvk: &Field<N>,
+        tcm: &Field<N>,
+        output_types: &[ValueType<N>],
+        output_registers: &[Option<Register<N>>],
+        output_ids: &[OutputID<N>],
+    ) -> Result<()> {
+        // Ensure the number of outputs matches the number of output types, output registers, and output IDs.
+        let num_outputs = self.outputs.len();
+        ensure!(num_outputs == output_types.len(), "Expected {} outputs, found {num_outputs}", output_types.len());
+        ensure!(num_outputs == output_registers.len(), "Expected {num_outputs} output registers");
+        ensure!(num_outputs == output_ids.len(), "Expected {num_outputs} output IDs, found {}", output_ids.len());
+        ensure!(num_outputs == self.output_ids.len(), "Expected {num_outputs} output IDs in the response");
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id =
+            N::hash_bhp1024(&(*network_id, program_id.name(), program_id.network(), function_name).to_bits_le())?;
+
+        for (index, (output, output_type)) in self.outputs.iter().zip_eq(output_types).enumerate() {
+            // Recompute the output ID from the claimed output.
+            let candidate_id = match Self::compute_output_id(
+                &function_id,
+                program_id,
+                num_inputs,
+                tvk,
+                tcm,
+                index,
+                output,
+                output_type,
+                output_registers[index].as_ref(),
+            ) {
+                Ok(candidate_id) => candidate_id,
+                Err(error) => bail!("Failed to compute the output ID for output {index}: {error}"),
+            };
+            // Ensure the output ID matches the given output ID.
+            ensure!(candidate_id == output_ids[index], "Output {index} does not match its output ID");
+            // Ensure the output ID matches the output ID in the response.
+            ensure!(candidate_id == self.output_ids[index], "Output {index} does not match the response output ID");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Literal, Plaintext};
+    use snarkvm_console_account::{Address, PrivateKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a response with one output of each visibility, returning it with its output types and registers.
+    fn sample_response(
+        rng: &mut TestRng,
+    ) -> (Response<CurrentNetwork>, Vec<ValueType<CurrentNetwork>>, Vec<Option<Register<CurrentNetwork>>>) {
+        // Sample a random private key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Prepare a record belonging to the address.
+        let record_string = format!(
+            "{{ owner: {address}.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        );
+
+        // Construct one output of each visibility.
+        let outputs = vec![
+            Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+            Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+            Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+            Value::from_str(&record_string).unwrap(),
+            Value::from_str(&record_string).unwrap(),
+        ];
+        let output_types = vec![
+            ValueType::from_str("amount.constant").unwrap(),
+            ValueType::from_str("amount.public").unwrap(),
+            ValueType::from_str("amount.private").unwrap(),
+            ValueType::from_str("token.record").unwrap(),
+            ValueType::from_str("token.aleo/token.record").unwrap(),
+        ];
+        let output_registers = (0..5).map(|locator| Some(Register::Locator(5 + locator))).collect::<Vec<_>>();
+
+        // Compute the response.
+        let response = Response::new(
+            &U16::new(CurrentNetwork::ID),
+            &ProgramID::from_str("token.aleo").unwrap(),
+            &Identifier::from_str("transfer").unwrap(),
+            2,
+            &Field::from_u64(1234),
+            &Field::from_u64(5678),
+            outputs,
+            &output_types,
+            &output_registers,
+        )
+        .unwrap();
+        (response, output_types, output_registers)
+    }
+
+    /// Verifies the given response against the given output IDs, using the parameters of `sample_response`.
+    fn verify(
+        response: &Response<CurrentNetwork>,
+        output_types: &[ValueType<CurrentNetwork>],
+        output_registers: &[Option<Register<CurrentNetwork>>],
+        output_ids: &[OutputID<CurrentNetwork>],
+    ) -> Result<()> {
+        response.verify(
+            &U16::new(CurrentNetwork::ID),
+            &ProgramID::from_str("token.aleo").unwrap(),
+            &Identifier::from_str("transfer").unwrap(),
+            2,
+            &Field::from_u64(1234),
+            &Field::from_u64(5678),
+            output_types,
+            output_registers,
+            output_ids,
+        )
+    }
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut TestRng::default();
+
+        let (response, output_types, output_registers) = sample_response(rng);
+        let output_ids = response.output_ids().to_vec();
+        verify(&response, &output_types, &output_registers, &output_ids).unwrap();
+
+        // Ensure a mismatching number of output IDs fails.
+        assert!(verify(&response, &output_types, &output_registers, &output_ids[1..]).is_err());
+    }
+
+    #[test]
+    fn test_verify_swapped_plaintext() {
+        let rng = &mut TestRng::default();
+
+        let (response, output_types, output_registers) = sample_response(rng);
+        let output_ids = response.output_ids().to_vec();
+
+        // Swap the plaintext of each non-record output, and ensure the failing index is reported.
+        for index in 0..3 {
+            let mut outputs = response.outputs().to_vec();
+            outputs[index] = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(index as u64))));
+            let candidate = Response::from((output_ids.clone(), outputs));
+
+            let error = verify(&candidate, &output_types, &output_registers, &output_ids).unwrap_err();
+            assert_eq!(error.to_string(), format!("Output {index} does not match its output ID"));
+        }
+    }
+
+    #[test]
+    fn test_verify_swapped_record() {
+        let rng = &mut TestRng::default();
+
+        let (response, output_types, output_registers) = sample_response(rng);
+        let (other, ..) = sample_response(rng);
+        let output_ids = response.output_ids().to_vec();
+
+        // Swap the record and external record outputs with a record of a different owner.
+        for index in [3, 4] {
+            let mut outputs = response.outputs().to_vec();
+            outputs[index] = other.outputs()[index].clone();
+            let candidate = Response::from((output_ids.clone(), outputs));
+
+            let error = verify(&candidate, &output_types, &output_registers, &output_ids).unwrap_err();
+            assert_eq!(error.to_string(), format!("Output {index} does not match its output ID"));
+        }
+
+        // Ensure a record output in place of a plaintext output is reported with its index.
+        let mut outputs = response.outputs().to_vec();
+        outputs.swap(1, 3);
+        let candidate = Response::from((output_ids.clone(), outputs));
+        let error = verify(&candidate, &output_types, &output_registers, &output_ids).unwrap_err();
+        assert!(error.to_string().starts_with("Failed to compute the output ID for output 1"));
+    }
+}
@@ -202,6 +202,38 @@ impl<E: Environment> Pow<&Field<E>> for Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Returns `self` to the power of the given `u64` exponent, using square-and-multiply.
+    #[inline]
+    pub fn pow_u64(&self, exponent: u64) -> Self {
+        let mut output = Self::one();
+        // Iterate over the bits of the exponent, from the most significant set bit.
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            output = output.square();
+            if (exponent >> i) & 1 == 1 {
+                output *= self;
+            }
+        }
+        output
+    }
+
+    /// Returns the `triple` of `self`.
+    #[inline]
+    pub fn triple(&self) -> Self {
+        Field::new(self.field.double() + self.field)
+    }
+
+    /// Returns the sum of the pairwise products of the given fields, i.e. `sum(a_i * b_i)`.
+    /// This uses a delayed-reduction inner product, if it is supported by the underlying field.
+    #[inline]
+    pub fn sum_of_products(a: &[Self], b: &[Self]) -> Result<Self> {
+        // Ensure the number of fields matches.
+        ensure!(a.len() == b.len(), "Mismatching number of fields: found {} and {}", a.len(), b.len());
+        // Compute the inner product.
+        Ok(Field::new(E::Field::sum_of_products(a.iter().map(|a| &a.field), b.iter().map(|b| &b.field))))
+    }
+}
+
 impl<E: Environment> Double for Field<E> {
     type Output = Field<E>;
 
@@ -287,6 +319,8 @@ mod tests {
 
     type CurrentEnvironment = Console;
 
+    const ITERATIONS: u64 = 100;
+
     #[test]
     fn test_div_by_zero_fails() {
         let one = Field::<CurrentEnvironment>::one();
@@ -295,4 +329,68 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_pow_u64() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let base: Field<CurrentEnvironment> = Uniform::rand(&mut rng);
+            // Compare against repeated multiplication.
+            let mut expected = Field::one();
+            for exponent in 0..=17u64 {
+                assert_eq!(expected, base.pow_u64(exponent));
+                expected *= base;
+            }
+            // Compare against the generic `pow` for a large exponent.
+            let exponent: u64 = rng.gen();
+            assert_eq!(base.pow(Field::from_u64(exponent)), base.pow_u64(exponent));
+            assert_eq!(base.pow(Field::from_u64(u64::MAX)), base.pow_u64(u64::MAX));
+        }
+
+        // Check the zero base.
+        assert!(Field::<CurrentEnvironment>::zero().pow_u64(0).is_one());
+        assert!(Field::<CurrentEnvironment>::zero().pow_u64(5).is_zero());
+    }
+
+    #[test]
+    fn test_double_and_triple() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let field: Field<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(field + field, field.double());
+            assert_eq!(field + field + field, field.triple());
+            assert_eq!(field.double() + field, field.triple());
+        }
+
+        // Check the zero and one cases.
+        assert!(Field::<CurrentEnvironment>::zero().double().is_zero());
+        assert!(Field::<CurrentEnvironment>::zero().triple().is_zero());
+        assert_eq!(Field::<CurrentEnvironment>::one() + Field::one() + Field::one(), Field::one().triple());
+    }
+
+    #[test]
+    fn test_sum_of_products() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_fields in 0..=32 {
+            let a: Vec<Field<CurrentEnvironment>> = (0..num_fields).map(|_| Uniform::rand(&mut rng)).collect();
+            let b: Vec<Field<CurrentEnvironment>> = (0..num_fields).map(|_| Uniform::rand(&mut rng)).collect();
+            // Compare against the naive inner product.
+            let expected = a.iter().zip_eq(&b).fold(Field::zero(), |sum, (a, b)| sum + *a * b);
+            assert_eq!(expected, Field::sum_of_products(&a, &b)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sum_of_products_length_mismatch() {
+        let mut rng = TestRng::default();
+
+        let a: Vec<Field<CurrentEnvironment>> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+        assert!(Field::sum_of_products(&a, &a[..3]).is_err());
+        assert!(Field::sum_of_products(&a[..3], &a).is_err());
+        assert!(Field::sum_of_products(&a, &[]).is_err());
+    }
 }
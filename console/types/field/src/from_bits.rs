@@ -20,6 +20,9 @@ impl<E: Environment> FromBits for Field<E> {
     /// Initializes a new field from a list of **little-endian** bits.
     ///   - If `bits_le` is longer than `E::Field::size_in_bits()`, the excess bits are enforced to be `0`s.
     ///   - If `bits_le` is shorter than `E::Field::size_in_bits()`, it is padded with `0`s up to field size.
+    ///   - If `bits_le` is longer than `E::Field::size_in_data_bits()`, its value is enforced to be below the modulus.
+    ///
+    /// Prefer `Field::from_bits_le_strict` or `Field::from_bits_le_mod`, which name the overflow policy explicitly.
     fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
         // Retrieve the data and field size.
         let size_in_data_bits = Field::<E>::size_in_data_bits();
@@ -71,6 +74,33 @@ impl<E: Environment> FromBits for Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Initializes a new field from a list of **little-endian** bits, rejecting any non-canonical encoding.
+    ///   - If `bits_le` is longer than `E::Field::size_in_bits()`, the excess bits must be `0`s.
+    ///   - If `bits_le` is longer than `E::Field::size_in_data_bits()`, its value must be less than the modulus.
+    ///
+    /// This matches `Field::from_bits_le_strict` in the circuit, which is unsatisfied whenever this method fails.
+    pub fn from_bits_le_strict(bits_le: &[bool]) -> Result<Self> {
+        <Self as FromBits>::from_bits_le(bits_le)
+    }
+
+    /// Initializes a new field from a list of **little-endian** bits of any length,
+    /// by reducing the value of the bits modulo the field modulus.
+    ///
+    /// This matches `Field::from_bits_le_mod` in the circuit.
+    pub fn from_bits_le_mod(bits_le: &[bool]) -> Self {
+        // Compute `(2^i * b_i + ... + 2^0 * b_0)` in the field, starting from the most significant bit.
+        let mut output = E::Field::zero();
+        for bit in bits_le.iter().rev() {
+            output.double_in_place();
+            if *bit {
+                output += E::Field::one();
+            }
+        }
+        Field::new(output)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,6 +159,41 @@ mod tests {
         check_from_bits_le()
     }
 
+    #[test]
+    fn test_from_bits_le_strict_and_mod() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let size_in_bits = Field::<CurrentEnvironment>::size_in_bits();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element, and ensure both variants recover it.
+            let expected: Field<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let bits_le = expected.to_bits_le();
+            assert_eq!(expected, Field::from_bits_le_strict(&bits_le)?);
+            assert_eq!(expected, Field::from_bits_le_mod(&bits_le));
+
+            // Add excess bits, and ensure only the reduction accepts a nonzero excess.
+            let excess = (0..rng.gen_range(1..64)).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let bits_le = [bits_le, excess.clone()].concat();
+            let shift = (0..size_in_bits).fold(Field::<CurrentEnvironment>::one(), |shift, _| shift + shift);
+            let expected_mod = expected + Field::from_bits_le_mod(&excess) * shift;
+            assert_eq!(expected_mod, Field::from_bits_le_mod(&bits_le));
+            let is_err = Field::<CurrentEnvironment>::from_bits_le_strict(&bits_le).is_err();
+            assert_eq!(excess.iter().any(|bit| *bit), is_err);
+        }
+
+        // Check the modulus boundary, where `-1` is encoded by the bits of `MODULUS - 1`.
+        let minus_one = -Field::<CurrentEnvironment>::one();
+        let mut modulus = minus_one.to_bits_le();
+        let position = modulus.iter().position(|bit| !bit).unwrap();
+        modulus[..position].iter_mut().for_each(|bit| *bit = false);
+        modulus[position] = true;
+        assert_eq!(minus_one, Field::from_bits_le_strict(&minus_one.to_bits_le())?);
+        assert!(Field::<CurrentEnvironment>::from_bits_le_strict(&modulus).is_err());
+        assert_eq!(Field::zero(), Field::<CurrentEnvironment>::from_bits_le_mod(&modulus));
+        Ok(())
+    }
+
     #[test]
     fn test_from_bits_be() -> Result<()> {
         check_from_bits_be()
@@ -25,6 +25,7 @@ mod from_bits;
 mod one;
 mod parse;
 mod random;
+mod roots_of_unity;
 mod serialize;
 mod size_in_bits;
 mod size_in_bytes;
@@ -20,13 +20,25 @@ impl<E: Environment> Parser for Field<E> {
     /// Parses a string into a field circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the value from the string.
+        let (string, field) = Self::parse_value(string)?;
+        // Parse the type name from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+
+        Ok((string, field))
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Parses a string into a field, without its type name.
+    fn parse_value(string: &str) -> ParserResult<Self> {
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
         // Parse the value from the string.
-        let (string, value): (&str, E::Field) =
-            map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, value): (&str, E::Field) = map_res(
+            recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))),
+            |primitive: &str| primitive.replace('_', "").parse(),
+        )(string)?;
         // Negate the value if the negative sign was present.
         let value = match negation {
             true => -value,
@@ -43,7 +55,8 @@ impl<E: Environment> FromStr for Field<E> {
     /// Parses a string into a field.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
+        // Parse the type name only if it is present, so that unsuffixed values (e.g. `123`) are also accepted.
+        match terminated(Self::parse_value, opt(tag(Self::type_name())))(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
                 ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
@@ -96,6 +109,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_optional_suffix() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let expected = Field::<CurrentEnvironment>::new(Uniform::rand(rng));
+
+            // Ensure the suffixed form parses.
+            let suffixed = expected.to_string();
+            assert_eq!(expected, Field::<CurrentEnvironment>::from_str(&suffixed)?);
+
+            // Ensure the unsuffixed form parses.
+            let unsuffixed = suffixed.trim_end_matches(Field::<CurrentEnvironment>::type_name());
+            assert_eq!(expected, Field::<CurrentEnvironment>::from_str(unsuffixed)?);
+
+            // Ensure the negated unsuffixed form parses.
+            assert_eq!(-expected, Field::<CurrentEnvironment>::from_str(&format!("-{unsuffixed}"))?);
+        }
+
+        // Ensure a mismatched or repeated type annotation fails.
+        assert!(Field::<CurrentEnvironment>::from_str("1u8").is_err());
+        assert!(Field::<CurrentEnvironment>::from_str("0group").is_err());
+        assert!(Field::<CurrentEnvironment>::from_str("0fieldfield").is_err());
+        assert!(Field::<CurrentEnvironment>::from_str("").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a field from the given element,
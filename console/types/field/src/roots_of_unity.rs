@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Returns the `n`-th roots of unity `[1, w, w^2, ..., w^(n-1)]`, where `w` is a primitive `n`-th root of unity.
+    /// This method requires `n` to be a power of two that divides the two-adic subgroup of the field.
+    pub fn roots_of_unity(n: usize) -> Result<Vec<Self>> {
+        // Ensure the domain size is a power of two.
+        ensure!(n.is_power_of_two(), "The domain size must be a power of two, found {n}");
+        // Retrieve the primitive `n`-th root of unity.
+        let root = match E::Field::get_root_of_unity(n) {
+            Some(root) => Field::new(root),
+            None => bail!("The domain size {n} exceeds the two-adicity of the field"),
+        };
+        // Compute the successive powers of the root.
+        Ok(core::iter::successors(Some(Self::one()), |power| Some(*power * root)).take(n).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_roots_of_unity() {
+        for log_n in 0..10 {
+            let n = 1usize << log_n;
+            let roots = Field::<CurrentEnvironment>::roots_of_unity(n).unwrap();
+            assert_eq!(n, roots.len());
+            assert!(roots[0].is_one());
+
+            // Ensure every root satisfies `w^n == 1`.
+            for root in &roots {
+                assert!(root.pow_u64(n as u64).is_one());
+            }
+            // Ensure the roots are distinct.
+            assert!(!has_duplicates(roots.iter()));
+            // Ensure the generator is primitive, i.e. `w^(n/2) == -1`.
+            if n > 1 {
+                assert_eq!(-Field::one(), roots[1].pow_u64(n as u64 / 2));
+            }
+        }
+    }
+
+    #[test]
+    fn test_roots_of_unity_fails() {
+        // Ensure sizes that are not a power of two are rejected.
+        for n in [0, 3, 6, 12, 1000] {
+            assert!(Field::<CurrentEnvironment>::roots_of_unity(n).is_err());
+        }
+        // Ensure sizes that exceed the two-adicity are rejected.
+        assert!(Field::<CurrentEnvironment>::roots_of_unity(1 << 62).is_err());
+    }
+}
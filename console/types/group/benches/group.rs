@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_network_environment::{prelude::*, Console};
+use snarkvm_console_types_group::{Group, Scalar};
+
+use criterion::Criterion;
+
+const NUM_MULTIPLICATIONS: usize = 1_000;
+const NUM_SUBGROUP_CHECKS: usize = 10_000;
+
+fn group_mul(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let bases = (0..NUM_MULTIPLICATIONS).map(|_| Group::<Console>::rand(rng)).collect::<Vec<_>>();
+    let scalars = (0..NUM_MULTIPLICATIONS).map(|_| Scalar::<Console>::rand(rng)).collect::<Vec<_>>();
+
+    c.bench_function("Group::mul 1k (double-and-add)", |b| {
+        b.iter(|| bases.iter().zip(&scalars).map(|(base, scalar)| **base * **scalar).collect::<Vec<_>>())
+    });
+    c.bench_function("Group::mul_vartime 1k", |b| {
+        b.iter(|| bases.iter().zip(&scalars).map(|(base, scalar)| base.mul_vartime(scalar)).collect::<Vec<_>>())
+    });
+    c.bench_function("Group::mul_constant_time 1k", |b| {
+        b.iter(|| bases.iter().zip(&scalars).map(|(base, scalar)| base.mul_constant_time(scalar)).collect::<Vec<_>>())
+    });
+    c.bench_function("Group::mul_batch_vartime 1k", |b| b.iter(|| Group::mul_batch_vartime(&scalars, &bases).unwrap()));
+}
+
+fn group_subgroup_check(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let elements = (0..NUM_SUBGROUP_CHECKS).map(|_| Group::<Console>::rand(rng)).collect::<Vec<_>>();
+
+    c.bench_function("Group::is_in_subgroup 10k", |b| b.iter(|| elements.iter().all(|e| e.is_in_subgroup())));
+    c.bench_function("Group::batch_check_subgroup 10k", |b| b.iter(|| Group::batch_check_subgroup(&elements, rng)));
+}
+
+criterion_group! {
+    name = group;
+    config = Criterion::default().sample_size(10);
+    targets = group_mul, group_subgroup_check,
+}
+
+criterion_main!(group);
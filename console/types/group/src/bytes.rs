@@ -32,6 +32,50 @@ impl<E: Environment> ToBytes for Group<E> {
     }
 }
 
+impl<E: Environment> Group<E> {
+    /// The flag set in the most significant bit of the last byte, if the group element is the identity.
+    const IDENTITY_FLAG: u8 = 0b1000_0000;
+
+    /// Returns the little-endian bytes of the x-coordinate of the group element,
+    /// with a flag in the most significant bit that is set IFF the group element is the identity.
+    pub fn to_bytes_with_flag(&self) -> Result<Vec<u8>> {
+        let mut bytes = self.to_x_coordinate().to_bytes_le()?;
+        // Note: The most significant bit of the x-coordinate is always unset, as the field modulus is less than 2^255.
+        if let Some(last_byte) = bytes.last_mut() {
+            if self.is_zero() {
+                *last_byte |= Self::IDENTITY_FLAG;
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Reads a group element from bytes produced by `to_bytes_with_flag`.
+    /// The identity is only accepted if its flag is set, so that it is never mistaken for an x-coordinate.
+    pub fn from_bytes_with_flag(bytes: &[u8]) -> Result<Self> {
+        // Ensure the number of bytes is correct.
+        let num_bytes = Field::<E>::size_in_bytes();
+        ensure!(bytes.len() == num_bytes, "Expected {num_bytes} bytes for a group element, found {}", bytes.len());
+
+        // Separate the identity flag from the x-coordinate.
+        let mut bytes = bytes.to_vec();
+        let is_identity = bytes[num_bytes - 1] & Self::IDENTITY_FLAG != 0;
+        bytes[num_bytes - 1] &= !Self::IDENTITY_FLAG;
+        let x_coordinate = Field::<E>::from_bytes_le(&bytes)?;
+
+        match is_identity {
+            true => {
+                ensure!(x_coordinate.is_zero(), "The identity flag is set on a non-zero x-coordinate");
+                Ok(Self::zero())
+            }
+            false => {
+                let group = Self::from_x_coordinate(x_coordinate)?;
+                ensure!(!group.is_zero(), "The identity must be encoded with its flag set");
+                Ok(group)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +100,41 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_flag() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new group.
+            let expected = Group::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Check the flagged byte representation, which matches the unflagged one for a non-identity element.
+            let expected_bytes = expected.to_bytes_with_flag()?;
+            assert_eq!(expected_bytes, expected.to_bytes_le()?);
+            assert_eq!(expected, Group::from_bytes_with_flag(&expected_bytes)?);
+            assert!(Group::<CurrentEnvironment>::from_bytes_with_flag(&expected_bytes[1..]).is_err());
+
+            // Ensure the identity flag is rejected on a non-zero x-coordinate.
+            let mut flagged_bytes = expected_bytes;
+            *flagged_bytes.last_mut().unwrap() |= 0b1000_0000;
+            assert!(Group::<CurrentEnvironment>::from_bytes_with_flag(&flagged_bytes).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_identity_bytes_with_flag() -> Result<()> {
+        let identity = Group::<CurrentEnvironment>::zero();
+
+        // Check the identity round-trips through its flagged byte representation.
+        let bytes = identity.to_bytes_with_flag()?;
+        assert_eq!(bytes.last(), Some(&0b1000_0000));
+        assert!(bytes[..bytes.len() - 1].iter().all(|byte| *byte == 0));
+        assert_eq!(identity, Group::from_bytes_with_flag(&bytes)?);
+
+        // Ensure the identity is rejected without its flag set.
+        assert!(Group::<CurrentEnvironment>::from_bytes_with_flag(&identity.to_bytes_le()?).is_err());
+        Ok(())
+    }
 }
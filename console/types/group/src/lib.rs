@@ -25,11 +25,13 @@ mod from_field;
 mod from_fields;
 mod from_x_coordinate;
 mod from_xy_coordinates;
+mod mul;
 mod parse;
 mod random;
 mod serialize;
 mod size_in_bits;
 mod size_in_bytes;
+mod subgroup;
 mod to_bits;
 mod to_field;
 mod to_fields;
@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The window size of the wNAF scalar multiplication.
+const WNAF_WINDOW_SIZE: u32 = 4;
+/// The modulus used to compute a wNAF digit, i.e. `2^WNAF_WINDOW_SIZE`.
+const WNAF_MODULUS: u64 = 1 << WNAF_WINDOW_SIZE;
+/// The number of precomputed odd multiples of the base, i.e. `[P, 3P, ..., (2^(WNAF_WINDOW_SIZE - 1) - 1)P]`.
+const WNAF_TABLE_SIZE: usize = 1 << (WNAF_WINDOW_SIZE - 2);
+
+impl<E: Environment> Group<E> {
+    /// Returns `self * scalar`, using a windowed non-adjacent form (wNAF) scalar multiplication.
+    ///
+    /// This method runs in variable time, and must not be used with secret scalars.
+    /// For secret scalars, use [`Self::mul_constant_time`].
+    pub fn mul_vartime(&self, scalar: &Scalar<E>) -> Self {
+        let table = E::Projective::batch_normalization_into_affine(Self::wnaf_table(&self.group));
+        Self::from_projective(Self::wnaf_evaluate(&Self::wnaf_digits(scalar), &table))
+    }
+
+    /// Returns `self * scalar`, using a Montgomery ladder over every bit of the scalar.
+    ///
+    /// This method performs the same sequence of group operations for every scalar, and selects
+    /// the ladder registers with a conditional swap instead of branching on the bits of the scalar.
+    /// It should be used when the scalar is secret (i.e. derived from a private key).
+    pub fn mul_constant_time(&self, scalar: &Scalar<E>) -> Self {
+        let mut r0 = E::Projective::zero();
+        let mut r1 = self.group;
+        // The registers are swapped whenever the current bit differs from the previous bit,
+        // so that every step computes `(r0, r1) := (2 * r0, r0 + r1)` on the swapped registers.
+        let mut swapped = false;
+        for bit in scalar.to_bits_be() {
+            r0.conditional_swap(&mut r1, swapped ^ bit);
+            swapped = bit;
+            r1 += r0;
+            r0.double_in_place();
+        }
+        r0.conditional_swap(&mut r1, swapped);
+        Self::from_projective(r0)
+    }
+
+    /// Returns `bases[i] * scalars[i]` for each `i`, using a wNAF scalar multiplication
+    /// whose precomputed tables are normalized in a single batch.
+    ///
+    /// This method runs in variable time, and must not be used with secret scalars.
+    pub fn mul_batch_vartime(scalars: &[Scalar<E>], bases: &[Group<E>]) -> Result<Vec<Self>> {
+        // Ensure the number of scalars matches the number of bases.
+        ensure!(
+            scalars.len() == bases.len(),
+            "Expected {} scalars for {} bases, found {}",
+            bases.len(),
+            bases.len(),
+            scalars.len()
+        );
+
+        // Compute the tables for every base, and normalize them together.
+        let tables = bases.iter().flat_map(|base| Self::wnaf_table(&base.group)).collect();
+        let tables = E::Projective::batch_normalization_into_affine(tables);
+
+        // Compute each product.
+        Ok(scalars
+            .iter()
+            .zip_eq(tables.chunks(WNAF_TABLE_SIZE))
+            .map(|(scalar, table)| Self::from_projective(Self::wnaf_evaluate(&Self::wnaf_digits(scalar), table)))
+            .collect())
+    }
+}
+
+impl<E: Environment> Group<E> {
+    /// Returns the odd multiples `[P, 3P, ..., (2^(WNAF_WINDOW_SIZE - 1) - 1)P]` of the given base `P`.
+    fn wnaf_table(base: &E::Projective) -> Vec<E::Projective> {
+        let double = base.double();
+        let mut table = Vec::with_capacity(WNAF_TABLE_SIZE);
+        table.push(*base);
+        for i in 1..WNAF_TABLE_SIZE {
+            table.push(table[i - 1] + double);
+        }
+        table
+    }
+
+    /// Returns the wNAF digits of the given scalar, in little-endian order.
+    /// Each digit is either zero, or odd and in the range `(-2^(WNAF_WINDOW_SIZE - 1), 2^(WNAF_WINDOW_SIZE - 1))`.
+    fn wnaf_digits(scalar: &Scalar<E>) -> Vec<i64> {
+        // Pad the scalar bits with zeros, so that the final carry is absorbed.
+        let mut bits = scalar.to_bits_le();
+        bits.resize(bits.len() + WNAF_WINDOW_SIZE as usize + 1, false);
+
+        let mut digits = vec![0i64; bits.len()];
+        let mut carry = 0u64;
+        let mut position = 0;
+        while position < bits.len() {
+            // Read the window starting at the current position.
+            let window = bits[position..]
+                .iter()
+                .take(WNAF_WINDOW_SIZE as usize)
+                .rev()
+                .fold(0u64, |window, bit| (window << 1) | *bit as u64)
+                + carry;
+            // If the window is even, move to the next bit.
+            if window & 1 == 0 {
+                position += 1;
+                continue;
+            }
+            // Otherwise, set the signed digit, and skip to the end of the window.
+            match window < WNAF_MODULUS / 2 {
+                true => {
+                    carry = 0;
+                    digits[position] = window as i64;
+                }
+                false => {
+                    carry = 1;
+                    digits[position] = window as i64 - WNAF_MODULUS as i64;
+                }
+            }
+            position += WNAF_WINDOW_SIZE as usize;
+        }
+        digits
+    }
+
+    /// Returns the product encoded by the given wNAF digits and the table of odd multiples of the base.
+    fn wnaf_evaluate(digits: &[i64], table: &[E::Affine]) -> E::Projective {
+        let mut output = E::Projective::zero();
+        for digit in digits.iter().rev() {
+            output.double_in_place();
+            match digit.cmp(&0) {
+                Ordering::Greater => output.add_assign_mixed(&table[(digit.unsigned_abs() / 2) as usize]),
+                Ordering::Less => output.add_assign_mixed(&-table[(digit.unsigned_abs() / 2) as usize]),
+                Ordering::Equal => (),
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the product, as computed by the underlying double-and-add implementation.
+    fn expected_product(
+        base: &Group<CurrentEnvironment>,
+        scalar: &Scalar<CurrentEnvironment>,
+    ) -> Group<CurrentEnvironment> {
+        Group::from_projective(**base * **scalar)
+    }
+
+    /// Returns the edge-case scalars, i.e. zero, one, two, and the modulus minus one.
+    fn edge_case_scalars() -> Vec<Scalar<CurrentEnvironment>> {
+        let one = Scalar::<CurrentEnvironment>::one();
+        vec![Scalar::zero(), one, one + one, -one]
+    }
+
+    #[test]
+    fn test_mul_vartime() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let base: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let scalar: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(expected_product(&base, &scalar), base.mul_vartime(&scalar));
+
+            for scalar in edge_case_scalars() {
+                assert_eq!(expected_product(&base, &scalar), base.mul_vartime(&scalar));
+            }
+        }
+        // Check the zero base.
+        let scalar: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+        assert_eq!(Group::zero(), Group::<CurrentEnvironment>::zero().mul_vartime(&scalar));
+        // Check the modulus minus one.
+        let base: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+        assert_eq!(-base, base.mul_vartime(&-Scalar::one()));
+    }
+
+    #[test]
+    fn test_mul_constant_time() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let base: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let scalar: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(expected_product(&base, &scalar), base.mul_constant_time(&scalar));
+
+            for scalar in edge_case_scalars() {
+                assert_eq!(expected_product(&base, &scalar), base.mul_constant_time(&scalar));
+            }
+        }
+        // Check the zero base.
+        let scalar: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+        assert_eq!(Group::zero(), Group::<CurrentEnvironment>::zero().mul_constant_time(&scalar));
+    }
+
+    #[test]
+    fn test_mul_batch_vartime() {
+        let mut rng = TestRng::default();
+
+        let mut bases = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Group<CurrentEnvironment>>>();
+        let mut scalars = (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Scalar<CurrentEnvironment>>>();
+        for scalar in edge_case_scalars() {
+            bases.push(Uniform::rand(&mut rng));
+            scalars.push(scalar);
+        }
+        bases.push(Group::zero());
+        scalars.push(Uniform::rand(&mut rng));
+
+        let products = Group::mul_batch_vartime(&scalars, &bases).unwrap();
+        assert_eq!(products.len(), bases.len());
+        for ((base, scalar), product) in bases.iter().zip(&scalars).zip(products) {
+            assert_eq!(expected_product(base, scalar), product);
+        }
+
+        // Ensure mismatched lengths are rejected.
+        assert!(Group::mul_batch_vartime(&scalars[1..], &bases).is_err());
+    }
+}
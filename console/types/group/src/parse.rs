@@ -20,21 +20,29 @@ impl<E: Environment> Parser for Group<E> {
     /// Parses a string into a group circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the group from the string.
+        let (string, group) = Self::parse_value(string)?;
+        // Parse the type name from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+
+        Ok((string, group))
+    }
+}
+
+impl<E: Environment> Group<E> {
+    /// Parses a string into a group, without its type name.
+    fn parse_value(string: &str) -> ParserResult<Self> {
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
         // Parse the group from the string.
-        let (string, group): (&str, Self) = map_res(tag(Self::type_name()), |_| {
+        map_res(recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))), |primitive: &str| {
             let x_coordinate = primitive.replace('_', "").parse()?;
             // Recover and negate the group element if the negative sign was present.
             match negation {
                 true => Ok(-Group::from_x_coordinate(Field::new(x_coordinate))?),
                 false => Group::from_x_coordinate(Field::new(x_coordinate)),
             }
-        })(string)?;
-
-        Ok((string, group))
+        })(string)
     }
 }
 
@@ -44,7 +52,8 @@ impl<E: Environment> FromStr for Group<E> {
     /// Parses a string into a group.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
+        // Parse the type name only if it is present, so that unsuffixed values (e.g. `123`) are also accepted.
+        match terminated(Self::parse_value, opt(tag(Self::type_name())))(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
                 ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
@@ -97,6 +106,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_optional_suffix() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let expected = Group::<CurrentEnvironment>::new(Uniform::rand(rng));
+
+            // Ensure the suffixed form parses.
+            let suffixed = expected.to_string();
+            assert_eq!(expected, Group::<CurrentEnvironment>::from_str(&suffixed)?);
+
+            // Ensure the unsuffixed form parses.
+            let unsuffixed = suffixed.trim_end_matches(Group::<CurrentEnvironment>::type_name());
+            assert_eq!(expected, Group::<CurrentEnvironment>::from_str(unsuffixed)?);
+
+            // Ensure the negated unsuffixed form parses.
+            assert_eq!(-expected, Group::<CurrentEnvironment>::from_str(&format!("-{unsuffixed}"))?);
+        }
+
+        // Ensure a mismatched or repeated type annotation fails.
+        assert!(Group::<CurrentEnvironment>::from_str("1u8").is_err());
+        assert!(Group::<CurrentEnvironment>::from_str("5field").is_err());
+        assert!(Group::<CurrentEnvironment>::from_str("0groupgroup").is_err());
+        assert!(Group::<CurrentEnvironment>::from_str("").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a group from the given element,
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of random subset sums checked in a batch subgroup check.
+/// Each round detects an invalid element with probability at least 1/2.
+const BATCH_CHECK_ROUNDS: usize = 128;
+
+impl<E: Environment> Group<E> {
+    /// Returns `true` if the group element is on the curve and in the prime-order subgroup.
+    pub fn is_in_subgroup(&self) -> bool {
+        let affine = self.group.to_affine();
+        affine.is_on_curve() && affine.is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Ensures every given group element is on the curve and in the prime-order subgroup.
+    ///
+    /// Instead of one scalar multiplication per element, each round samples a random subset of the
+    /// elements and performs a single subgroup check on their sum. As the sum of subgroup elements
+    /// is in the subgroup, and an element outside the subgroup changes the sum's torsion component
+    /// with probability 1/2, an invalid batch passes with probability at most `2^-BATCH_CHECK_ROUNDS`.
+    ///
+    /// If the batch check fails, each element is checked individually, and the error names the first invalid index.
+    pub fn batch_check_subgroup<R: Rng + CryptoRng>(elements: &[Self], rng: &mut R) -> Result<()> {
+        // For small batches, checking each element individually is cheaper.
+        if elements.len() <= BATCH_CHECK_ROUNDS {
+            return Self::check_subgroup_each(elements);
+        }
+
+        // Normalize the elements together, and ensure each one is on the curve.
+        let affines = E::Projective::batch_normalization_into_affine(elements.iter().map(|e| e.group).collect());
+        if !affines.iter().all(|affine| affine.is_on_curve()) {
+            return Self::check_subgroup_each(elements);
+        }
+
+        // Check the sum of a random subset of the elements in each round.
+        for _ in 0..BATCH_CHECK_ROUNDS {
+            let mut sum = E::Projective::zero();
+            for affine in &affines {
+                if rng.gen::<bool>() {
+                    sum.add_assign_mixed(affine);
+                }
+            }
+            if !sum.to_affine().is_in_correct_subgroup_assuming_on_curve() {
+                return Self::check_subgroup_each(elements);
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures each group element is on the curve and in the prime-order subgroup,
+    /// returning an error with the index of the first invalid element.
+    fn check_subgroup_each(elements: &[Self]) -> Result<()> {
+        match elements.iter().position(|element| !element.is_in_subgroup()) {
+            Some(index) => bail!("Group element at index {index} is not in the prime-order subgroup"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const NUM_ELEMENTS: usize = 300;
+
+    /// Returns a group element that is on the curve, but not in the prime-order subgroup.
+    fn sample_off_subgroup(rng: &mut TestRng) -> Group<CurrentEnvironment> {
+        // Add the point of order 2, i.e. `(0, -1)`, to a random subgroup element.
+        let torsion = Group::from_xy_coordinates_unchecked(Field::zero(), -Field::one());
+        Group::rand(rng) + torsion
+    }
+
+    #[test]
+    fn test_batch_check_subgroup() {
+        let mut rng = TestRng::default();
+
+        // Ensure an empty batch passes.
+        assert!(Group::<CurrentEnvironment>::batch_check_subgroup(&[], &mut rng).is_ok());
+
+        // Ensure a small and a large batch of valid elements pass.
+        for num_elements in [1, NUM_ELEMENTS] {
+            let elements = (0..num_elements).map(|_| Group::<CurrentEnvironment>::rand(&mut rng)).collect::<Vec<_>>();
+            assert!(elements.iter().all(|element| element.is_in_subgroup()));
+            assert!(Group::batch_check_subgroup(&elements, &mut rng).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_batch_check_subgroup_finds_invalid_index() {
+        let mut rng = TestRng::default();
+
+        let cases = [(10, 7), (NUM_ELEMENTS, 0), (NUM_ELEMENTS, 173), (NUM_ELEMENTS, NUM_ELEMENTS - 1)];
+        for (num_elements, index) in cases {
+            let mut elements =
+                (0..num_elements).map(|_| Group::<CurrentEnvironment>::rand(&mut rng)).collect::<Vec<_>>();
+            elements[index] = sample_off_subgroup(&mut rng);
+            assert!(!elements[index].is_in_subgroup());
+
+            let error = Group::batch_check_subgroup(&elements, &mut rng).unwrap_err().to_string();
+            assert_eq!(error, format!("Group element at index {index} is not in the prime-order subgroup"));
+        }
+    }
+}
@@ -32,6 +32,36 @@ impl<E: Environment, I: IntegerType> ToBytes for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new integer from **little-endian** bytes,
+    /// ensuring the number of bytes is exactly the size of the integer.
+    pub fn from_le_bytes_checked(bytes_le: &[u8]) -> Result<Self> {
+        // Ensure the number of bytes matches the size of the integer.
+        ensure!(
+            bytes_le.len() == Self::size_in_bytes(),
+            "Expected exactly {} bytes for '{}', found {} bytes",
+            Self::size_in_bytes(),
+            Self::type_name(),
+            bytes_le.len()
+        );
+        Self::read_le(bytes_le).map_err(|error| anyhow!("Failed to read '{}' from bytes: {error}", Self::type_name()))
+    }
+
+    /// Initializes a new integer from **big-endian** bytes,
+    /// ensuring the number of bytes is exactly the size of the integer.
+    pub fn from_be_bytes(bytes_be: &[u8]) -> Result<Self> {
+        let bytes_le = bytes_be.iter().rev().copied().collect::<Vec<_>>();
+        Self::from_le_bytes_checked(&bytes_le)
+    }
+
+    /// Returns the **big-endian** bytes of the integer.
+    pub fn to_be_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = self.to_bytes_le()?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,6 +90,76 @@ mod tests {
         Ok(())
     }
 
+    fn check_bytes_checked<I: IntegerType>(rng: &mut TestRng) -> Result<()> {
+        type Candidate<T> = Integer<CurrentEnvironment, T>;
+
+        let size_in_bytes = Integer::<CurrentEnvironment, I>::size_in_bytes();
+
+        let boundaries = [
+            Integer::<CurrentEnvironment, I>::MIN,
+            Integer::MAX,
+            Integer::zero(),
+            Integer::one(),
+        ];
+        let samples = (0..ITERATIONS).map(|_| Uniform::rand(rng)).collect::<Vec<Integer<CurrentEnvironment, I>>>();
+
+        for expected in boundaries.into_iter().chain(samples) {
+            // Check the little-endian byte representation.
+            let bytes_le = expected.to_bytes_le()?;
+            assert_eq!(size_in_bytes, bytes_le.len());
+            assert_eq!(expected, Integer::from_le_bytes_checked(&bytes_le)?);
+
+            // Check the big-endian byte representation.
+            let bytes_be = expected.to_be_bytes()?;
+            assert_eq!(bytes_le.iter().rev().copied().collect::<Vec<_>>(), bytes_be);
+            assert_eq!(expected, Integer::from_be_bytes(&bytes_be)?);
+
+            // Ensure a truncated or extended buffer is rejected.
+            assert!(Candidate::<I>::from_le_bytes_checked(&bytes_le[1..]).is_err());
+            assert!(Candidate::<I>::from_be_bytes(&bytes_be[1..]).is_err());
+            assert!(Candidate::<I>::from_le_bytes_checked(&[&bytes_le[..], &[0u8]].concat()).is_err());
+            assert!(Candidate::<I>::from_be_bytes(&[&[0u8], &bytes_be[..]].concat()).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_checked() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_bytes_checked::<u8>(&mut rng)?;
+        check_bytes_checked::<u16>(&mut rng)?;
+        check_bytes_checked::<u32>(&mut rng)?;
+        check_bytes_checked::<u64>(&mut rng)?;
+        check_bytes_checked::<u128>(&mut rng)?;
+
+        check_bytes_checked::<i8>(&mut rng)?;
+        check_bytes_checked::<i16>(&mut rng)?;
+        check_bytes_checked::<i32>(&mut rng)?;
+        check_bytes_checked::<i64>(&mut rng)?;
+        check_bytes_checked::<i128>(&mut rng)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_in_bytes() {
+        assert_eq!(1, U8::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(2, U16::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(4, U32::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(8, U64::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(16, U128::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(1, I8::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(2, I16::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(4, I32::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(8, I64::<CurrentEnvironment>::size_in_bytes());
+        assert_eq!(16, I128::<CurrentEnvironment>::size_in_bytes());
+
+        // Ensure the size is usable in a const context.
+        const SIZE: usize = U64::<CurrentEnvironment>::size_in_bytes();
+        assert_eq!(8, SIZE);
+    }
+
     #[test]
     fn test_bytes() -> Result<()> {
         let mut rng = TestRng::default();
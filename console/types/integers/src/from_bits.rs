@@ -28,6 +28,22 @@ impl<E: Environment, I: IntegerType> FromBits for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes a new integer from a list of **little-endian** bits,
+    /// ensuring the number of bits is exactly the size of the integer.
+    pub fn checked_from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the number of bits matches the size of the integer.
+        ensure!(
+            bits_le.len() == Self::size_in_bits(),
+            "Expected exactly {} bits for '{}', found {} bits",
+            Self::size_in_bits(),
+            Self::type_name(),
+            bits_le.len()
+        );
+        Self::from_bits_le(bits_le)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +97,27 @@ mod tests {
         Ok(())
     }
 
+    fn check_checked_from_bits_le<I: IntegerType>(rng: &mut TestRng) -> Result<()> {
+        for i in 1..ITERATIONS {
+            // Sample a random value.
+            let expected = Integer::<CurrentEnvironment, I>::new(Uniform::rand(rng));
+            let given_bits = expected.to_bits_le();
+
+            // Ensure the correct number of bits succeeds.
+            let candidate = Integer::<CurrentEnvironment, I>::checked_from_bits_le(&given_bits)?;
+            assert_eq!(expected, candidate);
+
+            // Ensure too few bits fails.
+            let num_bits = given_bits.len().saturating_sub(i);
+            assert!(Integer::<CurrentEnvironment, I>::checked_from_bits_le(&given_bits[..num_bits]).is_err());
+
+            // Ensure too many bits fails, even if the excess bits are zero.
+            let candidate = vec![given_bits, vec![false; i]].concat();
+            assert!(Integer::<CurrentEnvironment, I>::checked_from_bits_le(&candidate).is_err());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_from_bits_le() -> Result<()> {
         let mut rng = TestRng::default();
@@ -118,4 +155,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_checked_from_bits_le() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_checked_from_bits_le::<u8>(&mut rng)?;
+        check_checked_from_bits_le::<u16>(&mut rng)?;
+        check_checked_from_bits_le::<u32>(&mut rng)?;
+        check_checked_from_bits_le::<u64>(&mut rng)?;
+        check_checked_from_bits_le::<u128>(&mut rng)?;
+
+        check_checked_from_bits_le::<i8>(&mut rng)?;
+        check_checked_from_bits_le::<i16>(&mut rng)?;
+        check_checked_from_bits_le::<i32>(&mut rng)?;
+        check_checked_from_bits_le::<i64>(&mut rng)?;
+        check_checked_from_bits_le::<i128>(&mut rng)?;
+
+        Ok(())
+    }
 }
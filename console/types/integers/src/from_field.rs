@@ -36,7 +36,30 @@ impl<E: Environment, I: IntegerType> FromField for Integer<E, I> {
         ensure!(zero_bits.iter().all(|&bit| !bit), "Failed to convert integer to field: upper bits are not zero");
 
         // Return the integer.
-        Self::from_bits_le(bits_le)
+        Self::checked_from_bits_le(bits_le)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Initializes an integer from a field element, returning `None` if the field element
+    /// does not fit within the integer.
+    pub fn checked_from_field(field: &Field<E>) -> Option<Self> {
+        Self::from_field(field).ok()
+    }
+
+    /// Initializes an integer from a field element, keeping only the lower bits of the field element.
+    pub fn wrapping_from_field(field: &Field<E>) -> Self {
+        // Convert the field element into bits.
+        let bits_le = field.to_bits_le();
+        // Note: `unwrap()` is safe as exactly `Self::size_in_bits()` bits are provided.
+        Self::checked_from_bits_le(&bits_le[..Self::size_in_bits()]).unwrap()
+    }
+
+    /// Initializes an integer from a field element, returning `Self::MAX` if the field element
+    /// does not fit within the integer.
+    pub fn saturating_from_field(field: &Field<E>) -> Self {
+        // Note: A field element is never negative, so it can only overflow above the maximum.
+        Self::checked_from_field(field).unwrap_or(Self::MAX)
     }
 }
 
@@ -63,6 +86,53 @@ mod tests {
         Ok(())
     }
 
+    fn check_from_field_variants<I: IntegerType>() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let boundaries = [
+            Integer::<CurrentEnvironment, I>::MIN,
+            Integer::MAX,
+            Integer::zero(),
+            Integer::one(),
+        ];
+        let samples = (0..ITERATIONS).map(|_| Integer::rand(&mut rng)).collect::<Vec<_>>();
+
+        for expected in boundaries.into_iter().chain(samples) {
+            // Ensure an in-range field element is converted by every variant.
+            let field = expected.to_field()?;
+            assert_eq!(Some(expected), Integer::checked_from_field(&field));
+            assert_eq!(expected, Integer::wrapping_from_field(&field));
+            assert_eq!(expected, Integer::saturating_from_field(&field));
+
+            // Construct a field element with the same lower bits and a set bit just above the integer.
+            let mut bits_le = field.to_bits_le();
+            bits_le[Integer::<CurrentEnvironment, I>::size_in_bits()] = true;
+            let overflow = Field::<CurrentEnvironment>::from_bits_le(&bits_le)?;
+
+            // Ensure the out-of-range field element is handled by each variant.
+            assert!(Integer::<CurrentEnvironment, I>::from_field(&overflow).is_err());
+            assert_eq!(None, Integer::<CurrentEnvironment, I>::checked_from_field(&overflow));
+            assert_eq!(expected, Integer::wrapping_from_field(&overflow));
+            assert_eq!(Integer::<CurrentEnvironment, I>::MAX, Integer::saturating_from_field(&overflow));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_field_variants() -> Result<()> {
+        check_from_field_variants::<u8>()?;
+        check_from_field_variants::<u16>()?;
+        check_from_field_variants::<u32>()?;
+        check_from_field_variants::<u64>()?;
+        check_from_field_variants::<u128>()?;
+
+        check_from_field_variants::<i8>()?;
+        check_from_field_variants::<i16>()?;
+        check_from_field_variants::<i32>()?;
+        check_from_field_variants::<i64>()?;
+        check_from_field_variants::<i128>()
+    }
+
     #[test]
     fn test_u8_from_field() -> Result<()> {
         type I = u8;
@@ -16,10 +16,19 @@
 
 use super::*;
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the integer size in bytes, in a `const` context.
+    #[inline]
+    pub const fn size_in_bytes() -> usize {
+        ((I::BITS + 7) / 8) as usize
+    }
+}
+
 impl<E: Environment, I: IntegerType> SizeInBytes for Integer<E, I> {
     /// Returns the integer size in bytes.
     #[inline]
     fn size_in_bytes() -> usize {
-        ((I::BITS + 7) / 8) as usize
+        // Note: This resolves to the inherent `const` method above.
+        Self::size_in_bytes()
     }
 }
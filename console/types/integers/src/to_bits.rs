@@ -28,6 +28,23 @@ impl<E: Environment, I: IntegerType> ToBits for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the number of ones in the bit representation of `self`.
+    pub fn count_ones(&self) -> u32 {
+        self.to_bits_le().into_iter().map(u32::from).sum()
+    }
+
+    /// Returns the number of zeros in the bit representation of `self`.
+    pub fn count_zeros(&self) -> u32 {
+        self.to_bits_le().into_iter().map(|bit| u32::from(!bit)).sum()
+    }
+
+    /// Returns the number of leading zeros in the bit representation of `self`.
+    pub fn leading_zeros(&self) -> u32 {
+        self.to_bits_be().into_iter().take_while(|bit| !bit).map(|_| 1u32).sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,6 +82,30 @@ mod tests {
         }
     }
 
+    fn check_count_bits<I: IntegerType>(
+        rng: &mut TestRng,
+        count_ones: fn(I) -> u32,
+        count_zeros: fn(I) -> u32,
+        leading_zeros: fn(I) -> u32,
+    ) {
+        let check = |integer: Integer<CurrentEnvironment, I>| {
+            assert_eq!(count_ones(*integer), integer.count_ones());
+            assert_eq!(count_zeros(*integer), integer.count_zeros());
+            assert_eq!(leading_zeros(*integer), integer.leading_zeros());
+        };
+
+        // Check the edge cases.
+        check(Integer::zero());
+        check(Integer::one());
+        check(Integer::MAX);
+        check(Integer::MIN);
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            check(Uniform::rand(rng));
+        }
+    }
+
     #[test]
     fn test_to_bits_le() {
         let mut rng = TestRng::default();
@@ -98,4 +139,21 @@ mod tests {
         check_to_bits_be::<i64>(&mut rng);
         check_to_bits_be::<i128>(&mut rng);
     }
+
+    #[test]
+    fn test_count_bits() {
+        let mut rng = TestRng::default();
+
+        check_count_bits::<u8>(&mut rng, u8::count_ones, u8::count_zeros, u8::leading_zeros);
+        check_count_bits::<u16>(&mut rng, u16::count_ones, u16::count_zeros, u16::leading_zeros);
+        check_count_bits::<u32>(&mut rng, u32::count_ones, u32::count_zeros, u32::leading_zeros);
+        check_count_bits::<u64>(&mut rng, u64::count_ones, u64::count_zeros, u64::leading_zeros);
+        check_count_bits::<u128>(&mut rng, u128::count_ones, u128::count_zeros, u128::leading_zeros);
+
+        check_count_bits::<i8>(&mut rng, i8::count_ones, i8::count_zeros, i8::leading_zeros);
+        check_count_bits::<i16>(&mut rng, i16::count_ones, i16::count_zeros, i16::leading_zeros);
+        check_count_bits::<i32>(&mut rng, i32::count_ones, i32::count_zeros, i32::leading_zeros);
+        check_count_bits::<i64>(&mut rng, i64::count_ones, i64::count_zeros, i64::leading_zeros);
+        check_count_bits::<i128>(&mut rng, i128::count_ones, i128::count_zeros, i128::leading_zeros);
+    }
 }
@@ -202,6 +202,28 @@ impl<E: Environment> Pow<&Scalar<E>> for Scalar<E> {
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    /// Returns `self` to the power of the given `u64` exponent, using square-and-multiply.
+    #[inline]
+    pub fn pow_u64(&self, exponent: u64) -> Self {
+        let mut output = Self::one();
+        // Iterate over the bits of the exponent, from the most significant set bit.
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            output = output.square();
+            if (exponent >> i) & 1 == 1 {
+                output *= self;
+            }
+        }
+        output
+    }
+
+    /// Returns the `triple` of `self`.
+    #[inline]
+    pub fn triple(&self) -> Self {
+        Scalar::new(self.scalar.double() + self.scalar)
+    }
+}
+
 impl<E: Environment> Double for Scalar<E> {
     type Output = Scalar<E>;
 
@@ -274,6 +296,13 @@ mod tests {
 
     type CurrentEnvironment = Console;
 
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the scalar for the given `u64` value.
+    fn scalar_from_u64(value: u64) -> Scalar<CurrentEnvironment> {
+        Scalar::new(<CurrentEnvironment as Environment>::Scalar::from(value))
+    }
+
     #[test]
     fn test_div_by_zero_fails() {
         let one = Scalar::<CurrentEnvironment>::one();
@@ -282,4 +311,44 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_pow_u64() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let base: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+            // Compare against repeated multiplication.
+            let mut expected = Scalar::one();
+            for exponent in 0..=17u64 {
+                assert_eq!(expected, base.pow_u64(exponent));
+                expected *= base;
+            }
+            // Compare against the generic `pow` for a large exponent.
+            let exponent: u64 = rng.gen();
+            assert_eq!(base.pow(scalar_from_u64(exponent)), base.pow_u64(exponent));
+            assert_eq!(base.pow(scalar_from_u64(u64::MAX)), base.pow_u64(u64::MAX));
+        }
+
+        // Check the zero base.
+        assert!(Scalar::<CurrentEnvironment>::zero().pow_u64(0).is_one());
+        assert!(Scalar::<CurrentEnvironment>::zero().pow_u64(5).is_zero());
+    }
+
+    #[test]
+    fn test_double_and_triple() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let scalar: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(scalar + scalar, scalar.double());
+            assert_eq!(scalar + scalar + scalar, scalar.triple());
+            assert_eq!(scalar.double() + scalar, scalar.triple());
+        }
+
+        // Check the zero and one cases.
+        assert!(Scalar::<CurrentEnvironment>::zero().double().is_zero());
+        assert!(Scalar::<CurrentEnvironment>::zero().triple().is_zero());
+        assert_eq!(Scalar::<CurrentEnvironment>::one() + Scalar::one() + Scalar::one(), Scalar::one().triple());
+    }
 }
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromField for Scalar<E> {
+    type Field = Field<E>;
+
+    /// Initializes a scalar from a base field element.
+    /// This fails if the field element is greater than or equal to the scalar field modulus.
+    fn from_field(field: &Self::Field) -> Result<Self> {
+        // Note: We are narrowing the base field into the scalar field.
+        // As the scalar field modulus is less than the base field modulus,
+        // `from_bits_le` enforces that the field element is less than the scalar field modulus.
+        debug_assert!(Scalar::<E>::size_in_bits() < Field::<E>::size_in_bits());
+
+        Scalar::<E>::from_bits_le(&field.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_from_field() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let expected: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Ensure the scalar round-trips through the base field.
+            let candidate = Scalar::<CurrentEnvironment>::from_field(&expected.to_field()?)?;
+            assert_eq!(expected, candidate);
+        }
+
+        // Ensure the largest scalar round-trips through the base field.
+        let expected = -Scalar::<CurrentEnvironment>::one();
+        assert_eq!(expected, Scalar::from_field(&expected.to_field()?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_field_out_of_range() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure the scalar field modulus is rejected.
+        let modulus = (-Scalar::<CurrentEnvironment>::one()).to_field()? + Field::one();
+        assert!(Scalar::<CurrentEnvironment>::from_field(&modulus).is_err());
+
+        // Ensure the largest base field element is rejected.
+        assert!(Scalar::<CurrentEnvironment>::from_field(&-Field::one()).is_err());
+
+        for _ in 0..ITERATIONS {
+            // Sample a field element that is at least the scalar field modulus.
+            // Note: This does not wrap around, as the base field modulus exceeds
+            // the scalar field modulus by far more than 2^64.
+            let field = modulus + Field::from_u64(rng.gen());
+
+            // Ensure the field element is rejected.
+            assert!(Scalar::<CurrentEnvironment>::from_field(&field).is_err());
+        }
+        Ok(())
+    }
+}
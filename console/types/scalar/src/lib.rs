@@ -22,6 +22,7 @@ mod bitwise;
 mod bytes;
 mod compare;
 mod from_bits;
+mod from_field;
 mod one;
 mod parse;
 mod random;
@@ -20,13 +20,25 @@ impl<E: Environment> Parser for Scalar<E> {
     /// Parses a string into a scalar circuit.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the value from the string.
+        let (string, scalar) = Self::parse_value(string)?;
+        // Parse the type name from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+
+        Ok((string, scalar))
+    }
+}
+
+impl<E: Environment> Scalar<E> {
+    /// Parses a string into a scalar, without its type name.
+    fn parse_value(string: &str) -> ParserResult<Self> {
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
         // Parse the value from the string.
-        let (string, value): (&str, E::Scalar) =
-            map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, value): (&str, E::Scalar) = map_res(
+            recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))),
+            |primitive: &str| primitive.replace('_', "").parse(),
+        )(string)?;
         // Negate the value if the negative sign was present.
         let value = match negation {
             true => -value,
@@ -43,7 +55,8 @@ impl<E: Environment> FromStr for Scalar<E> {
     /// Parses a string into a scalar.
     #[inline]
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
+        // Parse the type name only if it is present, so that unsuffixed values (e.g. `123`) are also accepted.
+        match terminated(Self::parse_value, opt(tag(Self::type_name())))(string) {
             Ok((remainder, object)) => {
                 // Ensure the remainder is empty.
                 ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
@@ -96,6 +109,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_optional_suffix() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value.
+            let expected = Scalar::<CurrentEnvironment>::new(Uniform::rand(rng));
+
+            // Ensure the suffixed form parses.
+            let suffixed = expected.to_string();
+            assert_eq!(expected, Scalar::<CurrentEnvironment>::from_str(&suffixed)?);
+
+            // Ensure the unsuffixed form parses.
+            let unsuffixed = suffixed.trim_end_matches(Scalar::<CurrentEnvironment>::type_name());
+            assert_eq!(expected, Scalar::<CurrentEnvironment>::from_str(unsuffixed)?);
+
+            // Ensure the negated unsuffixed form parses.
+            assert_eq!(-expected, Scalar::<CurrentEnvironment>::from_str(&format!("-{unsuffixed}"))?);
+        }
+
+        // Ensure a mismatched or repeated type annotation fails.
+        assert!(Scalar::<CurrentEnvironment>::from_str("1u8").is_err());
+        assert!(Scalar::<CurrentEnvironment>::from_str("0group").is_err());
+        assert!(Scalar::<CurrentEnvironment>::from_str("0scalarscalar").is_err());
+        assert!(Scalar::<CurrentEnvironment>::from_str("").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a scalar from the given element,
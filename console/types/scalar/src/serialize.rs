@@ -36,6 +36,51 @@ impl<'de, E: Environment> Deserialize<'de> for Scalar<E> {
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    /// Serializes the scalar into a `0x`-prefixed big-endian hex string or as bytes.
+    /// This is intended for use with `#[serde(serialize_with = "Scalar::serialize_hex")]`.
+    pub fn serialize_hex<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                // Encode the scalar as big-endian bytes.
+                let bytes_le = self.to_bytes_le().map_err(ser::Error::custom)?;
+                let hex = bytes_le.iter().rev().map(|byte| format!("{byte:02x}")).collect::<String>();
+                serializer.collect_str(&format_args!("0x{hex}"))
+            }
+            false => ToBytesSerializer::serialize(self, serializer),
+        }
+    }
+
+    /// Deserializes the scalar from a `0x`-prefixed big-endian hex string or bytes.
+    /// This is intended for use with `#[serde(deserialize_with = "Scalar::deserialize_hex")]`.
+    pub fn deserialize_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let string = String::deserialize(deserializer)?;
+                // Ensure the string is a `0x`-prefixed hex string, that fits within the scalar.
+                let hex = match string.strip_prefix("0x") {
+                    Some(hex) if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) => hex,
+                    _ => return Err(de::Error::custom(format!("Invalid hex scalar '{string}'"))),
+                };
+                if hex.len() > 2 * Self::size_in_bytes() {
+                    let error = format!("Hex scalar '{string}' exceeds {} bytes", Self::size_in_bytes());
+                    return Err(de::Error::custom(error));
+                }
+                // Pad the hex string with leading zeros, and decode it as big-endian bytes.
+                let hex = format!("{hex:0>width$}", width = 2 * Self::size_in_bytes());
+                let bytes_le = (0..hex.len())
+                    .step_by(2)
+                    .rev()
+                    .map(|index| u8::from_str_radix(&hex[index..index + 2], 16))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(de::Error::custom)?;
+                Self::read_le(&bytes_le[..]).map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize(deserializer, "scalar", Self::size_in_bytes()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +128,39 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_serde_json_hex() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new scalar.
+            let expected = Scalar::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Serialize
+            let candidate_string = expected.serialize_hex(serde_json::value::Serializer)?;
+            let candidate_string = candidate_string.as_str().unwrap();
+            assert!(candidate_string.starts_with("0x"));
+            assert_eq!(2 + 2 * Scalar::<CurrentEnvironment>::size_in_bytes(), candidate_string.len());
+            // Ensure the hex form is distinct from the default form.
+            assert_ne!(expected.to_string(), candidate_string);
+            assert_ne!(serde_json::to_value(expected)?, serde_json::Value::from(candidate_string));
+
+            // Deserialize
+            let candidate = Scalar::deserialize_hex(serde_json::Value::from(candidate_string))?;
+            assert_eq!(expected, candidate);
+            // Ensure the default deserializer does not accept the hex form.
+            assert!(serde_json::from_value::<Scalar<CurrentEnvironment>>(candidate_string.into()).is_err());
+        }
+
+        // Ensure leading zeros may be omitted.
+        let one = Scalar::<CurrentEnvironment>::deserialize_hex(serde_json::Value::from("0x1"))?;
+        assert_eq!(Scalar::one(), one);
+
+        // Ensure malformed hex strings fail.
+        for string in ["", "0x", "1", "0xg", "0x+1", format!("0x1{}", "0".repeat(64)).as_str()] {
+            assert!(Scalar::<CurrentEnvironment>::deserialize_hex(serde_json::Value::from(string)).is_err());
+        }
+        Ok(())
+    }
 }
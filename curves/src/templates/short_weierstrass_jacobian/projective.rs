@@ -387,6 +387,17 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
         }
     }
 
+    #[inline]
+    fn conditional_swap(&mut self, other: &mut Self, condition: bool) {
+        // Compute the mask as `0` or `1`, and move each coordinate by `mask * (other - self)`.
+        let mask = P::BaseField::from(condition as u64);
+        for (a, b) in [(&mut self.x, &mut other.x), (&mut self.y, &mut other.y), (&mut self.z, &mut other.z)] {
+            let delta = (*b - *a) * mask;
+            *a += delta;
+            *b -= delta;
+        }
+    }
+
     #[inline]
     fn to_affine(&self) -> Affine<P> {
         (*self).into()
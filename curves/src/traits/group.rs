@@ -106,6 +106,9 @@ pub trait ProjectiveCurve:
     /// Sets `self := self + self`.
     fn double_in_place(&mut self);
 
+    /// Swaps `self` and `other` if `condition` is `true`, without branching on `condition`.
+    fn conditional_swap(&mut self, other: &mut Self, condition: bool);
+
     /// Converts this element into its affine representation.
     #[must_use]
     #[allow(clippy::wrong_self_convention)]
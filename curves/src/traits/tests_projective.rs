@@ -150,6 +150,30 @@ fn random_doubling_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     }
 }
 
+fn random_conditional_swap_test<G: ProjectiveCurve>(rng: &mut TestRng) {
+    for _ in 0..ITERATIONS {
+        let a = G::rand(rng);
+        let b = G::rand(rng);
+
+        // Ensure a `false` condition leaves both elements unchanged.
+        let (mut c, mut d) = (a, b);
+        c.conditional_swap(&mut d, false);
+        assert_eq!(c, a);
+        assert_eq!(d, b);
+
+        // Ensure a `true` condition swaps both elements.
+        c.conditional_swap(&mut d, true);
+        assert_eq!(c, b);
+        assert_eq!(d, a);
+
+        // Ensure swapping with zero preserves both elements.
+        let mut z = G::zero();
+        c.conditional_swap(&mut z, true);
+        assert!(c.is_zero());
+        assert_eq!(z, b);
+    }
+}
+
 fn random_negation_test<G: ProjectiveCurve>(rng: &mut TestRng) {
     for _ in 0..ITERATIONS {
         let r = G::rand(rng);
@@ -266,6 +290,7 @@ pub fn curve_tests<G: ProjectiveCurve>(rng: &mut TestRng) {
     random_addition_test::<G>(rng);
     random_multiplication_test::<G>(rng);
     random_doubling_test::<G>(rng);
+    random_conditional_swap_test::<G>(rng);
     random_negation_test::<G>(rng);
     random_transformation_test::<G>(rng);
 }
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ParameterError;
+
+use std::path::Path;
+
+/// Loads a custom parameter file from the given path.
+///
+/// Unless `skip_verification` is set, the file size and checksum are checked against the expected
+/// values before the bytes are returned. Skipping verification is intended for airgapped setups
+/// that use their own parameter sets, for which no checksum is compiled into this crate.
+pub fn load_file(
+    path: &Path,
+    expected_size: usize,
+    expected_checksum: &str,
+    skip_verification: bool,
+) -> Result<Vec<u8>, ParameterError> {
+    // Read the parameter file.
+    let buffer = std::fs::read(path)?;
+
+    if !skip_verification {
+        // Ensure the size matches.
+        if expected_size != buffer.len() {
+            return Err(ParameterError::SizeMismatch(expected_size, buffer.len()));
+        }
+
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(&buffer);
+        if expected_checksum != candidate_checksum {
+            return checksum_error!(path.display(), expected_checksum, candidate_checksum);
+        }
+    }
+
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    #[test]
+    fn test_load_file() {
+        let bytes = b"custom parameter bytes";
+        let expected_checksum = checksum!(bytes);
+
+        // Write the parameter file.
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(bytes).unwrap();
+
+        // Ensure the file loads.
+        let candidate = load_file(file.path(), bytes.len(), &expected_checksum, false).unwrap();
+        assert_eq!(&candidate, bytes);
+
+        // Corrupt the parameter file, while keeping its size.
+        let mut corrupted = bytes.to_vec();
+        corrupted[0] ^= 1;
+        std::fs::write(file.path(), &corrupted).unwrap();
+
+        // Ensure the corrupted file is rejected.
+        match load_file(file.path(), bytes.len(), &expected_checksum, false) {
+            Err(ParameterError::ChecksumMismatch { file: filename, expected, found }) => {
+                assert_eq!(filename, file.path().display().to_string());
+                assert_eq!(expected, expected_checksum);
+                assert_eq!(found, checksum!(&corrupted));
+            }
+            result => panic!("Expected a checksum mismatch, found {result:?}"),
+        }
+        // Ensure a file with the wrong size is rejected.
+        assert!(matches!(
+            load_file(file.path(), bytes.len() + 1, &expected_checksum, false),
+            Err(ParameterError::SizeMismatch(..))
+        ));
+
+        // Ensure the corrupted file loads when verification is skipped.
+        let candidate = load_file(file.path(), bytes.len(), &expected_checksum, true).unwrap();
+        assert_eq!(candidate, corrupted);
+    }
+}
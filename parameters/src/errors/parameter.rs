@@ -18,8 +18,8 @@ use std::fmt::Debug;
 
 #[derive(Debug, Error)]
 pub enum ParameterError {
-    #[error("expected checksum of {}, found checksum of {}", _0, _1)]
-    ChecksumMismatch(String, String),
+    #[error("'{file}' expected checksum of {expected}, found checksum of {found}")]
+    ChecksumMismatch { file: String, expected: String, found: String },
 
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
@@ -29,6 +29,9 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod custom;
+pub use custom::*;
+
 pub mod errors;
 pub use errors::*;
 
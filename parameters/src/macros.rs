@@ -24,8 +24,12 @@ macro_rules! checksum {
 
 #[macro_export]
 macro_rules! checksum_error {
-    ($expected: expr, $candidate: expr) => {
-        Err($crate::errors::ParameterError::ChecksumMismatch($expected, $candidate))
+    ($file: expr, $expected: expr, $candidate: expr) => {
+        Err($crate::errors::ParameterError::ChecksumMismatch {
+            file: $file.to_string(),
+            expected: $expected.to_string(),
+            found: $candidate,
+        })
     };
 }
 
@@ -109,7 +113,7 @@ macro_rules! impl_store_and_remote_fetch {
 }
 
 macro_rules! impl_load_bytes_logic_local {
-    ($buffer: expr, $expected_size: expr, $expected_checksum: expr) => {
+    ($filename: expr, $buffer: expr, $expected_size: expr, $expected_checksum: expr) => {
         // Ensure the size matches.
         if $expected_size != $buffer.len() {
             return Err($crate::errors::ParameterError::SizeMismatch($expected_size, $buffer.len()));
@@ -118,7 +122,7 @@ macro_rules! impl_load_bytes_logic_local {
         // Ensure the checksum matches.
         let candidate_checksum = checksum!($buffer);
         if $expected_checksum != candidate_checksum {
-            return checksum_error!($expected_checksum, candidate_checksum);
+            return checksum_error!($filename, $expected_checksum, candidate_checksum);
         }
 
         return Ok($buffer.to_vec());
@@ -159,7 +163,7 @@ macro_rules! impl_load_bytes_logic_remote {
                     // Ensure the checksum matches.
                     let candidate_checksum = checksum!(&buffer);
                     if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
+                        return checksum_error!($filename, $expected_checksum, candidate_checksum)
                     }
 
                     match Self::store_bytes(&buffer, &file_path) {
@@ -190,7 +194,7 @@ macro_rules! impl_load_bytes_logic_remote {
                     // Ensure the checksum matches.
                     let candidate_checksum = checksum!(&buffer);
                     if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
+                        return checksum_error!($filename, $expected_checksum, candidate_checksum)
                     }
 
                     buffer
@@ -208,7 +212,7 @@ macro_rules! impl_load_bytes_logic_remote {
         // Ensure the checksum matches.
         let candidate_checksum = checksum!(buffer.as_slice());
         if $expected_checksum != candidate_checksum {
-            return checksum_error!($expected_checksum, candidate_checksum)
+            return checksum_error!($filename, $expected_checksum, candidate_checksum)
         }
 
         return Ok(buffer)
@@ -232,9 +236,10 @@ macro_rules! impl_local {
                 let expected_size: usize =
                     metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
 
+                const FILENAME: &'static str = concat!($fname, ".", "usrs");
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", "usrs"));
 
-                impl_load_bytes_logic_local!(buffer, expected_size, expected_checksum);
+                impl_load_bytes_logic_local!(FILENAME, buffer, expected_size, expected_checksum);
             }
         }
 
@@ -261,9 +266,10 @@ macro_rules! impl_local {
                 let expected_size: usize =
                     metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
 
+                const FILENAME: &'static str = concat!($fname, ".", $ftype);
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", $ftype));
 
-                impl_load_bytes_logic_local!(buffer, expected_size, expected_checksum);
+                impl_load_bytes_logic_local!(FILENAME, buffer, expected_size, expected_checksum);
             }
         }
 
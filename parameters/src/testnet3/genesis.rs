@@ -17,9 +17,21 @@
 pub struct GenesisBytes;
 
 impl GenesisBytes {
+    /// The SHA-256 checksum of the genesis block.
+    const CHECKSUM: &'static str = "37e52383ebe3db273f567911bab93fbc5677d9bfa3130e9aed2cb488b6eb6a4c";
+
     pub const fn load_bytes() -> &'static [u8] {
         include_bytes!("./resources/block.genesis")
     }
+
+    /// Ensures the checksum of the genesis block matches the expected checksum.
+    pub fn verify() -> Result<(), crate::errors::ParameterError> {
+        let candidate_checksum = checksum!(Self::load_bytes());
+        if Self::CHECKSUM != candidate_checksum {
+            return checksum_error!("block.genesis", Self::CHECKSUM, candidate_checksum);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -30,5 +42,6 @@ mod tests {
     fn test_genesis_block() {
         let bytes = GenesisBytes::load_bytes();
         assert_eq!(1783, bytes.len() as u64, "Update me if serialization has changed");
+        assert!(GenesisBytes::verify().is_ok());
     }
 }
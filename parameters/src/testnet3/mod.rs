@@ -60,6 +60,19 @@ impl_local!(NegBeta, "resources/", "neg-powers-of-beta", "usrs");
 // Negative Powers of Beta in G2
 impl_local!(BetaH, "resources/", "beta-h", "usrs");
 
+/// Ensures the size and checksum of every parameter file bundled in this crate match the expected values.
+///
+/// Remote parameter files are verified when they are loaded, and are not checked here.
+pub fn verify_all() -> Result<(), crate::errors::ParameterError> {
+    GenesisBytes::verify()?;
+    Degree15::load_bytes()?;
+    ShiftedDegree15::load_bytes()?;
+    Gamma::load_bytes()?;
+    NegBeta::load_bytes()?;
+    BetaH::load_bytes()?;
+    Ok(())
+}
+
 // Mint
 impl_remote!(MintProver, REMOTE_URL, "resources/", "mint", "prover");
 impl_remote!(MintVerifier, REMOTE_URL, "resources/", "mint", "verifier");
@@ -115,3 +128,13 @@ lazy_static! {
     pub static ref INCLUSION_VERIFYING_KEY: Vec<u8> =
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_all() {
+        assert!(verify_all().is_ok());
+    }
+}
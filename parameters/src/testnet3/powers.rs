@@ -48,7 +48,7 @@ const NUM_POWERS_27: usize = 1 << 27;
 const NUM_POWERS_28: usize = 1 << 28;
 
 /// The maximum degree supported by the SRS.
-const MAX_NUM_POWERS: usize = NUM_POWERS_28;
+pub const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -94,6 +94,31 @@ impl<E: PairingEngine> PowersOfG<E> {
         Ok(powers)
     }
 
+    /// Initializes the powers from their constituent parts.
+    /// The `shifted_powers_of_beta_g` must be the highest powers, ending at `beta^{MAX_NUM_POWERS - 1} G`.
+    pub fn from_parts(
+        powers_of_beta_g: Vec<E::G1Affine>,
+        shifted_powers_of_beta_g: Vec<E::G1Affine>,
+        powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+        negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+        beta_h: E::G2Affine,
+    ) -> Result<Self> {
+        // Ensure the normal and shifted powers are present and do not overlap.
+        ensure!(!powers_of_beta_g.is_empty(), "The powers of beta G cannot be empty");
+        ensure!(!shifted_powers_of_beta_g.is_empty(), "The shifted powers of beta G cannot be empty");
+        ensure!(
+            powers_of_beta_g.len() + shifted_powers_of_beta_g.len() <= MAX_NUM_POWERS,
+            "The normal and shifted powers of beta G exceed the maximum number of powers"
+        );
+
+        let powers_of_beta_g = PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g };
+        let powers_of_beta_times_gamma_g = Arc::new(powers_of_beta_times_gamma_g);
+        let negative_powers_of_beta_h = Arc::new(negative_powers_of_beta_h);
+
+        // Initialize the powers.
+        Ok(Self { powers_of_beta_g, powers_of_beta_times_gamma_g, negative_powers_of_beta_h, beta_h })
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
         self.powers_of_beta_g.download_powers_for(&range)
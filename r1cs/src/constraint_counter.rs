@@ -23,6 +23,8 @@ pub struct ConstraintCounter {
     pub num_public_variables: usize,
     pub num_private_variables: usize,
     pub num_constraints: usize,
+    /// The number of lookup constraints, which are included in `num_constraints`.
+    pub num_lookup_constraints: usize,
 }
 
 impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
@@ -73,6 +75,7 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
         LC: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
     {
         self.num_constraints += 1;
+        self.num_lookup_constraints += 1;
         Ok(())
     }
 
@@ -93,6 +96,10 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
         self.num_constraints
     }
 
+    fn num_lookup_constraints(&self) -> usize {
+        self.num_lookup_constraints
+    }
+
     fn num_public_variables(&self) -> usize {
         self.num_public_variables
     }
@@ -0,0 +1,491 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SynthesisError,
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    Index,
+    LinearCombination,
+    LookupTable,
+    Variable,
+};
+use snarkvm_fields::Field;
+
+use indexmap::IndexMap;
+use std::{collections::BTreeMap, fmt};
+
+/// The matrix of a constraint, i.e. `A`, `B`, or `C` in `A * B = C`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Matrix {
+    A,
+    B,
+    C,
+}
+
+/// The kind of a recorded constraint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// A constraint added with `enforce`.
+    Enforce,
+    /// A constraint added with `enforce_lookup`, into the table at the given index.
+    Lookup(usize),
+}
+
+/// A constraint recorded by a `RecordingConstraintSystem`.
+/// Each linear combination maps the annotation path of a variable to its coefficient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedConstraint<F: Field> {
+    pub kind: ConstraintKind,
+    pub a: BTreeMap<String, F>,
+    pub b: BTreeMap<String, F>,
+    pub c: BTreeMap<String, F>,
+}
+
+/// Constraint system that records the namespaces, linear combinations, and lookup constraints
+/// of a circuit, keyed by annotation path. Variable assignments are never computed.
+pub struct RecordingConstraintSystem<F: Field> {
+    // the segments of the current namespace
+    namespace: Vec<String>,
+    // the annotation paths of the public variables
+    public_variables: Vec<String>,
+    // the annotation paths of the private variables
+    private_variables: Vec<String>,
+    // the recorded constraints, in order of insertion
+    constraints: IndexMap<String, RecordedConstraint<F>>,
+}
+
+impl<F: Field> Default for RecordingConstraintSystem<F> {
+    fn default() -> Self {
+        Self {
+            namespace: Vec::new(),
+            public_variables: vec!["ONE".to_string()],
+            private_variables: Vec::new(),
+            constraints: IndexMap::new(),
+        }
+    }
+}
+
+impl<F: Field> RecordingConstraintSystem<F> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Synthesizes the given circuit into a new recording constraint system.
+    pub fn synthesize<C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self, SynthesisError> {
+        let mut cs = Self::new();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs)
+    }
+
+    /// Returns the recorded constraints, keyed by annotation path.
+    pub fn constraints(&self) -> &IndexMap<String, RecordedConstraint<F>> {
+        &self.constraints
+    }
+
+    /// Returns the full path of the given annotation in the current namespace.
+    fn compute_path(&self, annotation: &str) -> String {
+        assert!(!annotation.contains('/'), "'/' is not allowed in names");
+        self.namespace.iter().map(String::as_str).chain([annotation]).collect::<Vec<_>>().join("/")
+    }
+
+    /// Returns the annotation path of the given variable.
+    fn variable_path(&self, variable: &Variable) -> String {
+        match variable.get_unchecked() {
+            Index::Public(index) => match self.public_variables.get(index) {
+                Some(path) => path.clone(),
+                None => format!("public {index}"),
+            },
+            Index::Private(index) => match self.private_variables.get(index) {
+                Some(path) => path.clone(),
+                None => format!("private {index}"),
+            },
+        }
+    }
+
+    /// Returns the given linear combination as a map from variable paths to (non-zero) coefficients.
+    fn record_lc(&self, lc: &LinearCombination<F>) -> BTreeMap<String, F> {
+        let mut terms = BTreeMap::<String, F>::new();
+        for (variable, coeff) in lc.as_ref() {
+            *terms.entry(self.variable_path(variable)).or_insert_with(F::zero) += coeff;
+        }
+        terms.retain(|_, coeff| !coeff.is_zero());
+        terms
+    }
+
+    /// Records the given constraint at the given path.
+    fn record(&mut self, path: String, constraint: RecordedConstraint<F>) {
+        if self.constraints.contains_key(&path) {
+            panic!("tried to create object at existing path: {path}");
+        }
+        self.constraints.insert(path, constraint);
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for RecordingConstraintSystem<F> {
+    type Root = Self;
+
+    fn add_lookup_table(&mut self, _: LookupTable<F>) {}
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let variable = Variable::new_unchecked(Index::Private(self.private_variables.len()));
+        self.private_variables.push(self.compute_path(annotation().as_ref()));
+        Ok(variable)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let variable = Variable::new_unchecked(Index::Public(self.public_variables.len()));
+        self.public_variables.push(self.compute_path(annotation().as_ref()));
+        Ok(variable)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let path = self.compute_path(annotation().as_ref());
+        let a = self.record_lc(&a(LinearCombination::zero()));
+        let b = self.record_lc(&b(LinearCombination::zero()));
+        let c = self.record_lc(&c(LinearCombination::zero()));
+        self.record(path, RecordedConstraint { kind: ConstraintKind::Enforce, a, b, c });
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        table_index: usize,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let path = self.compute_path(annotation().as_ref());
+        let a = self.record_lc(&a(LinearCombination::zero()));
+        let b = self.record_lc(&b(LinearCombination::zero()));
+        let c = self.record_lc(&c(LinearCombination::zero()));
+        self.record(path, RecordedConstraint { kind: ConstraintKind::Lookup(table_index), a, b, c });
+        Ok(())
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn();
+        assert!(!name.as_ref().contains('/'), "'/' is not allowed in names");
+        self.namespace.push(name.as_ref().to_string());
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.namespace.pop().is_some());
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.public_variables.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.private_variables.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        true
+    }
+}
+
+/// A term that differs between two constraints at the same path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TermDifference<F: Field> {
+    pub matrix: Matrix,
+    pub variable: String,
+    pub in_a: Option<F>,
+    pub in_b: Option<F>,
+}
+
+/// The differences between two constraints at the same path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintMismatch<F: Field> {
+    pub path: String,
+    /// The constraint kinds in `A` and `B`, if they differ.
+    pub kinds: Option<(ConstraintKind, ConstraintKind)>,
+    pub terms: Vec<TermDifference<F>>,
+}
+
+/// The structured difference between two synthesized constraint systems.
+/// Constraints are matched by annotation path, and their terms by the annotation paths of the variables,
+/// so that differences in variable numbering are not reported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintDiff<F: Field> {
+    /// The paths of the constraints that are only in `A`.
+    pub only_in_a: Vec<String>,
+    /// The paths of the constraints that are only in `B`.
+    pub only_in_b: Vec<String>,
+    /// The constraints at the same path in `A` and `B`, whose linear combinations differ.
+    pub mismatches: Vec<ConstraintMismatch<F>>,
+}
+
+impl<F: Field> ConstraintDiff<F> {
+    /// Returns the difference between the given constraint systems.
+    pub fn new(a: &RecordingConstraintSystem<F>, b: &RecordingConstraintSystem<F>) -> Self {
+        let only_in_a = a.constraints.keys().filter(|path| !b.constraints.contains_key(*path)).cloned().collect();
+        let only_in_b = b.constraints.keys().filter(|path| !a.constraints.contains_key(*path)).cloned().collect();
+
+        let mut mismatches = Vec::new();
+        for (path, constraint_a) in &a.constraints {
+            let constraint_b = match b.constraints.get(path) {
+                Some(constraint_b) => constraint_b,
+                None => continue,
+            };
+
+            let kinds = match constraint_a.kind == constraint_b.kind {
+                true => None,
+                false => Some((constraint_a.kind, constraint_b.kind)),
+            };
+            let mut terms = Vec::new();
+            for (matrix, lc_a, lc_b) in [
+                (Matrix::A, &constraint_a.a, &constraint_b.a),
+                (Matrix::B, &constraint_a.b, &constraint_b.b),
+                (Matrix::C, &constraint_a.c, &constraint_b.c),
+            ] {
+                let variables = lc_a.keys().chain(lc_b.keys().filter(|variable| !lc_a.contains_key(*variable)));
+                for variable in variables {
+                    let (in_a, in_b) = (lc_a.get(variable).copied(), lc_b.get(variable).copied());
+                    if in_a != in_b {
+                        terms.push(TermDifference { matrix, variable: variable.clone(), in_a, in_b });
+                    }
+                }
+            }
+
+            if kinds.is_some() || !terms.is_empty() {
+                mismatches.push(ConstraintMismatch { path: path.clone(), kinds, terms });
+            }
+        }
+
+        Self { only_in_a, only_in_b, mismatches }
+    }
+
+    /// Returns the difference between the constraint systems synthesized from the given circuits.
+    pub fn from_circuits<CA: ConstraintSynthesizer<F>, CB: ConstraintSynthesizer<F>>(
+        a: &CA,
+        b: &CB,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self::new(&RecordingConstraintSystem::synthesize(a)?, &RecordingConstraintSystem::synthesize(b)?))
+    }
+
+    /// Returns `true` if the constraint systems are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+impl<F: Field> fmt::Display for ConstraintDiff<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display = |coeff: &Option<F>| coeff.map_or_else(|| "-".to_string(), |coeff| coeff.to_string());
+
+        for path in &self.only_in_a {
+            writeln!(f, "only in A: {path}")?;
+        }
+        for path in &self.only_in_b {
+            writeln!(f, "only in B: {path}")?;
+        }
+        for ConstraintMismatch { path, kinds, terms } in &self.mismatches {
+            writeln!(f, "differs: {path}")?;
+            if let Some((kind_a, kind_b)) = kinds {
+                writeln!(f, "    kind: {kind_a:?} != {kind_b:?}")?;
+            }
+            for TermDifference { matrix, variable, in_a, in_b } in terms {
+                writeln!(f, "    {matrix:?}[{variable}]: {} != {}", display(in_a), display(in_b))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fr;
+    use snarkvm_fields::One;
+
+    /// The XOR circuit from the Marlin benchmarks.
+    #[derive(Copy, Clone)]
+    struct BenchmarkXOR {
+        num_xors: usize,
+        num_variables: usize,
+        /// If `true`, the circuit is synthesized with the modifications below.
+        modified: bool,
+    }
+
+    impl ConstraintSynthesizer<Fr> for BenchmarkXOR {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let value = || Ok(Fr::one());
+
+            // Modification: allocate the variables in a different order.
+            let (x, y, z) = match self.modified {
+                false => {
+                    let x = cs.alloc_input(|| "x", value)?;
+                    let y = cs.alloc_input(|| "y", value)?;
+                    let z = cs.alloc_input(|| "z", value)?;
+                    (x, y, z)
+                }
+                true => {
+                    let z = cs.alloc_input(|| "z", value)?;
+                    let y = cs.alloc_input(|| "y", value)?;
+                    let x = cs.alloc_input(|| "x", value)?;
+                    (x, y, z)
+                }
+            };
+            let one = cs.alloc(|| "one", value)?;
+            let sym_1 = cs.alloc(|| "sym_1", value)?;
+            let sym_2 = cs.alloc(|| "sym_2", value)?;
+            let sym_3 = cs.alloc(|| "sym_3", value)?;
+
+            for i in 0..(self.num_variables - 7) {
+                let _ = cs.alloc(|| format!("var {i}"), value)?;
+            }
+
+            for i in 0..self.num_xors {
+                let last = i + 1 == self.num_xors;
+                cs.enforce(|| format!("constraint gate 1 {i}"), |lc| lc + x + y, |lc| lc + one, |lc| lc + sym_1);
+                // Modification: triple `one` in the last gate 2.
+                let num_ones = if self.modified && last { 3 } else { 2 };
+                cs.enforce(
+                    || format!("constraint gate 2 {i}"),
+                    |lc| (0..num_ones).fold(lc, |lc, _| lc + one),
+                    |lc| lc + x,
+                    |lc| lc + sym_2,
+                );
+                cs.enforce(|| format!("constraint gate 3 {i}"), |lc| lc + sym_2, |lc| lc + y, |lc| lc + sym_3);
+                // Modification: drop the last gate 4.
+                if !(self.modified && last) {
+                    cs.enforce(
+                        || format!("constraint gate 4 {i}"),
+                        |lc| lc + sym_1,
+                        |lc| lc + one,
+                        |lc| lc + z + sym_3,
+                    );
+                }
+            }
+
+            // Modification: add a gate 5.
+            if self.modified {
+                cs.enforce(|| "constraint gate 5", |lc| lc + x, |lc| lc + y, |lc| lc + z);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_constraint_diff_equal() {
+        let circuit = BenchmarkXOR { num_xors: 3, num_variables: 10, modified: false };
+        let diff = ConstraintDiff::from_circuits(&circuit, &circuit).unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_constraint_diff_xor() {
+        let circuit = BenchmarkXOR { num_xors: 3, num_variables: 10, modified: false };
+        let modified = BenchmarkXOR { modified: true, ..circuit };
+
+        let cs = RecordingConstraintSystem::synthesize(&circuit).unwrap();
+        assert_eq!(cs.num_constraints(), 12);
+        assert_eq!(cs.num_public_variables(), 4);
+        assert_eq!(cs.num_private_variables(), 7);
+
+        let diff = ConstraintDiff::from_circuits(&circuit, &modified).unwrap();
+        assert!(!diff.is_empty());
+        assert_eq!(diff.only_in_a, vec!["constraint gate 4 2".to_string()]);
+        assert_eq!(diff.only_in_b, vec!["constraint gate 5".to_string()]);
+        assert_eq!(diff.mismatches, vec![ConstraintMismatch {
+            path: "constraint gate 2 2".to_string(),
+            kinds: None,
+            terms: vec![TermDifference {
+                matrix: Matrix::A,
+                variable: "one".to_string(),
+                in_a: Some(Fr::from(2u64)),
+                in_b: Some(Fr::from(3u64)),
+            }],
+        }]);
+        let expected = "only in A: constraint gate 4 2\n\
+                        only in B: constraint gate 5\n\
+                        differs: constraint gate 2 2\n    A[one]: 2 != 3\n";
+        assert_eq!(diff.to_string(), expected);
+    }
+
+    #[test]
+    fn test_constraint_diff_namespaces_and_lookups() {
+        struct Lookup(bool);
+
+        impl ConstraintSynthesizer<Fr> for Lookup {
+            fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+                let mut cs = cs.ns(|| "gadget");
+                let x = cs.alloc(|| "x", || Ok(Fr::one()))?;
+                let y = cs.alloc(|| "y", || Ok(Fr::one()))?;
+                match self.0 {
+                    true => cs.enforce_lookup(|| "check", |lc| lc + x, |lc| lc + y, |lc| lc + x, 0),
+                    false => {
+                        cs.enforce(|| "check", |lc| lc + x, |lc| lc + y, |lc| lc + y);
+                        Ok(())
+                    }
+                }
+            }
+        }
+
+        let one = Fr::one();
+        let diff = ConstraintDiff::from_circuits(&Lookup(true), &Lookup(false)).unwrap();
+        assert!(diff.only_in_a.is_empty());
+        assert!(diff.only_in_b.is_empty());
+        assert_eq!(diff.mismatches, vec![ConstraintMismatch {
+            path: "gadget/check".to_string(),
+            kinds: Some((ConstraintKind::Lookup(0), ConstraintKind::Enforce)),
+            terms: vec![
+                TermDifference { matrix: Matrix::C, variable: "gadget/x".to_string(), in_a: Some(one), in_b: None },
+                TermDifference { matrix: Matrix::C, variable: "gadget/y".to_string(), in_a: None, in_b: Some(one) },
+            ],
+        }]);
+    }
+}
@@ -62,6 +62,44 @@ pub trait ConstraintSystem<F: Field>: Sized {
         A: FnOnce() -> AR,
         AR: AsRef<str>;
 
+    /// Allocate a private boolean variable in the constraint system. The provided
+    /// function is used to determine the assignment of the bit, which is allocated
+    /// as `0` or `1` in the field and constrained to satisfy `bit * (1 - bit) = 0`.
+    fn alloc_bit<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<bool, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let annotation = annotation().as_ref().to_string();
+
+        // Allocate the bit as a field element.
+        let bit = self.alloc(|| &annotation, || f().map(|bit| if bit { F::one() } else { F::zero() }))?;
+        // Enforce that the bit is either 0 or 1.
+        self.enforce(|| format!("{annotation} booleanity"), |lc| lc + bit, |lc| lc + Self::one() - bit, |lc| lc);
+
+        Ok(bit)
+    }
+
+    /// Enforce that the given variable is nonzero. The provided function is used
+    /// to determine the assignment of the inverse of the variable, which is allocated
+    /// as a private variable and constrained to satisfy `var * inv = 1`.
+    fn enforce_nonzero<FN, A, AR>(&mut self, annotation: A, var: Variable, inv: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let annotation = annotation().as_ref().to_string();
+
+        // Allocate the inverse as a field element.
+        let inverse = self.alloc(|| format!("{annotation} inverse"), inv)?;
+        // Enforce that the product of the variable and its inverse is 1.
+        self.enforce(|| format!("{annotation} nonzero"), |lc| lc + var, |lc| lc + inverse, |lc| lc + Self::one());
+
+        Ok(inverse)
+    }
+
     /// Enforce that `A` * `B` = `C`. The `annotation` function is invoked in
     /// testing contexts in order to derive a unique name for the constraint
     /// in the current namespace.
@@ -89,6 +127,27 @@ pub trait ConstraintSystem<F: Field>: Sized {
         LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
         LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>;
 
+    /// Lookup a value given an input, in the table selected at runtime by `selector`.
+    /// The selector must be assigned `i` in order to look up in the table at `table_indices[i]`.
+    fn enforce_lookup_dynamic<A, AR, LA, LB, LC>(
+        &mut self,
+        _annotation: A,
+        _a: LA,
+        _b: LB,
+        _c: LC,
+        _selector: Variable,
+        _table_indices: &[usize],
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        Err(SynthesisError::DynamicLookupUnsupported)
+    }
+
     /// Create a new (sub)namespace and enter into it. Not intended
     /// for downstream use; use `namespace` instead.
     fn push_namespace<NR, N>(&mut self, name_fn: N)
@@ -118,6 +177,12 @@ pub trait ConstraintSystem<F: Field>: Sized {
     /// Output the number of constraints in the system.
     fn num_constraints(&self) -> usize;
 
+    /// Output the number of lookup constraints in the system, which are included in `num_constraints`.
+    /// Constraint systems that do not count lookup constraints separately return 0.
+    fn num_lookup_constraints(&self) -> usize {
+        0
+    }
+
     /// Output the number of public input variables to the system.
     fn num_public_variables(&self) -> usize;
 
@@ -194,6 +259,26 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).enforce_lookup(annotation, a, b, c, table_index)
     }
 
+    #[inline]
+    fn enforce_lookup_dynamic<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        selector: Variable,
+        table_indices: &[usize],
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        (**self).enforce_lookup_dynamic(annotation, a, b, c, selector, table_indices)
+    }
+
     #[inline]
     fn push_namespace<NR, N>(&mut self, name_fn: N)
     where
@@ -218,6 +303,11 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).num_constraints()
     }
 
+    #[inline]
+    fn num_lookup_constraints(&self) -> usize {
+        (**self).num_lookup_constraints()
+    }
+
     #[inline]
     fn num_public_variables(&self) -> usize {
         (**self).num_public_variables()
@@ -233,3 +323,51 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
         (**self).is_in_setup_mode()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestConstraintChecker;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_alloc_bit() {
+        let mut cs = TestConstraintChecker::<Fr>::new();
+
+        let a = cs.alloc_bit(|| "a", || Ok(true)).unwrap();
+        assert_eq!(cs.num_constraints(), 1);
+        let b = cs.alloc_bit(|| "b", || Ok(false)).unwrap();
+        assert_eq!(cs.num_constraints(), 2);
+        assert_eq!(cs.num_private_variables(), 2);
+        assert!(cs.is_satisfied());
+
+        // Ensure the bits are allocated as 1 and 0.
+        let (_, private_variables) = cs.assignment_dump();
+        assert_eq!(a, Variable::new_unchecked(Index::Private(0)));
+        assert_eq!(b, Variable::new_unchecked(Index::Private(1)));
+        assert_eq!(private_variables, vec![Fr::one(), Fr::zero()]);
+    }
+
+    #[test]
+    fn test_enforce_nonzero() {
+        let mut rng = TestRng::default();
+
+        // Ensure a nonzero value with its inverse is satisfied.
+        let mut cs = TestConstraintChecker::<Fr>::new();
+        let value = Fr::rand(&mut rng);
+        let a = cs.alloc(|| "a", || Ok(value)).unwrap();
+        cs.enforce_nonzero(|| "a", a, || value.inverse().ok_or(SynthesisError::DivisionByZero)).unwrap();
+        assert_eq!(cs.num_constraints(), 1);
+        assert_eq!(cs.num_private_variables(), 2);
+        assert!(cs.is_satisfied());
+
+        // Ensure a zero value with a bogus inverse is not satisfied.
+        let mut cs = TestConstraintChecker::<Fr>::new();
+        let b = cs.alloc(|| "b", || Ok(Fr::zero())).unwrap();
+        cs.enforce_nonzero(|| "b", b, || Ok(Fr::rand(&mut rng))).unwrap();
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.which_is_unsatisfied(), Some("b nonzero".to_string()));
+    }
+}
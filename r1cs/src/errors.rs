@@ -49,6 +49,10 @@ pub enum SynthesisError {
     /// of the wrong length.
     #[error("Wrong length for lookup key, field element count was {} but expected {}", _0, _1)]
     LookupKeyWrongLength(usize, usize),
+    /// During synthesis, we attempted a dynamic lookup in a constraint system
+    /// that does not support selecting the lookup table at runtime.
+    #[error("Dynamic lookups are not supported by this constraint system")]
+    DynamicLookupUnsupported,
     /// During proof generation, we encountered an identity in the CRS
     #[error("Encountered an identity element in the CRS")]
     UnexpectedIdentity,
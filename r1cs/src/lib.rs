@@ -26,6 +26,9 @@ pub use constraints::*;
 mod constraint_counter;
 pub use constraint_counter::*;
 
+mod constraint_diff;
+pub use constraint_diff::*;
+
 mod constraint_system;
 pub use constraint_system::{ConstraintSynthesizer, ConstraintSystem};
 
@@ -53,6 +56,11 @@ pub use test_constraint_system::{Fr, TestConstraintSystem};
 mod test_constraint_checker;
 pub use test_constraint_checker::TestConstraintChecker;
 
+#[cfg(feature = "timing")]
+mod timing_constraint_system;
+#[cfg(feature = "timing")]
+pub use timing_constraint_system::TimingConstraintSystem;
+
 use snarkvm_utilities::serialize::*;
 
 use std::cmp::Ordering;
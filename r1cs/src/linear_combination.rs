@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Variable;
+use crate::{Index, Variable};
 use snarkvm_fields::Field;
 
 use std::{
@@ -78,6 +78,28 @@ impl<F: Field> LinearCombination<F> {
         });
     }
 
+    /// Returns `true` if `self` only depends on the constant-one variable.
+    #[inline]
+    pub fn is_constant(&self) -> bool {
+        self.as_constant().is_some()
+    }
+
+    /// Returns the constant value of `self`, if it only depends on the constant-one variable.
+    /// Terms with a zero coefficient are ignored, and an empty linear combination is the constant zero.
+    #[inline]
+    pub fn as_constant(&self) -> Option<F> {
+        let one = Variable::new_unchecked(Index::Public(0));
+        let mut constant = F::zero();
+        for (var, coeff) in &self.0 {
+            if *var == one {
+                constant += coeff;
+            } else if !coeff.is_zero() {
+                return None;
+            }
+        }
+        Some(constant)
+    }
+
     /// Get the location of a variable in `self`.
     #[inline]
     pub fn get_var_loc(&self, search_var: &Variable) -> Result<usize, usize> {
@@ -431,8 +453,6 @@ impl<F: Field> Sub<(F, LinearCombination<F>)> for LinearCombination<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Index;
-
     use super::*;
     use snarkvm_curves::bls12_377::Fr;
 
@@ -444,4 +464,32 @@ mod tests {
         }
         assert_eq!(combo.0.len(), 1);
     }
+
+    #[test]
+    fn linear_combination_as_constant() {
+        let one = Variable::new_unchecked(Index::Public(0));
+        let public = Variable::new_unchecked(Index::Public(1));
+        let private = Variable::new_unchecked(Index::Private(0));
+
+        // A pure-constant linear combination.
+        let constant = LinearCombination::<Fr>::zero() + (Fr::from(3u64), one) + (Fr::from(4u64), one);
+        assert!(constant.is_constant());
+        assert_eq!(constant.as_constant(), Some(Fr::from(7u64)));
+
+        // A mixed linear combination.
+        let mixed = constant.clone() + (Fr::from(5u64), private);
+        assert!(!mixed.is_constant());
+        assert_eq!(mixed.as_constant(), None);
+        let mixed = LinearCombination::<Fr>::from(public) + (Fr::from(3u64), one);
+        assert_eq!(mixed.as_constant(), None);
+
+        // An empty linear combination.
+        let empty = LinearCombination::<Fr>::zero();
+        assert!(empty.is_constant());
+        assert_eq!(empty.as_constant(), Some(Fr::from(0u64)));
+
+        // Terms with a zero coefficient do not affect the constant.
+        let zeroed = LinearCombination(vec![(one, Fr::from(2u64)), (private, Fr::from(0u64))]);
+        assert_eq!(zeroed.as_constant(), Some(Fr::from(2u64)));
+    }
 }
@@ -87,6 +87,26 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for Namespace<'_, F,
         self.0.enforce_lookup(annotation, a, b, c, table_index)
     }
 
+    #[inline]
+    fn enforce_lookup_dynamic<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        selector: Variable,
+        table_indices: &[usize],
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.0.enforce_lookup_dynamic(annotation, a, b, c, selector, table_indices)
+    }
+
     // Downstream users who use `namespace` will never interact with these
     // functions and they will never be invoked because the namespace is
     // never a root constraint system.
@@ -115,6 +135,11 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for Namespace<'_, F,
         self.0.num_constraints()
     }
 
+    #[inline]
+    fn num_lookup_constraints(&self) -> usize {
+        self.0.num_lookup_constraints()
+    }
+
     #[inline]
     fn num_public_variables(&self) -> usize {
         self.0.num_public_variables()
@@ -17,22 +17,28 @@
 use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, LookupTable, Variable};
 use snarkvm_fields::Field;
 
+use core::fmt;
+
 /// Constraint system for testing purposes.
 pub struct TestConstraintChecker<F: Field> {
     // the list of currently applicable input variables
     public_variables: Vec<F>,
     // the list of currently applicable auxiliary variables
     private_variables: Vec<F>,
-    // the currently applicable lookup table
-    lookup_table: Option<LookupTable<F>>,
+    // the lookup tables, in the order they were added
+    lookup_tables: Vec<LookupTable<F>>,
     // whether or not unsatisfactory constraint has been found
     found_unsatisfactory_constraint: bool,
     // number of constraints
     num_constraints: usize,
+    // number of successful lookups, which are included in `num_constraints`
+    num_lookup_constraints: usize,
     // constraint path segments in the stack
     segments: Vec<String>,
     // the first unsatisfied constraint
     first_unsatisfied_constraint: Option<String>,
+    // the distinct keys found in each lookup table, if tracking is enabled
+    lookup_hits: Option<Vec<Vec<Vec<F>>>>,
 }
 
 impl<F: Field> Default for TestConstraintChecker<F> {
@@ -40,11 +46,13 @@ impl<F: Field> Default for TestConstraintChecker<F> {
         Self {
             public_variables: vec![F::one()],
             private_variables: vec![],
-            lookup_table: None,
+            lookup_tables: vec![],
             found_unsatisfactory_constraint: false,
             num_constraints: 0,
+            num_lookup_constraints: 0,
             segments: vec![],
             first_unsatisfied_constraint: None,
+            lookup_hits: None,
         }
     }
 }
@@ -54,6 +62,21 @@ impl<F: Field> TestConstraintChecker<F> {
         Self::default()
     }
 
+    /// Enables tracking of the keys found in each lookup table by `enforce_lookup`.
+    /// Tracking is disabled by default, to avoid the overhead of recording each key.
+    pub fn track_lookup_hits(&mut self) {
+        self.lookup_hits.get_or_insert_with(Vec::new);
+    }
+
+    /// Returns the distinct keys found in the lookup table at the given index, in the order they were first queried.
+    /// Returns an empty slice if tracking is disabled.
+    pub fn lookup_hits(&self, table_index: usize) -> &[Vec<F>] {
+        match self.lookup_hits.as_ref().and_then(|hits| hits.get(table_index)) {
+            Some(hits) => hits,
+            None => &[],
+        }
+    }
+
     pub fn which_is_unsatisfied(&self) -> Option<String> {
         self.first_unsatisfied_constraint.clone()
     }
@@ -80,17 +103,78 @@ impl<F: Field> TestConstraintChecker<F> {
         self.num_constraints
     }
 
+    #[inline]
+    pub fn num_lookup_constraints(&self) -> usize {
+        self.num_lookup_constraints
+    }
+
     #[inline]
     pub fn public_inputs(&self) -> Vec<F> {
         self.public_variables[1..].to_vec()
     }
+
+    /// Returns clones of the public and private variable assignments, skipping the leading `one`.
+    pub fn assignment_dump(&self) -> (Vec<F>, Vec<F>) {
+        (self.public_inputs(), self.private_variables.clone())
+    }
+
+    /// Checks that `c` is the value for the key `(a, b)` in the lookup table at the given index.
+    fn check_lookup(&mut self, a: F, b: F, c: F, table_index: usize) -> Result<(), SynthesisError> {
+        let res = if let Some(lookup_table) = self.lookup_tables.get(table_index) {
+            *lookup_table.lookup(&[a, b]).ok_or(SynthesisError::LookupValueMissing)?
+        } else {
+            if self.first_unsatisfied_constraint.is_none() {
+                self.found_unsatisfactory_constraint = true;
+                self.first_unsatisfied_constraint = Some("lookup".to_string());
+            }
+            return Err(SynthesisError::LookupTableMissing);
+        };
+
+        // Record the key, if tracking is enabled.
+        if let Some(lookup_hits) = self.lookup_hits.as_mut() {
+            if lookup_hits.len() <= table_index {
+                lookup_hits.resize(table_index + 1, Vec::new());
+            }
+            let key = vec![a, b];
+            if !lookup_hits[table_index].contains(&key) {
+                lookup_hits[table_index].push(key);
+            }
+        }
+
+        if c == res {
+            self.num_constraints += 1;
+            self.num_lookup_constraints += 1;
+            Ok(())
+        } else {
+            Err(SynthesisError::LookupValueMissing)
+        }
+    }
+}
+
+impl<F: Field> fmt::Display for TestConstraintChecker<F> {
+    /// Prints the variable assignments, along with the first unsatisfied constraint.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (public_variables, private_variables) = self.assignment_dump();
+        writeln!(f, "Public variables:")?;
+        for (index, value) in public_variables.iter().enumerate() {
+            writeln!(f, "    {}: {value}", index + 1)?;
+        }
+        writeln!(f, "Private variables:")?;
+        for (index, value) in private_variables.iter().enumerate() {
+            writeln!(f, "    {index}: {value}")?;
+        }
+        match self.which_is_unsatisfied() {
+            Some(constraint) => write!(f, "First unsatisfied constraint: {constraint}"),
+            None => write!(f, "All constraints are satisfied"),
+        }
+    }
 }
 
 impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
     type Root = Self;
 
     fn add_lookup_table(&mut self, lookup_table: LookupTable<F>) {
-        self.lookup_table = Some(lookup_table);
+        self.lookup_tables.push(lookup_table);
     }
 
     fn alloc<Fn, A, AR>(&mut self, _annotation: A, f: Fn) -> Result<Variable, SynthesisError>
@@ -151,7 +235,7 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
         a: LA,
         b: LB,
         c: LC,
-        _table_index: usize,
+        table_index: usize,
     ) -> Result<(), SynthesisError>
     where
         A: FnOnce() -> AR,
@@ -164,21 +248,40 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
         let b = self.eval_lc(&b(LinearCombination::zero()));
         let c = self.eval_lc(&c(LinearCombination::zero()));
 
-        let res = if let Some(lookup_table) = &self.lookup_table {
-            *lookup_table.lookup(&[a, b]).ok_or(SynthesisError::LookupValueMissing)?
-        } else {
-            if self.first_unsatisfied_constraint.is_none() {
-                self.found_unsatisfactory_constraint = true;
-                self.first_unsatisfied_constraint = Some("lookup".to_string());
-            }
-            return Err(SynthesisError::LookupTableMissing);
-        };
+        self.check_lookup(a, b, c, table_index)
+    }
 
-        if c == res {
-            self.num_constraints += 1;
-            Ok(())
-        } else {
-            Err(SynthesisError::LookupValueMissing)
+    fn enforce_lookup_dynamic<A, AR, LA, LB, LC>(
+        &mut self,
+        _: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        selector: Variable,
+        table_indices: &[usize],
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let a = self.eval_lc(&a(LinearCombination::zero()));
+        let b = self.eval_lc(&b(LinearCombination::zero()));
+        let c = self.eval_lc(&c(LinearCombination::zero()));
+
+        // Select the lookup table from the concrete value of the selector.
+        let selector = self.eval_lc(&LinearCombination::from(selector));
+        match (0..table_indices.len()).find(|i| F::from(*i as u64) == selector) {
+            Some(i) => self.check_lookup(a, b, c, table_indices[i]),
+            None => {
+                if self.first_unsatisfied_constraint.is_none() {
+                    self.found_unsatisfactory_constraint = true;
+                    self.first_unsatisfied_constraint = Some("lookup selector".to_string());
+                }
+                Err(SynthesisError::Unsatisfiable)
+            }
         }
     }
 
@@ -203,6 +306,11 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
         self.num_constraints()
     }
 
+    #[inline]
+    fn num_lookup_constraints(&self) -> usize {
+        self.num_lookup_constraints()
+    }
+
     #[inline]
     fn num_public_variables(&self) -> usize {
         self.public_variables.len()
@@ -218,3 +326,181 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintChecker<F> {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConstraintCounter, ConstraintSynthesizer, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_assignment_dump() {
+        let mut cs = TestConstraintChecker::<Fr>::new();
+        let a = cs.alloc_input(|| "a", || Ok(Fr::one())).unwrap();
+        let b = cs.alloc(|| "b", || Ok(Fr::one())).unwrap();
+        let c = cs.alloc(|| "c", || Ok(Fr::one() + Fr::one())).unwrap();
+        cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+
+        // Ensure the dump lengths match the number of variables, skipping the leading `one`.
+        let (public_variables, private_variables) = cs.assignment_dump();
+        assert_eq!(public_variables.len(), cs.num_public_variables() - 1);
+        assert_eq!(private_variables.len(), cs.num_private_variables());
+        assert_eq!(public_variables, vec![Fr::one()]);
+        assert_eq!(private_variables, vec![Fr::one(), Fr::one() + Fr::one()]);
+
+        // Ensure the display includes the unsatisfied constraint.
+        assert!(!cs.is_satisfied());
+        assert!(cs.to_string().contains("First unsatisfied constraint: a * b = c"));
+    }
+
+    #[test]
+    fn test_lookup_hits() {
+        // Construct an addition table over the keys `(i, j)` for `i, j` in `0..4`.
+        let mut table = LookupTable::default();
+        for i in 0..4u64 {
+            for j in 0..4u64 {
+                table.fill([Fr::from(i), Fr::from(j)], Fr::from(i + j));
+            }
+        }
+
+        // Query a subset of the keys, including a repeated key.
+        let queries = [(1u64, 2u64), (3, 0), (1, 2), (2, 2)];
+        let query = |cs: &mut TestConstraintChecker<Fr>| {
+            for (i, j) in queries {
+                let a = cs.alloc(|| format!("a_{i}_{j}"), || Ok(Fr::from(i))).unwrap();
+                let b = cs.alloc(|| format!("b_{i}_{j}"), || Ok(Fr::from(j))).unwrap();
+                let c = cs.alloc(|| format!("c_{i}_{j}"), || Ok(Fr::from(i + j))).unwrap();
+                cs.enforce_lookup(|| "lookup", |lc| lc + a, |lc| lc + b, |lc| lc + c, 0).unwrap();
+            }
+        };
+
+        // Ensure no hits are recorded by default.
+        let mut cs = TestConstraintChecker::<Fr>::new();
+        cs.add_lookup_table(table.clone());
+        query(&mut cs);
+        assert!(cs.lookup_hits(0).is_empty());
+
+        // Ensure the distinct keys are recorded once tracking is enabled.
+        let mut cs = TestConstraintChecker::<Fr>::new();
+        cs.add_lookup_table(table);
+        cs.track_lookup_hits();
+        query(&mut cs);
+        let expected = [(1u64, 2u64), (3, 0), (2, 2)].map(|(i, j)| vec![Fr::from(i), Fr::from(j)]);
+        assert_eq!(cs.lookup_hits(0), expected);
+        assert!(cs.lookup_hits(1).is_empty());
+        assert!(cs.is_satisfied());
+    }
+
+    /// The lookup circuit of the Marlin benchmarks, which looks up `x ^ y = z` in an XOR table `num_xors` times.
+    struct BenchmarkWithLookup {
+        x: Fr,
+        y: Fr,
+        z: Fr,
+        num_xors: usize,
+        num_variables: usize,
+        tables: Vec<LookupTable<Fr>>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for BenchmarkWithLookup {
+        fn generate_constraints<C: ConstraintSystem<Fr>>(&self, cs: &mut C) -> Result<(), SynthesisError> {
+            for table in &self.tables {
+                cs.add_lookup_table(table.clone());
+            }
+            let x = cs.alloc_input(|| "x", || Ok(self.x))?;
+            let y = cs.alloc_input(|| "y", || Ok(self.y))?;
+            let z = cs.alloc_input(|| "z", || Ok(self.z))?;
+            for i in 0..self.num_xors {
+                cs.enforce_lookup(|| format!("c_lookup {i}"), |lc| lc + x, |lc| lc + y, |lc| lc + z, 0)?;
+            }
+            for i in 0..(self.num_variables - 3) {
+                let _ = cs.alloc(|| format!("var {i}"), || Ok(self.x))?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_num_lookup_constraints() {
+        // Construct the XOR table over bits.
+        let mut table = LookupTable::default();
+        for (x, y) in [(0u64, 0u64), (0, 1), (1, 0), (1, 1)] {
+            table.fill([Fr::from(x), Fr::from(y)], Fr::from(x ^ y));
+        }
+
+        for num_xors in [0, 1, 10, 100] {
+            let circuit = BenchmarkWithLookup {
+                x: Fr::one(),
+                y: Fr::zero(),
+                z: Fr::one(),
+                num_xors,
+                num_variables: 10,
+                tables: vec![table.clone()],
+            };
+            let mut cs = TestConstraintChecker::<Fr>::new();
+            circuit.generate_constraints(&mut cs).unwrap();
+            assert!(cs.is_satisfied());
+
+            // Ensure the lookups are counted separately, and included in the number of constraints.
+            assert_eq!(ConstraintSystem::num_lookup_constraints(&cs), num_xors);
+            assert_eq!(ConstraintSystem::num_constraints(&cs), num_xors);
+
+            // Ensure an arithmetic constraint is not counted as a lookup.
+            cs.enforce(|| "arithmetic", |lc| lc, |lc| lc, |lc| lc);
+            assert_eq!(ConstraintSystem::num_lookup_constraints(&cs), num_xors);
+            assert_eq!(ConstraintSystem::num_constraints(&cs), num_xors + 1);
+
+            // Ensure the constraint counter and the test constraint system count the same lookups.
+            let mut counter = ConstraintCounter::default();
+            ConstraintSynthesizer::<Fr>::generate_constraints(&circuit, &mut counter).unwrap();
+            assert_eq!(ConstraintSystem::<Fr>::num_lookup_constraints(&counter), num_xors);
+            assert_eq!(ConstraintSystem::<Fr>::num_constraints(&counter), num_xors);
+
+            let mut test_cs = TestConstraintSystem::<Fr>::new();
+            circuit.generate_constraints(&mut test_cs).unwrap();
+            assert!(test_cs.is_satisfied());
+            assert_eq!(ConstraintSystem::num_lookup_constraints(&test_cs), num_xors);
+            assert_eq!(ConstraintSystem::num_constraints(&test_cs), num_xors);
+        }
+    }
+
+    #[test]
+    fn test_enforce_lookup_dynamic() {
+        let (one, two, three) = (Fr::from(1u64), Fr::from(2u64), Fr::from(3u64));
+
+        // Construct an addition table and a multiplication table.
+        let mut addition = LookupTable::default();
+        addition.fill([one, two], three);
+        let mut multiplication = LookupTable::default();
+        multiplication.fill([one, two], two);
+
+        let mut cs = TestConstraintChecker::<Fr>::new();
+        cs.add_lookup_table(addition);
+        cs.add_lookup_table(multiplication);
+
+        let a = cs.alloc(|| "a", || Ok(one)).unwrap();
+        let b = cs.alloc(|| "b", || Ok(two)).unwrap();
+        let sum = cs.alloc(|| "sum", || Ok(three)).unwrap();
+        let product = cs.alloc(|| "product", || Ok(two)).unwrap();
+
+        // Ensure the selector chooses each table.
+        for (selector, c) in [(0u64, sum), (1, product)] {
+            let selector = cs.alloc(|| "selector", || Ok(Fr::from(selector))).unwrap();
+            cs.enforce_lookup_dynamic(|| "lookup", |lc| lc + a, |lc| lc + b, |lc| lc + c, selector, &[0, 1]).unwrap();
+        }
+        assert_eq!(cs.num_constraints(), 2);
+        assert!(cs.is_satisfied());
+
+        // Ensure the result must match the selected table.
+        let selector = cs.alloc(|| "selector", || Ok(one)).unwrap();
+        let result = cs.enforce_lookup_dynamic(|| "lookup", |lc| lc + a, |lc| lc + b, |lc| lc + sum, selector, &[0, 1]);
+        assert!(matches!(result, Err(SynthesisError::LookupValueMissing)));
+
+        // Ensure a selector outside of the candidate tables is unsatisfied.
+        let selector = cs.alloc(|| "selector", || Ok(two)).unwrap();
+        let result = cs.enforce_lookup_dynamic(|| "lookup", |lc| lc + a, |lc| lc + b, |lc| lc + sum, selector, &[0, 1]);
+        assert!(matches!(result, Err(SynthesisError::Unsatisfiable)));
+        assert!(!cs.is_satisfied());
+        assert_eq!(cs.num_constraints(), 2);
+    }
+}
@@ -61,6 +61,7 @@ pub struct TestConstraint {
     a: Vec<(Variable, InternedField)>,
     b: Vec<(Variable, InternedField)>,
     c: Vec<(Variable, InternedField)>,
+    is_lookup: bool,
 }
 
 #[derive(Default, Debug)]
@@ -239,7 +240,7 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 
     pub fn which_is_unsatisfied(&self) -> Option<String> {
-        for TestConstraint { interned_path, a, b, c } in self.constraints.iter() {
+        for TestConstraint { interned_path, a, b, c, .. } in self.constraints.iter() {
             let mut a = self.eval_lc(a.as_ref());
             let b = self.eval_lc(b.as_ref());
             let c = self.eval_lc(c.as_ref());
@@ -277,6 +278,11 @@ impl<F: Field> TestConstraintSystem<F> {
         self.constraints.len()
     }
 
+    #[inline]
+    pub fn num_lookup_constraints(&self) -> usize {
+        self.constraints.iter().filter(|constraint| constraint.is_lookup).count()
+    }
+
     #[inline]
     pub fn get_constraint_path(&self, i: usize) -> String {
         self.unintern_path(self.constraints.iter().nth(i).unwrap().interned_path)
@@ -443,7 +449,7 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         let b = self.intern_fields(&b(LinearCombination::zero()));
         let c = self.intern_fields(&c(LinearCombination::zero()));
 
-        self.constraints.insert(TestConstraint { interned_path, a, b, c });
+        self.constraints.insert(TestConstraint { interned_path, a, b, c, is_lookup: false });
     }
 
     fn enforce_lookup<A, AR, LA, LB, LC>(
@@ -468,13 +474,13 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         let evaluated = vec![a.clone(), b.clone(), c.clone()].iter().map(|lc| self.eval_lc(lc)).collect::<Vec<F>>();
 
         let res = if let Some(lookup_table) = &self.lookup_table {
-            *lookup_table.lookup(&evaluated[..1]).ok_or(SynthesisError::LookupValueMissing)?
+            *lookup_table.lookup(&evaluated[..2]).ok_or(SynthesisError::LookupValueMissing)?
         } else {
             return Err(SynthesisError::LookupTableMissing);
         };
 
         if res == evaluated[2] {
-            self.constraints.insert(TestConstraint { interned_path, a, b, c });
+            self.constraints.insert(TestConstraint { interned_path, a, b, c, is_lookup: true });
             Ok(())
         } else {
             Err(SynthesisError::LookupValueMissing)
@@ -531,6 +537,11 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
         self.num_constraints()
     }
 
+    #[inline]
+    fn num_lookup_constraints(&self) -> usize {
+        self.num_lookup_constraints()
+    }
+
     #[inline]
     fn num_public_variables(&self) -> usize {
         self.public_variables.len()
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, LinearCombination, LookupTable, Variable};
+use snarkvm_fields::Field;
+
+use indexmap::IndexMap;
+use std::{
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+/// A constraint system that wraps another constraint system, and records the wall-clock time
+/// spent in each namespace. This is intended for profiling the synthesis of circuits.
+///
+/// The timings are keyed by the full path of the namespace (e.g. `outer/inner`), and are inclusive:
+/// the time spent in a namespace includes the time spent in its nested namespaces.
+/// If a namespace path is entered more than once, its durations are accumulated.
+pub struct TimingConstraintSystem<F: Field, CS: ConstraintSystem<F>> {
+    /// The inner constraint system.
+    inner: CS,
+    /// The currently open namespaces, with their full paths and start times.
+    open_namespaces: Vec<(String, Instant)>,
+    /// The accumulated duration of each namespace path, in the order they were first entered.
+    timings: IndexMap<String, Duration>,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> TimingConstraintSystem<F, CS> {
+    /// Wraps the given constraint system.
+    pub fn new(inner: CS) -> Self {
+        Self { inner, open_namespaces: Vec::new(), timings: IndexMap::new(), _field: PhantomData }
+    }
+
+    /// Returns the inner constraint system.
+    pub const fn inner(&self) -> &CS {
+        &self.inner
+    }
+
+    /// Returns the inner constraint system, consuming the wrapper.
+    pub fn into_inner(self) -> CS {
+        self.inner
+    }
+
+    /// Returns the accumulated duration of each namespace path, in the order they were first entered.
+    /// Namespaces that are still open are not included.
+    pub const fn timings(&self) -> &IndexMap<String, Duration> {
+        &self.timings
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for TimingConstraintSystem<F, CS> {
+    type Root = Self;
+
+    fn one() -> Variable {
+        CS::one()
+    }
+
+    fn add_lookup_table(&mut self, table: LookupTable<F>) {
+        self.inner.add_lookup_table(table)
+    }
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.inner.alloc(annotation, f)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.inner.alloc_input(annotation, f)
+    }
+
+    fn alloc_bit<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<bool, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.inner.alloc_bit(annotation, f)
+    }
+
+    fn enforce_nonzero<FN, A, AR>(&mut self, annotation: A, var: Variable, inv: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.inner.enforce_nonzero(annotation, var, inv)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.inner.enforce(annotation, a, b, c)
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        table_index: usize,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.inner.enforce_lookup(annotation, a, b, c, table_index)
+    }
+
+    fn enforce_lookup_dynamic<A, AR, LA, LB, LC>(
+        &mut self,
+        annotation: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        selector: Variable,
+        table_indices: &[usize],
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.inner.enforce_lookup_dynamic(annotation, a, b, c, selector, table_indices)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn().as_ref().to_string();
+
+        // Compute the full path of the namespace.
+        let path = match self.open_namespaces.last() {
+            Some((parent, _)) => format!("{parent}/{name}"),
+            None => name.clone(),
+        };
+        self.inner.push_namespace(|| name);
+
+        // Start the timer for the namespace.
+        self.open_namespaces.push((path, Instant::now()));
+    }
+
+    fn pop_namespace(&mut self) {
+        // Stop the timer for the namespace, and accumulate its duration.
+        if let Some((path, start)) = self.open_namespaces.pop() {
+            *self.timings.entry(path).or_default() += start.elapsed();
+        }
+        self.inner.pop_namespace();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.inner.num_constraints()
+    }
+
+    fn num_lookup_constraints(&self) -> usize {
+        self.inner.num_lookup_constraints()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.inner.num_public_variables()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.inner.num_private_variables()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.inner.is_in_setup_mode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, TestConstraintChecker};
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_timings_per_namespace() {
+        let mut cs = TimingConstraintSystem::new(TestConstraintChecker::<Fr>::new());
+
+        // Synthesize a constraint in a nested namespace, and another in a sibling namespace, twice.
+        for _ in 0..2 {
+            let mut outer = cs.ns(|| "outer");
+            {
+                let mut inner = outer.ns(|| "inner");
+                let a = inner.alloc(|| "a", || Ok(Fr::one())).unwrap();
+                std::thread::sleep(Duration::from_millis(2));
+                inner.enforce(|| "a * 1 = a", |lc| lc + a, |lc| lc + TestConstraintChecker::<Fr>::one(), |lc| lc + a);
+            }
+            drop(outer);
+
+            let mut sibling = cs.ns(|| "sibling");
+            let b = sibling.alloc_bit(|| "b", || Ok(true)).unwrap();
+            sibling.enforce(|| "b * b = b", |lc| lc + b, |lc| lc + b, |lc| lc + b);
+        }
+
+        // Ensure the timings are recorded per namespace path, and accumulated across entries.
+        let timings = cs.timings();
+        assert_eq!(timings.keys().collect::<Vec<_>>(), ["outer/inner", "outer", "sibling"]);
+        assert!(timings["outer/inner"] >= Duration::from_millis(4));
+        assert!(timings["outer"] >= timings["outer/inner"]);
+
+        // Ensure the constraints were forwarded to the inner constraint system.
+        assert_eq!(cs.num_constraints(), 6);
+        assert_eq!(cs.num_private_variables(), 4);
+        assert!(cs.into_inner().is_satisfied());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use console::{
+    network::Testnet3,
+    prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use snarkvm_synthesizer::{ProgramMemory, ProgramStore};
+
+use criterion::{BatchSize, Criterion};
+
+type CurrentNetwork = Testnet3;
+type CurrentStore = ProgramStore<CurrentNetwork, ProgramMemory<CurrentNetwork>>;
+
+/// The number of mapping entries to insert.
+const NUM_ENTRIES: u64 = 10_000;
+
+/// Initializes a program store with an empty mapping.
+fn initialize_store() -> (CurrentStore, ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>) {
+    let program_id = ProgramID::from_str("hello.aleo").unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let program_store = ProgramStore::open(None).unwrap();
+    program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+    (program_store, program_id, mapping_name)
+}
+
+/// Samples the mapping entries to insert.
+fn sample_entries() -> Vec<(Plaintext<CurrentNetwork>, Value<CurrentNetwork>)> {
+    (0..NUM_ENTRIES)
+        .map(|i| (Plaintext::from_str(&format!("{i}u64")).unwrap(), Value::from_str(&format!("{i}u128")).unwrap()))
+        .collect()
+}
+
+fn insert_mapping_entries(c: &mut Criterion) {
+    let entries = sample_entries();
+
+    c.bench_function(&format!("ProgramStore::update_key_value ({NUM_ENTRIES} entries)"), |b| {
+        b.iter_batched(
+            || (initialize_store(), entries.clone()),
+            |((program_store, program_id, mapping_name), entries)| {
+                program_store.start_atomic();
+                for (key, value) in entries {
+                    program_store.update_key_value(&program_id, &mapping_name, key, value).unwrap();
+                }
+                program_store.finish_atomic().unwrap();
+            },
+            BatchSize::PerIteration,
+        )
+    });
+
+    c.bench_function(&format!("ProgramStore::update_key_values ({NUM_ENTRIES} entries)"), |b| {
+        b.iter_batched(
+            || (initialize_store(), entries.clone()),
+            |((program_store, program_id, mapping_name), entries)| {
+                program_store.update_key_values(&program_id, &mapping_name, entries).unwrap();
+            },
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+criterion_group! {
+    name = store;
+    config = Criterion::default().sample_size(10);
+    targets = insert_mapping_entries
+}
+
+criterion_main!(store);
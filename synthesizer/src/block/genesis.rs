@@ -15,7 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::ConsensusStorage;
+use crate::{ConsensusMemory, ConsensusStorage, ConsensusStore};
+use console::{account::ViewKey, program::Value};
 
 impl<N: Network> Block<N> {
     /// Initializes a new genesis block.
@@ -52,6 +53,87 @@ impl<N: Network> Block<N> {
         }
     }
 
+    /// Initializes a new genesis block, followed by the blocks that distribute the starting supply
+    /// to the given allocations. The allocations must be non-zero and sum to the starting supply.
+    ///
+    /// The genesis block holds exactly one transaction, which mints the starting supply to the caller.
+    /// Each following block holds one `credits.aleo/transfer` from the caller to the next allocation,
+    /// so the blocks must be added to a VM in order, starting from height 0.
+    pub fn genesis_with_allocations<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        allocations: &[(Address<N>, u64)],
+        rng: &mut R,
+    ) -> Result<Vec<Self>> {
+        // Ensure there is at least one allocation.
+        ensure!(!allocations.is_empty(), "The genesis block requires at least one allocation");
+        // Ensure each allocation is non-zero.
+        ensure!(allocations.iter().all(|(_, amount)| *amount > 0), "The genesis allocations must be non-zero");
+        // Ensure the allocations sum to the starting supply.
+        let total = allocations
+            .iter()
+            .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+            .ok_or_else(|| anyhow!("The genesis allocations overflowed"))?;
+        ensure!(
+            total == N::STARTING_SUPPLY,
+            "The genesis allocations total {total} gates, but the starting supply is {} gates",
+            N::STARTING_SUPPLY
+        );
+
+        // Initialize a VM to build the blocks against.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        // Prepare the caller.
+        let view_key = ViewKey::try_from(private_key)?;
+
+        // Construct the genesis block.
+        let genesis = Self::genesis(&vm, private_key, rng)?;
+        vm.add_next_block(&genesis)?;
+
+        // Retrieve the record that holds the starting supply.
+        let mut record = match genesis.records().next() {
+            Some((_, record)) => record.decrypt(&view_key)?,
+            None => bail!("The genesis block does not contain a record"),
+        };
+
+        let mut blocks = vec![genesis];
+        for (address, amount) in allocations {
+            // Transfer the allocation from the caller.
+            let inputs = [
+                Value::Record(record),
+                Value::from_str(&address.to_string())?,
+                Value::from_str(&format!("{amount}u64"))?,
+            ];
+            let authorization = vm.authorize(private_key, "credits.aleo", "transfer", inputs.into_iter(), rng)?;
+            let transaction = Transaction::execute_authorization(&vm, authorization, None, rng)?;
+            let transactions = Transactions::from(&[transaction]);
+
+            // Construct the next block.
+            let previous = blocks.last().ok_or_else(|| anyhow!("Missing the previous block"))?;
+            let metadata = Metadata::new(
+                N::ID,
+                previous.round() + 1,
+                previous.height() + 1,
+                N::GENESIS_COINBASE_TARGET,
+                N::GENESIS_PROOF_TARGET,
+                previous.last_coinbase_target(),
+                previous.last_coinbase_timestamp(),
+                previous.timestamp() + 1,
+                0,
+            )?;
+            let state_root = *vm.block_store().current_state_root();
+            let header = Header::from(state_root, transactions.to_root()?, Field::zero(), metadata)?;
+            let block = Self::new(private_key, previous.hash(), header, transactions, None, rng)?;
+            vm.add_next_block(&block)?;
+
+            // Retrieve the change record of the caller, which is the last output of the transfer.
+            record = match block.records().last() {
+                Some((_, record)) => record.decrypt(&view_key)?,
+                None => bail!("The allocation block does not contain a change record"),
+            };
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
     /// Returns `true` if the block is a genesis block.
     pub fn is_genesis(&self) -> bool {
         // Ensure the previous block hash is zero.
@@ -85,4 +167,93 @@ mod tests {
         // println!("{}", serde_json::to_string_pretty(&block).unwrap());
         assert!(new_genesis_block.is_genesis());
     }
+
+    #[test]
+    fn test_genesis_with_allocations() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis caller and the pre-funded accounts.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let private_keys = (0..3).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+        let addresses = private_keys.iter().map(|key| Address::try_from(key).unwrap()).collect::<Vec<_>>();
+        let amounts = [1_000_000, 2_000_000, CurrentNetwork::STARTING_SUPPLY - 3_000_000];
+        let allocations = addresses.iter().copied().zip(amounts).collect::<Vec<_>>();
+
+        // Ensure the allocations must sum to the starting supply.
+        assert!(Block::genesis_with_allocations(&caller_private_key, &allocations[..2], rng).is_err());
+        assert!(Block::genesis_with_allocations(&caller_private_key, &[], rng).is_err());
+
+        // Construct the genesis block and the allocation blocks.
+        let blocks = Block::genesis_with_allocations(&caller_private_key, &allocations, rng).unwrap();
+        assert_eq!(blocks.len(), 1 + allocations.len());
+        assert!(blocks[0].is_genesis());
+        assert_eq!(blocks[0].transactions().len(), 1);
+        assert!(blocks[1..].iter().all(|block| !block.is_genesis()));
+
+        // Load the blocks into a fresh VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        for block in &blocks {
+            vm.add_next_block(block).unwrap();
+        }
+        assert!(vm.block_store().contains_block_height(allocations.len() as u32).unwrap());
+
+        // Ensure each account received its allocation.
+        let mut records = Vec::new();
+        for (private_key, amount) in private_keys.iter().zip(amounts) {
+            let view_key = ViewKey::try_from(private_key).unwrap();
+            let owned = blocks
+                .iter()
+                .flat_map(|block| block.records())
+                .filter(|(_, record)| record.is_owner(&view_key))
+                .map(|(_, record)| record.decrypt(&view_key).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(owned.len(), 1);
+            assert_eq!(***owned[0].gates(), amount);
+            records.push(owned[0].clone());
+        }
+
+        // Transfer from the first pre-funded account to the second.
+        let inputs = [
+            Value::Record(records[0].clone()),
+            Value::from_str(&addresses[1].to_string()).unwrap(),
+            Value::from_str("400000u64").unwrap(),
+        ];
+        let authorization =
+            vm.authorize(&private_keys[0], "credits.aleo", "transfer", inputs.into_iter(), rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction).is_ok());
+        assert!(vm.verify_transaction(&transaction));
+
+        // Add the transfer in the next block.
+        let previous = blocks.last().unwrap();
+        let transactions = Transactions::from(&[transaction]);
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            previous.round() + 1,
+            previous.height() + 1,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            previous.last_coinbase_target(),
+            previous.last_coinbase_timestamp(),
+            previous.timestamp() + 1,
+            0,
+        )
+        .unwrap();
+        let state_root = *vm.block_store().current_state_root();
+        let header = Header::from(state_root, transactions.to_root().unwrap(), Field::zero(), metadata).unwrap();
+        let block = Block::new(&private_keys[0], previous.hash(), header, transactions, None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the second account received the transfer, and the first account received its change.
+        for (private_key, amount) in [(&private_keys[1], 400_000), (&private_keys[0], amounts[0] - 400_000)] {
+            let view_key = ViewKey::try_from(private_key).unwrap();
+            let owned = block
+                .records()
+                .filter(|(_, record)| record.is_owner(&view_key))
+                .map(|(_, record)| record.decrypt(&view_key).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(owned.len(), 1);
+            assert_eq!(***owned[0].gates(), amount);
+        }
+    }
 }
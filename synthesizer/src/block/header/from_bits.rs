@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBits for Header<N> {
+    /// Reads the block header from little-endian bits, as produced by `Header::to_bits_le`.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Self::from_bits(bits_le, Field::from_bits_le, u64::from_bits_le)
+    }
+
+    /// Reads the block header from big-endian bits, as produced by `Header::to_bits_be`.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Self::from_bits(bits_be, Field::from_bits_be, u64::from_bits_be)
+    }
+}
+
+impl<N: Network> Header<N> {
+    /// Reads the block header from the given bits, using the given field and integer decoders.
+    /// Each integer is decoded as a `u64` from its own width, and narrowed to its type.
+    fn from_bits(
+        bits: &[bool],
+        field_from_bits: impl Fn(&[bool]) -> Result<Field<N>>,
+        integer_from_bits: impl Fn(&[bool]) -> Result<u64>,
+    ) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(
+            bits.len() == Self::size_in_bits(),
+            "Expected {} bits for a block header, found {}",
+            Self::size_in_bits(),
+            bits.len()
+        );
+
+        // Prepare a closure to read the next `size` bits.
+        let mut offset = 0;
+        let mut next = |size: usize| {
+            let slice = &bits[offset..offset + size];
+            offset += size;
+            slice
+        };
+
+        // Read the state roots and the coinbase accumulator point.
+        let field_size = Field::<N>::size_in_bits();
+        let previous_state_root = field_from_bits(next(field_size))?;
+        let transactions_root = field_from_bits(next(field_size))?;
+        let coinbase_accumulator_point = field_from_bits(next(field_size))?;
+
+        // Read the metadata.
+        let network = integer_from_bits(next(u16::BITS as usize))? as u16;
+        let round = integer_from_bits(next(u64::BITS as usize))?;
+        let height = integer_from_bits(next(u32::BITS as usize))? as u32;
+        let coinbase_target = integer_from_bits(next(u64::BITS as usize))?;
+        let proof_target = integer_from_bits(next(u64::BITS as usize))?;
+        let last_coinbase_target = integer_from_bits(next(u64::BITS as usize))?;
+        let last_coinbase_timestamp = integer_from_bits(next(u64::BITS as usize))? as i64;
+        let timestamp = integer_from_bits(next(u64::BITS as usize))? as i64;
+        let version_bits = integer_from_bits(next(u32::BITS as usize))? as u32;
+        let metadata = Metadata::new(
+            network,
+            round,
+            height,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+            version_bits,
+        )?;
+
+        // Construct the block header.
+        Self::from(previous_state_root, transactions_root, coinbase_accumulator_point, metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for expected in [*crate::vm::test_helpers::sample_genesis_block(&mut rng).header()].into_iter() {
+            // Check the little-endian bit representation.
+            let expected_bits = expected.to_bits_le();
+            assert_eq!(expected, Header::from_bits_le(&expected_bits)?);
+            assert!(Header::<CurrentNetwork>::from_bits_le(&expected_bits[1..]).is_err());
+
+            // Check the big-endian bit representation.
+            let expected_bits = expected.to_bits_be();
+            assert_eq!(expected, Header::from_bits_be(&expected_bits)?);
+            assert!(Header::<CurrentNetwork>::from_bits_be(&expected_bits[1..]).is_err());
+        }
+        Ok(())
+    }
+}
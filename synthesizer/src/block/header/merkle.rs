@@ -117,6 +117,7 @@ mod tests {
                     u64::rand(rng),
                     rng.gen_range(0..i64::MAX),
                     rng.gen_range(0..i64::MAX),
+                    0,
                 )?,
             )?;
 
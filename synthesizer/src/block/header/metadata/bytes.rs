@@ -23,7 +23,7 @@ impl<N: Network> FromBytes for Metadata<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid metadata version"));
         }
 
@@ -36,9 +36,14 @@ impl<N: Network> FromBytes for Metadata<N> {
         let last_coinbase_target = u64::read_le(&mut reader)?;
         let last_coinbase_timestamp = i64::read_le(&mut reader)?;
         let timestamp = i64::read_le(&mut reader)?;
+        // Read the version bits, which are only included once they are activated.
+        let version_bits = match version {
+            0 => 0,
+            _ => u32::read_le(&mut reader)?,
+        };
 
         // Construct the metadata.
-        Self::new(
+        let metadata = Self::new(
             network,
             round,
             height,
@@ -47,8 +52,15 @@ impl<N: Network> FromBytes for Metadata<N> {
             last_coinbase_target,
             last_coinbase_timestamp,
             timestamp,
+            version_bits,
         )
-        .map_err(|e| error(e.to_string()))
+        .map_err(|e| error(e.to_string()))?;
+
+        // Ensure the version matches the activation of the version bits.
+        match (version == 1) == metadata.has_version_bits() {
+            true => Ok(metadata),
+            false => Err(error("Invalid metadata version for the block height")),
+        }
     }
 }
 
@@ -57,7 +69,10 @@ impl<N: Network> ToBytes for Metadata<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        match self.has_version_bits() {
+            true => 1u16.write_le(&mut writer)?,
+            false => 0u16.write_le(&mut writer)?,
+        }
 
         // Write to the buffer.
         self.network.write_le(&mut writer)?;
@@ -67,7 +82,12 @@ impl<N: Network> ToBytes for Metadata<N> {
         self.proof_target.write_le(&mut writer)?;
         self.last_coinbase_target.write_le(&mut writer)?;
         self.last_coinbase_timestamp.write_le(&mut writer)?;
-        self.timestamp.write_le(&mut writer)
+        self.timestamp.write_le(&mut writer)?;
+        // Write the version bits, if they are activated.
+        match self.has_version_bits() {
+            true => self.version_bits.write_le(&mut writer),
+            false => Ok(()),
+        }
     }
 }
 
@@ -28,6 +28,7 @@ impl<N: Network> Metadata<N> {
         let last_coinbase_target = N::GENESIS_COINBASE_TARGET;
         let last_coinbase_timestamp = N::GENESIS_TIMESTAMP;
         let timestamp = N::GENESIS_TIMESTAMP;
+        let version_bits = 0;
 
         // Return the genesis metadata.
         Self::new(
@@ -39,6 +40,7 @@ impl<N: Network> Metadata<N> {
             last_coinbase_target,
             last_coinbase_timestamp,
             timestamp,
+            version_bits,
         )
     }
 
@@ -60,6 +62,8 @@ impl<N: Network> Metadata<N> {
             && self.last_coinbase_timestamp == N::GENESIS_TIMESTAMP
             // Ensure the timestamp in the genesis block is `GENESIS_TIMESTAMP`.
             && self.timestamp == N::GENESIS_TIMESTAMP
+            // Ensure the version bits in the genesis block are zero.
+            && self.version_bits == 0u32
     }
 }
 
@@ -109,5 +113,6 @@ mod tests {
         assert_eq!(metadata.last_coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
         assert_eq!(metadata.last_coinbase_timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
         assert_eq!(metadata.timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
+        assert_eq!(metadata.version_bits(), 0);
     }
 }
@@ -43,6 +43,8 @@ pub struct Metadata<N: Network> {
     last_coinbase_timestamp: i64,
     /// The Unix timestamp (UTC) for this block - 8 bytes.
     timestamp: i64,
+    /// The version bits, used by validators to signal readiness for protocol upgrades - 4 bytes.
+    version_bits: u32,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -59,6 +61,7 @@ impl<N: Network> Metadata<N> {
         last_coinbase_target: u64,
         last_coinbase_timestamp: i64,
         timestamp: i64,
+        version_bits: u32,
     ) -> Result<Self> {
         // Construct a new metadata.
         let metadata = Self {
@@ -70,6 +73,7 @@ impl<N: Network> Metadata<N> {
             last_coinbase_target,
             last_coinbase_timestamp,
             timestamp,
+            version_bits,
             _phantom: PhantomData,
         };
         // Ensure the header is valid.
@@ -102,9 +106,16 @@ impl<N: Network> Metadata<N> {
                     && self.last_coinbase_timestamp >= N::GENESIS_TIMESTAMP
                     // Ensure the timestamp in the block is after the genesis timestamp.
                     && self.timestamp > N::GENESIS_TIMESTAMP
+                    // Ensure the version bits are zero, if they are not yet activated.
+                    && (self.has_version_bits() || self.version_bits == 0u32)
             }
         }
     }
+
+    /// Returns `true` if the version bits are activated at the height of this block.
+    pub const fn has_version_bits(&self) -> bool {
+        self.height >= N::VERSION_BITS_ACTIVATION_HEIGHT
+    }
 }
 
 impl<N: Network> Metadata<N> {
@@ -147,4 +158,92 @@ impl<N: Network> Metadata<N> {
     pub const fn timestamp(&self) -> i64 {
         self.timestamp
     }
+
+    /// Returns the version bits for this block.
+    pub const fn version_bits(&self) -> u32 {
+        self.version_bits
+    }
+
+    /// Returns `true` if this block signals readiness for the protocol upgrade assigned to the given bit.
+    pub const fn signals(&self, bit: u8) -> bool {
+        bit < u32::BITS as u8 && (self.version_bits >> bit) & 1 == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples metadata at the given height, with the given version bits.
+    fn sample_metadata(height: u32, version_bits: u32) -> Result<Metadata<CurrentNetwork>> {
+        Metadata::new(
+            CurrentNetwork::ID,
+            u64::from(height),
+            height,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+            version_bits,
+        )
+    }
+
+    #[test]
+    fn test_version_bits_activation() -> Result<()> {
+        let activation_height = CurrentNetwork::VERSION_BITS_ACTIVATION_HEIGHT;
+
+        // Ensure set version bits are rejected below the activation height.
+        assert!(sample_metadata(1, 0).is_ok());
+        assert!(sample_metadata(1, 1).is_err());
+        assert!(sample_metadata(activation_height - 1, 0).is_ok());
+        assert!(sample_metadata(activation_height - 1, 1 << 31).is_err());
+
+        // Ensure set version bits are accepted at and after the activation height.
+        let metadata = sample_metadata(activation_height, 0b101)?;
+        assert!(metadata.signals(0));
+        assert!(!metadata.signals(1));
+        assert!(metadata.signals(2));
+        assert!(!metadata.signals(32));
+        assert!(!metadata.signals(u8::MAX));
+        assert!(sample_metadata(activation_height + 1, u32::MAX)?.signals(31));
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_bits_serialization() -> Result<()> {
+        let activation_height = CurrentNetwork::VERSION_BITS_ACTIVATION_HEIGHT;
+
+        for expected in [sample_metadata(activation_height - 1, 0)?, sample_metadata(activation_height, 0xdead_beef)?] {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Metadata::read_le(&expected_bytes[..])?);
+            // Check the JSON representation.
+            let expected_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected, serde_json::from_str(&expected_string)?);
+        }
+
+        // Ensure the version bits are only encoded once they are activated.
+        let before = sample_metadata(activation_height - 1, 0)?;
+        let after = sample_metadata(activation_height, 0)?;
+        assert_eq!(before.to_bytes_le()?.len() + 4, after.to_bytes_le()?.len());
+        assert_eq!(before.to_bits_le().len() + 32, after.to_bits_le().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_bits_change_hash() -> Result<()> {
+        let activation_height = CurrentNetwork::VERSION_BITS_ACTIVATION_HEIGHT;
+
+        let metadata = sample_metadata(activation_height, 0)?;
+        for bit in 0..u32::BITS {
+            // Flip a single version bit, and ensure the metadata hash changes.
+            let candidate = sample_metadata(activation_height, 1 << bit)?;
+            assert_ne!(metadata.to_hash()?, candidate.to_hash()?);
+        }
+        Ok(())
+    }
 }
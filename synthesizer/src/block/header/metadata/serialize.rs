@@ -23,7 +23,7 @@ impl<N: Network> Serialize for Metadata<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut metadata = serializer.serialize_struct("Metadata", 8)?;
+                let mut metadata = serializer.serialize_struct("Metadata", 9)?;
                 metadata.serialize_field("network", &self.network)?;
                 metadata.serialize_field("round", &self.round)?;
                 metadata.serialize_field("height", &self.height)?;
@@ -32,6 +32,7 @@ impl<N: Network> Serialize for Metadata<N> {
                 metadata.serialize_field("last_coinbase_target", &self.last_coinbase_target)?;
                 metadata.serialize_field("last_coinbase_timestamp", &self.last_coinbase_timestamp)?;
                 metadata.serialize_field("timestamp", &self.timestamp)?;
+                metadata.serialize_field("version_bits", &self.version_bits)?;
                 metadata.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -54,6 +55,7 @@ impl<'de, N: Network> Deserialize<'de> for Metadata<N> {
                     DeserializeExt::take_from_value::<D>(&mut metadata, "last_coinbase_target")?,
                     DeserializeExt::take_from_value::<D>(&mut metadata, "last_coinbase_timestamp")?,
                     DeserializeExt::take_from_value::<D>(&mut metadata, "timestamp")?,
+                    DeserializeExt::take_from_value::<D>(&mut metadata, "version_bits")?,
                 )
                 .map_err(de::Error::custom)?)
             }
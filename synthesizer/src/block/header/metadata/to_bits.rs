@@ -18,6 +18,7 @@ use super::*;
 
 impl<N: Network> ToBits for Metadata<N> {
     /// Returns the little-endian bits of the metadata.
+    /// The version bits are only included once they are activated.
     fn to_bits_le(&self) -> Vec<bool> {
         vec![
             self.network.to_bits_le(),                 // 2 bytes
@@ -28,11 +29,16 @@ impl<N: Network> ToBits for Metadata<N> {
             self.last_coinbase_target.to_bits_le(),    // 8 bytes
             self.last_coinbase_timestamp.to_bits_le(), // 8 bytes
             self.timestamp.to_bits_le(),               // 8 bytes
+            match self.has_version_bits() {
+                true => self.version_bits.to_bits_le(), // 4 bytes
+                false => vec![],
+            },
         ]
         .concat()
     }
 
     /// Returns the big-endian bits of the metadata.
+    /// The version bits are only included once they are activated.
     fn to_bits_be(&self) -> Vec<bool> {
         vec![
             self.network.to_bits_be(),                 // 2 bytes
@@ -43,6 +49,10 @@ impl<N: Network> ToBits for Metadata<N> {
             self.last_coinbase_target.to_bits_be(),    // 8 bytes
             self.last_coinbase_timestamp.to_bits_be(), // 8 bytes
             self.timestamp.to_bits_be(),               // 8 bytes
+            match self.has_version_bits() {
+                true => self.version_bits.to_bits_be(), // 4 bytes
+                false => vec![],
+            },
         ]
         .concat()
     }
@@ -20,9 +20,13 @@ impl<N: Network> Metadata<N> {
     /// Returns the metadata hash.
     pub fn to_hash(&self) -> Result<Field<N>> {
         // Construct the metadata bits (the last leaf in the Merkle tree).
-        let metadata_bits = self.to_bits_le(); // 432 bits
+        let metadata_bits = self.to_bits_le(); // 432 bits, or 464 bits with the version bits
         // Ensure the metadata bits is the correct size.
-        ensure!(metadata_bits.len() == 432, "Incorrect metadata size");
+        let expected_size = match self.has_version_bits() {
+            true => 464,
+            false => 432,
+        };
+        ensure!(metadata_bits.len() == expected_size, "Incorrect metadata size");
         // Hash the metadata bits.
         let metadata_hash = N::hash_bhp512(&metadata_bits)?;
         // Return the metadata hash.
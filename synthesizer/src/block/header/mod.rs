@@ -18,10 +18,13 @@ mod metadata;
 pub use metadata::*;
 
 mod bytes;
+mod from_bits;
 mod genesis;
 mod merkle;
 mod serialize;
 mod string;
+mod timestamp;
+mod to_bits;
 
 use crate::block::Transactions;
 use console::{
@@ -134,4 +137,14 @@ impl<N: Network> Header<N> {
     pub const fn timestamp(&self) -> i64 {
         self.metadata.timestamp()
     }
+
+    /// Returns the version bits for this block.
+    pub const fn version_bits(&self) -> u32 {
+        self.metadata.version_bits()
+    }
+
+    /// Returns `true` if this block signals readiness for the protocol upgrade assigned to the given bit.
+    pub const fn signals(&self, bit: u8) -> bool {
+        self.metadata.signals(bit)
+    }
 }
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Header<N> {
+    /// Checks that the timestamp of this block header is after the timestamp of the parent block header,
+    /// and is at most `max_future_secs` seconds ahead of the given `now` (a Unix timestamp in UTC).
+    pub fn verify_timestamp(&self, parent: &Header<N>, now: i64, max_future_secs: u64) -> Result<()> {
+        // Ensure the timestamp is after the parent timestamp.
+        ensure!(
+            self.timestamp() > parent.timestamp(),
+            "Block {} has a timestamp ({}) that is not after its parent timestamp ({})",
+            self.height(),
+            self.timestamp(),
+            parent.timestamp()
+        );
+        // Compute the latest permitted timestamp.
+        let max_timestamp = now.saturating_add(i64::try_from(max_future_secs).unwrap_or(i64::MAX));
+        // Ensure the timestamp is not too far in the future.
+        ensure!(
+            self.timestamp() <= max_timestamp,
+            "Block {} has a timestamp ({}) more than {max_future_secs} seconds ahead of the current time ({now})",
+            self.height(),
+            self.timestamp()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a block header with the given height and timestamp.
+    fn sample_header(height: u32, timestamp: i64) -> Header<CurrentNetwork> {
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            height as u64,
+            height,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            timestamp,
+            0,
+        )
+        .unwrap();
+        Header::from(Field::one(), Field::one(), Field::zero(), metadata).unwrap()
+    }
+
+    #[test]
+    fn test_verify_timestamp() {
+        let now = CurrentNetwork::GENESIS_TIMESTAMP + 1000;
+        let parent = sample_header(1, now - 100);
+
+        // Ensure a timestamp after the parent and before the current time is valid.
+        assert!(sample_header(2, now - 99).verify_timestamp(&parent, now, 0).is_ok());
+        assert!(sample_header(2, now).verify_timestamp(&parent, now, 0).is_ok());
+        // Ensure a timestamp within the permitted future window is valid.
+        assert!(sample_header(2, now + 10).verify_timestamp(&parent, now, 10).is_ok());
+        assert!(sample_header(2, now + 10).verify_timestamp(&parent, now, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_verify_timestamp_not_monotonic() {
+        let now = CurrentNetwork::GENESIS_TIMESTAMP + 1000;
+        let parent = sample_header(1, now - 100);
+
+        // Ensure a timestamp equal to the parent timestamp is invalid.
+        assert!(sample_header(2, now - 100).verify_timestamp(&parent, now, 10).is_err());
+        // Ensure a timestamp before the parent timestamp is invalid.
+        assert!(sample_header(2, now - 101).verify_timestamp(&parent, now, 10).is_err());
+    }
+
+    #[test]
+    fn test_verify_timestamp_too_far_in_future() {
+        let now = CurrentNetwork::GENESIS_TIMESTAMP + 1000;
+        let parent = sample_header(1, now - 100);
+
+        // Ensure a timestamp beyond the permitted future window is invalid.
+        assert!(sample_header(2, now + 1).verify_timestamp(&parent, now, 0).is_err());
+        assert!(sample_header(2, now + 11).verify_timestamp(&parent, now, 10).is_err());
+    }
+}
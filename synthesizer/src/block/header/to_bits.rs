@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> ToBits for Header<N> {
+    /// Returns the little-endian bits of the block header.
+    /// Unlike the metadata bits, the version bits are always included, so the length is constant.
+    fn to_bits_le(&self) -> Vec<bool> {
+        vec![
+            self.previous_state_root.to_bits_le(),                // Field::size_in_bits()
+            self.transactions_root.to_bits_le(),                  // Field::size_in_bits()
+            self.coinbase_accumulator_point.to_bits_le(),         // Field::size_in_bits()
+            self.metadata.network().to_bits_le(),                 // 2 bytes
+            self.metadata.round().to_bits_le(),                   // 8 bytes
+            self.metadata.height().to_bits_le(),                  // 4 bytes
+            self.metadata.coinbase_target().to_bits_le(),         // 8 bytes
+            self.metadata.proof_target().to_bits_le(),            // 8 bytes
+            self.metadata.last_coinbase_target().to_bits_le(),    // 8 bytes
+            self.metadata.last_coinbase_timestamp().to_bits_le(), // 8 bytes
+            self.metadata.timestamp().to_bits_le(),               // 8 bytes
+            self.metadata.version_bits().to_bits_le(),            // 4 bytes
+        ]
+        .concat()
+    }
+
+    /// Returns the big-endian bits of the block header.
+    /// Unlike the metadata bits, the version bits are always included, so the length is constant.
+    fn to_bits_be(&self) -> Vec<bool> {
+        vec![
+            self.previous_state_root.to_bits_be(),                // Field::size_in_bits()
+            self.transactions_root.to_bits_be(),                  // Field::size_in_bits()
+            self.coinbase_accumulator_point.to_bits_be(),         // Field::size_in_bits()
+            self.metadata.network().to_bits_be(),                 // 2 bytes
+            self.metadata.round().to_bits_be(),                   // 8 bytes
+            self.metadata.height().to_bits_be(),                  // 4 bytes
+            self.metadata.coinbase_target().to_bits_be(),         // 8 bytes
+            self.metadata.proof_target().to_bits_be(),            // 8 bytes
+            self.metadata.last_coinbase_target().to_bits_be(),    // 8 bytes
+            self.metadata.last_coinbase_timestamp().to_bits_be(), // 8 bytes
+            self.metadata.timestamp().to_bits_be(),               // 8 bytes
+            self.metadata.version_bits().to_bits_be(),            // 4 bytes
+        ]
+        .concat()
+    }
+}
+
+impl<N: Network> Header<N> {
+    /// Returns the number of bits in the bit encoding of the block header.
+    pub fn size_in_bits() -> usize {
+        // The state roots and coinbase accumulator point, followed by the metadata.
+        3 * Field::<N>::size_in_bits() + (u16::BITS + 6 * u64::BITS + 2 * u32::BITS) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_bits_length_is_constant() {
+        let mut rng = TestRng::default();
+
+        // Sample the genesis header, and a header at a height with the version bits activated.
+        let genesis = *crate::vm::test_helpers::sample_genesis_block(&mut rng).header();
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            1,
+            CurrentNetwork::VERSION_BITS_ACTIVATION_HEIGHT,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + 1,
+            1,
+        )
+        .unwrap();
+        let header = Header::from(Field::one(), Field::one(), Field::zero(), metadata).unwrap();
+
+        for header in [genesis, header] {
+            assert_eq!(header.to_bits_le().len(), Header::<CurrentNetwork>::size_in_bits());
+            assert_eq!(header.to_bits_be().len(), Header::<CurrentNetwork>::size_in_bits());
+        }
+    }
+}
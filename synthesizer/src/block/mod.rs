@@ -29,6 +29,7 @@ pub use transition::*;
 mod bytes;
 mod genesis;
 mod serialize;
+mod state_path;
 mod string;
 
 use crate::{
@@ -111,6 +112,54 @@ impl<N: Network> Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Ensures the roots in the block header match the roots recomputed from the block body.
+    /// On failure, returns an error naming the mismatched root.
+    pub fn verify_roots(&self) -> Result<()> {
+        let height = self.height();
+
+        // Ensure each transaction ID matches the Merkle root of the transaction.
+        for transaction in self.transactions.iter() {
+            let root = transaction.to_root()?;
+            ensure!(
+                *transaction.id() == root,
+                "Block {height} has a mismatched transaction root: expected '{}', found '{root}'",
+                transaction.id()
+            );
+        }
+
+        // Ensure the transactions root matches the transactions.
+        let transactions_root = self.transactions.to_root()?;
+        ensure!(
+            self.header.transactions_root() == transactions_root,
+            "Block {height} has a mismatched transactions root: expected '{}', found '{transactions_root}'",
+            self.header.transactions_root()
+        );
+
+        // Ensure the coinbase accumulator point matches the coinbase solution.
+        let accumulator_point = match &self.coinbase {
+            Some(coinbase_solution) => coinbase_solution.to_accumulator_point()?,
+            None => Field::<N>::zero(),
+        };
+        ensure!(
+            self.header.coinbase_accumulator_point() == accumulator_point,
+            "Block {height} has a mismatched coinbase accumulator point: expected '{}', found '{accumulator_point}'",
+            self.header.coinbase_accumulator_point()
+        );
+
+        // Ensure the block hash matches the previous block hash and the header root.
+        let header_root = self.header.to_root()?;
+        let block_hash = N::hash_bhp1024(&[self.previous_hash.to_bits_le(), header_root.to_bits_le()].concat())?;
+        ensure!(
+            *self.block_hash == block_hash,
+            "Block {height} has a mismatched header root: the block hash '{}' does not commit to '{header_root}'",
+            self.block_hash
+        );
+
+        Ok(())
+    }
+}
+
 impl<N: Network> Block<N> {
     /// Returns the block hash.
     pub const fn hash(&self) -> N::BlockHash {
@@ -198,6 +247,11 @@ impl<N: Network> Block<N> {
     pub const fn timestamp(&self) -> i64 {
         self.header.timestamp()
     }
+
+    /// Returns the version bits for this block.
+    pub const fn version_bits(&self) -> u32 {
+        self.header.version_bits()
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -435,6 +489,30 @@ mod tests {
 
     use indexmap::IndexMap;
 
+    #[test]
+    fn test_verify_roots() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a valid block passes.
+        let (block, _) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        block.verify_roots().unwrap();
+
+        // Tamper with the transactions, keeping the header and signature.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let tampered = Block::from(
+            block.previous_hash(),
+            *block.header(),
+            Transactions::from(&[transaction]),
+            block.coinbase().cloned(),
+            *block.signature(),
+        )
+        .unwrap();
+
+        // Ensure the tampered block is detected.
+        let error = tampered.verify_roots().unwrap_err();
+        assert!(error.to_string().contains("mismatched transactions root"), "{error}");
+    }
+
     #[test]
     fn test_find_transaction_for_transition_id() {
         let rng = &mut TestRng::default();
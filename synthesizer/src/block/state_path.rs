@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::program::{BlockTree, HeaderLeaf, StatePath};
+
+impl<N: Network> Block<N> {
+    /// Returns the state path for the given `commitment` in this block, against the given `block tree`.
+    ///
+    /// The block tree must contain this block hash at the leaf index corresponding to the block height.
+    /// This allows state paths to be constructed against historical block trees, as well as against
+    /// block trees that are extended with a candidate block that is not yet in storage.
+    pub fn to_state_path(&self, commitment: &Field<N>, block_tree: &BlockTree<N>) -> Result<StatePath<N>> {
+        // Retrieve the transaction that contains the commitment.
+        let transaction = match self.find_transaction_for_commitment(commitment) {
+            Some(transaction) => transaction,
+            None => bail!("Commitment '{commitment}' does not exist in block {}", self.height()),
+        };
+        // Retrieve the transition that contains the commitment.
+        let transition = match self.find_transition_for_commitment(commitment) {
+            Some(transition) => transition,
+            None => bail!("The transition for commitment '{commitment}' is not in block {}", self.height()),
+        };
+
+        // Ensure the block tree contains this block.
+        ensure!(
+            (self.height() as usize) < block_tree.number_of_leaves(),
+            "The block tree does not contain block {} (it has {} leaves)",
+            self.height(),
+            block_tree.number_of_leaves()
+        );
+
+        // Construct the global state root and block path.
+        let global_state_root = *block_tree.root();
+        let block_path = block_tree.prove(self.height() as usize, &self.hash().to_bits_le())?;
+
+        // Construct the transition path and transaction leaf.
+        let transition_leaf = transition.to_leaf(commitment, false)?;
+        let transition_path = transition.to_path(&transition_leaf)?;
+
+        // Construct the transactions path.
+        let transaction_id = transaction.id();
+        let transactions_path = match self.transactions().to_path(transaction_id) {
+            Ok(transactions_path) => transactions_path,
+            Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
+        };
+
+        // Construct the transaction path and transaction leaf.
+        let transaction_leaf = transaction.to_leaf(transition.id())?;
+        let transaction_path = transaction.to_path(&transaction_leaf)?;
+
+        // Construct the block header path.
+        let block_header = self.header();
+        let header_root = block_header.to_root()?;
+        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
+        let header_path = block_header.to_path(&header_leaf)?;
+
+        Ok(StatePath::from(
+            global_state_root.into(),
+            block_path,
+            self.hash(),
+            self.previous_hash(),
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        ))
+    }
+}
@@ -25,6 +25,76 @@ impl<N: Network> FromStr for Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Returns an iterator over the blocks in a JSON array, deserializing one block at a time.
+    /// The reader is consumed incrementally, so memory usage is bounded by the size of a single block.
+    /// For performance, the reader should be buffered (e.g. with `std::io::BufReader`).
+    pub fn stream_from_reader<R: Read>(mut reader: R) -> impl Iterator<Item = Result<Self>> {
+        // Indicates whether the opening bracket has been read.
+        let mut is_started = false;
+        // Indicates whether the closing bracket has been read, or an error has occurred.
+        let mut is_finished = false;
+
+        core::iter::from_fn(move || {
+            if is_finished {
+                return None;
+            }
+            match Self::read_next_from_json_array(&mut reader, &mut is_started) {
+                Ok(Some(block)) => Some(Ok(block)),
+                Ok(None) => {
+                    is_finished = true;
+                    None
+                }
+                Err(error) => {
+                    is_finished = true;
+                    Some(Err(error))
+                }
+            }
+        })
+    }
+
+    /// Reads the next block from a JSON array, returning `None` once the closing bracket is read.
+    fn read_next_from_json_array<R: Read>(reader: &mut R, is_started: &mut bool) -> Result<Option<Self>> {
+        // Read the next non-whitespace byte.
+        let mut byte = next_non_whitespace_byte(reader)?;
+
+        match *is_started {
+            // Ensure the stream starts with an opening bracket.
+            false => {
+                ensure!(byte == b'[', "Expected '[' at the start of the JSON array of blocks");
+                *is_started = true;
+                byte = next_non_whitespace_byte(reader)?;
+                if byte == b']' {
+                    return Ok(None);
+                }
+            }
+            // Ensure each subsequent block is preceded by a comma.
+            true => match byte {
+                b']' => return Ok(None),
+                b',' => byte = next_non_whitespace_byte(reader)?,
+                _ => bail!("Expected ',' or ']' in the JSON array of blocks, found '{}'", byte as char),
+            },
+        }
+
+        // Deserialize the block, starting with the byte that was already read.
+        // Note: Deserializing a JSON object does not read past its closing brace.
+        let prefix = [byte];
+        let mut deserializer = serde_json::Deserializer::from_reader((&prefix[..]).chain(&mut *reader));
+        Ok(Some(Self::deserialize(&mut deserializer)?))
+    }
+}
+
+/// Returns the next non-whitespace byte from the reader.
+fn next_non_whitespace_byte<R: Read>(reader: &mut R) -> Result<u8> {
+    let mut byte = [0u8; 1];
+    loop {
+        reader.read_exact(&mut byte)?;
+        if !byte[0].is_ascii_whitespace() {
+            return Ok(byte[0]);
+        }
+    }
+}
+
 impl<N: Network> Debug for Block<N> {
     /// Prints the block as a JSON-string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -38,3 +108,53 @@ impl<N: Network> Display for Block<N> {
         write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_stream_from_reader() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the blocks.
+        let blocks = vec![
+            Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?,
+            crate::vm::test_helpers::sample_genesis_block(&mut rng),
+            test_helpers::sample_block_and_transaction(&mut rng).0,
+        ];
+
+        // Construct the JSON array of blocks.
+        let entries = blocks.iter().map(|block| block.to_string()).collect::<Vec<_>>();
+        let json = format!("[\n  {}\n]\n", entries.join(",\n  "));
+
+        // Ensure the streamed blocks match an eager parse.
+        let expected: Vec<Block<CurrentNetwork>> = serde_json::from_str(&json)?;
+        let candidate = Block::<CurrentNetwork>::stream_from_reader(json.as_bytes()).collect::<Result<Vec<_>>>()?;
+        assert_eq!(blocks, expected);
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_from_reader_empty() {
+        let mut stream = Block::<CurrentNetwork>::stream_from_reader(" [ ] ".as_bytes());
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_from_reader_malformed() {
+        // Ensure a missing opening bracket is rejected.
+        let mut stream = Block::<CurrentNetwork>::stream_from_reader("{}".as_bytes());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+
+        // Ensure a truncated array is rejected.
+        let mut stream = Block::<CurrentNetwork>::stream_from_reader("[".as_bytes());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+}
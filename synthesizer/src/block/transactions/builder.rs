@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder that assembles the transactions of a block one at a time,
+/// maintaining the transactions Merkle tree and the cumulative size and fee of the transactions.
+#[derive(Clone)]
+pub struct TransactionsBuilder<N: Network> {
+    /// The transactions added to the builder.
+    transactions: IndexMap<N::TransactionID, Transaction<N>>,
+    /// The Merkle tree of the transaction IDs.
+    tree: TransactionsTree<N>,
+    /// The cumulative size of the transactions in bytes.
+    total_size: usize,
+    /// The cumulative fee of the transactions.
+    total_fee: i64,
+}
+
+impl<N: Network> TransactionsBuilder<N> {
+    /// Initializes a new builder with no transactions.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            transactions: IndexMap::new(),
+            tree: N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&[])?,
+            total_size: 0,
+            total_fee: 0,
+        })
+    }
+
+    /// Appends the given transaction, updating the transactions root.
+    pub fn push(&mut self, transaction: Transaction<N>) -> Result<()> {
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already in the builder.
+        ensure!(
+            !self.transactions.contains_key(&transaction_id),
+            "Transaction '{transaction_id}' is already in the block transactions"
+        );
+        // Ensure the number of transactions is within the allowed range.
+        ensure!(
+            self.transactions.len() < Transactions::<N>::MAX_TRANSACTIONS,
+            "Block cannot exceed {} transactions",
+            Transactions::<N>::MAX_TRANSACTIONS
+        );
+
+        // Compute the size and fee of the transaction.
+        let (size, fee) = Self::size_and_fee(&transaction)?;
+        let total_size =
+            self.total_size.checked_add(size).ok_or_else(|| anyhow!("The transactions size overflowed"))?;
+        let total_fee = self.total_fee.checked_add(fee).ok_or_else(|| anyhow!("The transactions fee overflowed"))?;
+
+        // Append the transaction ID to the Merkle tree.
+        self.tree.append(&[transaction_id.to_bits_le()])?;
+        // Update the builder, now that the tree is updated.
+        self.transactions.insert(transaction_id, transaction);
+        self.total_size = total_size;
+        self.total_fee = total_fee;
+        Ok(())
+    }
+
+    /// Removes the last transaction, updating the transactions root.
+    /// Returns `None` if the builder is empty.
+    pub fn pop(&mut self) -> Result<Option<Transaction<N>>> {
+        // Retrieve the last transaction.
+        let transaction = match self.transactions.last() {
+            Some((_, transaction)) => transaction,
+            None => return Ok(None),
+        };
+
+        // Compute the size and fee of the transaction.
+        let (size, fee) = Self::size_and_fee(transaction)?;
+
+        // Remove the transaction ID from the Merkle tree.
+        self.tree.remove_last_n(1)?;
+        // Update the builder, now that the tree is updated.
+        self.total_size -= size;
+        self.total_fee -= fee;
+        Ok(self.transactions.pop().map(|(_, transaction)| transaction))
+    }
+
+    /// Returns the transactions root of the current transactions.
+    pub fn root(&self) -> Field<N> {
+        *self.tree.root()
+    }
+
+    /// Returns the number of transactions in the builder.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if the builder contains no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns the cumulative size of the transactions in bytes.
+    pub const fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    /// Returns the cumulative fee of the transactions.
+    pub const fn total_fee(&self) -> i64 {
+        self.total_fee
+    }
+
+    /// Returns the transactions, in the order they were added.
+    pub fn finalize(self) -> Transactions<N> {
+        Transactions { transactions: self.transactions }
+    }
+
+    /// Returns the size in bytes and the fee of the given transaction.
+    fn size_and_fee(transaction: &Transaction<N>) -> Result<(usize, i64)> {
+        Ok((transaction.to_bytes_le()?.len(), transaction.fee()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{block::Block, vm::test_helpers::CurrentNetwork};
+
+    /// Returns a list of distinct sample transactions.
+    fn sample_transactions(rng: &mut TestRng) -> Vec<Transaction<CurrentNetwork>> {
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let (_, transaction) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        genesis
+            .transactions()
+            .iter()
+            .cloned()
+            .chain([
+                transaction,
+                crate::vm::test_helpers::sample_deployment_transaction(rng),
+                crate::vm::test_helpers::sample_execution_transaction(rng),
+            ])
+            .collect()
+    }
+
+    /// Ensures the builder matches a from-scratch construction of its transactions.
+    fn check_builder(builder: &TransactionsBuilder<CurrentNetwork>, expected: &[Transaction<CurrentNetwork>]) {
+        let transactions = Transactions::from(expected);
+        assert_eq!(builder.len(), expected.len());
+        assert_eq!(builder.root(), transactions.to_root().unwrap());
+        assert_eq!(builder.clone().finalize(), transactions);
+
+        let expected_size = expected.iter().map(|transaction| transaction.to_bytes_le().unwrap().len()).sum();
+        let expected_fee = expected.iter().map(|transaction| transaction.fee().unwrap()).sum();
+        assert_eq!(builder.total_size(), expected_size);
+        assert_eq!(builder.total_fee(), expected_fee);
+    }
+
+    #[test]
+    fn test_push_and_pop() {
+        let rng = &mut TestRng::default();
+
+        let transactions = sample_transactions(rng);
+        assert!(transactions.len() >= 4);
+
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new().unwrap();
+        let mut expected = Vec::new();
+        check_builder(&builder, &expected);
+
+        // Ensure popping an empty builder returns `None`.
+        assert!(builder.pop().unwrap().is_none());
+
+        // Interleave pushes and pops.
+        for (index, transaction) in transactions.iter().enumerate() {
+            builder.push(transaction.clone()).unwrap();
+            expected.push(transaction.clone());
+            check_builder(&builder, &expected);
+
+            // Ensure a duplicate transaction is rejected.
+            assert!(builder.push(transaction.clone()).is_err());
+            check_builder(&builder, &expected);
+
+            if index % 2 == 1 {
+                assert_eq!(builder.pop().unwrap(), expected.pop());
+                check_builder(&builder, &expected);
+
+                builder.push(transaction.clone()).unwrap();
+                expected.push(transaction.clone());
+                check_builder(&builder, &expected);
+            }
+        }
+
+        // Pop all of the transactions.
+        while !expected.is_empty() {
+            assert_eq!(builder.pop().unwrap(), expected.pop());
+            check_builder(&builder, &expected);
+        }
+        assert!(builder.is_empty());
+    }
+
+    #[test]
+    fn test_block_assembly() {
+        let rng = &mut TestRng::default();
+
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Assemble the genesis transactions with the builder.
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new().unwrap();
+        for transaction in genesis.transactions().iter() {
+            builder.push(transaction.clone()).unwrap();
+        }
+        assert_eq!(builder.root(), genesis.transactions_root());
+
+        // Ensure the assembled block matches the genesis block.
+        let block = Block::from(
+            genesis.previous_hash(),
+            *genesis.header(),
+            builder.finalize(),
+            genesis.coinbase().cloned(),
+            *genesis.signature(),
+        )
+        .unwrap();
+        assert_eq!(block, genesis);
+    }
+}
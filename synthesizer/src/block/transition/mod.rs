@@ -22,6 +22,7 @@ pub use output::Output;
 
 mod bytes;
 mod merkle;
+mod outgoing;
 mod serialize;
 mod string;
 
@@ -45,7 +46,7 @@ use console::{
         ValueType,
         TRANSITION_DEPTH,
     },
-    types::{Field, Group},
+    types::{Field, Group, I64},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -316,6 +317,44 @@ impl<N: Network> Transition<N> {
     }
 }
 
+impl<N: Network> Transition<N> {
+    /// Returns the public inputs for verifying the transition proof, with one vector per proof instance.
+    /// The `calls` are the transitions of the external functions called by this transition,
+    /// in the order they are defined in the function.
+    pub fn to_verifier_inputs(&self, calls: &[&Transition<N>]) -> Result<Vec<Vec<N::Field>>> {
+        // Compute the x- and y-coordinate of `tpk`.
+        let (tpk_x, tpk_y) = self.tpk.to_xy_coordinates();
+
+        // [Inputs] Construct the verifier inputs to verify the proof.
+        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, *self.tcm];
+        // [Inputs] Extend the verifier inputs with the input IDs.
+        inputs.extend(self.inputs.iter().flat_map(|input| input.verifier_inputs()));
+        // [Inputs] Extend the verifier inputs with the input and output IDs of each external call.
+        for call in calls {
+            // [Inputs] Extend the verifier inputs with the input IDs of the external call.
+            inputs.extend(call.inputs().iter().flat_map(|input| input.verifier_inputs()));
+            // [Inputs] Extend the verifier inputs with the output IDs of the external call.
+            inputs.extend(call.output_ids().map(|id| **id));
+        }
+        // [Inputs] Extend the verifier inputs with the output IDs.
+        inputs.extend(self.outputs.iter().flat_map(|output| output.verifier_inputs()));
+
+        // [Inputs] Extend the verifier inputs with the checksum of the inputs for finalize, if they exist.
+        if let Some(finalize) = &self.finalize {
+            // Convert the finalize inputs into concatenated bits.
+            let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
+            // Compute the checksum of the finalize inputs.
+            inputs.push(*N::hash_bhp1024(&finalize_bits)?);
+        }
+
+        // [Inputs] Extend the verifier inputs with the fee.
+        inputs.push(*I64::<N>::new(self.fee).to_field()?);
+
+        // Return the verifier inputs, as a single proof instance.
+        Ok(vec![inputs])
+    }
+}
+
 impl<N: Network> Transition<N> {
     /// Returns `true` if the transition contains the given serial number.
     pub fn contains_serial_number(&self, serial_number: &Field<N>) -> bool {
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    account::{Address, ViewKey},
+    program::{Literal, Owner, Plaintext},
+    types::U16,
+};
+
+impl<N: Network> Transition<N> {
+    /// Returns the transition view key `tvk`, if the given view key belongs to the signer of the transition.
+    pub fn to_tvk(&self, view_key: &ViewKey<N>) -> Result<Option<Field<N>>> {
+        // Compute the candidate `tvk` as `view_key * tpk`, which equals `r * caller` for the signer.
+        let tvk = self.tpk.mul_constant_time(view_key).to_x_coordinate();
+        // Ensure the transition commitment is `Hash(tvk)`.
+        match N::hash_psd2(&[tvk])? == self.tcm {
+            true => Ok(Some(tvk)),
+            false => Ok(None),
+        }
+    }
+
+    /// Returns the record view key of each output record that the signer of the transition sent,
+    /// as `(commitment, record view key)` pairs, using the signer's view key.
+    ///
+    /// The owner of a private record is found among the signer's address and the addresses in the inputs
+    /// of the transition. Output records whose owner is not found are skipped.
+    /// If the given view key does not belong to the signer of the transition, no record view keys are returned.
+    pub fn output_record_view_keys(&self, sender_view_key: &ViewKey<N>) -> Result<Vec<(Field<N>, Field<N>)>> {
+        // Compute the `tvk`, skipping transitions that were not signed by the sender.
+        let tvk = match self.to_tvk(sender_view_key)? {
+            Some(tvk) => tvk,
+            None => return Ok(vec![]),
+        };
+
+        // Retrieve the output records.
+        let mut records = self.outputs.iter().flat_map(Output::record).collect::<Vec<_>>();
+        if records.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Collect the candidate owners of the output records.
+        let mut owners = vec![sender_view_key.to_address()];
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), self.program_id.name(), self.program_id.network(), self.function_name)
+                .to_bits_le(),
+        )?;
+        for (index, input) in self.inputs.iter().enumerate() {
+            let plaintext = match input {
+                Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => plaintext.clone(),
+                Input::Private(_, Some(ciphertext)) => {
+                    // Compute the input view key as `Hash(function ID || tvk || index)`.
+                    let input_view_key = N::hash_psd4(&[function_id, tvk, Field::from_u16(index as u16)])?;
+                    ciphertext.decrypt_symmetric(input_view_key)?
+                }
+                _ => continue,
+            };
+            collect_addresses(&plaintext, &mut owners);
+        }
+
+        // The encryption randomizer of each output record is `HashToScalar(tvk || index)`, where the index is
+        // the locator of the output register, which is not included in the transition. As the record nonce is
+        // `randomizer * G`, the locators are searched in ascending order until each nonce is matched.
+        let mut record_view_keys = Vec::with_capacity(records.len());
+        for locator in 0..(N::MAX_INPUTS + N::MAX_INSTRUCTIONS) as u64 {
+            if records.is_empty() {
+                break;
+            }
+            // Compute the encryption randomizer and the record nonce.
+            let randomizer = N::hash_to_scalar_psd2(&[tvk, Field::from_u64(locator)])?;
+            let nonce = N::g_scalar_multiply(&randomizer);
+
+            while let Some(position) = records.iter().position(|(_, record)| record.nonce() == &nonce) {
+                let (commitment, record) = records.swap_remove(position);
+                // Find the owner of the record, and compute the record view key as `(owner * randomizer).x`.
+                let record_view_key = match record.owner() {
+                    Owner::Public(owner) => Some((**owner * randomizer).to_x_coordinate()),
+                    Owner::Private(ciphertext) => owners.iter().find_map(|owner| {
+                        let record_view_key = (**owner * randomizer).to_x_coordinate();
+                        // Decrypt the owner with the 0th randomizer, and compare it against the candidate.
+                        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1);
+                        let expected = owner.to_x_coordinate() + randomizers[0];
+                        (ciphertext.first() == Some(&expected)).then_some(record_view_key)
+                    }),
+                };
+                if let Some(record_view_key) = record_view_key {
+                    record_view_keys.push((*commitment, record_view_key));
+                }
+            }
+        }
+        Ok(record_view_keys)
+    }
+}
+
+/// Appends the addresses in the given plaintext to `addresses`.
+fn collect_addresses<N: Network>(plaintext: &Plaintext<N>, addresses: &mut Vec<Address<N>>) {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(address), ..) => {
+            if !addresses.contains(address) {
+                addresses.push(*address);
+            }
+        }
+        Plaintext::Literal(..) => (),
+        Plaintext::Struct(members, ..) => members.values().for_each(|member| collect_addresses(member, addresses)),
+    }
+}
@@ -34,21 +34,31 @@ pub struct PartialSolution<N: Network> {
 
 impl<N: Network> PartialSolution<N> {
     /// Initializes a new instance of the partial solution.
+    ///
+    /// The inputs are stored as given, and are returned unchanged by the accessors.
+    /// Note: This does not check that the commitment is valid for the address and nonce,
+    /// see `ProverSolution::verify` for the full check.
     pub fn new<C: Into<PuzzleCommitment<N>>>(address: Address<N>, nonce: u64, commitment: C) -> Self {
         Self { address, nonce, commitment: commitment.into() }
     }
 
     /// Returns the address of the prover.
+    ///
+    /// Together with the nonce, the address determines the prover polynomial of the solution.
     pub const fn address(&self) -> Address<N> {
         self.address
     }
 
     /// Returns the nonce for the solution.
+    ///
+    /// Together with the address, the nonce determines the prover polynomial of the solution.
     pub const fn nonce(&self) -> u64 {
         self.nonce
     }
 
     /// Returns the commitment for the solution.
+    ///
+    /// The commitment determines the target of the solution, see [`Self::to_target`].
     pub const fn commitment(&self) -> PuzzleCommitment<N> {
         self.commitment
     }
@@ -67,3 +77,30 @@ impl<N: Network> PartialSolution<N> {
         if hash_to_u64 == 0 { Ok(u64::MAX) } else { Ok(u64::MAX / hash_to_u64) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_accessors() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+            let nonce = u64::rand(&mut rng);
+            let commitment = KZGCommitment(rng.gen());
+
+            // Ensure the accessors return the constructor inputs.
+            let partial_solution = PartialSolution::new(address, nonce, commitment);
+            assert_eq!(partial_solution.address(), address);
+            assert_eq!(partial_solution.nonce(), nonce);
+            assert_eq!(partial_solution.commitment(), PuzzleCommitment::from(commitment));
+        }
+        Ok(())
+    }
+}
@@ -32,6 +32,16 @@ impl<N: Network> PuzzleCommitment<N> {
     pub const fn new(commitment: KZGCommitment<<N as Environment>::PairingCurve>) -> Self {
         Self { commitment }
     }
+
+    /// Initializes a new instance of the puzzle commitment from the given group element.
+    pub const fn from_affine(point: <<N as Environment>::PairingCurve as PairingEngine>::G1Affine) -> Self {
+        Self::new(KZGCommitment(point))
+    }
+
+    /// Returns the group element of the puzzle commitment.
+    pub fn to_affine(&self) -> <<N as Environment>::PairingCurve as PairingEngine>::G1Affine {
+        self.commitment.0
+    }
 }
 
 impl<N: Network> From<KZGCommitment<<N as Environment>::PairingCurve>> for PuzzleCommitment<N> {
@@ -54,3 +64,27 @@ impl<N: Network> Deref for PuzzleCommitment<N> {
         &self.commitment
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_affine_round_trip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..100 {
+            // Sample a new puzzle commitment.
+            let expected = PuzzleCommitment::<CurrentNetwork>::new(KZGCommitment(rng.gen()));
+
+            // Ensure the group element round-trips, without changing the byte representation.
+            let candidate = PuzzleCommitment::<CurrentNetwork>::from_affine(expected.to_affine());
+            assert_eq!(expected, candidate);
+            assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+            assert_eq!(expected.to_affine(), expected.0);
+        }
+    }
+}
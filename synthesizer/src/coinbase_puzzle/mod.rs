@@ -112,25 +112,11 @@ impl<N: Network> CoinbasePuzzle<N> {
         minimum_proof_target: Option<u64>,
     ) -> Result<ProverSolution<N>> {
         // Retrieve the coinbase proving key.
-        let pk = match self {
-            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
-            Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
-        };
-
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
-
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
-        let (commitment, _rand) =
-            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+        let pk = self.coinbase_proving_key()?;
 
-        let partial_solution = PartialSolution::new(address, nonce, commitment);
+        // Commit to the product polynomial.
+        let (partial_solution, polynomial, product_evaluations) =
+            Self::commit_product(pk, epoch_challenge, address, nonce)?;
 
         // Check that the minimum target is met.
         if let Some(minimum_target) = minimum_proof_target {
@@ -141,21 +127,33 @@ impl<N: Network> CoinbasePuzzle<N> {
             );
         }
 
-        let point = hash_commitment(&commitment)?;
-        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+        // Open the product polynomial.
+        Self::open_product(pk, epoch_challenge, partial_solution, &polynomial, &product_evaluations)
+    }
 
-        let proof = KZG10::open_lagrange(
-            &pk.lagrange_basis(),
-            pk.product_domain_elements(),
-            &product_evaluations,
-            point,
-            product_eval_at_point,
-        )?;
-        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
+    /// Returns a prover solution to the coinbase puzzle, if it meets the given minimum proof target.
+    /// Otherwise, returns `None`, without computing the (more expensive) opening proof.
+    pub fn prove_with_target(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: u64,
+    ) -> Result<Option<ProverSolution<N>>> {
+        // Retrieve the coinbase proving key.
+        let pk = self.coinbase_proving_key()?;
 
-        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
+        // Commit to the product polynomial.
+        let (partial_solution, polynomial, product_evaluations) =
+            Self::commit_product(pk, epoch_challenge, address, nonce)?;
 
-        Ok(ProverSolution::new(partial_solution, proof))
+        // Exit early if the minimum target is not met.
+        if partial_solution.to_target()? < minimum_proof_target {
+            return Ok(None);
+        }
+
+        // Open the product polynomial.
+        Self::open_product(pk, epoch_challenge, partial_solution, &polynomial, &product_evaluations).map(Some)
     }
 
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
@@ -359,6 +357,60 @@ impl<N: Network> CoinbasePuzzle<N> {
 }
 
 impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns the partial solution for the given inputs, along with the prover polynomial
+    /// and the evaluations of the product polynomial over the product domain.
+    fn commit_product(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<(
+        PartialSolution<N>,
+        DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        Vec<<N::PairingCurve as PairingEngine>::Fr>,
+    )> {
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+
+        let product_evaluations = {
+            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
+            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
+                polynomial_evaluations,
+                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+            );
+            product_evaluations
+        };
+        let (commitment, _rand) =
+            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+
+        Ok((PartialSolution::new(address, nonce, commitment), polynomial, product_evaluations))
+    }
+
+    /// Returns the prover solution for the given partial solution, by opening the product polynomial.
+    fn open_product(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        partial_solution: PartialSolution<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        product_evaluations: &[<N::PairingCurve as PairingEngine>::Fr],
+    ) -> Result<ProverSolution<N>> {
+        let commitment = *partial_solution.commitment();
+        let point = hash_commitment(&commitment)?;
+        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+
+        let proof = KZG10::open_lagrange(
+            &pk.lagrange_basis(),
+            pk.product_domain_elements(),
+            product_evaluations,
+            point,
+            product_eval_at_point,
+        )?;
+        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
+
+        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
+
+        Ok(ProverSolution::new(partial_solution, proof))
+    }
+
     /// Checks that the degree for the epoch and prover polynomial is within bounds,
     /// and returns the evaluation domain for the product polynomial.
     pub(crate) fn product_domain(degree: u32) -> Result<EvaluationDomain<N::Field>> {
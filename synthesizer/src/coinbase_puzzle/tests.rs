@@ -16,9 +16,9 @@
 
 use super::*;
 use console::{account::*, network::Testnet3};
-use snarkvm_utilities::Uniform;
+use snarkvm_utilities::{FromBytes, Uniform};
 
-use rand::RngCore;
+use rand::{Rng, RngCore};
 
 const ITERATIONS: u64 = 100;
 
@@ -108,3 +108,70 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_prove_with_target() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for log_degree in 5..10 {
+        let degree = (1 << log_degree) - 1;
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), rng.gen(), degree).unwrap();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            let nonce = u64::rand(&mut rng);
+
+            let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+            let proof_target = solution.to_target().unwrap();
+
+            // Assert that a solution is returned if the minimum target is met.
+            for minimum_target in [0, proof_target.saturating_sub(1), proof_target] {
+                let candidate = puzzle.prove_with_target(&epoch_challenge, address, nonce, minimum_target).unwrap();
+                assert_eq!(candidate, Some(solution));
+            }
+
+            // Assert that no solution is returned if the minimum target is too high.
+            if proof_target < u64::MAX {
+                let candidate = puzzle.prove_with_target(&epoch_challenge, address, nonce, proof_target + 1).unwrap();
+                assert!(candidate.is_none());
+            }
+        }
+
+        // Assert that a verifier cannot prove.
+        let verifier = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+        let address = Address::try_from(PrivateKey::<Testnet3>::new(&mut rng).unwrap()).unwrap();
+        assert!(verifier.prove_with_target(&epoch_challenge, address, 0, 0).is_err());
+    }
+}
+
+#[test]
+fn test_epoch_challenge_from_block_hash() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 5) - 1;
+    let epoch_number = rng.next_u32();
+    let epoch_block_hash: <Testnet3 as Network>::BlockHash = rng.gen();
+
+    // Ensure the epoch challenge is deterministic in the epoch number and block hash.
+    let expected = EpochChallenge::<Testnet3>::new(epoch_number, epoch_block_hash, degree).unwrap();
+    let candidate = EpochChallenge::<Testnet3>::new(epoch_number, epoch_block_hash, degree).unwrap();
+    assert_eq!(expected, candidate);
+    assert_eq!(expected.epoch_polynomial_evaluations(), candidate.epoch_polynomial_evaluations());
+
+    // Ensure the epoch challenge round-trips through bytes.
+    let bytes = expected.to_bytes_le().unwrap();
+    let candidate = EpochChallenge::<Testnet3>::from_bytes_le(&bytes).unwrap();
+    assert_eq!(expected, candidate);
+    assert_eq!(expected.epoch_polynomial_evaluations(), candidate.epoch_polynomial_evaluations());
+
+    // Ensure a different block hash yields a different epoch challenge.
+    let other = EpochChallenge::<Testnet3>::new(epoch_number, rng.gen(), degree).unwrap();
+    assert_ne!(expected.epoch_polynomial_evaluations(), other.epoch_polynomial_evaluations());
+}
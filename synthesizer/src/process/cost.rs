@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::program::{CostTable, Function};
+
+impl<N: Network> Process<N> {
+    /// Returns the cost (in microcredits) of the given deployment.
+    /// This is the cost of the deployment bytes, plus the cost of every function in the program.
+    #[inline]
+    pub fn deployment_cost(&self, deployment: &Deployment<N>) -> Result<u64> {
+        // Retrieve the cost table.
+        let table = CostTable::for_network::<N>()?;
+        // Compute the cost of the deployment bytes.
+        let num_bytes = deployment.to_bytes_le()?.len() as u64;
+        let mut cost = num_bytes
+            .checked_mul(table.deployment_per_byte)
+            .ok_or_else(|| anyhow!("Overflow in the cost of the deployment"))?;
+        // Add the cost of each function, including its finalize logic.
+        for function in deployment.program().functions().values() {
+            cost = cost
+                .checked_add(Self::function_cost(function)?)
+                .ok_or_else(|| anyhow!("Overflow in the cost of the deployment"))?;
+        }
+        Ok(cost)
+    }
+
+    /// Returns the cost (in microcredits) of the given execution.
+    /// This is the cost of the execution bytes, plus the cost of the function in every transition.
+    #[inline]
+    pub fn execution_cost(&self, execution: &Execution<N>) -> Result<u64> {
+        // Retrieve the cost table.
+        let table = CostTable::for_network::<N>()?;
+        // Compute the cost of the execution bytes.
+        let num_bytes = execution.to_bytes_le()?.len() as u64;
+        let mut cost = num_bytes
+            .checked_mul(table.execution_per_byte)
+            .ok_or_else(|| anyhow!("Overflow in the cost of the execution"))?;
+        // Add the cost of the function in each transition, including its finalize logic.
+        for transition in execution.transitions() {
+            let function = self.get_stack(transition.program_id())?.get_function(transition.function_name())?;
+            cost = cost
+                .checked_add(Self::function_cost(&function)?)
+                .ok_or_else(|| anyhow!("Overflow in the cost of the execution"))?;
+        }
+        Ok(cost)
+    }
+
+    /// Returns the cost (in microcredits) of the given function, including its finalize logic.
+    fn function_cost(function: &Function<N>) -> Result<u64> {
+        let finalize_cost = match function.finalize_logic() {
+            Some(finalize) => finalize.cost_in_microcredits()?,
+            None => 0,
+        };
+        function
+            .cost_in_microcredits()?
+            .checked_add(finalize_cost)
+            .ok_or_else(|| anyhow!("Overflow in the cost of function '{}'", function.name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        process::test_helpers::{sample_execution, sample_process},
+        Transaction,
+    };
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_execution_cost() {
+        // Initialize the process.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let process = sample_process(&program);
+
+        // Retrieve the execution.
+        let execution = sample_execution();
+        let num_bytes = execution.to_bytes_le().unwrap().len() as u64;
+
+        // Ensure the cost is the execution bytes, plus a single `add`.
+        assert_eq!(process.execution_cost(&execution).unwrap(), num_bytes * CostTable::V1.execution_per_byte + 2);
+    }
+
+    #[test]
+    fn test_deployment_cost() {
+        let rng = &mut TestRng::default();
+
+        // Retrieve the deployment.
+        let deployment = match crate::vm::test_helpers::sample_deployment_transaction(rng) {
+            Transaction::Deploy(_, deployment, _) => deployment,
+            _ => panic!("Expected a deployment transaction"),
+        };
+        let num_bytes = deployment.to_bytes_le().unwrap().len() as u64;
+
+        // Compute the expected cost of the functions.
+        let mut function_cost = 0;
+        for function in deployment.program().functions().values() {
+            function_cost += function.cost_in_microcredits().unwrap();
+            if let Some(finalize) = function.finalize_logic() {
+                function_cost += finalize.cost_in_microcredits().unwrap();
+            }
+        }
+
+        // Ensure the cost is the deployment bytes, plus the cost of the functions.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        assert_eq!(
+            process.deployment_cost(&deployment).unwrap(),
+            num_bytes * CostTable::V1.deployment_per_byte + function_cost
+        );
+    }
+}
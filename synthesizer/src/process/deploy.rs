@@ -39,6 +39,26 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys the given program ID, if it does not exist, and returns the deployment alongside
+    /// the (non-fatal) diagnostics from statically analyzing the program.
+    #[inline]
+    pub fn deploy_with_diagnostics<A: circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<(Deployment<N>, DeploymentDiagnostics<N>)> {
+        // Compute the deployment.
+        let deployment = self.deploy::<A, R>(program, rng)?;
+
+        // Analyze the program, and emit each warning.
+        let diagnostics = DeploymentDiagnostics::new(program);
+        for warning in diagnostics.warnings() {
+            warn!("Program '{}': {warning}", program.id());
+        }
+
+        Ok((deployment, diagnostics))
+    }
+
     /// Verifies the given deployment is ordered.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
@@ -56,7 +76,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
-        let verification = stack.verify_deployment::<A, R>(deployment, rng);
+        let verification = stack.verify_deployment::<A, R>(deployment, &self.certificate_cache, rng);
         lap!(timer, "Verify the deployment");
 
         finish!(timer);
@@ -64,6 +84,27 @@ impl<N: Network> Process<N> {
         verification
     }
 
+    /// Verifies the structure of the given deployment, without synthesizing its circuits.
+    /// This is intended for unconfirmed deployments; `verify_deployment` must still be called before inclusion.
+    #[inline]
+    pub fn verify_deployment_cheap(&self, deployment: &Deployment<N>) -> Result<()> {
+        let timer = timer!("Process::verify_deployment_cheap");
+        // Retrieve the program ID.
+        let program_id = deployment.program().id();
+        // Ensure the program does not already exist in the process.
+        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed, by computing the stack.
+        Stack::new(self, deployment.program())?;
+        lap!(timer, "Compute the stack");
+
+        // Ensure the verifying keys and certificates are well-formed.
+        deployment.verify_cheap()?;
+        lap!(timer, "Verify the deployment structure");
+
+        finish!(timer);
+        Ok(())
+    }
+
     /// Finalizes the deployment.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -131,8 +172,10 @@ impl<N: Network> Process<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::{network::Testnet3, program::Register};
     use snarkvm_utilities::TestRng;
 
+    type CurrentNetwork = Testnet3;
     type CurrentAleo = circuit::network::AleoV0;
 
     #[test]
@@ -156,4 +199,77 @@ mod tests {
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
     }
+
+    #[test]
+    fn test_deploy_with_diagnostics() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program whose finalize has an unused input and an unused register.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program diagnostics.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    finalize r0 r1 r2;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    increment account[r0] by r1;
+",
+        )
+        .unwrap();
+
+        // Initialize a new process.
+        let process = Process::load().unwrap();
+        // Deploy the program, and ensure the diagnostics are produced.
+        let (deployment, diagnostics) = process.deploy_with_diagnostics::<CurrentAleo, _>(&program, rng).unwrap();
+        assert_eq!(deployment.program().id(), program.id());
+
+        let function = Identifier::from_str("compute").unwrap();
+        let expected = DeploymentWarning::UnusedInput { function, register: Register::Locator(2) };
+        assert_eq!(diagnostics.warnings(), &[expected]);
+        assert_eq!(diagnostics.warnings()[0].to_string(), "Input 'r2' in finalize 'compute' is never used");
+
+        // Ensure a program without finalize logic has no diagnostics.
+        let program = crate::vm::test_helpers::sample_program();
+        let (_, diagnostics) = process.deploy_with_diagnostics::<CurrentAleo, _>(&program, rng).unwrap();
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_verify_deployment_cheap() {
+        let rng = &mut TestRng::default();
+
+        // Fetch the program from the deployment.
+        let program = crate::vm::test_helpers::sample_program();
+        // Initialize a new process.
+        let process = Process::load().unwrap();
+        // Deploy the program.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure the deployment passes both the cheap and the full verification.
+        process.verify_deployment_cheap(&deployment).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+
+        // Truncate the circuit commitments of the first verifying key.
+        let mut verifying_keys = deployment.verifying_keys().clone();
+        let (_, (verifying_key, _)) = &mut verifying_keys[0];
+        let mut marlin_verifying_key = (**verifying_key).clone();
+        marlin_verifying_key.circuit_commitments.pop();
+        *verifying_key = VerifyingKey::new(Arc::new(marlin_verifying_key));
+        let deployment = Deployment::new(deployment.edition(), program, verifying_keys).unwrap();
+
+        // Ensure the truncated verifying key is rejected by the cheap verification.
+        let error = process.verify_deployment_cheap(&deployment).unwrap_err();
+        assert!(error.to_string().contains("circuit commitments"));
+    }
 }
@@ -23,7 +23,20 @@ impl<N: Network> Process<N> {
         &self,
         authorization: Authorization<N>,
     ) -> Result<Response<N>> {
-        let timer = timer!("Process::evaluate");
+        self.evaluate_with_mode::<A>(authorization, EvaluationMode::Standard).map(|(response, _)| response)
+    }
+
+    /// Evaluates a program function on the given request, in the given evaluation mode.
+    /// In `EvaluationMode::ConsoleOnly`, this also returns the metrics of each call frame, for cost estimation.
+    ///
+    /// Note: Evaluation never synthesizes or loads a circuit key, including for nested calls.
+    #[inline]
+    pub fn evaluate_with_mode<A: circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        authorization: Authorization<N>,
+        mode: EvaluationMode,
+    ) -> Result<(Response<N>, Vec<CallMetrics<N>>)> {
+        let timer = timer!("Process::evaluate_with_mode");
 
         // Retrieve the main request (without popping it).
         let request = authorization.peek_next()?;
@@ -31,13 +44,23 @@ impl<N: Network> Process<N> {
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Evaluating '{}/{}'...", request.program_id(), request.function_name()).dimmed());
 
+        // Initialize the metrics.
+        let metrics = Arc::new(RwLock::new(Vec::new()));
+        // Initialize the call stack.
+        let call_stack = match mode {
+            EvaluationMode::Standard => CallStack::evaluate(authorization)?,
+            EvaluationMode::ConsoleOnly => CallStack::evaluate_with_metrics(authorization, metrics.clone())?,
+        };
+
         // Evaluate the function.
-        let response =
-            self.get_stack(request.program_id())?.evaluate_function::<A>(CallStack::evaluate(authorization)?);
+        let response = self.get_stack(request.program_id())?.evaluate_function::<A>(call_stack)?;
         lap!(timer, "Evaluate the function");
 
+        // Extract the metrics.
+        let metrics = Arc::try_unwrap(metrics).unwrap().into_inner();
+
         finish!(timer);
 
-        response
+        Ok((response, metrics))
     }
 }
@@ -144,14 +144,6 @@ impl<N: Network> Process<N> {
                 false => ensure!(transition.fee() >= &0, "The fee must be zero or positive"),
             }
 
-            // Compute the x- and y-coordinate of `tpk`.
-            let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
-
-            // [Inputs] Construct the verifier inputs to verify the proof.
-            let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm()];
-            // [Inputs] Extend the verifier inputs with the input IDs.
-            inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-
             // Retrieve the stack.
             let stack = self.get_stack(transition.program_id())?;
             // Retrieve the function from the stack.
@@ -166,64 +158,42 @@ impl<N: Network> Process<N> {
                     }
                 }
             }
-            // If there are function calls, append their inputs and outputs.
-            if num_function_calls > 0 {
-                // This loop takes the last `num_function_call` transitions, and reverses them
-                // to order them in the order they were defined in the function.
-                for transition in queue.transitions().rev().take(num_function_calls).rev() {
-                    // [Inputs] Extend the verifier inputs with the input IDs of the external call.
-                    inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-                    // [Inputs] Extend the verifier inputs with the output IDs of the external call.
-                    inputs.extend(transition.output_ids().map(|id| **id));
-                }
-            }
-
-            // [Inputs] Extend the verifier inputs with the output IDs.
-            inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+            // This takes the last `num_function_call` transitions, and reverses them
+            // to order them in the order they were defined in the function.
+            let calls = queue.transitions().rev().take(num_function_calls).rev().collect::<Vec<_>>();
 
             // Ensure the transition contains finalize inputs, if the function has a finalize scope.
-            if let Some((command, logic)) = function.finalize() {
-                // Ensure the transition contains finalize inputs.
-                match transition.finalize() {
-                    Some(finalize) => {
-                        // Retrieve the number of operands.
-                        let num_operands = command.operands().len();
-                        // Retrieve the number of inputs.
-                        let num_inputs = logic.inputs().len();
-
-                        // Ensure the number of inputs for finalize is within the allowed range.
-                        ensure!(finalize.len() <= N::MAX_INPUTS, "Transition exceeds maximum inputs for finalize");
-                        // Ensure the number of inputs for finalize matches in the finalize command.
-                        ensure!(finalize.len() == num_operands, "The number of inputs for finalize is incorrect");
-                        // Ensure the number of inputs for finalize matches in the finalize logic.
-                        ensure!(finalize.len() == num_inputs, "The number of inputs for finalize is incorrect");
-
-                        // Convert the finalize inputs into concatenated bits.
-                        let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
-                        // Compute the checksum of the finalize inputs.
-                        let checksum = N::hash_bhp1024(&finalize_bits)?;
-
-                        // [Inputs] Extend the verifier inputs with the inputs for finalize.
-                        inputs.push(*checksum);
+            match function.finalize() {
+                Some((command, logic)) => {
+                    // Ensure the transition contains finalize inputs.
+                    match transition.finalize() {
+                        Some(finalize) => {
+                            // Retrieve the number of operands.
+                            let num_operands = command.operands().len();
+                            // Retrieve the number of inputs.
+                            let num_inputs = logic.inputs().len();
+
+                            // Ensure the number of inputs for finalize is within the allowed range.
+                            ensure!(finalize.len() <= N::MAX_INPUTS, "Transition exceeds maximum inputs for finalize");
+                            // Ensure the number of inputs for finalize matches in the finalize command.
+                            ensure!(finalize.len() == num_operands, "The number of inputs for finalize is incorrect");
+                            // Ensure the number of inputs for finalize matches in the finalize logic.
+                            ensure!(finalize.len() == num_inputs, "The number of inputs for finalize is incorrect");
+                        }
+                        None => bail!("The transition is missing inputs for 'finalize'"),
                     }
-                    None => bail!("The transition is missing inputs for 'finalize'"),
+                }
+                // Ensure the transition does not contain finalize inputs, if the function has no finalize scope.
+                None => {
+                    ensure!(transition.finalize().is_none(), "The transition contains unexpected inputs for 'finalize'")
                 }
             }
-
-            // [Inputs] Extend the verifier inputs with the fee.
-            inputs.push(*I64::<N>::new(*transition.fee()).to_field()?);
-            lap!(timer, "Construct the verifier inputs");
-
-            #[cfg(debug_assertions)]
-            println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
+            lap!(timer, "Verify the inputs for finalize");
 
             // Retrieve the verifying key.
             let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
             // Ensure the transition proof is valid.
-            ensure!(
-                verifying_key.verify(function.name(), &inputs, transition.proof()),
-                "Transition is invalid - failed to verify transition proof"
-            );
+            verify_transition_proof(&verifying_key, &transition, &calls)?;
 
             lap!(timer, "Verify transition proof for {}", function.name());
         }
@@ -239,6 +209,28 @@ impl<N: Network> Process<N> {
         &self,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
+    ) -> Result<()> {
+        self.finalize_execution_internal(store, execution, None)
+    }
+
+    /// Finalizes the execution, and records each evaluated `finalize` command in the given trace.
+    /// This method assumes the given execution **is valid**.
+    #[inline]
+    pub fn finalize_execution_with_trace<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        execution: &Execution<N>,
+        trace: &mut FinalizeTrace<N>,
+    ) -> Result<()> {
+        self.finalize_execution_internal(store, execution, Some(trace))
+    }
+
+    /// Finalizes the execution, and records each evaluated `finalize` command in the trace, if one is given.
+    fn finalize_execution_internal<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        execution: &Execution<N>,
+        mut trace: Option<&mut FinalizeTrace<N>>,
     ) -> Result<()> {
         let timer = timer!("Program::finalize_execution");
 
@@ -291,12 +283,44 @@ impl<N: Network> Process<N> {
                     registers.store(stack, register, input.clone())
                 })?;
 
+                // Open a checkpoint for the finalize scope, if it is part of an atomic batch.
+                let checkpoint = match store.is_atomic_in_progress() {
+                    true => Some(store.checkpoint()?),
+                    false => None,
+                };
+
                 // Evaluate the commands.
+                let mut result = Ok(());
                 for command in finalize.commands() {
-                    // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.evaluate_finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
+                    // Open a trace entry for the command, if a trace is given.
+                    let entry = trace.as_ref().map(|_| FinalizeTraceEntry::open(stack, store, &registers, command));
+                    // Evaluate the command.
+                    let outcome = command
+                        .evaluate_finalize(stack, store, &mut registers)
+                        .map_err(|error| anyhow!("'finalize' failed to evaluate command ({command}): {error}"));
+                    // Record the trace entry, if a trace is given.
+                    if let (Some(trace), Some(entry)) = (trace.as_deref_mut(), entry) {
+                        trace.push(entry.close(store, &registers, &outcome));
+                    }
+                    // Stop at the first command that fails.
+                    if outcome.is_err() {
+                        result = outcome;
+                        break;
+                    }
+                }
+                // Store the mapping writes of the finalize scope, with one batched update per mapping.
+                if result.is_ok() {
+                    result = registers.flush(store, stack.program_id());
+                }
+
+                // Commit the writes of the finalize scope, or roll them back if the evaluation failed.
+                match (checkpoint, result) {
+                    (Some(checkpoint), Ok(())) => store.commit(checkpoint)?,
+                    (Some(checkpoint), Err(error)) => {
+                        store.rollback_to(checkpoint)?;
+                        return Err(error);
                     }
+                    (None, result) => result?,
                 }
 
                 // Retrieve the output operands.
@@ -18,6 +18,7 @@ mod stack;
 pub use stack::*;
 
 mod authorize;
+mod cost;
 mod deploy;
 mod evaluate;
 mod execute;
@@ -26,7 +27,7 @@ mod execute_fee;
 use crate::{
     block::{Input, Transition},
     program::{Instruction, Operand, Program},
-    snark::{ProvingKey, UniversalSRS, VerifyingKey},
+    snark::{verify_transition_proof, ProvingKey, UniversalSRS, VerifyingKey},
     store::{ProgramStorage, ProgramStore},
 };
 use console::{
@@ -53,6 +54,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The cache of verified deployment certificates.
+    certificate_cache: CertificateCache<N>,
 }
 
 impl<N: Network> Process<N> {
@@ -62,7 +65,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            certificate_cache: CertificateCache::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -108,7 +115,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            certificate_cache: CertificateCache::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -155,7 +166,11 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load_with_cache(cache: &mut HashMap<String, (ProvingKey<N>, VerifyingKey<N>)>) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            certificate_cache: CertificateCache::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -190,6 +205,12 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the cache of verified deployment certificates.
+    #[inline]
+    pub const fn certificate_cache(&self) -> &CertificateCache<N> {
+        &self.certificate_cache
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -545,6 +566,73 @@ function hello_world:
         process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
     }
 
+    #[test]
+    fn test_process_synthesize_key_with_limit() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r#"program testing.aleo;
+
+function hello_world:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#,
+        )
+        .unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("hello_world").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Synthesize the circuit once, to measure the number of constraints and variables.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let inputs = [Value::from_str("1u32").unwrap(), Value::from_str("2u32").unwrap()];
+        let input_types = stack.get_function(&function_name).unwrap().input_types();
+        let request =
+            Request::sign(&private_key, *program.id(), function_name, inputs.iter(), &input_types, rng).unwrap();
+        let assignments = Assignments::<CurrentNetwork>::default();
+        let call_stack = CallStack::CheckDeployment(vec![request], private_key, assignments.clone());
+        stack.execute_function::<CurrentAleo, _>(call_stack, rng).unwrap();
+        let (num_constraints, num_variables) = {
+            let assignments = assignments.read();
+            let assignment = assignments.last().unwrap();
+            (
+                assignment.num_constraints() + assignment.num_lookup_constraints(),
+                assignment.num_public() + assignment.num_private(),
+            )
+        };
+
+        // Ensure synthesis just over the constraint limit fails.
+        let limit = circuit::ConstraintLimit::new(num_constraints - 1, num_variables);
+        let error = stack.synthesize_key_with_limit::<CurrentAleo, _>(&function_name, limit, rng).unwrap_err();
+        let error = error.downcast_ref::<circuit::ConstraintLimitExceeded>().unwrap();
+        assert!(matches!(error, circuit::ConstraintLimitExceeded::Constraints { .. }));
+        assert!(!error.scope().is_empty());
+        assert!(!stack.contains_proving_key(&function_name));
+
+        // Ensure synthesis just over the variable limit fails.
+        let limit = circuit::ConstraintLimit::new(num_constraints, num_variables - 1);
+        let error = stack.synthesize_key_with_limit::<CurrentAleo, _>(&function_name, limit, rng).unwrap_err();
+        let error = error.downcast_ref::<circuit::ConstraintLimitExceeded>().unwrap();
+        assert!(matches!(error, circuit::ConstraintLimitExceeded::Variables { .. }));
+        assert!(!stack.contains_proving_key(&function_name));
+
+        // Ensure the previous constraint limit is restored.
+        assert_eq!(circuit::ConstraintLimit::unlimited(), <CurrentAleo as circuit::Environment>::constraint_limit());
+
+        // Ensure synthesis just under the limit succeeds.
+        let limit = circuit::ConstraintLimit::new(num_constraints, num_variables);
+        stack.synthesize_key_with_limit::<CurrentAleo, _>(&function_name, limit, rng).unwrap();
+        assert!(stack.contains_proving_key(&function_name));
+    }
+
     #[test]
     fn test_process_multirecords() {
         // Initialize a new program.
@@ -1170,6 +1258,225 @@ function transfer:
         // assert_eq!(215810, CurrentAleo::num_gates());
     }
 
+    #[test]
+    fn test_process_evaluate_console_only() {
+        // Initialize a chain of three programs, where each program calls the next.
+        let program2 = Program::<CurrentNetwork>::from_str(
+            r"
+program three.aleo;
+
+function triple:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    add r1 r0 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+        let program1 = Program::<CurrentNetwork>::from_str(
+            r"
+import three.aleo;
+
+program two.aleo;
+
+function compute:
+    input r0 as u64.private;
+    call three.aleo/triple r0 into r1;
+    add r1 r1 into r2;
+    mul r2 2u64 into r3;
+    output r3 as u64.private;",
+        )
+        .unwrap();
+        let program0 = Program::<CurrentNetwork>::from_str(
+            r"
+import two.aleo;
+
+program one.aleo;
+
+function main:
+    input r0 as u64.private;
+    call two.aleo/compute r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program2).unwrap();
+        process.add_program(&program1).unwrap();
+        process.add_program(&program0).unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorize the function call.
+        let function_name = Identifier::from_str("main").unwrap();
+        let r0 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program0.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 3);
+
+        // Evaluate the function in console-only mode.
+        stack::NUM_KEY_SYNTHESES.with(|num_key_syntheses| num_key_syntheses.set(0));
+        let (response, metrics) =
+            process.evaluate_with_mode::<CurrentAleo>(authorization.replicate(), EvaluationMode::ConsoleOnly).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str("60u64").unwrap()]);
+
+        // Ensure no circuit keys were synthesized.
+        assert_eq!(0, stack::NUM_KEY_SYNTHESES.with(|num_key_syntheses| num_key_syntheses.get()));
+        for (program_id, function_name) in [("one.aleo", "main"), ("two.aleo", "compute"), ("three.aleo", "triple")] {
+            let stack = process.get_stack(ProgramID::from_str(program_id).unwrap()).unwrap();
+            assert!(!stack.contains_proving_key(&Identifier::from_str(function_name).unwrap()));
+        }
+
+        // Ensure the number of instructions is recorded for each call frame, from the innermost call outwards.
+        let candidate = metrics
+            .iter()
+            .map(|metrics| {
+                (metrics.program_id.to_string(), metrics.function_name.to_string(), metrics.num_instructions)
+            })
+            .collect::<Vec<_>>();
+        let expected = [("three.aleo", "triple", 2), ("two.aleo", "compute", 3), ("one.aleo", "main", 1)]
+            .map(|(program_id, function_name, num_instructions)| {
+                (program_id.to_string(), function_name.to_string(), num_instructions)
+            });
+        assert_eq!(candidate, expected);
+
+        // Ensure the standard mode returns the same response, without metrics.
+        let (candidate, metrics) =
+            process.evaluate_with_mode::<CurrentAleo>(authorization.replicate(), EvaluationMode::Standard).unwrap();
+        assert_eq!(response, candidate);
+        assert!(metrics.is_empty());
+        assert_eq!(authorization.len(), 3);
+
+        // Ensure the proving path returns the same outputs, and synthesizes the circuit keys.
+        let (candidate, _execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), candidate.outputs());
+        assert_eq!(3, stack::NUM_KEY_SYNTHESES.with(|num_key_syntheses| num_key_syntheses.get()));
+    }
+
+    #[test]
+    fn test_process_external_struct_compatibility() {
+        // Initialize a new program.
+        let program0 = Program::<CurrentNetwork>::from_str(
+            r"
+program child.aleo;
+
+struct message:
+    sender as address;
+    amount as u64;
+
+record ticket:
+    owner as address.private;
+    gates as u64.private;
+    info as message.private;
+
+function relay:
+    input r0 as message.private;
+    output r0 as message.private;
+
+function create:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as message;
+    output r1 as message.private;
+
+function issue:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as message;
+    cast self.caller 0u64 r1 into r2 as ticket.record;
+    output r2 as ticket.record;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = super::test_helpers::sample_process(&program0);
+
+        // Returns a program that calls `child.aleo/relay`, with the given struct members.
+        let sample_caller = |name: &str, members: &str| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+import child.aleo;
+
+program {name}.aleo;
+
+struct message:
+{members}
+
+function call_relay:
+    input r0 as message.private;
+    call child.aleo/relay r0 into r1;
+    output r1 as message.private;"
+            ))
+            .unwrap()
+        };
+
+        // Ensure an identical struct definition passes.
+        let program1 = sample_caller("identical", "    sender as address;\n    amount as u64;");
+        process.add_program(&program1).unwrap();
+
+        // Ensure a struct definition differing in one member type fails.
+        let program2 = sample_caller("mismatched", "    sender as address;\n    amount as u128;");
+        let error = process.add_program(&program2).unwrap_err().to_string();
+        assert!(error.contains("'mismatched.aleo'") && error.contains("'child.aleo'"), "Unexpected error: {error}");
+        assert!(error.contains("member 'amount'"), "Unexpected error: {error}");
+
+        // Ensure a struct definition with a different member order fails.
+        let program3 = sample_caller("reordered", "    amount as u64;\n    sender as address;");
+        let error = process.add_program(&program3).unwrap_err().to_string();
+        assert!(error.contains("member 'amount'"), "Unexpected error: {error}");
+
+        // Ensure a caller that does not define the struct fails.
+        let program4 = Program::<CurrentNetwork>::from_str(
+            r"
+import child.aleo;
+
+program undefined.aleo;
+
+function call_create:
+    input r0 as u64.private;
+    call child.aleo/create r0 into r1;
+    output r0 as u64.private;",
+        )
+        .unwrap();
+        let error = process.add_program(&program4).unwrap_err().to_string();
+        assert!(error.contains("Struct 'message'") && error.contains("'undefined.aleo'"), "Unexpected error: {error}");
+
+        // Returns a program that calls `child.aleo/issue`, with the given struct definition.
+        let sample_record_caller = |name: &str, struct_definition: &str| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+import child.aleo;
+
+program {name}.aleo;
+{struct_definition}
+function call_issue:
+    input r0 as u64.private;
+    call child.aleo/issue r0 into r1;
+    output r1 as child.aleo/ticket.record;"
+            ))
+            .unwrap()
+        };
+
+        // Ensure a caller that handles the record without defining its struct-typed entry passes.
+        let program5 = sample_record_caller("opaque", "");
+        process.add_program(&program5).unwrap();
+
+        // Ensure a caller with an identical struct-typed record entry passes.
+        let program6 =
+            sample_record_caller("sameentry", "\nstruct message:\n    sender as address;\n    amount as u64;\n");
+        process.add_program(&program6).unwrap();
+
+        // Ensure a caller with a mismatched struct-typed record entry fails.
+        let program7 =
+            sample_record_caller("badentry", "\nstruct message:\n    sender as address;\n    amount as u32;\n");
+        let error = process.add_program(&program7).unwrap_err().to_string();
+        assert!(error.contains("'badentry.aleo'"), "Unexpected error: {error}");
+        assert!(error.contains("member 'amount'"), "Unexpected error: {error}");
+    }
+
     #[test]
     fn test_process_execute_and_finalize_increment() {
         // Initialize a new program.
@@ -1267,6 +1574,98 @@ finalize compute:
         assert_eq!(candidate, Value::from_str("8u64").unwrap());
     }
 
+    #[test]
+    fn test_process_execute_and_finalize_with_trace() {
+        // Initialize a new program, whose finalize scope aborts on its last command.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+    decrement account[r0] by r1;
+    add r1 r1 into r2;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process, and initialize a new program store.
+        let mut process = Process::load().unwrap();
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize and execute the function call.
+        let function_name = Identifier::from_str("compute").unwrap();
+        let inputs = [r0.clone(), r1, r2];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let transaction_id = crate::Transaction::from_execution(execution.clone(), None).unwrap().id();
+
+        // Finalize the execution with a trace, and ensure it aborts.
+        let mut trace = FinalizeTrace::new(transaction_id, FinalizeTrace::<CurrentNetwork>::DEFAULT_MAX_ENTRIES);
+        assert!(process.finalize_execution_with_trace(&store, &execution, &mut trace).is_err());
+
+        // Ensure each command is traced.
+        let key = Plaintext::from(Literal::Address(caller));
+        let entries = trace.entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(trace.num_dropped(), 0);
+        // Check the increment.
+        assert_eq!(entries[0].command(), "increment account[r0] by r1;");
+        assert_eq!(entries[0].operands(), &[Some(r0), Some(Value::from_str("8u64").unwrap())]);
+        assert_eq!(entries[0].mapping().unwrap().to_string(), "account");
+        assert_eq!(entries[0].key(), Some(&key));
+        assert_eq!(entries[0].read(), None);
+        assert_eq!(entries[0].written(), Some(&Value::from_str("8u64").unwrap()));
+        assert_eq!(entries[0].error(), None);
+        // Check the decrement.
+        assert_eq!(entries[1].read(), Some(&Value::from_str("8u64").unwrap()));
+        assert_eq!(entries[1].written(), Some(&Value::from_str("0u64").unwrap()));
+        assert_eq!(entries[1].error(), None);
+        // Check the aborting command.
+        assert_eq!(entries[2].mapping(), None);
+        assert_eq!(entries[2].written(), None);
+        assert!(entries[2].error().unwrap().contains("not supported"));
+
+        // Finalize the execution with a capped trace, and ensure the remaining commands are dropped.
+        let mut trace = FinalizeTrace::new(transaction_id, 1);
+        assert!(process.finalize_execution_with_trace(&store, &execution, &mut trace).is_err());
+        assert_eq!(trace.entries().len(), 1);
+        assert_eq!(trace.entries()[0].read(), Some(&Value::from_str("0u64").unwrap()));
+        assert_eq!(trace.num_dropped(), 2);
+    }
+
     #[test]
     fn test_process_execute_and_finalize_increment_decrement() {
         // Initialize a new program.
@@ -1365,6 +1764,75 @@ finalize compute:
         assert_eq!(candidate, Value::from_str("0u64").unwrap());
     }
 
+    #[test]
+    fn test_process_execute_and_finalize_assert_range() {
+        // Initialize a new program, which checks a range in both the function and the finalize scope.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.public;
+    assert.range r0 10u64 1000u64;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    assert.range r0 10u64 500u64;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Authorizes the function call on the given input.
+        let authorize = |input: &str, rng: &mut TestRng| {
+            let input = Value::<CurrentNetwork>::from_str(input).unwrap();
+            process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [input].iter(), rng)
+        };
+
+        // Ensure a value in both ranges is proven, verified, and finalized.
+        let authorization = authorize("300u64", rng).unwrap();
+        let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution::<true>(&execution).unwrap();
+        process.finalize_execution(&store, &execution).unwrap();
+
+        // Ensure a value in the function range, but not in the finalize range, is verified but not finalized.
+        let authorization = authorize("700u64", rng).unwrap();
+        let (_, execution, _, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        process.verify_execution::<true>(&execution).unwrap();
+        let error = process.finalize_execution(&store, &execution).unwrap_err().to_string();
+        assert!(error.contains("assert.range"), "Unexpected error: {error}");
+
+        // Ensure a value outside the function range cannot be evaluated.
+        let authorization = authorize("5u64", rng).unwrap();
+        assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+        // Ensure a value outside the function range cannot be proven, as its witness does not satisfy the circuit.
+        let error = process.execute::<CurrentAleo, _>(authorization, rng).unwrap_err().to_string();
+        assert!(error.contains("is not satisfied"), "Unexpected error: {error}");
+    }
+
     #[test]
     fn test_process_execute_mint_public() {
         // Initialize a new program.
@@ -1693,4 +2161,118 @@ function compute:
         // assert_eq!(41685, CurrentAleo::num_constraints());
         // assert_eq!(159387, CurrentAleo::num_gates());
     }
+
+    #[test]
+    fn test_process_execute_hash_to_group() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program nullifier.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash_to_group.psd4 r0 into r1;
+    output r1 as group.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str("1field").unwrap();
+
+        // Declare the expected output value.
+        let group = CurrentNetwork::hash_to_group_psd4(&r0.to_fields().unwrap()).unwrap();
+        let r1 = Value::<CurrentNetwork>::Plaintext(Plaintext::from(Literal::Group(group)));
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(r1, candidate[0]);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(r1, candidate[0]);
+
+        process.verify_execution::<false>(&execution).unwrap();
+    }
+
+    #[test]
+    fn test_process_verify_transition_proof() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct the process.
+        let process = test_helpers::sample_process(&program);
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                function_name,
+                ["5u32", "10u32"].into_iter(),
+                rng,
+            )
+            .unwrap();
+        // Execute the request.
+        let (_response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(execution.len(), 1);
+
+        // Retrieve the transition and verifying key.
+        let transition = execution.peek().unwrap();
+        let verifying_key = process.get_verifying_key(program.id(), function_name).unwrap();
+
+        // Verify the transition proof, without the process.
+        assert!(verify_transition_proof(&verifying_key, transition, &[]).is_ok());
+
+        // Ensure the verifier inputs consist of a single proof instance.
+        let mut inputs = transition.to_verifier_inputs(&[]).unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert!(verifying_key.verify_batch("compute", &inputs, transition.proof()));
+
+        // Corrupt the transition commitment in the verifier inputs.
+        inputs[0][3] += *Field::<CurrentNetwork>::one();
+        assert!(!verifying_key.verify_batch("compute", &inputs, transition.proof()));
+    }
 }
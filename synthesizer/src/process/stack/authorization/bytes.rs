@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for Authorization<N> {
+    /// Reads the authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid authorization version"));
+        }
+        // Read the number of requests.
+        let num_requests = u16::read_le(&mut reader)?;
+        // Read the requests.
+        let requests = (0..num_requests).map(|_| Request::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the new `Authorization` instance.
+        Ok(Self::new(&requests))
+    }
+}
+
+impl<N: Network> ToBytes for Authorization<N> {
+    /// Writes the authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Retrieve the requests.
+        let requests = self.to_vec_deque();
+        // Ensure the number of requests is within bounds.
+        let num_requests = match u16::try_from(requests.len()) {
+            Ok(num_requests) => num_requests,
+            Err(_) => return Err(error(format!("Too many requests in the authorization ({})", requests.len()))),
+        };
+
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+        // Write the number of requests.
+        num_requests.write_le(&mut writer)?;
+        // Write the requests.
+        for request in &requests {
+            request.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization.
+        let expected = test_helpers::sample_authorization(rng);
+        assert!(!expected.is_empty());
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = Authorization::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(expected.to_vec_deque(), candidate.to_vec_deque());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        assert!(Authorization::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -14,10 +14,50 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod bytes;
+
+use crate::Process;
 use console::{network::prelude::*, program::Request};
 
 use parking_lot::RwLock;
-use std::{collections::VecDeque, sync::Arc};
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
+
+/// The reasons for which two authorizations can not be merged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuthorizationError {
+    /// The authorization being merged into has no root request, so the merged requests would be misordered.
+    MissingRootRequest,
+    /// The request at the given index is for a different network than the root request.
+    NetworkMismatch(usize),
+    /// The request at the given index is signed by a different caller than the root request.
+    CallerMismatch(usize),
+    /// The request at the given index has a transition commitment that does not match its transition view key.
+    InvalidTransitionCommitment(usize),
+    /// The request at the given index has the same transition view key as an earlier request.
+    DuplicateRequest(usize),
+    /// The request at the given index is not the next call of the root request.
+    OrderMismatch(usize),
+}
+
+impl Display for AuthorizationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingRootRequest => write!(f, "Cannot merge into an authorization without a root request"),
+            Self::NetworkMismatch(index) => write!(f, "Request {index} is for a different network"),
+            Self::CallerMismatch(index) => write!(f, "Request {index} is signed by a different caller"),
+            Self::InvalidTransitionCommitment(index) => {
+                write!(f, "Request {index} has an invalid transition commitment")
+            }
+            Self::DuplicateRequest(index) => write!(f, "Request {index} is a duplicate of an earlier request"),
+            Self::OrderMismatch(index) => write!(f, "Request {index} is not the next call of the root request"),
+        }
+    }
+}
+
+impl std::error::Error for AuthorizationError {}
 
 #[derive(Clone)]
 pub struct Authorization<N: Network> {
@@ -70,4 +110,203 @@ impl<N: Network> Authorization<N> {
     pub fn to_vec_deque(&self) -> VecDeque<Request<N>> {
         self.requests.read().clone()
     }
+
+    /// Returns an iterator over a snapshot of the requests in the authorization.
+    pub fn requests(&self) -> impl Iterator<Item = Request<N>> {
+        self.to_vec_deque().into_iter()
+    }
+
+    /// Returns a new authorization, with the requests of `other` appended to the requests of `self`.
+    ///
+    /// The merged requests must share the network and caller of the root request in `self`,
+    /// each transition commitment must match its transition view key, and no request may appear twice.
+    /// The requests of `other` must continue the call order of the root request, as defined in the given process.
+    /// On failure, the returned error downcasts to an `AuthorizationError`.
+    pub fn merge(self, other: Self, process: &Process<N>) -> Result<Self> {
+        // Concatenate the requests.
+        let requests = self.requests().chain(other.requests()).collect::<Vec<_>>();
+
+        // Retrieve the root request.
+        let root = match requests.first() {
+            Some(root) if !self.is_empty() => root,
+            Some(_) => bail!(AuthorizationError::MissingRootRequest),
+            None => return Ok(Self::new(&[])),
+        };
+
+        // Retrieve the order in which the root request consumes its requests.
+        let call_order = process.get_stack(*root.program_id())?.get_call_order(root.function_name())?;
+
+        let mut transition_view_keys = HashSet::with_capacity(requests.len());
+        for (index, request) in requests.iter().enumerate() {
+            // Ensure the request is in the same transaction scope as the root request.
+            if request.network_id() != root.network_id() {
+                bail!(AuthorizationError::NetworkMismatch(index))
+            }
+            if request.caller() != root.caller() {
+                bail!(AuthorizationError::CallerMismatch(index))
+            }
+            // Ensure the transition commitment is `Hash(tvk)`.
+            if N::hash_psd2(&[*request.tvk()])? != *request.tcm() {
+                bail!(AuthorizationError::InvalidTransitionCommitment(index))
+            }
+            // Ensure the request is not a duplicate.
+            if !transition_view_keys.insert(*request.tvk()) {
+                bail!(AuthorizationError::DuplicateRequest(index))
+            }
+            // Ensure the request is the next call of the root request.
+            match call_order.get(index) {
+                Some((program_id, function_name))
+                    if program_id == request.program_id() && function_name == request.function_name() => {}
+                _ => bail!(AuthorizationError::OrderMismatch(index)),
+            }
+        }
+        Ok(Self::new(&requests))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::{Process, Program};
+    use console::{account::PrivateKey, network::Testnet3, program::Value};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns a process with a `wallet.aleo` program, whose `transfer` function calls `token.aleo`.
+    pub(crate) fn sample_process() -> Process<CurrentNetwork> {
+        // Initialize the token program.
+        let program0 = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+function mint:
+    input r0 as u64.private;
+    add r0 1u64 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        // Initialize the wallet program.
+        let program1 = Program::<CurrentNetwork>::from_str(
+            r"
+import token.aleo;
+
+program wallet.aleo;
+
+function transfer:
+    input r0 as u64.private;
+    call token.aleo/mint r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program0).unwrap();
+        process.add_program(&program1).unwrap();
+        process
+    }
+
+    /// Returns an authorization for `wallet.aleo/transfer`, signed by the given private key.
+    pub(crate) fn sample_authorization_with_key<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut R,
+    ) -> Authorization<CurrentNetwork> {
+        let input = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+        sample_process()
+            .authorize::<CurrentAleo, _>(private_key, "wallet.aleo", "transfer", [input].iter(), rng)
+            .unwrap()
+    }
+
+    /// Returns an authorization for `wallet.aleo/transfer`.
+    pub(crate) fn sample_authorization<R: Rng + CryptoRng>(rng: &mut R) -> Authorization<CurrentNetwork> {
+        sample_authorization_with_key(&PrivateKey::new(rng).unwrap(), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns the `AuthorizationError` of the given merge.
+    fn merge_error(a: Authorization<CurrentNetwork>, b: Authorization<CurrentNetwork>) -> AuthorizationError {
+        a.merge(b, &test_helpers::sample_process()).unwrap_err().downcast::<AuthorizationError>().unwrap()
+    }
+
+    #[test]
+    fn test_introspection() {
+        let rng = &mut TestRng::default();
+
+        let authorization = test_helpers::sample_authorization(rng);
+        assert_eq!(authorization.len(), 2);
+        let requests = authorization.requests().collect::<Vec<_>>();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].program_id().to_string(), "wallet.aleo");
+        assert_eq!(requests[1].program_id().to_string(), "token.aleo");
+
+        // Ensure peeking and iterating do not consume the requests.
+        assert_eq!(authorization.peek_next().unwrap(), requests[0]);
+        assert_eq!(authorization.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_and_execute() {
+        let rng = &mut TestRng::default();
+
+        // Authorize the root call in one process.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization = test_helpers::sample_authorization_with_key(&private_key, rng);
+        let requests = authorization.to_vec_deque();
+
+        // Split the authorization, and transport the second half as bytes to another process.
+        let root = Authorization::new(&[requests[0].clone()]);
+        let bytes = Authorization::new(&[requests[1].clone()]).to_bytes_le().unwrap();
+        let callee = Authorization::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+
+        // Merge the authorizations, and execute the result.
+        let process = test_helpers::sample_process();
+        let merged = root.merge(callee, &process).unwrap();
+        assert_eq!(merged.to_vec_deque(), requests);
+        let (response, execution, _, _) = process.execute::<CurrentAleo, _>(merged, rng).unwrap();
+        assert_eq!(response.outputs()[0].to_string(), "6u64");
+        process.verify_execution::<false>(&execution).unwrap();
+    }
+
+    #[test]
+    fn test_merge_fails() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization = test_helpers::sample_authorization_with_key(&private_key, rng);
+        let requests = authorization.to_vec_deque();
+        let root = Authorization::new(&[requests[0].clone()]);
+
+        // Ensure a merge into an empty authorization is rejected.
+        let error = merge_error(Authorization::new(&[]), root.replicate());
+        assert_eq!(error, AuthorizationError::MissingRootRequest);
+        // Ensure a duplicate request is rejected.
+        let error = merge_error(root.replicate(), authorization.replicate());
+        assert_eq!(error, AuthorizationError::DuplicateRequest(1));
+        // Ensure a request from another caller is rejected.
+        let other = test_helpers::sample_authorization(rng);
+        let error = merge_error(root.replicate(), other);
+        assert_eq!(error, AuthorizationError::CallerMismatch(1));
+
+        // Ensure requests that do not continue the call order of the root request are rejected.
+        let fresh = test_helpers::sample_authorization_with_key(&private_key, rng).to_vec_deque();
+        let error = merge_error(root.replicate(), Authorization::new(&[fresh[0].clone()]));
+        assert_eq!(error, AuthorizationError::OrderMismatch(1));
+        let error = merge_error(Authorization::new(&[requests[1].clone()]), root.replicate());
+        assert_eq!(error, AuthorizationError::OrderMismatch(1));
+        let error = merge_error(authorization.replicate(), Authorization::new(&[fresh[1].clone()]));
+        assert_eq!(error, AuthorizationError::OrderMismatch(2));
+
+        // Ensure the failed merges did not modify the authorizations.
+        assert_eq!(root.len(), 1);
+        assert_eq!(authorization.len(), 2);
+    }
 }
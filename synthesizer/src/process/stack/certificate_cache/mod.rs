@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Certificate, Stack, VerifyingKey};
+use console::{network::prelude::*, program::Identifier, types::Field};
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// The default number of certificates held by a certificate cache.
+pub const DEFAULT_CERTIFICATE_CACHE_CAPACITY: usize = 1024;
+
+/// A cache of the certificates that have been verified for a `(program hash, function name)`.
+/// The program hash covers the program and the programs it imports, which determine the circuit of each function.
+/// An entry is only valid for the verifying key and certificate it was verified with.
+///
+/// The cache holds at most `capacity` entries, and evicts the least recently used entry when it is full.
+#[derive(Clone)]
+pub struct CertificateCache<N: Network> {
+    /// The mapping of `(program hash, function name)` to `(verifying key hash, certificate)`,
+    /// from least to most recently used.
+    #[allow(clippy::type_complexity)]
+    entries: Arc<RwLock<IndexMap<(Field<N>, Identifier<N>), (Field<N>, Certificate<N>)>>>,
+    /// The maximum number of entries in the cache.
+    capacity: usize,
+    /// The number of lookups that were found in the cache.
+    num_hits: Arc<AtomicUsize>,
+    /// The number of lookups that were not found in the cache.
+    num_misses: Arc<AtomicUsize>,
+}
+
+impl<N: Network> Default for CertificateCache<N> {
+    /// Initializes an empty certificate cache with the default capacity.
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            capacity: DEFAULT_CERTIFICATE_CACHE_CAPACITY,
+            num_hits: Default::default(),
+            num_misses: Default::default(),
+        }
+    }
+}
+
+impl<N: Network> CertificateCache<N> {
+    /// Initializes an empty certificate cache with the given capacity.
+    pub fn new(capacity: usize) -> Result<Self> {
+        // Ensure the capacity is nonzero.
+        ensure!(capacity > 0, "The certificate cache capacity must be greater than zero");
+        Ok(Self { capacity, ..Default::default() })
+    }
+
+    /// Returns the hash of the program in the given stack and the programs it imports.
+    pub fn hash_program(stack: &Stack<N>) -> Result<Field<N>> {
+        let mut bytes = stack.program().to_bytes_le()?;
+        for program_id in stack.program().imports().keys() {
+            bytes.extend(stack.get_external_program(program_id)?.to_bytes_le()?);
+        }
+        N::hash_bhp1024(&bytes.to_bits_le())
+    }
+
+    /// Returns `true` if the certificate was previously verified for the given function and verifying key,
+    /// in the program with the given hash.
+    pub fn is_verified(
+        &self,
+        program_hash: &Field<N>,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+    ) -> Result<bool> {
+        // Compute the verifying key hash.
+        let verifying_key_hash = Self::hash_verifying_key(verifying_key)?;
+        // Check the cache.
+        let mut entries = self.entries.write();
+        let is_verified = match entries.get_full(&(*program_hash, *function_name)) {
+            Some((index, _, (candidate_hash, candidate_certificate))) => {
+                (*candidate_hash == verifying_key_hash && candidate_certificate == certificate).then_some(index)
+            }
+            None => None,
+        };
+        // On a hit, mark the entry as the most recently used.
+        if let Some(index) = is_verified {
+            let last = entries.len() - 1;
+            entries.move_index(index, last);
+        }
+        let is_verified = is_verified.is_some();
+        // Update the counters.
+        match is_verified {
+            true => self.num_hits.fetch_add(1, Ordering::SeqCst),
+            false => self.num_misses.fetch_add(1, Ordering::SeqCst),
+        };
+        Ok(is_verified)
+    }
+
+    /// Inserts the verified certificate for the given function and verifying key, in the program with the given hash.
+    /// This replaces any previous entry for the `(program hash, function name)`,
+    /// and evicts the least recently used entry if the cache is full.
+    pub fn insert(
+        &self,
+        program_hash: &Field<N>,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+    ) -> Result<()> {
+        // Compute the verifying key hash.
+        let verifying_key_hash = Self::hash_verifying_key(verifying_key)?;
+        // Insert the entry as the most recently used.
+        let mut entries = self.entries.write();
+        entries.shift_remove(&(*program_hash, *function_name));
+        entries.insert((*program_hash, *function_name), (verifying_key_hash, certificate.clone()));
+        // Evict the least recently used entries.
+        while entries.len() > self.capacity {
+            entries.shift_remove_index(0);
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum number of entries in the cache.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.entries.write().clear();
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    /// Returns the number of lookups that were found in the cache.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of lookups that were not found in the cache.
+    /// Each miss requires the circuit to be synthesized.
+    pub fn num_misses(&self) -> usize {
+        self.num_misses.load(Ordering::SeqCst)
+    }
+
+    /// Returns the hash of the given verifying key.
+    fn hash_verifying_key(verifying_key: &VerifyingKey<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&verifying_key.to_bytes_le()?.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Deployment, Process, Program};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_verify_deployment_is_cached() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment = crate::process::stack::deployment::test_helpers::sample_deployment();
+
+        // Verify the deployment.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        let num_misses = process.certificate_cache().num_misses();
+        assert_eq!(num_misses, deployment.verifying_keys().len());
+        assert_eq!(process.certificate_cache().num_hits(), 0);
+
+        // Verify the deployment again, and ensure no circuit is synthesized.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        assert_eq!(process.certificate_cache().num_misses(), num_misses);
+        assert_eq!(process.certificate_cache().num_hits(), deployment.verifying_keys().len());
+    }
+
+    #[test]
+    fn test_eviction() {
+        // Sample a verifying key and certificate.
+        let deployment = crate::process::stack::deployment::test_helpers::sample_deployment();
+        let (function_name, (verifying_key, certificate)) = deployment.verifying_keys()[0].clone();
+        let is_verified = |cache: &CertificateCache<CurrentNetwork>, program_hash: u64| {
+            cache.is_verified(&Field::from_u64(program_hash), &function_name, &verifying_key, &certificate).unwrap()
+        };
+        let insert = |cache: &CertificateCache<CurrentNetwork>, program_hash: u64| {
+            cache.insert(&Field::from_u64(program_hash), &function_name, &verifying_key, &certificate).unwrap()
+        };
+
+        // Ensure the capacity must be nonzero.
+        assert!(CertificateCache::<CurrentNetwork>::new(0).is_err());
+        assert_eq!(CertificateCache::<CurrentNetwork>::default().capacity(), DEFAULT_CERTIFICATE_CACHE_CAPACITY);
+
+        // Fill the cache.
+        let cache = CertificateCache::<CurrentNetwork>::new(2).unwrap();
+        insert(&cache, 1);
+        insert(&cache, 2);
+        assert_eq!(cache.len(), 2);
+
+        // Ensure a hit marks the entry as the most recently used, so the other entry is evicted.
+        assert!(is_verified(&cache, 1));
+        insert(&cache, 3);
+        assert_eq!(cache.len(), 2);
+        assert!(is_verified(&cache, 1));
+        assert!(is_verified(&cache, 3));
+        assert!(!is_verified(&cache, 2));
+
+        // Ensure reinserting an entry does not grow the cache, and marks it as the most recently used.
+        insert(&cache, 1);
+        insert(&cache, 4);
+        assert_eq!(cache.len(), 2);
+        assert!(is_verified(&cache, 1));
+        assert!(!is_verified(&cache, 3));
+
+        // Ensure many distinct programs never grow the cache beyond its capacity.
+        (5..100).for_each(|program_hash| insert(&cache, program_hash));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_forged_certificate_is_rejected() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment = crate::process::stack::deployment::test_helpers::sample_deployment();
+        // Sample a deployment with the same program ID, and a different circuit.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    mul r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let other = Process::<CurrentNetwork>::load().unwrap().deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Verify the deployment, to populate the cache.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        assert_eq!(process.certificate_cache().len(), 1);
+
+        let (function_name, (verifying_key, certificate)) = deployment.verifying_keys()[0].clone();
+        let (_, (other_verifying_key, other_certificate)) = other.verifying_keys()[0].clone();
+        assert_ne!(verifying_key, other_verifying_key);
+
+        // Ensure a forged certificate for the cached verifying key is rejected.
+        let forged = vec![(function_name, (verifying_key, other_certificate))];
+        let forged = Deployment::new(deployment.edition(), deployment.program().clone(), forged).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&forged, rng).is_err());
+
+        // Ensure the cached certificate for a different verifying key is rejected.
+        let forged = vec![(function_name, (other_verifying_key, certificate))];
+        let forged = Deployment::new(deployment.edition(), deployment.program().clone(), forged).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&forged, rng).is_err());
+        assert_eq!(process.certificate_cache().num_hits(), 0);
+    }
+
+    #[test]
+    fn test_different_program_with_same_name_is_not_cached() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment = crate::process::stack::deployment::test_helpers::sample_deployment();
+        // Sample a deployment with the same program ID and function name, and a different program body.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    mul r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let other = Process::<CurrentNetwork>::load().unwrap().deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure the program hashes differ.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let program_hash = CertificateCache::hash_program(&Stack::new(&process, deployment.program()).unwrap());
+        let other_hash = CertificateCache::hash_program(&Stack::new(&process, other.program()).unwrap());
+        assert_ne!(program_hash.unwrap(), other_hash.unwrap());
+
+        // Verify both deployments, and ensure the second one is not served from the cache.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&other, rng).unwrap();
+        assert_eq!(process.certificate_cache().len(), 2);
+        assert_eq!(process.certificate_cache().num_misses(), 2);
+        assert_eq!(process.certificate_cache().num_hits(), 0);
+    }
+}
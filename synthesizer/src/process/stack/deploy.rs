@@ -31,9 +31,12 @@ impl<N: Network> Stack<N> {
         // Initialize a vector for the verifying keys and certificates.
         let mut verifying_keys = Vec::with_capacity(self.program.functions().len());
 
+        // Initialize the constraint limit for each function.
+        let limit = circuit::ConstraintLimit::new(N::MAX_DEPLOYMENT_CONSTRAINTS, N::MAX_DEPLOYMENT_VARIABLES);
+
         for function_name in self.program.functions().keys() {
-            // Synthesize the proving and verifying key.
-            self.synthesize_key::<A, R>(function_name, rng)?;
+            // Synthesize the proving and verifying key, halting if the function exceeds the constraint limit.
+            self.synthesize_key_with_limit::<A, R>(function_name, limit, rng)?;
             lap!(timer, "Synthesize key for {function_name}");
 
             // Retrieve the proving key.
@@ -61,6 +64,7 @@ impl<N: Network> Stack<N> {
     pub fn verify_deployment<A: circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
+        certificate_cache: &CertificateCache<N>,
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Stack::verify_deployment");
@@ -75,11 +79,19 @@ impl<N: Network> Stack<N> {
         // Check Verifying Keys //
 
         let program_id = self.program.id();
+        // Compute the hash of the program and its imports, which determine the circuit of each function.
+        let program_hash = CertificateCache::hash_program(self)?;
 
         // Iterate through the program functions.
         for (function, (_, (verifying_key, certificate))) in
             deployment.program().functions().values().zip_eq(deployment.verifying_keys())
         {
+            // Skip the synthesis, if the certificate was previously verified.
+            if certificate_cache.is_verified(&program_hash, function.name(), verifying_key, certificate)? {
+                lap!(timer, "Found the certificate for {} in the cache", function.name());
+                continue;
+            }
+
             // Initialize a burner private key.
             let burner_private_key = PrivateKey::new(rng)?;
             // Compute the burner address.
@@ -129,6 +141,8 @@ impl<N: Network> Stack<N> {
                         bail!("The certificate for function '{}' is invalid in '{program_id}'", function.name())
                     }
                     lap!(timer, "Ensure the certificate is valid");
+                    // Cache the verified certificate.
+                    certificate_cache.insert(&program_hash, function.name(), verifying_key, certificate)?;
                 }
             };
         }
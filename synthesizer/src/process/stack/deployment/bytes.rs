@@ -22,14 +22,16 @@ impl<N: Network> FromBytes for Deployment<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version != 0 && version != 1 {
             return Err(error("Invalid deployment version"));
         }
+        // Determine if the components are length-prefixed.
+        let is_length_prefixed = version == 1;
 
         // Read the edition.
         let edition = u16::read_le(&mut reader)?;
         // Read the program.
-        let program = Program::read_le(&mut reader)?;
+        let program = read_component::<Program<N>, _>(&mut reader, is_length_prefixed)?;
 
         // Read the number of entries in the bundle.
         let num_entries = u16::read_le(&mut reader)?;
@@ -39,9 +41,9 @@ impl<N: Network> FromBytes for Deployment<N> {
             // Read the identifier.
             let identifier = Identifier::<N>::read_le(&mut reader)?;
             // Read the verifying key.
-            let verifying_key = VerifyingKey::<N>::read_le(&mut reader)?;
+            let verifying_key = read_component::<VerifyingKey<N>, _>(&mut reader, is_length_prefixed)?;
             // Read the certificate.
-            let certificate = Certificate::<N>::read_le(&mut reader)?;
+            let certificate = read_component::<Certificate<N>, _>(&mut reader, is_length_prefixed)?;
             // Add the entry.
             verifying_keys.push((identifier, (verifying_key, certificate)));
         }
@@ -55,11 +57,11 @@ impl<N: Network> ToBytes for Deployment<N> {
     /// Writes the deployment to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        1u16.write_le(&mut writer)?;
         // Write the edition.
         self.edition.write_le(&mut writer)?;
         // Write the program.
-        self.program.write_le(&mut writer)?;
+        write_component(&self.program, &mut writer)?;
         // Write the number of entries in the bundle.
         (self.verifying_keys.len() as u16).write_le(&mut writer)?;
         // Write each entry.
@@ -67,14 +69,43 @@ impl<N: Network> ToBytes for Deployment<N> {
             // Write the function name.
             function_name.write_le(&mut writer)?;
             // Write the verifying key.
-            verifying_key.write_le(&mut writer)?;
+            write_component(verifying_key, &mut writer)?;
             // Write the certificate.
-            certificate.write_le(&mut writer)?;
+            write_component(certificate, &mut writer)?;
         }
         Ok(())
     }
 }
 
+/// Reads a component of the deployment, which is prefixed with its length in bytes if `is_length_prefixed`.
+fn read_component<T: FromBytes, R: Read>(mut reader: R, is_length_prefixed: bool) -> IoResult<T> {
+    match is_length_prefixed {
+        true => {
+            // Read the number of bytes in the component.
+            let num_bytes = u32::read_le(&mut reader)?;
+            // Read the component, without reading past its declared length.
+            let mut component_reader = reader.take(u64::from(num_bytes));
+            let component = T::read_le(&mut component_reader)?;
+            // Ensure the component consumed exactly its declared length.
+            match component_reader.limit() == 0 {
+                true => Ok(component),
+                false => Err(error("Deployment component does not match its declared length")),
+            }
+        }
+        false => T::read_le(&mut reader),
+    }
+}
+
+/// Writes a component of the deployment, prefixed with its length in bytes.
+fn write_component<T: ToBytes, W: Write>(component: &T, mut writer: W) -> IoResult<()> {
+    // Serialize the component.
+    let bytes = component.to_bytes_le().map_err(|err| error(err.to_string()))?;
+    // Write the number of bytes in the component.
+    u32::try_from(bytes.len()).map_err(|err| error(err.to_string()))?.write_le(&mut writer)?;
+    // Write the component.
+    writer.write_all(&bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,4 +124,34 @@ mod tests {
         assert!(Deployment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_are_length_prefixed() -> Result<()> {
+        // Construct a new deployment.
+        let expected = test_helpers::sample_deployment();
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the program is length-prefixed, after the version and the edition.
+        let program_bytes = expected.program().to_bytes_le()?;
+        assert_eq!(u32::from_bytes_le(&expected_bytes[4..8])? as usize, program_bytes.len());
+        assert_eq!(&expected_bytes[8..8 + program_bytes.len()], &program_bytes[..]);
+
+        // Ensure a truncated component is rejected.
+        let mut truncated = expected_bytes.clone();
+        truncated[4..8].copy_from_slice(&(program_bytes.len() as u32 - 1).to_le_bytes());
+        assert!(Deployment::<CurrentNetwork>::read_le(&truncated[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_are_smaller_than_json() -> Result<()> {
+        // Construct a new deployment.
+        let expected = test_helpers::sample_deployment();
+
+        // Compare the sizes of the binary and JSON representations.
+        let num_bytes = expected.to_bytes_le()?.len();
+        let num_json_bytes = expected.to_string().len();
+        assert!(num_bytes * 3 < num_json_bytes * 2, "Binary size is {num_bytes}, JSON size is {num_json_bytes}");
+        Ok(())
+    }
 }
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Program;
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register},
+};
+
+/// A non-fatal warning about a program, produced during deployment.
+#[derive(Clone, PartialEq, Eq)]
+pub enum DeploymentWarning<N: Network> {
+    /// The command at the given index in the finalize of `function` is unreachable.
+    UnreachableCommand { function: Identifier<N>, index: usize },
+    /// The register in the finalize of `function` is written to, but never read from.
+    UnusedRegister { function: Identifier<N>, register: Register<N> },
+    /// The input register in the finalize of `function` is never read from.
+    UnusedInput { function: Identifier<N>, register: Register<N> },
+    /// The finalize of `function` references a mapping that is not declared in the program.
+    UndeclaredMapping { function: Identifier<N>, mapping: Identifier<N> },
+}
+
+impl<N: Network> Debug for DeploymentWarning<N> {
+    /// Prints the deployment warning as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for DeploymentWarning<N> {
+    /// Prints the deployment warning as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnreachableCommand { function, index } => {
+                write!(f, "Command {index} in finalize '{function}' is unreachable")
+            }
+            Self::UnusedRegister { function, register } => {
+                write!(f, "Register '{register}' in finalize '{function}' is written to but never read")
+            }
+            Self::UnusedInput { function, register } => {
+                write!(f, "Input '{register}' in finalize '{function}' is never used")
+            }
+            Self::UndeclaredMapping { function, mapping } => {
+                write!(f, "Mapping '{mapping}' in finalize '{function}' is not declared")
+            }
+        }
+    }
+}
+
+/// The non-fatal diagnostics for a program, returned alongside its deployment.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DeploymentDiagnostics<N: Network> {
+    /// The warnings, in order of the program functions.
+    warnings: Vec<DeploymentWarning<N>>,
+}
+
+impl<N: Network> DeploymentDiagnostics<N> {
+    /// Initializes the diagnostics by analyzing the finalize logic of each function in the given program.
+    pub fn new(program: &Program<N>) -> Self {
+        let mut warnings = Vec::new();
+        for (function_name, function) in program.functions() {
+            // Skip functions without finalize logic.
+            let finalize = match function.finalize_logic() {
+                Some(finalize) => finalize,
+                None => continue,
+            };
+            let function = *function_name;
+
+            // Analyze the finalize logic.
+            let analysis = finalize.analyze();
+            for index in analysis.unreachable_commands() {
+                warnings.push(DeploymentWarning::UnreachableCommand { function, index });
+            }
+            for register in analysis.unused_registers() {
+                warnings.push(DeploymentWarning::UnusedRegister { function, register: register.clone() });
+            }
+            for register in analysis.unused_inputs() {
+                warnings.push(DeploymentWarning::UnusedInput { function, register: register.clone() });
+            }
+            for mapping in analysis.undeclared_mappings(program) {
+                warnings.push(DeploymentWarning::UndeclaredMapping { function, mapping });
+            }
+        }
+        Self { warnings }
+    }
+
+    /// Returns the warnings.
+    pub fn warnings(&self) -> &[DeploymentWarning<N>] {
+        &self.warnings
+    }
+
+    /// Returns `true` if there are no warnings.
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
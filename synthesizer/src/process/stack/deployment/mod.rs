@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod diagnostics;
+pub use diagnostics::*;
+
 mod bytes;
 mod serialize;
 mod string;
@@ -21,8 +24,10 @@ mod string;
 use crate::{Certificate, Program, VerifyingKey};
 use console::{
     network::prelude::*,
-    program::{Identifier, ProgramID},
+    program::{Identifier, ProgramID, ValueType},
+    types::Field,
 };
+use snarkvm_algorithms::snark::marlin::{AHPForR1CS, MarlinHidingMode};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Deployment<N: Network> {
@@ -98,6 +103,104 @@ impl<N: Network> Deployment<N> {
         Ok(())
     }
 
+    /// Checks that the deployment is for the given `program ID`, and that the program body and verifying keys
+    /// are consistent with it.
+    pub fn verify_program_id_matches(&self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the submitted program ID matches the ID declared in the program.
+        ensure!(
+            self.program.id() == program_id,
+            "Program ID mismatch: the deployment is for '{program_id}', but the program declares '{}'",
+            self.program.id()
+        );
+        // Ensure the program body and verifying keys refer to the program ID.
+        self.check_program_references()
+    }
+
+    /// Checks that the program body and verifying keys are consistent with the declared program ID.
+    fn check_program_references(&self) -> Result<()> {
+        let program_id = self.program.id();
+
+        // Ensure the program does not import itself.
+        ensure!(
+            !self.program.imports().contains_key(program_id),
+            "Program ID mismatch: program '{program_id}' imports itself"
+        );
+        // Ensure the program does not reference itself as an external program.
+        for function in self.program.functions().values() {
+            for value_type in function.input_types().iter().chain(function.output_types().iter()) {
+                if let ValueType::ExternalRecord(locator) = value_type {
+                    ensure!(
+                        locator.program_id() != program_id,
+                        "Program ID mismatch: function '{}' references '{locator}' as an external record",
+                        function.name()
+                    );
+                }
+            }
+        }
+        // Ensure each verifying key corresponds to a function in the program.
+        for (function_name, _) in &self.verifying_keys {
+            ensure!(
+                self.program.contains_function(function_name),
+                "Program ID mismatch: the verifying key for '{function_name}' is not a function in '{program_id}'"
+            );
+        }
+        Ok(())
+    }
+
+    /// Performs the structural checks of the deployment, without verifying the certificates.
+    ///
+    /// This is intended to admit unconfirmed deployments into the mempool. It does not synthesize
+    /// the function circuits, so `Process::verify_deployment` must still be called before inclusion.
+    pub fn verify_cheap(&self) -> Result<()> {
+        // Ensure the deployment is ordered.
+        self.check_is_ordered()?;
+        // Ensure the program body and verifying keys are consistent with the program ID.
+        self.check_program_references()?;
+
+        // Retrieve the expected number of circuit commitments per verifying key.
+        let num_expected_commitments = AHPForR1CS::<N::Field, MarlinHidingMode>::index_polynomial_labels().count();
+
+        for (function_name, (verifying_key, certificate)) in &self.verifying_keys {
+            // Ensure the verifying key contains the expected number of circuit commitments.
+            ensure!(
+                verifying_key.circuit_commitments.len() == num_expected_commitments,
+                "The verifying key for '{function_name}' has {} circuit commitments (expected {})",
+                verifying_key.circuit_commitments.len(),
+                num_expected_commitments
+            );
+
+            let circuit_info = &verifying_key.circuit_info;
+            // Ensure the number of public inputs is well-formed.
+            ensure!(
+                circuit_info.num_public_inputs > 0 && circuit_info.num_public_inputs <= circuit_info.num_variables,
+                "The verifying key for '{function_name}' has an invalid number of public inputs ({})",
+                circuit_info.num_public_inputs
+            );
+            // Ensure the number of constraints is within the network limit.
+            ensure!(
+                circuit_info.num_constraints as u64 <= N::MAX_DEPLOYMENT_CONSTRAINTS,
+                "The circuit for '{function_name}' has {} constraints, exceeding the limit of {}",
+                circuit_info.num_constraints,
+                N::MAX_DEPLOYMENT_CONSTRAINTS
+            );
+            // Ensure the number of variables is within the network limit.
+            ensure!(
+                circuit_info.num_variables as u64 <= N::MAX_DEPLOYMENT_VARIABLES,
+                "The circuit for '{function_name}' has {} variables, exceeding the limit of {}",
+                circuit_info.num_variables,
+                N::MAX_DEPLOYMENT_VARIABLES
+            );
+
+            // Ensure the certificate contains a single evaluation proof.
+            ensure!(
+                certificate.pc_proof.proof.len() == 1,
+                "The certificate for '{function_name}' has {} evaluation proofs, expected 1",
+                certificate.pc_proof.proof.len()
+            );
+        }
+        Ok(())
+    }
+
     /// Returns the edition.
     pub const fn edition(&self) -> u16 {
         self.edition
@@ -117,6 +220,27 @@ impl<N: Network> Deployment<N> {
     pub const fn verifying_keys(&self) -> &Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))> {
         &self.verifying_keys
     }
+
+    /// Returns the number of verifying keys.
+    pub fn num_verifying_keys(&self) -> usize {
+        self.verifying_keys.len()
+    }
+
+    /// Returns the verifying key for the given function name, if it exists.
+    pub fn verifying_key_for(&self, function: &Identifier<N>) -> Option<&VerifyingKey<N>> {
+        // The verifying keys are ordered as the functions in the program, so the index of the function is used.
+        let index = self.program.functions().get_index_of(function)?;
+        match self.verifying_keys.get(index) {
+            Some((name, (verifying_key, _))) if name == function => Some(verifying_key),
+            _ => None,
+        }
+    }
+
+    /// Returns the type hash of each struct in the deployed program.
+    /// These are used to check that struct definitions are compatible across program calls.
+    pub fn struct_type_hashes(&self) -> Result<Vec<(Identifier<N>, Field<N>)>> {
+        self.program.structs().iter().map(|(name, struct_)| Ok((*name, struct_.to_type_hash()?))).collect()
+    }
 }
 
 #[cfg(test)]
@@ -159,3 +283,79 @@ function compute:
             .clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verifying_key_for() {
+        // Initialize a program with multiple functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lookup.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    output r1 as u32.private;
+
+function double:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    mul r2 2u32 into r3;
+    output r3 as u32.public;",
+        )
+        .unwrap();
+
+        // Compute the deployment.
+        let rng = &mut TestRng::default();
+        let process = crate::Process::load().unwrap();
+        let deployment = process.deploy::<circuit::network::AleoV0, _>(&program, rng).unwrap();
+        assert_eq!(deployment.num_verifying_keys(), 2);
+
+        // Ensure each verifying key is found by its function name.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            assert_eq!(deployment.verifying_key_for(function_name), Some(verifying_key));
+        }
+        let add_one = Identifier::from_str("add_one").unwrap();
+        let double = Identifier::from_str("double").unwrap();
+        assert_ne!(deployment.verifying_key_for(&add_one), deployment.verifying_key_for(&double));
+
+        // Ensure a missing function has no verifying key.
+        assert!(deployment.verifying_key_for(&Identifier::from_str("missing").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_verify_program_id_matches() {
+        let deployment = test_helpers::sample_deployment();
+        let program_id = *deployment.program().id();
+        assert!(deployment.verify_program_id_matches(&program_id).is_ok());
+
+        // Ensure a different submitted program ID is rejected.
+        let other_id = ProgramID::<CurrentNetwork>::from_str("other.aleo").unwrap();
+        let error = deployment.verify_program_id_matches(&other_id).unwrap_err();
+        assert!(error.to_string().contains("Program ID mismatch"), "Unexpected error: {error}");
+
+        // Swap the program body for one with a different program ID.
+        let source = deployment.program().to_string().replace("program testing.aleo;", "program stale.aleo;");
+        let program = Program::<CurrentNetwork>::from_str(&source).unwrap();
+        let swapped = Deployment::new(deployment.edition(), program, deployment.verifying_keys().clone()).unwrap();
+        // Ensure the swapped deployment is rejected for the submitted program ID.
+        let error = swapped.verify_program_id_matches(&program_id).unwrap_err();
+        assert!(error.to_string().contains("Program ID mismatch"), "Unexpected error: {error}");
+        // Ensure the swapped deployment is accepted for its own program ID.
+        assert!(swapped.verify_program_id_matches(swapped.program().id()).is_ok());
+
+        // Ensure a program that imports itself is rejected, even when the program ID matches.
+        let source = format!("import stale.aleo;\n\n{}", swapped.program());
+        let program = Program::<CurrentNetwork>::from_str(&source).unwrap();
+        let mutated = Deployment::new(deployment.edition(), program, deployment.verifying_keys().clone()).unwrap();
+        let error = mutated.verify_program_id_matches(mutated.program().id()).unwrap_err();
+        assert!(error.to_string().contains("imports itself"), "Unexpected error: {error}");
+    }
+}
@@ -53,11 +53,9 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
-            }
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If the evaluation fails, return the error with the instruction context.
+            self.evaluate_instruction(closure.name(), index, instruction, &mut registers)?;
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -82,6 +80,18 @@ impl<N: Network> Stack<N> {
             .collect();
         lap!(timer, "Load the outputs");
 
+        // Add the metrics, if the call stack records them.
+        if let Some(metrics) = metrics {
+            metrics.write().push(CallMetrics {
+                program_id: *self.program_id(),
+                function_name: *function.name(),
+                num_instructions: function.instructions().len(),
+                num_request_constraints: 0,
+                num_function_constraints: 0,
+                num_response_constraints: 0,
+            });
+        }
+
         finish!(timer);
         outputs
     }
@@ -99,12 +109,18 @@ impl<N: Network> Stack<N> {
 
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, ..) => (authorization.next()?, call_stack),
             CallStack::Execute(authorization, ..) => (authorization.peek_next()?, call_stack.replicate()),
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
         };
         lap!(timer, "Retrieve the next request");
 
+        // Retrieve the metrics, if the call stack records them.
+        let metrics = match &call_stack {
+            CallStack::Evaluate(_, metrics) => metrics.clone(),
+            _ => None,
+        };
+
         // Ensure the network ID matches.
         ensure!(
             **request.network_id() == N::ID,
@@ -151,11 +167,9 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in function.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
-            }
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If the evaluation fails, return the error with the instruction context.
+            self.evaluate_instruction(function.name(), index, instruction, &mut registers)?;
         }
         lap!(timer, "Evaluate the instructions");
 
@@ -207,4 +221,59 @@ impl<N: Network> Stack<N> {
             &output_registers,
         )
     }
+
+    /// Checks that the outputs in the given response match the output types of the function,
+    /// and are consistent with the given output IDs, without re-executing the function.
+    pub fn verify_response(
+        &self,
+        function_name: &Identifier<N>,
+        response: &Response<N>,
+        tvk: &Field<N>,
+        tcm: &Field<N>,
+        output_ids: &[OutputID<N>],
+    ) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Retrieve the output types.
+        let output_types = function.output_types();
+
+        // Ensure the number of outputs matches.
+        if output_types.len() != response.outputs().len() {
+            bail!(
+                "Function '{function_name}' in the program '{}' expects {} outputs, but {} were provided.",
+                self.program.id(),
+                output_types.len(),
+                response.outputs().len()
+            )
+        }
+        // Ensure the outputs match their output types.
+        for (index, (output, output_type)) in response.outputs().iter().zip_eq(&output_types).enumerate() {
+            if let Err(error) = self.matches_value_type(output, output_type) {
+                bail!("Output {index} does not match the output type '{output_type}': {error}")
+            }
+        }
+
+        // Map the output operands to registers.
+        let output_registers = function
+            .outputs()
+            .iter()
+            .map(|output| match output.operand() {
+                Operand::Register(register) => Some(register.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the outputs are consistent with the output IDs.
+        response.verify(
+            &U16::new(N::ID),
+            self.program.id(),
+            function_name,
+            function.inputs().len(),
+            tvk,
+            tcm,
+            &output_types,
+            &output_registers,
+            output_ids,
+        )
+    }
 }
@@ -0,0 +1,436 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Opcode;
+use console::program::Register;
+
+/// The context of the instruction that halted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionContext<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function (or closure) name.
+    function_name: Identifier<N>,
+    /// The index of the instruction in the function (or closure).
+    index: usize,
+    /// The opcode of the instruction.
+    opcode: Opcode,
+    /// The destination register of the instruction, if it has one.
+    destination: Option<Register<N>>,
+}
+
+impl<N: Network> InstructionContext<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function (or closure) name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the instruction in the function (or closure).
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the opcode of the instruction.
+    pub const fn opcode(&self) -> Opcode {
+        self.opcode
+    }
+
+    /// Returns the destination register of the instruction, if it has one.
+    pub const fn destination(&self) -> Option<&Register<N>> {
+        self.destination.as_ref()
+    }
+}
+
+impl<N: Network> Display for InstructionContext<N> {
+    /// Prints the context, i.e. `token.aleo/transfer at instruction 7 (destination r5)`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{} at instruction {}", self.program_id, self.function_name, self.index)?;
+        match &self.destination {
+            Some(destination) => write!(f, " (destination {destination})"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An error from an instruction that halted during evaluation or execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvaluateError<N: Network> {
+    /// The instruction overflowed, i.e. `18446744073709551615u64 + 1u64`.
+    Overflow { context: InstructionContext<N>, expression: String },
+    /// The instruction underflowed, i.e. `0u64 - 1u64`.
+    Underflow { context: InstructionContext<N>, expression: String },
+    /// The instruction divided by zero, i.e. `1u64 / 0u64`.
+    DivisionByZero { context: InstructionContext<N>, expression: String },
+    /// The instruction halted for another reason, with the given message.
+    Halted { context: InstructionContext<N>, message: String },
+}
+
+impl<N: Network> EvaluateError<N> {
+    /// Returns the context of the instruction that halted.
+    pub const fn context(&self) -> &InstructionContext<N> {
+        match self {
+            Self::Overflow { context, .. } => context,
+            Self::Underflow { context, .. } => context,
+            Self::DivisionByZero { context, .. } => context,
+            Self::Halted { context, .. } => context,
+        }
+    }
+
+    /// Returns the error for the given instruction, if it would halt on the given console operands.
+    fn from_operands(context: InstructionContext<N>, operands: &[Literal<N>]) -> Option<Self> {
+        let opcode = *context.opcode;
+        if !halts(opcode, operands) {
+            return None;
+        }
+
+        // Prints the operands as an expression, i.e. `1u64 + 2u64`.
+        let symbol = match opcode {
+            "add" => Some("+"),
+            "sub" => Some("-"),
+            "mul" => Some("*"),
+            "div" => Some("/"),
+            "rem" => Some("%"),
+            "pow" => Some("**"),
+            "shl" => Some("<<"),
+            "shr" => Some(">>"),
+            _ => None,
+        };
+        let expression = match (symbol, operands) {
+            (Some(symbol), [first, second]) => format!("{first} {symbol} {second}"),
+            _ => format!("{opcode} {}", operands.iter().map(|operand| operand.to_string()).join(" ")),
+        };
+
+        Some(match (opcode, operands) {
+            ("div" | "div.w" | "rem" | "rem.w" | "mod", [_, second]) if is_zero(second) => {
+                Self::DivisionByZero { context, expression }
+            }
+            ("inv", [first]) if is_zero(first) => Self::DivisionByZero { context, expression },
+            ("add", [_, second]) if is_negative(second) => Self::Underflow { context, expression },
+            ("sub", [_, second]) if !is_negative(second) => Self::Underflow { context, expression },
+            ("mul", [first, second]) if is_negative(first) != is_negative(second) => {
+                Self::Underflow { context, expression }
+            }
+            _ => Self::Overflow { context, expression },
+        })
+    }
+}
+
+impl<N: Network> Display for EvaluateError<N> {
+    /// Prints the error, i.e. `add overflow in token.aleo/transfer at instruction 7 (destination r5): 1u8 + 255u8`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow { context, expression } => {
+                write!(f, "{} overflow in {context}: {expression}", context.opcode)
+            }
+            Self::Underflow { context, expression } => {
+                write!(f, "{} underflow in {context}: {expression}", context.opcode)
+            }
+            Self::DivisionByZero { context, expression } => {
+                write!(f, "{} division by zero in {context}: {expression}", context.opcode)
+            }
+            Self::Halted { context, message } => write!(f, "{} halted in {context}: {message}", context.opcode),
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for EvaluateError<N> {}
+
+/// Returns `true` if the given opcode halts on the given console operands.
+fn halts<N: Network>(opcode: &str, operands: &[Literal<N>]) -> bool {
+    macro_rules! halts {
+        ($($variant:ident),+) => {
+            match (opcode, operands) {
+                $(
+                    ("add", [Literal::$variant(a), Literal::$variant(b)]) => (**a).checked_add(**b).is_none(),
+                    ("sub", [Literal::$variant(a), Literal::$variant(b)]) => (**a).checked_sub(**b).is_none(),
+                    ("mul", [Literal::$variant(a), Literal::$variant(b)]) => (**a).checked_mul(**b).is_none(),
+                    ("div", [Literal::$variant(a), Literal::$variant(b)]) => (**a).checked_div(**b).is_none(),
+                    ("rem", [Literal::$variant(a), Literal::$variant(b)]) => (**a).checked_rem(**b).is_none(),
+                    ("abs", [Literal::$variant(a)]) => (**a).checked_abs().is_none(),
+                    ("neg", [Literal::$variant(a)]) => (**a).checked_neg().is_none(),
+                    ("pow", [Literal::$variant(a), b]) => as_u32(b).map_or(false, |b| (**a).checked_pow(b).is_none()),
+                    // Note that `checked_shl` here deviates from Rust semantics, and halts on overflow.
+                    ("shl", [Literal::$variant(a), b]) => as_u32(b).map_or(false, |b| {
+                        console::prelude::traits::integers::CheckedShl::checked_shl(&**a, &b).is_none()
+                    }),
+                    ("shr", [Literal::$variant(a), b]) => as_u32(b).map_or(false, |b| (**a).checked_shr(b).is_none()),
+                )+
+                ("div" | "div.w" | "rem" | "rem.w" | "mod", [_, second]) => is_zero(second),
+                ("inv", [first]) => is_zero(first),
+                _ => false,
+            }
+        };
+    }
+    halts!(I8, I16, I32, I64, I128, U8, U16, U32, U64, U128)
+}
+
+/// Returns the given literal as a `u32`, if it is an unsigned integer of at most 32 bits.
+fn as_u32<N: Network>(literal: &Literal<N>) -> Option<u32> {
+    match literal {
+        Literal::U8(value) => Some(**value as u32),
+        Literal::U16(value) => Some(**value as u32),
+        Literal::U32(value) => Some(**value),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the given literal is a zero integer, field, or scalar.
+fn is_zero<N: Network>(literal: &Literal<N>) -> bool {
+    match literal {
+        Literal::Field(value) => value.is_zero(),
+        Literal::Scalar(value) => value.is_zero(),
+        Literal::I8(value) => value.is_zero(),
+        Literal::I16(value) => value.is_zero(),
+        Literal::I32(value) => value.is_zero(),
+        Literal::I64(value) => value.is_zero(),
+        Literal::I128(value) => value.is_zero(),
+        Literal::U8(value) => value.is_zero(),
+        Literal::U16(value) => value.is_zero(),
+        Literal::U32(value) => value.is_zero(),
+        Literal::U64(value) => value.is_zero(),
+        Literal::U128(value) => value.is_zero(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the given literal is a negative signed integer.
+fn is_negative<N: Network>(literal: &Literal<N>) -> bool {
+    match literal {
+        Literal::I8(value) => **value < 0,
+        Literal::I16(value) => **value < 0,
+        Literal::I32(value) => **value < 0,
+        Literal::I64(value) => **value < 0,
+        Literal::I128(value) => **value < 0,
+        _ => false,
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Evaluates the given instruction, attaching the instruction context if it halts.
+    pub(crate) fn evaluate_instruction<A: circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        function_name: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Load the console operands, to check if the instruction halts before evaluating it.
+        let operands = instruction
+            .operands()
+            .iter()
+            .filter_map(|operand| match registers.load(self, operand) {
+                Ok(Value::Plaintext(Plaintext::Literal(literal, ..))) => Some(literal),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // Ensure the instruction does not halt on the operands.
+        if let Some(error) =
+            EvaluateError::from_operands(self.instruction_context(function_name, index, instruction), &operands)
+        {
+            return Err(error.into());
+        }
+
+        match instruction.evaluate(self, registers) {
+            Ok(()) => Ok(()),
+            // If the error is from a halt in an inner call, propagate it as is.
+            Err(error) if error.is::<EvaluateError<N>>() => Err(error),
+            Err(error) => {
+                let context = self.instruction_context(function_name, index, instruction);
+                Err(EvaluateError::Halted { context, message: error.to_string() }.into())
+            }
+        }
+    }
+
+    /// Executes the given instruction, attaching the instruction context if it fails.
+    pub(crate) fn execute_instruction<A: circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        function_name: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        match instruction.execute(self, registers) {
+            Ok(()) => Ok(()),
+            // If the error is from a halt in an inner call, propagate it as is.
+            Err(error) if error.is::<EvaluateError<N>>() => Err(error),
+            Err(error) => {
+                let context = self.instruction_context(function_name, index, instruction);
+                Err(EvaluateError::Halted { context, message: error.to_string() }.into())
+            }
+        }
+    }
+
+    /// Returns the context of the given instruction.
+    fn instruction_context(
+        &self,
+        function_name: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+    ) -> InstructionContext<N> {
+        InstructionContext {
+            program_id: *self.program_id(),
+            function_name: *function_name,
+            index,
+            opcode: instruction.opcode(),
+            destination: instruction.destinations().into_iter().next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    const PROGRAM: &str = r"
+program token.aleo;
+
+function transfer:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    mul r1 1u64 into r2;
+    add r0 r2 into r3;
+    output r3 as u64.private;
+
+function split:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    div r0 r1 into r2;
+    output r2 as u32.private;
+
+function withdraw:
+    input r0 as u8.private;
+    add r0 0u8 into r1;
+    sub r1 5u8 into r2;
+    output r2 as u8.private;";
+
+    /// Authorizes the given function with the given inputs.
+    fn authorize(
+        process: &Process<CurrentNetwork>,
+        function_name: &str,
+        inputs: &[&str],
+        rng: &mut TestRng,
+    ) -> Authorization<CurrentNetwork> {
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                &program_id,
+                Identifier::from_str(function_name).unwrap(),
+                inputs.iter(),
+                rng,
+            )
+            .unwrap()
+    }
+
+    /// Authorizes the given function with the given inputs, and returns the evaluation error.
+    fn evaluate_error(process: &Process<CurrentNetwork>, function_name: &str, inputs: &[&str]) -> Error {
+        let rng = &mut TestRng::default();
+        let authorization = authorize(process, function_name, inputs, rng);
+        process.evaluate::<CurrentAleo>(authorization).unwrap_err()
+    }
+
+    /// Authorizes the given function with the given inputs, and returns the execution error.
+    fn execute_error(process: &Process<CurrentNetwork>, function_name: &str, inputs: &[&str]) -> Error {
+        let rng = &mut TestRng::default();
+        let authorization = authorize(process, function_name, inputs, rng);
+        process.execute::<CurrentAleo, _>(authorization, rng).unwrap_err()
+    }
+
+    #[test]
+    fn test_evaluate_error_context() {
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&Program::from_str(PROGRAM).unwrap()).unwrap();
+
+        // Ensure an overflow reports the context.
+        let error = evaluate_error(&process, "transfer", &["18446744073709551615u64", "1u64"]);
+        assert_eq!(
+            error.to_string(),
+            "add overflow in token.aleo/transfer at instruction 1 (destination r3): 18446744073709551615u64 + 1u64"
+        );
+        let error = error.downcast_ref::<EvaluateError<CurrentNetwork>>().unwrap();
+        assert!(matches!(error, EvaluateError::Overflow { .. }));
+        assert_eq!(error.context().function_name().to_string(), "transfer");
+        assert_eq!(error.context().index(), 1);
+        assert_eq!(error.context().destination().map(|register| register.to_string()), Some("r3".to_string()));
+
+        // Ensure a division by zero reports the context.
+        let error = evaluate_error(&process, "split", &["7u32", "0u32"]);
+        assert_eq!(
+            error.to_string(),
+            "div division by zero in token.aleo/split at instruction 0 (destination r2): 7u32 / 0u32"
+        );
+        let error = error.downcast_ref::<EvaluateError<CurrentNetwork>>().unwrap();
+        assert!(matches!(error, EvaluateError::DivisionByZero { .. }));
+        assert_eq!(error.context().index(), 0);
+
+        // Ensure an underflow reports the context.
+        let error = evaluate_error(&process, "withdraw", &["3u8"]);
+        assert_eq!(
+            error.to_string(),
+            "sub underflow in token.aleo/withdraw at instruction 1 (destination r2): 3u8 - 5u8"
+        );
+        let error = error.downcast_ref::<EvaluateError<CurrentNetwork>>().unwrap();
+        assert!(matches!(error, EvaluateError::Underflow { .. }));
+        assert_eq!(*error.context().opcode(), "sub");
+    }
+    #[test]
+    fn test_execute_error_context() {
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&Program::from_str(PROGRAM).unwrap()).unwrap();
+
+        // Ensure an overflow reports the context.
+        let error = execute_error(&process, "transfer", &["18446744073709551615u64", "1u64"]);
+        assert_eq!(
+            error.to_string(),
+            "add overflow in token.aleo/transfer at instruction 1 (destination r3): 18446744073709551615u64 + 1u64"
+        );
+        let error = error.downcast_ref::<EvaluateError<CurrentNetwork>>().unwrap();
+        assert!(matches!(error, EvaluateError::Overflow { .. }));
+        assert_eq!(error.context().index(), 1);
+
+        // Ensure a division by zero reports the context.
+        let error = execute_error(&process, "split", &["7u32", "0u32"]);
+        assert_eq!(
+            error.to_string(),
+            "div division by zero in token.aleo/split at instruction 0 (destination r2): 7u32 / 0u32"
+        );
+        assert!(matches!(
+            error.downcast_ref::<EvaluateError<CurrentNetwork>>(),
+            Some(EvaluateError::DivisionByZero { .. })
+        ));
+
+        // Ensure a valid execution succeeds.
+        let rng = &mut TestRng::default();
+        let authorization = authorize(&process, "transfer", &["1u64", "2u64"], rng);
+        assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_ok());
+    }
+}
@@ -66,16 +66,18 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
-                // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
-                }
+                // If the evaluation fails, return the error with the instruction context.
+                self.evaluate_instruction(closure.name(), index, instruction, &mut registers)?;
             }
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            self.execute_instruction(closure.name(), index, instruction, &mut registers)?;
+            // Ensure the circuit is within the constraint limit.
+            if let Some(error) = A::constraint_limit_exceeded() {
+                return Err(error.into());
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -140,6 +142,18 @@ impl<N: Network> Stack<N> {
             //A::add_lookup_table(LookupTable::from(table));
             A::add_lookup_table(table.into_lookup_table::<A>());
         });
+        // Add the range check lookup table, if this program uses `assert.range`.
+        // Note: This table must follow the program tables, as its index is `self.program.tables().len()`.
+        let uses_range_check = self
+            .program
+            .functions()
+            .values()
+            .flat_map(|function| function.instructions())
+            .chain(self.program.closures().values().flat_map(|closure| closure.instructions()))
+            .any(|instruction| matches!(instruction, Instruction::AssertRange(..)));
+        if uses_range_check {
+            A::add_lookup_table(AssertRange::<N>::lookup_table::<A>());
+        }
 
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
@@ -226,17 +240,19 @@ impl<N: Network> Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
-                // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
-                }
+                // If the evaluation fails, return the error with the instruction context.
+                self.evaluate_instruction(function.name(), index, instruction, &mut registers)?;
             }
 
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            self.execute_instruction(function.name(), index, instruction, &mut registers)?;
+            // Ensure the circuit is within the constraint limit.
+            if let Some(error) = A::constraint_limit_exceeded() {
+                return Err(error.into());
+            }
 
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
@@ -483,6 +499,11 @@ impl<N: Network> Stack<N> {
             );
         }
 
+        // Ensure the circuit is within the constraint limit.
+        if let Some(error) = A::constraint_limit_exceeded() {
+            return Err(error.into());
+        }
+
         // Eject the circuit assignment and reset the circuit.
         let assignment = A::eject_assignment_and_reset();
 
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FinalizeRegisters<N> {
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`,
+    /// including the pending writes of the finalize scope.
+    pub fn get_value<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        match self.writes.get(mapping_name).and_then(|writes| writes.get(key)) {
+            Some(value) => Ok(Some(value.clone())),
+            None => store.get_value(program_id, mapping_name, key),
+        }
+    }
+
+    /// Queues the given `(key, value)` pair for the given `mapping name`.
+    /// The pending writes are stored when the finalize scope is flushed.
+    pub fn update_value(&mut self, mapping_name: &Identifier<N>, key: Plaintext<N>, value: Value<N>) {
+        self.writes.entry(*mapping_name).or_default().insert(key, value);
+    }
+
+    /// Stores the pending writes of the finalize scope, with one batched update per mapping.
+    pub fn flush<P: ProgramStorage<N>>(&mut self, store: &ProgramStore<N, P>, program_id: &ProgramID<N>) -> Result<()> {
+        for (mapping_name, writes) in self.writes.drain(..) {
+            store.update_key_values(program_id, &mapping_name, writes.into_iter().collect())?;
+        }
+        Ok(())
+    }
+}
@@ -15,12 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod load;
+mod mapping;
 mod store;
 
-use crate::{FinalizeTypes, Operand, Stack};
+use crate::{FinalizeTypes, Operand, ProgramStorage, ProgramStore, Stack};
 use console::{
     network::prelude::*,
-    program::{Entry, Literal, Plaintext, Register, Value},
+    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Register, Value},
 };
 
 use indexmap::IndexMap;
@@ -31,12 +32,14 @@ pub struct FinalizeRegisters<N: Network> {
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
     registers: IndexMap<u64, Value<N>>,
+    /// The pending mapping writes of the finalize scope, which are stored in one batch per mapping.
+    writes: IndexMap<Identifier<N>, IndexMap<Plaintext<N>, Value<N>>>,
 }
 
 impl<N: Network> FinalizeRegisters<N> {
     /// Initializes a new set of registers, given the finalize types.
     #[inline]
     pub fn new(finalize_types: FinalizeTypes<N>) -> Self {
-        Self { finalize_types, registers: IndexMap::new() }
+        Self { finalize_types, registers: IndexMap::new(), writes: IndexMap::new() }
     }
 }
@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod serialize;
+
+use crate::{finalize::Command, FinalizeRegisters, ProgramStorage, ProgramStore, Stack};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+/// The trace of the `finalize` commands evaluated for a transaction.
+///
+/// The trace is intended for operators debugging a node, and therefore records all values without redaction.
+/// To bound its memory usage, entries beyond `max_entries` are dropped, and only counted.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FinalizeTrace<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The traced commands, in the order they were evaluated.
+    entries: Vec<FinalizeTraceEntry<N>>,
+    /// The maximum number of entries to record.
+    max_entries: usize,
+    /// The number of entries that were dropped, after reaching the maximum.
+    num_dropped: usize,
+    /// The error that aborted the transaction, if any.
+    error: Option<String>,
+}
+
+impl<N: Network> FinalizeTrace<N> {
+    /// The default maximum number of entries in a trace.
+    pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+    /// Initializes a new trace for the given transaction ID, recording at most `max_entries` entries.
+    pub const fn new(transaction_id: N::TransactionID, max_entries: usize) -> Self {
+        Self { transaction_id, entries: Vec::new(), max_entries, num_dropped: 0, error: None }
+    }
+
+    /// Records the given entry, or drops it if the trace is full.
+    pub fn push(&mut self, entry: FinalizeTraceEntry<N>) {
+        match self.entries.len() < self.max_entries {
+            true => self.entries.push(entry),
+            false => self.num_dropped = self.num_dropped.saturating_add(1),
+        }
+    }
+
+    /// Records the error that aborted the transaction.
+    pub fn set_error(&mut self, error: &Error) {
+        self.error = Some(error.to_string());
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the traced commands, in the order they were evaluated.
+    pub fn entries(&self) -> &[FinalizeTraceEntry<N>] {
+        &self.entries
+    }
+
+    /// Returns the maximum number of entries to record.
+    pub const fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Returns the number of entries that were dropped, after reaching the maximum.
+    pub const fn num_dropped(&self) -> usize {
+        self.num_dropped
+    }
+
+    /// Returns the error that aborted the transaction, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// The trace of a single `finalize` command.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FinalizeTraceEntry<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The command, as a string.
+    command: String,
+    /// The resolved values of the operands, or `None` if an operand could not be loaded.
+    operands: Vec<Option<Value<N>>>,
+    /// The mapping accessed by the command, if any.
+    mapping: Option<Identifier<N>>,
+    /// The mapping key accessed by the command, if any.
+    key: Option<Plaintext<N>>,
+    /// The mapping value read by the command, if any.
+    read: Option<Value<N>>,
+    /// The mapping value written by the command, if any.
+    written: Option<Value<N>>,
+    /// The error of the command, if it failed.
+    error: Option<String>,
+}
+
+impl<N: Network> FinalizeTraceEntry<N> {
+    /// Initializes a trace entry for the given command, before it is evaluated.
+    pub fn open<P: ProgramStorage<N>>(
+        stack: &Stack<N>,
+        store: &ProgramStore<N, P>,
+        registers: &FinalizeRegisters<N>,
+        command: &Command<N>,
+    ) -> Self {
+        // Resolve the operands, and the mapping key accessed by the command.
+        let (operands, mapping, key) = match command {
+            Command::Decrement(decrement) => (
+                decrement.operands(),
+                Some(*decrement.mapping_name()),
+                registers.load_plaintext(stack, decrement.key()).ok(),
+            ),
+            Command::Instruction(instruction) => (instruction.operands().to_vec(), None, None),
+            Command::Increment(increment) => (
+                increment.operands(),
+                Some(*increment.mapping_name()),
+                registers.load_plaintext(stack, increment.key()).ok(),
+            ),
+        };
+        let operands = operands.iter().map(|operand| registers.load(stack, operand).ok()).collect();
+
+        // Read the mapping value, before the command is evaluated.
+        let read = match (&mapping, &key) {
+            (Some(mapping), Some(key)) => registers.get_value(store, stack.program_id(), mapping, key).ok().flatten(),
+            _ => None,
+        };
+
+        Self {
+            program_id: *stack.program_id(),
+            command: command.to_string(),
+            operands,
+            mapping,
+            key,
+            read,
+            written: None,
+            error: None,
+        }
+    }
+
+    /// Completes the trace entry, given the outcome of evaluating the command.
+    pub fn close<P: ProgramStorage<N>>(
+        mut self,
+        store: &ProgramStore<N, P>,
+        registers: &FinalizeRegisters<N>,
+        outcome: &Result<()>,
+    ) -> Self {
+        match outcome {
+            // Read the mapping value, after the command is evaluated.
+            Ok(()) => {
+                if let (Some(mapping), Some(key)) = (&self.mapping, &self.key) {
+                    self.written = registers.get_value(store, &self.program_id, mapping, key).ok().flatten();
+                }
+            }
+            Err(error) => self.error = Some(error.to_string()),
+        }
+        self
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the command, as a string.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Returns the resolved values of the operands, or `None` if an operand could not be loaded.
+    pub fn operands(&self) -> &[Option<Value<N>>] {
+        &self.operands
+    }
+
+    /// Returns the mapping accessed by the command, if any.
+    pub const fn mapping(&self) -> Option<&Identifier<N>> {
+        self.mapping.as_ref()
+    }
+
+    /// Returns the mapping key accessed by the command, if any.
+    pub const fn key(&self) -> Option<&Plaintext<N>> {
+        self.key.as_ref()
+    }
+
+    /// Returns the mapping value read by the command, if any.
+    pub const fn read(&self) -> Option<&Value<N>> {
+        self.read.as_ref()
+    }
+
+    /// Returns the mapping value written by the command, if any.
+    pub const fn written(&self) -> Option<&Value<N>> {
+        self.written.as_ref()
+    }
+
+    /// Returns the error of the command, if it failed.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_entry(command: &str) -> FinalizeTraceEntry<CurrentNetwork> {
+        FinalizeTraceEntry {
+            program_id: ProgramID::from_str("testing.aleo").unwrap(),
+            command: command.to_string(),
+            operands: vec![Some(Value::from_str("3u64").unwrap()), None],
+            mapping: Some(Identifier::from_str("account").unwrap()),
+            key: Some(Plaintext::from_str("1field").unwrap()),
+            read: None,
+            written: Some(Value::from_str("3u64").unwrap()),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_max_entries() {
+        let mut trace = FinalizeTrace::<CurrentNetwork>::new(Default::default(), 2);
+
+        // Record more entries than the maximum.
+        for i in 0..5 {
+            trace.push(sample_entry(&format!("command {i}")));
+        }
+        // Ensure only the first entries are kept, and the rest are counted.
+        assert_eq!(trace.entries().len(), 2);
+        assert_eq!(trace.entries()[1].command(), "command 1");
+        assert_eq!(trace.num_dropped(), 3);
+
+        // Ensure the error is kept, even when the trace is full.
+        trace.set_error(&anyhow!("aborted"));
+        assert_eq!(trace.error(), Some("aborted"));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut trace = FinalizeTrace::<CurrentNetwork>::new(Default::default(), 1);
+        trace.push(sample_entry("increment account[r0] by r1;"));
+
+        // Ensure the trace serializes to JSON, including all values.
+        let json = serde_json::to_value(&trace).unwrap();
+        let entry = &json["entries"][0];
+        assert_eq!(entry["command"], "increment account[r0] by r1;");
+        assert_eq!(entry["operands"][0], "3u64");
+        assert!(entry["operands"][1].is_null());
+        assert_eq!(entry["mapping"], "account");
+        assert_eq!(entry["key"], "1field");
+        assert!(entry["read"].is_null());
+        assert_eq!(entry["written"], "3u64");
+        assert_eq!(json["num_dropped"], 0);
+        assert_eq!(trace.to_string(), json.to_string());
+    }
+}
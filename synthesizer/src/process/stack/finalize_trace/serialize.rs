@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Serialize for FinalizeTrace<N> {
+    /// Serializes the finalize trace into a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut trace = serializer.serialize_struct("FinalizeTrace", 5)?;
+        trace.serialize_field("transaction_id", &self.transaction_id)?;
+        trace.serialize_field("entries", &self.entries)?;
+        trace.serialize_field("max_entries", &self.max_entries)?;
+        trace.serialize_field("num_dropped", &self.num_dropped)?;
+        trace.serialize_field("error", &self.error)?;
+        trace.end()
+    }
+}
+
+impl<N: Network> Serialize for FinalizeTraceEntry<N> {
+    /// Serializes the finalize trace entry into a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entry = serializer.serialize_struct("FinalizeTraceEntry", 8)?;
+        entry.serialize_field("program_id", &self.program_id)?;
+        entry.serialize_field("command", &self.command)?;
+        entry.serialize_field("operands", &self.operands)?;
+        entry.serialize_field("mapping", &self.mapping)?;
+        entry.serialize_field("key", &self.key)?;
+        entry.serialize_field("read", &self.read)?;
+        entry.serialize_field("written", &self.written)?;
+        entry.serialize_field("error", &self.error)?;
+        entry.end()
+    }
+}
+
+impl<N: Network> Display for FinalizeTrace<N> {
+    /// Displays the finalize trace as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
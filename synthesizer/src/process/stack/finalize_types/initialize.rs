@@ -116,9 +116,9 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             RegisterType::ExternalRecord(locator) => {
-                // Ensure the external record type is defined in the program.
-                if !stack.contains_external_record(locator) {
-                    bail!("External record '{locator}' in '{}' is not defined.", stack.program_id())
+                // Ensure the external record type is defined in an imported program.
+                if let Err(error) = stack.check_external_record(locator) {
+                    bail!("External record '{locator}' in '{}' is not defined. {error}", stack.program_id())
                 }
             }
         };
@@ -165,9 +165,9 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             RegisterType::ExternalRecord(locator) => {
-                // Ensure the external record type is defined in the program.
-                if !stack.contains_external_record(locator) {
-                    bail!("External record '{locator}' in '{}' is not defined.", stack.program_id())
+                // Ensure the external record type is defined in an imported program.
+                if let Err(error) = stack.check_external_record(locator) {
+                    bail!("External record '{locator}' in '{}' is not defined. {error}", stack.program_id())
                 }
             }
         };
@@ -363,7 +363,7 @@ impl<N: Network> FinalizeTypes<N> {
             }
             Opcode::Assert(opcode) => {
                 // Ensure the instruction belongs to the defined set.
-                if !["assert.eq", "assert.neq"].contains(&opcode) {
+                if !["assert.eq", "assert.neq", "assert.range"].contains(&opcode) {
                     bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
                 }
                 // Ensure the instruction is the correct one.
@@ -376,6 +376,10 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::AssertNeq(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "assert.range" => ensure!(
+                        matches!(instruction, Instruction::AssertRange(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -487,6 +491,7 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash_to_group.psd4",
                 ]
                 .contains(&opcode)
                 {
@@ -530,6 +535,10 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash_to_group.psd4" => ensure!(
+                        matches!(instruction, Instruction::HashToGroupPSD4(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -91,7 +91,7 @@ impl<N: Network> Stack<N> {
 
     /// Checks that the given plaintext matches the layout of the plaintext type.
     pub fn matches_plaintext(&self, plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> Result<()> {
-        self.matches_plaintext_internal(plaintext, plaintext_type, 0)
+        plaintext.matches(plaintext_type, self.program().structs())
     }
 }
 
@@ -175,7 +175,7 @@ impl<N: Network> Stack<N> {
             (Entry::Constant(plaintext), EntryType::Constant(plaintext_type))
             | (Entry::Public(plaintext), EntryType::Public(plaintext_type))
             | (Entry::Private(plaintext), EntryType::Private(plaintext_type)) => {
-                match self.matches_plaintext_internal(plaintext, plaintext_type, depth) {
+                match plaintext.matches_at_depth(plaintext_type, self.program().structs(), depth) {
                     Ok(()) => Ok(()),
                     Err(error) => bail!("Invalid record entry '{record_name}.{entry_name}': {error}"),
                 }
@@ -185,86 +185,4 @@ impl<N: Network> Stack<N> {
             ),
         }
     }
-
-    /// Checks that the given plaintext matches the layout of the plaintext type.
-    ///
-    /// This method enforces `N::MAX_DATA_DEPTH` and `N::MAX_DATA_ENTRIES` limits.
-    fn matches_plaintext_internal(
-        &self,
-        plaintext: &Plaintext<N>,
-        plaintext_type: &PlaintextType<N>,
-        depth: usize,
-    ) -> Result<()> {
-        // If the depth exceeds the maximum depth, then the plaintext type is invalid.
-        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
-
-        // Ensure the plaintext matches the plaintext definition in the program.
-        match plaintext_type {
-            PlaintextType::Literal(literal_type) => match plaintext {
-                // If `plaintext` is a literal, it must match the literal type.
-                Plaintext::Literal(literal, ..) => {
-                    // Ensure the literal type matches.
-                    match literal.to_type() == *literal_type {
-                        true => Ok(()),
-                        false => bail!("'{plaintext_type}' is invalid: expected {literal_type}, found {literal}"),
-                    }
-                }
-                // If `plaintext` is a struct, this is a mismatch.
-                Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
-            },
-            PlaintextType::Struct(struct_name) => {
-                // Ensure the struct name is valid.
-                ensure!(!Program::is_reserved_keyword(struct_name), "Struct '{struct_name}' is reserved");
-
-                // Retrieve the struct from the program.
-                let struct_ = match self.program().get_struct(struct_name) {
-                    Ok(struct_) => struct_,
-                    Err(..) => bail!("Struct '{struct_name}' is not defined in the program"),
-                };
-
-                // Ensure the struct name matches.
-                if struct_.name() != struct_name {
-                    bail!("Expected struct '{struct_name}', found struct '{}'", struct_.name())
-                }
-
-                // Retrieve the struct members.
-                let members = match plaintext {
-                    Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
-                    Plaintext::Struct(members, ..) => members,
-                };
-
-                // Ensure the number of struct members does not exceed the maximum.
-                let num_members = members.len();
-                ensure!(
-                    num_members <= N::MAX_DATA_ENTRIES,
-                    "'{struct_name}' cannot exceed {} entries",
-                    N::MAX_DATA_ENTRIES
-                );
-
-                // Ensure the number of struct members match.
-                let expected_num_members = struct_.members().len();
-                if expected_num_members != num_members {
-                    bail!("'{struct_name}' expected {expected_num_members} members, found {num_members} members")
-                }
-
-                // Ensure the struct members match, in the same order.
-                for (i, ((expected_name, expected_type), (member_name, member))) in
-                    struct_.members().iter().zip_eq(members.iter()).enumerate()
-                {
-                    // Ensure the member name matches.
-                    if expected_name != member_name {
-                        bail!(
-                            "Member '{i}' in '{struct_name}' is incorrect: expected '{expected_name}', found '{member_name}'"
-                        )
-                    }
-                    // Ensure the member name is valid.
-                    ensure!(!Program::is_reserved_keyword(member_name), "Member name '{member_name}' is reserved");
-                    // Ensure the member plaintext matches (recursive call).
-                    self.matches_plaintext_internal(member, expected_type, depth + 1)?;
-                }
-
-                Ok(())
-            }
-        }
-    }
 }
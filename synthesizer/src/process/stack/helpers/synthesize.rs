@@ -70,6 +70,35 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Synthesizes the proving key and verifying key for the given function name,
+    /// returning a `ConstraintLimitExceeded` error if the circuit exceeds the given constraint limit.
+    #[inline]
+    pub fn synthesize_key_with_limit<A: circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        limit: circuit::ConstraintLimit,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Set the constraint limit, saving the previous limit.
+        let previous_limit = A::constraint_limit();
+        A::set_constraint_limit(limit);
+
+        // Synthesize the circuit, which is checked against the constraint limit after each instruction.
+        let result = self.synthesize_key::<A, R>(function_name, rng);
+
+        // Restore the previous constraint limit.
+        A::set_constraint_limit(previous_limit);
+
+        match result {
+            Err(error) if error.is::<circuit::ConstraintLimitExceeded>() => {
+                // Clear the partially-synthesized circuit.
+                A::reset();
+                Err(error.context(format!("Function '{function_name}' exceeds the constraint limit")))
+            }
+            result => result,
+        }
+    }
+
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
     #[inline]
     pub fn synthesize_from_assignment(
@@ -82,6 +111,9 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
+        #[cfg(test)]
+        NUM_KEY_SYNTHESES.with(|num_key_syntheses| num_key_syntheses.set(num_key_syntheses.get() + 1));
+
         // Synthesize the proving and verifying key.
         let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(function_name, assignment)?;
         // Insert the proving key.
@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ProvingKey;
+use console::network::prelude::*;
+
+use core::any::{Any, TypeId};
+use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// The default number of inclusion proving keys held by the process-wide cache.
+pub const DEFAULT_INCLUSION_KEY_CACHE_CAPACITY: usize = 16;
+
+/// The circuit variant of an inclusion proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InclusionVariant {
+    /// The inclusion proof of an execution, with the given number of input state paths.
+    Execution(usize),
+    /// The inclusion proof of a fee, with the given number of input state paths.
+    Fee(usize),
+}
+
+/// A cache of inclusion proving keys, keyed by circuit variant, with least-recently-used eviction.
+#[derive(Clone)]
+pub struct InclusionKeyCache<N: Network> {
+    /// The mapping of circuit variants to proving keys, from least to most recently used.
+    entries: Arc<RwLock<IndexMap<InclusionVariant, ProvingKey<N>>>>,
+    /// The maximum number of entries in the cache.
+    capacity: Arc<AtomicUsize>,
+    /// The number of lookups that were found in the cache.
+    num_hits: Arc<AtomicUsize>,
+    /// The number of lookups that were not found in the cache.
+    num_misses: Arc<AtomicUsize>,
+}
+
+impl<N: Network> Default for InclusionKeyCache<N> {
+    /// Initializes an empty cache with the default capacity.
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            capacity: Arc::new(AtomicUsize::new(DEFAULT_INCLUSION_KEY_CACHE_CAPACITY)),
+            num_hits: Default::default(),
+            num_misses: Default::default(),
+        }
+    }
+}
+
+impl<N: Network> InclusionKeyCache<N> {
+    /// Initializes an empty cache with the given capacity.
+    pub fn new(capacity: usize) -> Result<Self> {
+        // Ensure the capacity is nonzero.
+        ensure!(capacity > 0, "The inclusion key cache capacity must be greater than zero");
+        Ok(Self { capacity: Arc::new(AtomicUsize::new(capacity)), ..Default::default() })
+    }
+
+    /// Returns the process-wide cache for the network.
+    pub fn global() -> Self {
+        static INSTANCES: OnceCell<RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> = OnceCell::new();
+        let instances = INSTANCES.get_or_init(Default::default);
+
+        // Return the cache for the network, if it exists.
+        if let Some(cache) = instances.read().get(&TypeId::of::<N>()).and_then(|cache| cache.downcast_ref::<Self>()) {
+            return cache.clone();
+        }
+        // Otherwise, initialize the cache for the network.
+        let mut instances = instances.write();
+        let cache = instances.entry(TypeId::of::<N>()).or_insert_with(|| Box::new(Self::default()));
+        match cache.downcast_ref::<Self>() {
+            Some(cache) => cache.clone(),
+            None => unreachable!("The inclusion key cache is keyed by the network type"),
+        }
+    }
+
+    /// Returns the inclusion proving key for the given variant, loading it on a miss.
+    pub fn get(&self, variant: InclusionVariant) -> Result<ProvingKey<N>> {
+        self.get_or_load(variant, || Ok(ProvingKey::new(N::inclusion_proving_key().clone())))
+    }
+
+    /// Returns the proving key for the given variant, calling `load` on a miss.
+    /// The cache is locked while loading, so each variant is loaded at most once for concurrent callers.
+    pub fn get_or_load(
+        &self,
+        variant: InclusionVariant,
+        load: impl FnOnce() -> Result<ProvingKey<N>>,
+    ) -> Result<ProvingKey<N>> {
+        let mut entries = self.entries.write();
+        // On a hit, mark the entry as the most recently used.
+        if let Some(proving_key) = entries.shift_remove(&variant) {
+            entries.insert(variant, proving_key.clone());
+            self.num_hits.fetch_add(1, Ordering::SeqCst);
+            return Ok(proving_key);
+        }
+        self.num_misses.fetch_add(1, Ordering::SeqCst);
+
+        // Load the proving key.
+        let proving_key = load()?;
+        entries.insert(variant, proving_key.clone());
+        // Evict the least recently used entries.
+        Self::evict(&mut entries, self.capacity());
+        Ok(proving_key)
+    }
+
+    /// Loads the inclusion proving keys for the given variants, if they are not already cached.
+    /// This is intended to be called at startup, so the first transactions do not pay the loading cost.
+    pub fn preload(&self, variants: &[InclusionVariant]) -> Result<()> {
+        // Ensure the variants fit in the cache.
+        ensure!(variants.len() <= self.capacity(), "Cannot preload more inclusion keys than the cache capacity");
+        variants.iter().try_for_each(|variant| self.get(*variant).map(|_| ()))
+    }
+
+    /// Returns `true` if the proving key for the given variant is cached.
+    pub fn contains(&self, variant: &InclusionVariant) -> bool {
+        self.entries.read().contains_key(variant)
+    }
+
+    /// Returns the maximum number of entries in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity.load(Ordering::SeqCst)
+    }
+
+    /// Sets the maximum number of entries in the cache, evicting the least recently used entries if needed.
+    pub fn set_capacity(&self, capacity: usize) -> Result<()> {
+        // Ensure the capacity is nonzero.
+        ensure!(capacity > 0, "The inclusion key cache capacity must be greater than zero");
+        let mut entries = self.entries.write();
+        self.capacity.store(capacity, Ordering::SeqCst);
+        Self::evict(&mut entries, capacity);
+        Ok(())
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.entries.write().clear();
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    /// Returns the number of lookups that were found in the cache.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of lookups that were not found in the cache.
+    /// Each miss requires the proving key to be loaded.
+    pub fn num_misses(&self) -> usize {
+        self.num_misses.load(Ordering::SeqCst)
+    }
+
+    /// Removes the least recently used entries until there are at most `capacity` entries.
+    fn evict(entries: &mut IndexMap<InclusionVariant, ProvingKey<N>>, capacity: usize) {
+        while entries.len() > capacity {
+            entries.shift_remove_index(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a loader that counts the number of times it is called.
+    fn counting_loader(counter: &AtomicUsize) -> impl FnOnce() -> Result<ProvingKey<CurrentNetwork>> + '_ {
+        move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(ProvingKey::new(CurrentNetwork::inclusion_proving_key().clone()))
+        }
+    }
+
+    #[test]
+    fn test_concurrent_lookups_load_once_per_variant() {
+        let cache = InclusionKeyCache::<CurrentNetwork>::new(8).unwrap();
+        let variants = [InclusionVariant::Execution(1), InclusionVariant::Execution(2), InclusionVariant::Fee(1)];
+        let num_loads = AtomicUsize::new(0);
+
+        // Look up every variant from several threads at once.
+        let num_threads = 8;
+        std::thread::scope(|scope| {
+            for _ in 0..num_threads {
+                scope.spawn(|| {
+                    for variant in variants {
+                        cache.get_or_load(variant, counting_loader(&num_loads)).unwrap();
+                    }
+                });
+            }
+        });
+
+        // Ensure each variant was loaded exactly once.
+        assert_eq!(num_loads.load(Ordering::SeqCst), variants.len());
+        assert_eq!(cache.num_misses(), variants.len());
+        assert_eq!(cache.num_hits(), num_threads * variants.len() - variants.len());
+        assert_eq!(cache.len(), variants.len());
+    }
+
+    #[test]
+    fn test_eviction_with_capacity_one() {
+        let cache = InclusionKeyCache::<CurrentNetwork>::new(1).unwrap();
+        let num_loads = AtomicUsize::new(0);
+
+        // Load the execution variant.
+        let execution = InclusionVariant::Execution(1);
+        cache.get_or_load(execution, counting_loader(&num_loads)).unwrap();
+        assert!(cache.contains(&execution));
+
+        // Load the fee variant, which evicts the execution variant.
+        let fee = InclusionVariant::Fee(1);
+        cache.get_or_load(fee, counting_loader(&num_loads)).unwrap();
+        assert!(cache.contains(&fee));
+        assert!(!cache.contains(&execution));
+        assert_eq!(cache.len(), 1);
+
+        // Ensure the evicted variant is loaded again.
+        cache.get_or_load(execution, counting_loader(&num_loads)).unwrap();
+        assert_eq!(num_loads.load(Ordering::SeqCst), 3);
+        assert_eq!(cache.num_misses(), 3);
+        assert_eq!(cache.num_hits(), 0);
+    }
+
+    #[test]
+    fn test_eviction_order_and_capacity() {
+        let cache = InclusionKeyCache::<CurrentNetwork>::new(2).unwrap();
+        let (a, b, c) = (InclusionVariant::Execution(1), InclusionVariant::Execution(2), InclusionVariant::Fee(1));
+
+        // Ensure a hit marks the entry as the most recently used.
+        cache.preload(&[a, b]).unwrap();
+        cache.get(a).unwrap();
+        cache.get(c).unwrap();
+        assert!(cache.contains(&a) && cache.contains(&c) && !cache.contains(&b));
+
+        // Ensure shrinking the capacity evicts the least recently used entry.
+        cache.set_capacity(1).unwrap();
+        assert!(cache.contains(&c) && !cache.contains(&a));
+
+        // Ensure the capacity must be nonzero, and preloading must fit in the cache.
+        assert!(InclusionKeyCache::<CurrentNetwork>::new(0).is_err());
+        assert!(cache.set_capacity(0).is_err());
+        assert!(cache.preload(&[a, b]).is_err());
+    }
+
+    #[test]
+    fn test_global_is_shared() {
+        let variant = InclusionVariant::Fee(1);
+        InclusionKeyCache::<CurrentNetwork>::global().preload(&[variant]).unwrap();
+        assert!(InclusionKeyCache::<CurrentNetwork>::global().contains(&variant));
+    }
+}
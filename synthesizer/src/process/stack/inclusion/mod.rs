@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod key_cache;
+pub use key_cache::*;
+
 use crate::{
     BlockStorage,
     BlockStore,
@@ -321,7 +324,7 @@ impl<N: Network> Inclusion<N> {
             }
             false => {
                 // Fetch the inclusion proving key.
-                let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+                let proving_key = InclusionKeyCache::<N>::global().get(InclusionVariant::Execution(assignments.len()))?;
 
                 // Compute the inclusion batch proof.
                 let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
@@ -421,7 +424,7 @@ impl<N: Network> Inclusion<N> {
         }
 
         // Fetch the inclusion proving key.
-        let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+        let proving_key = InclusionKeyCache::<N>::global().get(InclusionVariant::Fee(assignments.len()))?;
 
         // Compute the inclusion batch proof.
         let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
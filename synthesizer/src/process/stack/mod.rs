@@ -17,9 +17,15 @@
 mod authorization;
 pub use authorization::*;
 
+mod certificate_cache;
+pub use certificate_cache::*;
+
 mod deployment;
 pub use deployment::*;
 
+mod evaluate_error;
+pub use evaluate_error::*;
+
 mod execution;
 pub use execution::*;
 
@@ -29,6 +35,9 @@ pub use fee::*;
 mod finalize_registers;
 pub use finalize_registers::*;
 
+mod finalize_trace;
+pub use finalize_trace::*;
+
 mod finalize_types;
 pub use finalize_types::*;
 
@@ -48,6 +57,7 @@ mod execute;
 mod helpers;
 
 use crate::{
+    AssertRange,
     CallOperator,
     Certificate,
     Closure,
@@ -72,6 +82,7 @@ use console::{
         Identifier,
         Literal,
         Locator,
+        OutputID,
         Owner,
         Plaintext,
         PlaintextType,
@@ -84,7 +95,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::{Field, Group, U64},
+    types::{Field, Group, U16, U64},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -104,19 +115,37 @@ pub struct CallMetrics<N: Network> {
     pub num_response_constraints: u64,
 }
 
+/// The mode in which a function is evaluated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EvaluationMode {
+    /// Evaluates the function, returning the response.
+    Standard,
+    /// Evaluates the function purely with console types, returning the response and the metrics of each call frame.
+    /// Note: As no circuit is synthesized in this mode, the constraint counts in the metrics are zero.
+    ConsoleOnly,
+}
+
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
-    Evaluate(Authorization<N>),
+    Evaluate(Authorization<N>, Option<Arc<RwLock<Vec<CallMetrics<N>>>>>),
     Execute(Authorization<N>, Arc<RwLock<Execution<N>>>, Arc<RwLock<Inclusion<N>>>, Arc<RwLock<Vec<CallMetrics<N>>>>),
 }
 
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Ok(CallStack::Evaluate(authorization, None))
+    }
+
+    /// Initializes a call stack as `Self::Evaluate`, which records the metrics of each call frame.
+    pub fn evaluate_with_metrics(
+        authorization: Authorization<N>,
+        metrics: Arc<RwLock<Vec<CallMetrics<N>>>>,
+    ) -> Result<Self> {
+        Ok(CallStack::Evaluate(authorization, Some(metrics)))
     }
 
     /// Initializes a call stack as `Self::Execute`.
@@ -145,7 +174,10 @@ impl<N: Network> CallStack<N> {
                 *private_key,
                 Arc::new(RwLock::new(assignments.read().clone())),
             ),
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
+            CallStack::Evaluate(authorization, metrics) => CallStack::Evaluate(
+                authorization.replicate(),
+                metrics.as_ref().map(|metrics| Arc::new(RwLock::new(metrics.read().clone()))),
+            ),
             CallStack::Execute(authorization, execution, inclusion, metrics) => CallStack::Execute(
                 authorization.replicate(),
                 Arc::new(RwLock::new(execution.read().clone())),
@@ -161,7 +193,7 @@ impl<N: Network> CallStack<N> {
             CallStack::Authorize(requests, ..) => requests.push(request),
             CallStack::Synthesize(requests, ..) => requests.push(request),
             CallStack::CheckDeployment(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -175,7 +207,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, ..) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -188,12 +220,18 @@ impl<N: Network> CallStack<N> {
             | CallStack::CheckDeployment(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
 }
 
+#[cfg(test)]
+thread_local! {
+    /// The number of circuit keys synthesized on the current thread.
+    pub(crate) static NUM_KEY_SYNTHESES: core::cell::Cell<usize> = core::cell::Cell::new(0);
+}
+
 #[derive(Clone)]
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
@@ -254,15 +292,39 @@ impl<N: Network> Stack<N> {
     /// Returns `true` if the stack contains the external record.
     #[inline]
     pub fn contains_external_record(&self, locator: &Locator<N>) -> bool {
-        // Retrieve the external program.
-        match self.get_external_program(locator.program_id()) {
-            // Return `true` if the external record exists.
-            Ok(external_program) => external_program.contains_record(locator.resource()),
-            // Return `false` otherwise.
-            Err(_) => false,
+        self.check_external_record(locator).is_ok()
+    }
+
+    /// Ensures the given locator references a record in a program imported by the current program.
+    #[inline]
+    pub fn check_external_record(&self, locator: &Locator<N>) -> Result<()> {
+        // Resolve the external program.
+        let external_program = self.resolve_external_program(locator)?;
+        // Ensure the external record exists.
+        match external_program.contains_record(locator.resource()) {
+            true => Ok(()),
+            false => bail!("Record '{}' is not defined in '{}'.", locator.resource(), locator.program_id()),
         }
     }
 
+    /// Returns the external program referenced by the given locator.
+    /// This method ensures the locator references a program imported by the current program.
+    #[inline]
+    pub fn resolve_external_program(&self, locator: &Locator<N>) -> Result<&Program<N>> {
+        // Retrieve the program ID.
+        let program_id = locator.program_id();
+        // Ensure the locator does not reference the current program.
+        if self.program_id() == program_id {
+            bail!("Locator '{locator}' does not reference an external program.");
+        }
+        // Ensure the current program contains an import for this external program.
+        if !self.program.imports().contains_key(program_id) {
+            bail!("External program '{program_id}' is not imported by '{}'.", self.program_id());
+        }
+        // Retrieve the external program.
+        self.get_external_program(program_id)
+    }
+
     /// Returns the external stack for the given program ID.
     #[inline]
     pub fn get_external_stack(&self, program_id: &ProgramID<N>) -> Result<&Stack<N>> {
@@ -330,6 +392,28 @@ impl<N: Network> Stack<N> {
         Ok(num_calls)
     }
 
+    /// Returns the program ID and function name of each request for the given function (including the function itself),
+    /// in the order in which they are consumed during execution.
+    pub fn get_call_order(&self, function_name: &Identifier<N>) -> Result<Vec<(ProgramID<N>, Identifier<N>)>> {
+        // Start with the function itself.
+        let mut call_order = vec![(*self.program_id(), *function_name)];
+        for instruction in self.get_function(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine if this is a function call.
+                if call.is_function_call(self)? {
+                    // Append the requests of the callee.
+                    call_order.extend(match call.operator() {
+                        CallOperator::Locator(locator) => {
+                            self.get_external_stack(locator.program_id())?.get_call_order(locator.resource())?
+                        }
+                        CallOperator::Resource(resource) => self.get_call_order(resource)?,
+                    });
+                }
+            }
+        }
+        Ok(call_order)
+    }
+
     /// Returns the register types for the given closure or function name.
     #[inline]
     pub fn get_register_types(&self, name: &Identifier<N>) -> Result<&RegisterTypes<N>> {
@@ -200,9 +200,9 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             RegisterType::ExternalRecord(locator) => {
-                // Ensure the external record type is defined in the program.
-                if !stack.contains_external_record(locator) {
-                    bail!("External record '{locator}' in '{}' is not defined.", stack.program_id())
+                // Ensure the external record type is defined in an imported program.
+                if let Err(error) = stack.check_external_record(locator) {
+                    bail!("External record '{locator}' in '{}' is not defined. {error}", stack.program_id())
                 }
             }
         };
@@ -249,9 +249,9 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             RegisterType::ExternalRecord(locator) => {
-                // Ensure the external record type is defined in the program.
-                if !stack.contains_external_record(locator) {
-                    bail!("External record '{locator}' in '{}' is not defined.", stack.program_id())
+                // Ensure the external record type is defined in an imported program.
+                if let Err(error) = stack.check_external_record(locator) {
+                    bail!("External record '{locator}' in '{}' is not defined. {error}", stack.program_id())
                 }
             }
         };
@@ -301,6 +301,65 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the struct with the given name is defined identically in the current program and the external program.
+    fn check_external_struct(stack: &Stack<N>, external: &Program<N>, struct_name: &Identifier<N>) -> Result<()> {
+        // Retrieve the struct definition from the external program.
+        let expected = external.get_struct(struct_name)?;
+        // Retrieve the struct definition from the current program.
+        let candidate = match stack.program().get_struct(struct_name) {
+            Ok(candidate) => candidate,
+            Err(_) => bail!("Struct '{struct_name}' in '{}' is not defined in '{}'", external.id(), stack.program_id()),
+        };
+
+        // Ensure the struct type hashes match.
+        if candidate.to_type_hash()? != expected.to_type_hash()? {
+            // Find the first mismatched member.
+            let num_members = candidate.members().len().max(expected.members().len());
+            let mismatched_member = (0..num_members)
+                .find_map(|index| match (candidate.members().get_index(index), expected.members().get_index(index)) {
+                    (Some(a), Some(b)) if a == b => None,
+                    (Some((member_name, _)), _) | (None, Some((member_name, _))) => Some(*member_name),
+                    (None, None) => None,
+                })
+                .map(|member_name| member_name.to_string())
+                .unwrap_or_default();
+            bail!(
+                "Struct '{struct_name}' in '{}' does not match its definition in '{}' (member '{mismatched_member}')",
+                stack.program_id(),
+                external.id()
+            )
+        }
+
+        // Ensure the member structs are also defined identically.
+        for plaintext_type in expected.members().values() {
+            if let PlaintextType::Struct(member_struct_name) = plaintext_type {
+                Self::check_external_struct(stack, external, member_struct_name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the struct-typed entries of the given external record are defined identically in the current program.
+    /// The current program only handles the record as an opaque value, so it is not required to define these structs.
+    fn check_external_record(stack: &Stack<N>, external: &Program<N>, record_name: &Identifier<N>) -> Result<()> {
+        // Retrieve the record definition from the external program.
+        let record_type = external.get_record(record_name)?;
+        // Ensure the struct-typed entries are defined identically, if they are defined in the current program.
+        for entry_type in record_type.entries().values() {
+            match entry_type {
+                EntryType::Constant(PlaintextType::Struct(struct_name))
+                | EntryType::Public(PlaintextType::Struct(struct_name))
+                | EntryType::Private(PlaintextType::Struct(struct_name)) => {
+                    if stack.program().contains_struct(struct_name) {
+                        Self::check_external_struct(stack, external, struct_name)?;
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the correct instruction.
     /// This method is called when adding a new closure or function to the program.
     #[inline]
@@ -324,7 +383,7 @@ impl<N: Network> RegisterTypes<N> {
             }
             Opcode::Assert(opcode) => {
                 // Ensure the instruction belongs to the defined set.
-                if !["assert.eq", "assert.neq"].contains(&opcode) {
+                if !["assert.eq", "assert.neq", "assert.range"].contains(&opcode) {
                     bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
                 }
                 // Ensure the instruction is the correct one.
@@ -337,6 +396,10 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::AssertNeq(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "assert.range" => ensure!(
+                        matches!(instruction, Instruction::AssertRange(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -350,25 +413,52 @@ impl<N: Network> RegisterTypes<N> {
                 // Retrieve the operator.
                 match call.operator() {
                     CallOperator::Locator(locator) => {
-                        // Retrieve the program ID.
-                        let program_id = locator.program_id();
                         // Retrieve the resource from the locator.
                         let resource = locator.resource();
 
-                        // Ensure the locator does not reference the current program.
-                        if stack.program_id() == program_id {
-                            bail!("Locator '{locator}' does not reference an external program.");
-                        }
-                        // Ensure the current program contains an import for this external program.
-                        if !stack.program().imports().keys().contains(program_id) {
-                            bail!("External program '{}' is not imported by '{program_id}'.", locator.program_id());
-                        }
-
-                        // Retrieve the program.
-                        let external = stack.get_external_program(program_id)?;
-                        // Ensure the function or closure exists in the program.
-                        if !external.contains_function(resource) && !external.contains_closure(resource) {
+                        // Retrieve the program, ensuring the locator references an imported external program.
+                        let external = stack.resolve_external_program(locator)?;
+                        // Retrieve the struct and record names in the inputs and outputs of the function or closure.
+                        let (struct_names, record_names) = if let Ok(function) = external.get_function(resource) {
+                            let value_types =
+                                function.input_types().into_iter().chain(function.output_types().into_iter());
+                            let mut struct_names = Vec::new();
+                            let mut record_names = Vec::new();
+                            for value_type in value_types {
+                                match value_type {
+                                    ValueType::Constant(PlaintextType::Struct(struct_name))
+                                    | ValueType::Public(PlaintextType::Struct(struct_name))
+                                    | ValueType::Private(PlaintextType::Struct(struct_name)) => {
+                                        struct_names.push(struct_name)
+                                    }
+                                    ValueType::Record(record_name) => record_names.push(record_name),
+                                    _ => (),
+                                }
+                            }
+                            (struct_names, record_names)
+                        } else if let Ok(closure) = external.get_closure(resource) {
+                            let struct_names = closure
+                                .inputs()
+                                .iter()
+                                .map(|input| input.register_type())
+                                .chain(closure.outputs().iter().map(|output| output.register_type()))
+                                .filter_map(|register_type| match register_type {
+                                    RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Some(*struct_name),
+                                    _ => None,
+                                })
+                                .collect::<Vec<_>>();
+                            (struct_names, Vec::new())
+                        } else {
+                            // Ensure the function or closure exists in the program.
                             bail!("'{resource}' is not defined in '{}'.", external.id())
+                        };
+                        // Ensure the structs are defined identically in both programs.
+                        for struct_name in &struct_names {
+                            Self::check_external_struct(stack, external, struct_name)?;
+                        }
+                        // Ensure the struct-typed record entries are defined identically in both programs.
+                        for record_name in &record_names {
+                            Self::check_external_record(stack, external, record_name)?;
                         }
                     }
                     CallOperator::Resource(resource) => {
@@ -501,6 +591,7 @@ impl<N: Network> RegisterTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash_to_group.psd4",
                 ]
                 .contains(&opcode)
                 {
@@ -544,6 +635,10 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash_to_group.psd4" => ensure!(
+                        matches!(instruction, Instruction::HashToGroupPSD4(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
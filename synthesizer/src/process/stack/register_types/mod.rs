@@ -149,7 +149,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 RegisterType::ExternalRecord(locator) => {
                     // Ensure the external record type exists.
-                    ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
+                    stack.check_external_record(locator)?;
                     // Retrieve the member type from the external record.
                     if path_name == &Identifier::from_str("owner")? {
                         // If the member is the owner, then output the address type.
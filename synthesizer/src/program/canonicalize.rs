@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Operand;
+use console::network::prelude::*;
+
+/// An output statement, which can be canonically ordered with `canonicalize_outputs`.
+pub trait OutputStatement<N: Network>: ToBytes + PartialEq {
+    /// Returns the output operand.
+    fn output_operand(&self) -> &Operand<N>;
+}
+
+/// Sorts the given outputs by operand kind, then by their serialized bytes, and removes exact duplicates.
+/// The resulting order is independent of the initial order, which is used for deterministic program hashing.
+pub fn canonicalize_outputs<N: Network, O: OutputStatement<N>>(outputs: &mut Vec<O>) {
+    // Sort the outputs, serializing each output once.
+    outputs.sort_by_cached_key(|output| {
+        // Note: Output statements are always serializable, so the fallback is unreachable in practice.
+        (operand_kind(output.output_operand()), output.to_bytes_le().unwrap_or_default())
+    });
+    // Remove the duplicates, which are now adjacent.
+    outputs.dedup();
+}
+
+/// Returns the rank of the operand kind, in canonical order.
+const fn operand_kind<N: Network>(operand: &Operand<N>) -> u8 {
+    match operand {
+        Operand::Literal(..) => 0,
+        Operand::Register(..) => 1,
+        Operand::ProgramID(..) => 2,
+        Operand::Caller => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{finalize::Finalize, Function};
+    use console::network::Testnet3;
+
+    use rand::seq::SliceRandom;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_canonicalize_function_outputs() {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function outputs:
+    input r0 as field.private;
+    input r1 as u8.public;
+    output r1 as u8.public;
+    output r0 as field.private;
+    output 1field as field.public;
+    output self.caller as address.public;
+    output r0 as field.public;",
+        )
+        .unwrap();
+        let outputs = function.outputs().iter().cloned().collect::<Vec<_>>();
+
+        // Canonicalize the outputs.
+        let mut expected = outputs.clone();
+        canonicalize_outputs(&mut expected);
+        assert_eq!(expected.len(), outputs.len());
+        assert_eq!(expected[0].output_operand(), &Operand::from_str("1field").unwrap());
+        assert_eq!(expected.last().unwrap().output_operand(), &Operand::Caller);
+
+        // Ensure shuffling and duplicating the outputs yields the same canonical order.
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            let mut candidate = [outputs.clone(), outputs.clone()].concat();
+            candidate.shuffle(rng);
+            canonicalize_outputs(&mut candidate);
+            assert_eq!(candidate, expected);
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_finalize_outputs() {
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            r"
+finalize outputs:
+    input r0 as u64.public;
+    input r1 as field.public;
+    output r1 as field.public;
+    output 5u64 as u64.public;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+        let outputs = finalize.outputs().iter().cloned().collect::<Vec<_>>();
+
+        // Canonicalize the outputs.
+        let mut expected = outputs.clone();
+        canonicalize_outputs(&mut expected);
+        assert_eq!(expected.len(), 3);
+
+        // Ensure shuffling and duplicating the outputs yields the same canonical order.
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            let mut candidate = [outputs.clone(), outputs.clone()].concat();
+            candidate.shuffle(rng);
+            canonicalize_outputs(&mut candidate);
+            assert_eq!(candidate, expected);
+        }
+    }
+}
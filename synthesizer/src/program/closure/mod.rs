@@ -23,7 +23,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::Instruction;
+use crate::{Instruction, InstructionVisitor};
 use console::{
     network::prelude::*,
     program::{Identifier, Register, RegisterType},
@@ -69,6 +69,11 @@ impl<N: Network> Closure<N> {
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
     }
+
+    /// Visits each instruction of the closure, in order, with the given visitor.
+    pub fn visit<V: InstructionVisitor<N>>(&self, visitor: &mut V) -> Result<()> {
+        self.instructions.iter().try_for_each(|instruction| visitor.visit_instruction(instruction))
+    }
 }
 
 impl<N: Network> Closure<N> {
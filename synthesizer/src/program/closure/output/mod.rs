@@ -17,7 +17,7 @@
 mod bytes;
 mod parse;
 
-use crate::Operand;
+use crate::{Operand, OutputStatement};
 
 use console::{network::prelude::*, program::RegisterType};
 
@@ -45,6 +45,14 @@ impl<N: Network> Output<N> {
     }
 }
 
+impl<N: Network> OutputStatement<N> for Output<N> {
+    /// Returns the output operand.
+    #[inline]
+    fn output_operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+}
+
 impl<N: Network> TypeName for Output<N> {
     /// Returns the type name as a string.
     #[inline]
@@ -0,0 +1,381 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Instruction, InstructionVisitor, Operand};
+use console::{
+    network::{prelude::*, Testnet3},
+    program::{LiteralType, PlaintextType, Register},
+    types::Field,
+};
+
+use indexmap::IndexMap;
+
+/// The costs (in microcredits) of the instructions and commands in a program.
+///
+/// These numbers are consensus-relevant. To change them, add a new constant set,
+/// and select it for the corresponding network in [`CostTable::for_network`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CostTable {
+    /// The cost of a simple literal operation (i.e. `add`, `and`, `is.eq`, `ternary`).
+    pub literal: u64,
+    /// The cost of an expensive literal operation (i.e. `mul`, `div`, `pow`, `sqrt`).
+    pub literal_expensive: u64,
+    /// The cost of an `assert.eq`, `assert.neq`, or `assert.range` operation.
+    pub assert: u64,
+    /// The cost of a `call` operation, excluding the cost of the callee.
+    pub call: u64,
+    /// The cost of a `cast` operation.
+    pub cast: u64,
+    /// The cost of a `lookup` operation.
+    pub lookup: u64,
+    /// The cost of a BHP hash, for each input chunk.
+    pub bhp_per_chunk: u64,
+    /// The cost of a Pedersen hash, for each input chunk.
+    pub ped_per_chunk: u64,
+    /// The cost of a Poseidon hash, for each permutation.
+    pub psd_per_permutation: u64,
+    /// The additional cost of a commitment, for blinding with the randomizer.
+    pub commit_randomizer: u64,
+    /// The cost of a `finalize` command.
+    pub finalize: u64,
+    /// The cost of an `increment` or `decrement` command.
+    pub mapping_update: u64,
+    /// The cost of each byte in a deployment.
+    pub deployment_per_byte: u64,
+    /// The cost of each byte in an execution.
+    pub execution_per_byte: u64,
+}
+
+impl CostTable {
+    /// The cost table for `Testnet3`.
+    pub const V1: Self = Self {
+        literal: 2,
+        literal_expensive: 10,
+        assert: 2,
+        call: 10,
+        cast: 5,
+        lookup: 10,
+        bhp_per_chunk: 50,
+        ped_per_chunk: 30,
+        psd_per_permutation: 20,
+        commit_randomizer: 60,
+        finalize: 10,
+        mapping_update: 100,
+        deployment_per_byte: 1,
+        execution_per_byte: 1,
+    };
+
+    /// Returns the cost table for the given network.
+    pub fn for_network<N: Network>() -> Result<&'static Self> {
+        match N::ID == Testnet3::ID {
+            true => Ok(&Self::V1),
+            false => bail!("There is no cost table for network '{}'", N::NAME),
+        }
+    }
+
+    /// Returns the cost of the given instruction.
+    /// The `register_sizes` map the locator of each register to its size in bits, if it is known.
+    pub fn instruction_cost<N: Network>(
+        &self,
+        instruction: &Instruction<N>,
+        register_sizes: &IndexMap<u64, u64>,
+    ) -> Result<u64> {
+        let mut visitor = InstructionCost::new(self, register_sizes);
+        visitor.visit_instruction(instruction)?;
+        Ok(visitor.cost())
+    }
+
+    /// Returns the size in bits of the given literal type, if it is fixed.
+    pub fn literal_type_size_in_bits<N: Network>(literal_type: &LiteralType) -> Option<u64> {
+        match literal_type {
+            LiteralType::Address | LiteralType::Field | LiteralType::Group => Some(Field::<N>::size_in_bits() as u64),
+            LiteralType::Scalar => Some(console::types::Scalar::<N>::size_in_bits() as u64),
+            LiteralType::Boolean => Some(1),
+            LiteralType::I8 | LiteralType::U8 => Some(8),
+            LiteralType::I16 | LiteralType::U16 => Some(16),
+            LiteralType::I32 | LiteralType::U32 => Some(32),
+            LiteralType::I64 | LiteralType::U64 => Some(64),
+            LiteralType::I128 | LiteralType::U128 => Some(128),
+            LiteralType::String => None,
+        }
+    }
+
+    /// Returns the size in bits of the given plaintext type, if it is fixed.
+    pub fn plaintext_type_size_in_bits<N: Network>(plaintext_type: &PlaintextType<N>) -> Option<u64> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Self::literal_type_size_in_bits::<N>(literal_type),
+            PlaintextType::Struct(..) => None,
+        }
+    }
+}
+
+impl CostTable {
+    /// Returns the cost of the given hash or commit instruction, which scales with the size of its first operand.
+    fn hash_cost<N: Network>(&self, instruction: &Instruction<N>, register_sizes: &IndexMap<u64, u64>) -> Result<u64> {
+        // Retrieve the size in bits of the first operand.
+        let size_in_bits = || match instruction.operands().first() {
+            Some(operand) => Ok(Self::operand_size_in_bits(operand, register_sizes)),
+            None => bail!("Instruction '{}' has no operands", instruction.opcode()),
+        };
+        // Returns the cost of hashing the first operand in chunks of the given size.
+        let per_chunk = |cost: u64, chunk_size_in_bits: u64| -> Result<u64> {
+            Self::checked_mul(cost, Self::num_chunks(size_in_bits()?, chunk_size_in_bits))
+        };
+        // Returns the cost of hashing the first operand with a Poseidon permutation of the given rate.
+        let per_permutation = |rate: u64| -> Result<u64> {
+            let num_fields = Self::num_chunks(size_in_bits()?, Field::<N>::size_in_data_bits() as u64);
+            Self::checked_mul(self.psd_per_permutation, Self::num_chunks(num_fields, rate))
+        };
+        // Returns the cost of committing to the first operand in chunks of the given size.
+        let commit = |cost: u64, chunk_size_in_bits: u64| -> Result<u64> {
+            per_chunk(cost, chunk_size_in_bits)?
+                .checked_add(self.commit_randomizer)
+                .ok_or_else(|| anyhow!("Overflow in the cost of '{}'", instruction.opcode()))
+        };
+
+        match instruction {
+            Instruction::CommitBHP256(..) => commit(self.bhp_per_chunk, 256),
+            Instruction::CommitBHP512(..) => commit(self.bhp_per_chunk, 512),
+            Instruction::CommitBHP768(..) => commit(self.bhp_per_chunk, 768),
+            Instruction::CommitBHP1024(..) => commit(self.bhp_per_chunk, 1024),
+            Instruction::CommitPED64(..) => commit(self.ped_per_chunk, 64),
+            Instruction::CommitPED128(..) => commit(self.ped_per_chunk, 128),
+            Instruction::HashBHP256(..) => per_chunk(self.bhp_per_chunk, 256),
+            Instruction::HashBHP512(..) => per_chunk(self.bhp_per_chunk, 512),
+            Instruction::HashBHP768(..) => per_chunk(self.bhp_per_chunk, 768),
+            Instruction::HashBHP1024(..) => per_chunk(self.bhp_per_chunk, 1024),
+            Instruction::HashPED64(..) => per_chunk(self.ped_per_chunk, 64),
+            Instruction::HashPED128(..) => per_chunk(self.ped_per_chunk, 128),
+            Instruction::HashPSD2(..) => per_permutation(2),
+            Instruction::HashPSD4(..) => per_permutation(4),
+            Instruction::HashPSD8(..) => per_permutation(8),
+            // Hashing to a group additionally maps two field elements onto the curve, each costing a square root.
+            Instruction::HashToGroupPSD4(..) => per_permutation(4)?
+                .checked_add(2 * self.literal_expensive)
+                .ok_or_else(|| anyhow!("Overflow in the cost of '{}'", instruction.opcode())),
+            _ => bail!("Instruction '{}' is not a hash or commit instruction", instruction.opcode()),
+        }
+    }
+
+    /// Returns the size in bits of the given operand.
+    /// If the size is not known, the operand is priced as a single field element.
+    fn operand_size_in_bits<N: Network>(operand: &Operand<N>, register_sizes: &IndexMap<u64, u64>) -> u64 {
+        match operand {
+            Operand::Literal(literal) => literal.size_in_bits() as u64,
+            Operand::Register(Register::Locator(locator)) => match register_sizes.get(locator) {
+                Some(size_in_bits) => *size_in_bits,
+                None => Field::<N>::size_in_bits() as u64,
+            },
+            Operand::Register(Register::Member(..)) | Operand::ProgramID(..) | Operand::Caller => {
+                Field::<N>::size_in_bits() as u64
+            }
+        }
+    }
+
+    /// Returns the number of chunks of the given size needed to cover the given number of bits (at least one).
+    fn num_chunks(size_in_bits: u64, chunk_size_in_bits: u64) -> u64 {
+        ((size_in_bits + chunk_size_in_bits - 1) / chunk_size_in_bits).max(1)
+    }
+
+    /// Returns `cost * multiplier`, or an error on overflow.
+    fn checked_mul(cost: u64, multiplier: u64) -> Result<u64> {
+        cost.checked_mul(multiplier).ok_or_else(|| anyhow!("Overflow in the instruction cost"))
+    }
+}
+
+/// An instruction visitor that sums the cost (in microcredits) of the visited instructions.
+pub struct InstructionCost<'a> {
+    /// The cost table.
+    table: &'a CostTable,
+    /// The size in bits of each register, by locator, where known.
+    register_sizes: &'a IndexMap<u64, u64>,
+    /// The total cost of the visited instructions.
+    cost: u64,
+}
+
+impl<'a> InstructionCost<'a> {
+    /// Initializes a new instruction cost visitor.
+    /// The `register_sizes` map the locator of each register to its size in bits, if it is known.
+    pub const fn new(table: &'a CostTable, register_sizes: &'a IndexMap<u64, u64>) -> Self {
+        Self { table, register_sizes, cost: 0 }
+    }
+
+    /// Returns the total cost of the visited instructions.
+    pub const fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    /// Adds the given cost to the total.
+    fn add(&mut self, cost: u64) -> Result<()> {
+        self.cost = self.cost.checked_add(cost).ok_or_else(|| anyhow!("Overflow in the instruction cost"))?;
+        Ok(())
+    }
+}
+
+impl<N: Network> InstructionVisitor<N> for InstructionCost<'_> {
+    fn visit_arithmetic(&mut self, instruction: &Instruction<N>) -> Result<()> {
+        match instruction {
+            Instruction::Div(..)
+            | Instruction::DivWrapped(..)
+            | Instruction::Inv(..)
+            | Instruction::Modulo(..)
+            | Instruction::Mul(..)
+            | Instruction::MulWrapped(..)
+            | Instruction::Pow(..)
+            | Instruction::PowWrapped(..)
+            | Instruction::Rem(..)
+            | Instruction::RemWrapped(..)
+            | Instruction::Square(..)
+            | Instruction::SquareRoot(..) => self.add(self.table.literal_expensive),
+            _ => self.add(self.table.literal),
+        }
+    }
+
+    fn visit_hash(&mut self, instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.hash_cost(instruction, self.register_sizes)?)
+    }
+
+    fn visit_commit(&mut self, instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.hash_cost(instruction, self.register_sizes)?)
+    }
+
+    fn visit_call(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.call)
+    }
+
+    fn visit_cast(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.cast)
+    }
+
+    fn visit_assert(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.assert)
+    }
+
+    fn visit_is(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.literal)
+    }
+
+    fn visit_ternary(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.literal)
+    }
+
+    fn visit_lookup(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        self.add(self.table.lookup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Function;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_cost_table_for_network() {
+        assert_eq!(CostTable::for_network::<CurrentNetwork>().unwrap(), &CostTable::V1);
+    }
+
+    #[test]
+    fn test_function_cost() {
+        // Pin the cost of an arithmetic function.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    is.eq r3 r0 into r4;
+    output r3 as u64.private;",
+        )
+        .unwrap();
+        assert_eq!(function.cost_in_microcredits().unwrap(), 2 + 10 + 2);
+
+        // Pin the cost of a function with hashes and commitments on single-chunk inputs.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function digest:
+    input r0 as field.private;
+    input r1 as u8.private;
+    hash.bhp256 r0 into r2;
+    hash.bhp256 r1 into r3;
+    hash.psd2 r0 into r4;
+    commit.ped64 r1 1scalar into r5;
+    output r2 as field.private;",
+        )
+        .unwrap();
+        assert_eq!(function.cost_in_microcredits().unwrap(), 50 + 50 + 20 + (30 + 60));
+
+        // Pin the cost of a function with a finalize scope.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    finalize r0 r1 r2;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    decrement account[r0] by r2;
+    increment account[r1] by r2;",
+        )
+        .unwrap();
+        assert_eq!(function.cost_in_microcredits().unwrap(), 10);
+        assert_eq!(function.finalize_logic().unwrap().cost_in_microcredits().unwrap(), 100 + 100);
+    }
+
+    #[test]
+    fn test_hash_costs_more_than_arithmetic() {
+        let arithmetic = Function::<CurrentNetwork>::from_str(
+            r"
+function arithmetic:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    add r1 r0 into r2;
+    add r2 r0 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+        let hashes = Function::<CurrentNetwork>::from_str(
+            r"
+function hashes:
+    input r0 as field.private;
+    hash.bhp1024 r0 into r1;
+    hash.bhp1024 r1 into r2;
+    hash.bhp1024 r2 into r3;
+    output r3 as field.private;",
+        )
+        .unwrap();
+        assert_eq!(arithmetic.instructions().len(), hashes.instructions().len());
+        assert!(hashes.cost_in_microcredits().unwrap() > arithmetic.cost_in_microcredits().unwrap());
+    }
+
+    #[test]
+    fn test_operand_size_multiplier() {
+        let table = CostTable::V1;
+
+        // A 1024-bit input spans four BHP256 chunks.
+        let instruction = Instruction::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1;").unwrap();
+        let register_sizes = IndexMap::from([(0, 1024)]);
+        assert_eq!(table.instruction_cost(&instruction, &register_sizes).unwrap(), 4 * table.bhp_per_chunk);
+        let register_sizes = IndexMap::from([(0, 256)]);
+        assert_eq!(table.instruction_cost(&instruction, &register_sizes).unwrap(), table.bhp_per_chunk);
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Operand, Program};
+
+/// The result of statically analyzing a finalize block.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FinalizeAnalysis<N: Network> {
+    /// For each command (in order), whether the command is reachable.
+    reachable: Vec<bool>,
+    /// The destination registers that are written to, but never read from.
+    unused_registers: IndexSet<Register<N>>,
+    /// The input registers that are never read from.
+    unused_inputs: IndexSet<Register<N>>,
+    /// The mappings referenced by the commands, in order of first reference.
+    mappings: IndexSet<Identifier<N>>,
+}
+
+impl<N: Network> FinalizeAnalysis<N> {
+    /// Returns, for each command (in order), whether the command is reachable.
+    pub fn reachable(&self) -> &[bool] {
+        &self.reachable
+    }
+
+    /// Returns the indices of the commands that are unreachable.
+    pub fn unreachable_commands(&self) -> Vec<usize> {
+        self.reachable.iter().enumerate().filter(|(_, reachable)| !**reachable).map(|(index, _)| index).collect()
+    }
+
+    /// Returns the destination registers that are written to, but never read from.
+    pub const fn unused_registers(&self) -> &IndexSet<Register<N>> {
+        &self.unused_registers
+    }
+
+    /// Returns the input registers that are never read from.
+    pub const fn unused_inputs(&self) -> &IndexSet<Register<N>> {
+        &self.unused_inputs
+    }
+
+    /// Returns the mappings referenced by the commands.
+    pub const fn mappings(&self) -> &IndexSet<Identifier<N>> {
+        &self.mappings
+    }
+
+    /// Returns the referenced mappings that are not declared in the given program.
+    pub fn undeclared_mappings(&self, program: &Program<N>) -> Vec<Identifier<N>> {
+        self.mappings.iter().filter(|mapping| !program.contains_mapping(mapping)).copied().collect()
+    }
+}
+
+impl<N: Network> Finalize<N> {
+    /// Statically analyzes the finalize block, returning its reachability, unused registers, and referenced mappings.
+    pub fn analyze(&self) -> FinalizeAnalysis<N> {
+        // Collect the locators of every register that is read from.
+        let mut reads = IndexSet::new();
+        let mut record_reads = |operands: &[Operand<N>]| {
+            for operand in operands {
+                if let Operand::Register(register) = operand {
+                    reads.insert(register.locator());
+                }
+            }
+        };
+
+        // Collect the destination registers and referenced mappings.
+        let mut destinations = IndexSet::new();
+        let mut mappings = IndexSet::new();
+        for command in &self.commands {
+            match command {
+                Command::Decrement(decrement) => {
+                    record_reads(&decrement.operands());
+                    mappings.insert(*decrement.mapping_name());
+                }
+                Command::Instruction(instruction) => {
+                    record_reads(instruction.operands());
+                    destinations.extend(instruction.destinations());
+                }
+                Command::Increment(increment) => {
+                    record_reads(&increment.operands());
+                    mappings.insert(*increment.mapping_name());
+                }
+            }
+        }
+        for output in &self.outputs {
+            record_reads(std::slice::from_ref(output.operand()));
+        }
+
+        // Determine the registers that are never read from.
+        let unused_registers =
+            destinations.into_iter().filter(|register| !reads.contains(&register.locator())).collect();
+        let unused_inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.register().clone())
+            .filter(|register| !reads.contains(&register.locator()))
+            .collect();
+
+        // Finalize does not contain branch instructions (yet), so every command is reachable.
+        let reachable = vec![true; self.commands.len()];
+
+        FinalizeAnalysis { reachable, unused_registers, unused_inputs, mappings }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_analyze_unused_input_and_undeclared_mapping() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program test.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function foo:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        )?;
+
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            r"
+finalize foo:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 1u64 into r3;
+    add r1 2u64 into r4;
+    increment account[r0] by r3;
+    decrement balances[r0] by r3;
+    output r1 as u64.public;",
+        )?;
+
+        let analysis = finalize.analyze();
+        assert_eq!(analysis.reachable(), &[true; 5]);
+        assert!(analysis.unreachable_commands().is_empty());
+        assert_eq!(analysis.unused_inputs().iter().cloned().collect::<Vec<_>>(), vec![Register::Locator(2)]);
+        assert_eq!(analysis.unused_registers().iter().cloned().collect::<Vec<_>>(), vec![Register::Locator(4)]);
+        assert_eq!(
+            analysis.mappings().iter().copied().collect::<Vec<_>>(),
+            vec![Identifier::from_str("account")?, Identifier::from_str("balances")?]
+        );
+        assert_eq!(analysis.undeclared_mappings(&program), vec![Identifier::from_str("balances")?]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_clean_finalize() -> Result<()> {
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            r"
+finalize foo:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;",
+        )?;
+
+        let analysis = finalize.analyze();
+        assert_eq!(analysis.reachable(), &[true]);
+        assert!(analysis.unused_inputs().is_empty());
+        assert!(analysis.unused_registers().is_empty());
+        Ok(())
+    }
+}
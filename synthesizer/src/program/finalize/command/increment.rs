@@ -83,7 +83,7 @@ impl<N: Network> Increment<N> {
         let increment = registers.load_literal(stack, &self.second)?;
 
         // Retrieve the starting value from storage as a literal.
-        let start = match store.get_value(stack.program_id(), &self.mapping, &key)? {
+        let start = match registers.get_value(store, stack.program_id(), &self.mapping, &key)? {
             Some(Value::Plaintext(Plaintext::Literal(literal, _))) => literal,
             Some(Value::Plaintext(Plaintext::Struct(..))) => bail!("Cannot 'increment' by an 'struct'"),
             Some(Value::Record(..)) => bail!("Cannot 'increment' by a 'record'"),
@@ -129,8 +129,8 @@ impl<N: Network> Increment<N> {
 
         // Construct the value.
         let value = Value::Plaintext(Plaintext::from(outcome));
-        // Update the value in storage.
-        store.update_key_value(stack.program_id(), &self.mapping, key, value)?;
+        // Queue the value, to be stored when the finalize scope is flushed.
+        registers.update_value(&self.mapping, key, value);
 
         Ok(())
     }
@@ -49,6 +49,9 @@ impl<N: Network> Command<N> {
             Command::Decrement(decrement) => decrement.evaluate_finalize(stack, store, registers),
             // TODO (howardwu): Implement support for instructions (consider using a trait for `Registers::load/store`).
             // Command::Instruction(instruction) => instruction.evaluate_finalize(stack, registers),
+            Command::Instruction(Instruction::AssertRange(assert_range)) => {
+                assert_range.evaluate_finalize(stack, registers)
+            }
             Command::Instruction(_) => bail!("Instructions in 'finalize' are not supported (yet)."),
             Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers),
         }
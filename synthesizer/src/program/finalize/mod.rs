@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod analyze;
+pub use analyze::*;
+
 mod command;
 pub use command::*;
 
@@ -26,7 +29,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::Instruction;
+use crate::{CostTable, Instruction};
 use console::{
     network::prelude::*,
     program::{FinalizeType, Identifier, Register},
@@ -82,6 +85,35 @@ impl<N: Network> Finalize<N> {
     pub fn output_types(&self) -> Vec<FinalizeType<N>> {
         self.outputs.iter().map(|output| *output.finalize_type()).collect()
     }
+
+    /// Returns the cost (in microcredits) of the finalize commands.
+    pub fn cost_in_microcredits(&self) -> Result<u64> {
+        // Retrieve the cost table.
+        let table = CostTable::for_network::<N>()?;
+        // Retrieve the sizes of the input registers, where known.
+        let register_sizes = self
+            .inputs
+            .iter()
+            .filter_map(|input| match (input.register(), input.finalize_type()) {
+                (Register::Locator(locator), FinalizeType::Public(plaintext_type)) => {
+                    CostTable::plaintext_type_size_in_bits::<N>(plaintext_type).map(|size| (*locator, size))
+                }
+                _ => None,
+            })
+            .collect();
+        // Sum the cost of each command.
+        let mut cost = 0u64;
+        for command in &self.commands {
+            let command_cost = match command {
+                Command::Decrement(..) | Command::Increment(..) => table.mapping_update,
+                Command::Instruction(instruction) => table.instruction_cost(instruction, &register_sizes)?,
+            };
+            cost = cost
+                .checked_add(command_cost)
+                .ok_or_else(|| anyhow!("Overflow in the cost of finalize '{}'", self.name))?;
+        }
+        Ok(cost)
+    }
 }
 
 impl<N: Network> Finalize<N> {
@@ -17,7 +17,7 @@
 mod bytes;
 mod parse;
 
-use crate::Operand;
+use crate::{Operand, OutputStatement};
 
 use console::{network::prelude::*, program::FinalizeType};
 
@@ -45,6 +45,22 @@ impl<N: Network> Output<N> {
     }
 }
 
+impl<N: Network> Output<N> {
+    /// Returns `true` if the outputs have the same declared finalize type and operand kind.
+    /// Unlike `PartialEq`, two outputs from different register locators are considered equal.
+    pub fn structurally_eq(&self, other: &Self) -> bool {
+        self.finalize_type == other.finalize_type && self.operand.structurally_eq(&other.operand)
+    }
+}
+
+impl<N: Network> OutputStatement<N> for Output<N> {
+    /// Returns the output operand.
+    #[inline]
+    fn output_operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+}
+
 impl<N: Network> TypeName for Output<N> {
     /// Returns the type name as a string.
     #[inline]
@@ -60,6 +76,23 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_output_structurally_eq() -> Result<()> {
+        let check = |a: &str, b: &str| -> Result<bool> {
+            Ok(Output::<CurrentNetwork>::from_str(a)?.structurally_eq(&Output::from_str(b)?))
+        };
+        // Ensure renumbered registers are structurally equal, but not strictly equal.
+        assert!(check("output r0 as field.public;", "output r5 as field.public;")?);
+        let output = Output::<CurrentNetwork>::from_str("output r0 as field.public;")?;
+        assert_ne!(output, Output::from_str("output r5 as field.public;")?);
+        assert!(check("output r0.owner as field.public;", "output r2.owner as field.public;")?);
+        // Ensure genuinely different outputs are not structurally equal.
+        assert!(!check("output r0 as field.public;", "output r0 as u8.public;")?);
+        assert!(!check("output r0.owner as field.public;", "output r0.gates as field.public;")?);
+        assert!(!check("output r0 as field.public;", "output r0.owner as field.public;")?);
+        Ok(())
+    }
+
     #[test]
     fn test_output_type_name() {
         assert_eq!(Output::<CurrentNetwork>::type_name(), "output");
@@ -19,13 +19,17 @@ use input::*;
 
 mod output;
 use output::*;
+pub use output::{Output as FunctionOutput, ParseError};
 
 mod bytes;
 mod parse;
 
 use crate::{
     program::finalize::{Finalize, FinalizeCommand},
+    CostTable,
     Instruction,
+    InstructionCost,
+    InstructionVisitor,
 };
 use console::{
     network::prelude::*,
@@ -99,6 +103,43 @@ impl<N: Network> Function<N> {
     pub fn finalize_logic(&self) -> Option<&Finalize<N>> {
         self.finalize.as_ref().map(|(_, finalize)| finalize)
     }
+
+    /// Visits each instruction of the function, in order, with the given visitor.
+    pub fn visit<V: InstructionVisitor<N>>(&self, visitor: &mut V) -> Result<()> {
+        self.instructions.iter().try_for_each(|instruction| visitor.visit_instruction(instruction))
+    }
+
+    /// Returns the cost (in microcredits) of the function instructions and finalize command.
+    /// This excludes the cost of the finalize logic, see [`Finalize::cost_in_microcredits`].
+    pub fn cost_in_microcredits(&self) -> Result<u64> {
+        // Retrieve the cost table.
+        let table = CostTable::for_network::<N>()?;
+        // Retrieve the sizes of the input registers, where known.
+        let register_sizes = self
+            .inputs
+            .iter()
+            .filter_map(|input| match (input.register(), input.value_type()) {
+                (
+                    Register::Locator(locator),
+                    ValueType::Constant(plaintext_type)
+                    | ValueType::Public(plaintext_type)
+                    | ValueType::Private(plaintext_type),
+                ) => CostTable::plaintext_type_size_in_bits::<N>(plaintext_type).map(|size| (*locator, size)),
+                _ => None,
+            })
+            .collect();
+        // Sum the cost of each instruction.
+        let mut visitor = InstructionCost::new(table, &register_sizes);
+        self.visit(&mut visitor).map_err(|error| anyhow!("Invalid cost for function '{}': {error}", self.name))?;
+        let mut cost = visitor.cost();
+        // Add the cost of the finalize command.
+        if self.finalize.is_some() {
+            cost = cost
+                .checked_add(table.finalize)
+                .ok_or_else(|| anyhow!("Overflow in the cost of function '{}'", self.name))?;
+        }
+        Ok(cost)
+    }
 }
 
 impl<N: Network> Function<N> {
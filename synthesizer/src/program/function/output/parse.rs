@@ -64,6 +64,76 @@ impl<N: Network> FromStr for Output<N> {
     }
 }
 
+/// An error encountered while parsing a statement, along with its byte offset in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset of the statement in the input.
+    offset: usize,
+    /// The error message.
+    message: String,
+}
+
+impl ParseError {
+    /// Returns the byte offset of the statement in the input.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    /// Prints the parse error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.offset)
+    }
+}
+
+impl<N: Network> Output<N> {
+    /// Parses a block of output statements, recovering from errors at `;` boundaries.
+    ///
+    /// Returns every output statement that parsed, along with an error for each statement that did not.
+    pub fn parse_all(string: &str) -> (Vec<Self>, Vec<ParseError>) {
+        let mut outputs = Vec::new();
+        let mut errors = Vec::new();
+
+        let mut remainder = string;
+        loop {
+            // Skip the whitespace and comments before the next statement.
+            if let Ok((string, _)) = Sanitizer::parse(remainder) {
+                remainder = string;
+            }
+            // If the input is exhausted, then stop.
+            if remainder.is_empty() {
+                break;
+            }
+
+            // Compute the byte offset of the statement.
+            let offset = string.len() - remainder.len();
+            match Self::parse(remainder) {
+                Ok((string, output)) => {
+                    outputs.push(output);
+                    remainder = string;
+                }
+                Err(error) => {
+                    let message = format!("Failed to parse output statement. {error}");
+                    errors.push(ParseError { offset, message });
+                    // Recover at the end of the statement, if there is one.
+                    match remainder.find(';') {
+                        Some(index) => remainder = &remainder[index + 1..],
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        (outputs, errors)
+    }
+}
+
 impl<N: Network> Debug for Output<N> {
     /// Prints the output as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -137,4 +207,47 @@ mod tests {
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
         assert_eq!(format!("{output}"), "output r2 as token.record;");
     }
+
+    #[test]
+    fn test_output_parse_all() -> Result<()> {
+        let string = r"
+    output r0 as field.private;
+    output r1 u8.public;
+    output r2 as token.record; // The minted record.
+    output 0u8 as u8.public;";
+
+        let (outputs, errors) = Output::<CurrentNetwork>::parse_all(string);
+
+        // Ensure the valid statements are parsed.
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs[0], Output::from_str("output r0 as field.private;")?);
+        assert_eq!(outputs[1], Output::from_str("output r2 as token.record;")?);
+        assert_eq!(outputs[2], Output::from_str("output 0u8 as u8.public;")?);
+
+        // Ensure the broken statement is reported at its offset.
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].offset(), string.find("output r1").unwrap());
+        assert!(errors[0].message().starts_with("Failed to parse output statement."));
+
+        // Ensure a trailing statement without a semicolon is reported.
+        let (outputs, errors) = Output::<CurrentNetwork>::parse_all("output r0 as field.private; output r1 as");
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].offset(), 28);
+
+        // Ensure an empty input yields nothing.
+        let (outputs, errors) = Output::<CurrentNetwork>::parse_all("  \n");
+        assert!(outputs.is_empty() && errors.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_parse_all_is_exported() {
+        // Ensure `parse_all` and its error type are reachable from the crate root.
+        let (outputs, errors): (_, Vec<crate::ParseError>) =
+            crate::FunctionOutput::<CurrentNetwork>::parse_all("output r0 as field.private; output r1;");
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].offset(), 28);
+    }
 }
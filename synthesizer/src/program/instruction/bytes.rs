@@ -95,4 +95,39 @@ mod tests {
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_hash_to_group() -> Result<()> {
+        let instruction = "hash_to_group.psd4 r0 into r1;";
+        let expected = Instruction::<CurrentNetwork>::from_str(instruction)?;
+        assert!(matches!(expected, Instruction::HashToGroupPSD4(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the opcode follows the opcodes that precede it.
+        let index = Instruction::<CurrentNetwork>::OPCODES.iter().position(|opcode| *opcode == expected.opcode());
+        assert_eq!(index, Some(57));
+        assert_eq!(expected_bytes[..2], 57u16.to_le_bytes());
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_assert_range() -> Result<()> {
+        let instruction = "assert.range r0 0u64 1000000u64;";
+        let expected = Instruction::<CurrentNetwork>::from_str(instruction)?;
+        assert!(matches!(expected, Instruction::AssertRange(..)));
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the opcode is appended to the end of the opcode list.
+        let index = Instruction::<CurrentNetwork>::OPCODES.len() - 1;
+        assert_eq!(expected_bytes[..2], (index as u16).to_le_bytes());
+
+        let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(instruction, candidate.to_string());
+        Ok(())
+    }
 }
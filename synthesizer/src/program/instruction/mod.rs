@@ -23,6 +23,9 @@ pub use operand::*;
 mod operation;
 pub use operation::*;
 
+mod visitor;
+pub use visitor::*;
+
 mod bytes;
 mod parse;
 
@@ -73,6 +76,8 @@ pub enum Instruction<N: Network> {
     AssertEq(AssertEq<N>),
     /// Asserts `first` and `second` are **not** equal.
     AssertNeq(AssertNeq<N>),
+    /// Asserts `first` lies within the inclusive range from `second` to `third`.
+    AssertRange(AssertRange<N>),
     /// Calls a closure on the operands.
     Call(Call<N>),
     /// Casts the operands into the declared type.
@@ -117,6 +122,8 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a Poseidon hash-to-group with an input rate of 4.
+    HashToGroupPSD4(HashToGroupPSD4<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -262,6 +269,8 @@ macro_rules! instruction {
             Ternary,
             Xor,
             Lookup,
+            HashToGroupPSD4,
+            AssertRange,
         }}
     };
     // A variant **without** curly braces:
@@ -356,6 +365,15 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns `true` if the instruction always produces the same outputs for the same operands.
+    ///
+    /// A `call` may invoke a function, which samples fresh randomness (i.e. for record nonces),
+    /// so it is conservatively treated as non-deterministic.
+    #[inline]
+    pub const fn is_deterministic(&self) -> bool {
+        !matches!(self, Self::Call(..))
+    }
+
     /// Evaluates the instruction.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N, BaseField = N::Field>>(
@@ -408,9 +426,23 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            57,
+            59,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
     }
+
+    #[test]
+    fn test_is_deterministic() {
+        for (instruction, expected) in [
+            ("add r0 r1 into r2;", true),
+            ("hash.psd2 r0 into r1;", true),
+            ("commit.bhp256 r0 r1 into r2;", true),
+            ("cast r0 r1 into r2 as foo;", true),
+            ("call foo r0 into r1;", false),
+        ] {
+            let instruction = Instruction::<CurrentNetwork>::from_str(instruction).unwrap();
+            assert_eq!(instruction.is_deterministic(), expected, "Mismatch for '{instruction}'");
+        }
+    }
 }
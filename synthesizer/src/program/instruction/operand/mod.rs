@@ -68,6 +68,36 @@ impl<N: Network> From<&Register<N>> for Operand<N> {
     }
 }
 
+impl<N: Network> Operand<N> {
+    /// Returns `true` if the operands are of the same kind, ignoring the locators of registers.
+    /// Register members are only equal if they access the same identifiers.
+    pub fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Register(Register::Locator(..)), Self::Register(Register::Locator(..))) => true,
+            (Self::Register(Register::Member(_, a)), Self::Register(Register::Member(_, b))) => a == b,
+            (a, b) => a == b,
+        }
+    }
+
+    /// Returns the value of the operand as a `u64`, if it is an integer literal within the range of a `u64`.
+    /// Otherwise, returns `None`.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Literal(Literal::I8(value)) => u64::try_from(**value).ok(),
+            Self::Literal(Literal::I16(value)) => u64::try_from(**value).ok(),
+            Self::Literal(Literal::I32(value)) => u64::try_from(**value).ok(),
+            Self::Literal(Literal::I64(value)) => u64::try_from(**value).ok(),
+            Self::Literal(Literal::I128(value)) => u64::try_from(**value).ok(),
+            Self::Literal(Literal::U8(value)) => Some(u64::from(**value)),
+            Self::Literal(Literal::U16(value)) => Some(u64::from(**value)),
+            Self::Literal(Literal::U32(value)) => Some(u64::from(**value)),
+            Self::Literal(Literal::U64(value)) => Some(**value),
+            Self::Literal(Literal::U128(value)) => u64::try_from(**value).ok(),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +125,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_operand_structurally_eq() -> Result<()> {
+        let check = |a: &str, b: &str| -> Result<bool> {
+            Ok(Operand::<CurrentNetwork>::from_str(a)?.structurally_eq(&Operand::from_str(b)?))
+        };
+        assert!(check("r0", "r7")?);
+        assert!(check("r0.owner", "r3.owner")?);
+        assert!(check("1field", "1field")?);
+        assert!(check("self.caller", "self.caller")?);
+        assert!(!check("r0", "r0.owner")?);
+        assert!(!check("r0.owner", "r0.gates")?);
+        assert!(!check("1field", "2field")?);
+        assert!(!check("r0", "1field")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_operand_as_u64() -> Result<()> {
+        let check =
+            |operand: &str| -> Result<Option<u64>> { Ok(Operand::<CurrentNetwork>::from_str(operand)?.as_u64()) };
+        // Ensure integer literals within the range of a `u64` are returned.
+        assert_eq!(check("0u8")?, Some(0));
+        assert_eq!(check("255u8")?, Some(255));
+        assert_eq!(check("7i16")?, Some(7));
+        assert_eq!(check("65535u16")?, Some(65535));
+        assert_eq!(check("2147483647i32")?, Some(2147483647));
+        assert_eq!(check("18446744073709551615u64")?, Some(u64::MAX));
+        assert_eq!(check("18446744073709551615u128")?, Some(u64::MAX));
+        assert_eq!(check("9223372036854775807i64")?, Some(i64::MAX as u64));
+        // Ensure integer literals outside the range of a `u64` are not returned.
+        assert_eq!(check("-1i8")?, None);
+        assert_eq!(check("-9223372036854775808i64")?, None);
+        assert_eq!(check("18446744073709551616u128")?, None);
+        assert_eq!(check("-1i128")?, None);
+        // Ensure non-integer literals and non-literal operands are not returned.
+        assert_eq!(check("1field")?, None);
+        assert_eq!(check("true")?, None);
+        assert_eq!(check("r0")?, None);
+        assert_eq!(check("r0.owner")?, None);
+        assert_eq!(check("self.caller")?, None);
+        assert_eq!(check("credits.aleo")?, None);
+        Ok(())
+    }
+
     #[test]
     fn test_operand_from_register_member() -> Result<()> {
         let register = Register::from_str("r0.owner")?;
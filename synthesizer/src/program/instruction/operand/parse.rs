@@ -20,9 +20,21 @@ impl<N: Network> Parser for Operand<N> {
     /// Parses a string into a operand.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a literal grouped in parentheses, i.e. `(1scalar)`.
+        fn parse_grouped_literal<N: Network>(string: &str) -> ParserResult<Literal<N>> {
+            // Parse the opening parenthesis from the string.
+            let (string, _) = pair(tag("("), Sanitizer::parse_whitespaces)(string)?;
+            // Parse the literal from the string.
+            let (string, literal) = Literal::parse(string)?;
+            // Parse the closing parenthesis from the string.
+            let (string, _) = pair(Sanitizer::parse_whitespaces, tag(")"))(string)?;
+            Ok((string, literal))
+        }
+
         // Parse to determine the operand (order matters).
         alt((
             map(Literal::parse, |literal| Self::Literal(literal)),
+            map(parse_grouped_literal, |literal| Self::Literal(literal)),
             map(Register::parse, |register| Self::Register(register)),
             map(tag("self.caller"), |_| Self::Caller),
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
@@ -95,6 +107,15 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(Operand::Caller, operand);
 
+        // Ensure a literal may be grouped in parentheses.
+        let operand = Operand::<CurrentNetwork>::parse("(1scalar)").unwrap().1;
+        assert_eq!(Operand::Literal(Literal::from_str("1scalar")?), operand);
+        let operand = Operand::<CurrentNetwork>::parse("( -5i8 )").unwrap().1;
+        assert_eq!(Operand::Literal(Literal::from_str("-5i8")?), operand);
+        // Ensure only literals may be grouped.
+        assert!(Operand::<CurrentNetwork>::parse("(r0)").is_err());
+        assert!(Operand::<CurrentNetwork>::parse("(1scalar").is_err());
+
         // Sanity check a failure case.
         let (remainder, operand) = Operand::<CurrentNetwork>::parse("1field.private").unwrap();
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
@@ -0,0 +1,587 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, Stack};
+
+use circuit::{LinearCombination, Mode};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+};
+use snarkvm_r1cs::LookupTable;
+
+/// The number of bits in each limb of a range check.
+const LIMB_SIZE_IN_BITS: usize = 16;
+
+/// Asserts the first operand lies within the (inclusive) range given by the second and third operands.
+/// i.e. `assert.range r0 0u64 1000000u64;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AssertRange<N: Network> {
+    /// The operands, ordered as `[value, lower, upper]`.
+    operands: Vec<Operand<N>>,
+}
+
+impl<N: Network> AssertRange<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Assert("assert.range")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Assert range operations must have three operands");
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![]
+    }
+
+    /// Returns the lookup table of all 16-bit limbs, encoded as the entries `[limb, 0] => 0`.
+    /// This table is shared by every `assert.range` in a circuit, and must be added once per circuit.
+    pub fn lookup_table<A: circuit::Aleo<Network = N, BaseField = N::Field>>() -> LookupTable<A::BaseField> {
+        let mut lookup_table = LookupTable::default();
+        for limb in 0..(1u64 << LIMB_SIZE_IN_BITS) {
+            lookup_table.fill([A::BaseField::from(limb), A::BaseField::zero()], A::BaseField::zero());
+        }
+        lookup_table
+    }
+
+    /// Returns the index of the range check lookup table, which follows the tables declared in the program.
+    pub fn table_index(stack: &Stack<N>) -> usize {
+        stack.program().tables().len()
+    }
+}
+
+impl<N: Network> AssertRange<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let value = registers.load_literal(stack, &self.operands[0])?;
+        let lower = registers.load_literal(stack, &self.operands[1])?;
+        let upper = registers.load_literal(stack, &self.operands[2])?;
+        // Assert the value is within the range.
+        Self::check_range(&value, &lower, &upper)
+    }
+
+    /// Evaluates the instruction in a finalize scope.
+    #[inline]
+    pub fn evaluate_finalize(&self, stack: &Stack<N>, registers: &FinalizeRegisters<N>) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let value = registers.load_literal(stack, &self.operands[0])?;
+        let lower = registers.load_literal(stack, &self.operands[1])?;
+        let upper = registers.load_literal(stack, &self.operands[2])?;
+        // Assert the value is within the range.
+        Self::check_range(&value, &lower, &upper)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        use circuit::ToBits;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let value = registers.load_literal_circuit(stack, &self.operands[0])?;
+        let lower = registers.load_literal_circuit(stack, &self.operands[1])?;
+        let upper = registers.load_literal_circuit(stack, &self.operands[2])?;
+
+        // Ensure the inputs are integers of the same type.
+        let literal_type = value.to_type();
+        ensure!(
+            is_integer_type(&literal_type) && lower.to_type() == literal_type && upper.to_type() == literal_type,
+            "Instruction '{}' expects three integers of the same type",
+            Self::opcode()
+        );
+
+        // Compute the number of limbs needed to represent a difference of two integers of this type.
+        let num_bits = value.to_bits_le().len();
+        let num_limbs = (num_bits + LIMB_SIZE_IN_BITS - 1) / LIMB_SIZE_IN_BITS;
+
+        // Retrieve the index of the range check lookup table.
+        let table_index = Self::table_index(stack);
+
+        // Map the inputs into the field, preserving their order.
+        let value = to_ordered_field::<A>(&value);
+        let lower = to_ordered_field::<A>(&lower);
+        let upper = to_ordered_field::<A>(&upper);
+
+        // Enforce `0 <= value - lower` and `0 <= upper - value`, by decomposing each difference into limbs.
+        // As the field is much larger than the integer types, a negative difference wraps around to a field
+        // element that is too large to be recomposed from `num_limbs` limbs of 16 bits.
+        for difference in [&value - &lower, &upper - &value] {
+            // Decompose the difference into limbs.
+            let bits_le = difference.value().to_bigint().to_bits_le();
+            let limbs = bits_le
+                .chunks(LIMB_SIZE_IN_BITS)
+                .take(num_limbs)
+                .map(|chunk| chunk.iter().rev().fold(0u64, |limb, bit| (limb << 1) | *bit as u64))
+                .collect::<Vec<_>>();
+
+            // If the difference is a constant, check it directly.
+            if difference.is_constant() {
+                ensure!(
+                    bits_le.iter().skip(num_limbs * LIMB_SIZE_IN_BITS).all(|bit| !bit),
+                    "'{}' failed: the value is not in the range",
+                    Self::opcode()
+                );
+                continue;
+            }
+
+            // Witness each limb, and enforce it is in the lookup table.
+            let mut recomposition = A::zero();
+            for (i, limb) in limbs.into_iter().enumerate() {
+                let limb = LinearCombination::from(A::new_variable(Mode::Private, A::BaseField::from(limb)));
+                A::enforce_lookup(
+                    || format!("assert.range limb {i}"),
+                    |lc| lc + &limb,
+                    |lc| lc,
+                    |lc| lc,
+                    table_index,
+                )?;
+                recomposition += &limb * &A::BaseField::from(1u128 << (i * LIMB_SIZE_IN_BITS));
+            }
+            // Enforce the limbs recompose to the difference.
+            A::assert_eq(recomposition, difference);
+        }
+        Ok(())
+    }
+
+    /// Ensures the given value is within the (inclusive) range `[lower, upper]`.
+    fn check_range(value: &Literal<N>, lower: &Literal<N>, upper: &Literal<N>) -> Result<()> {
+        macro_rules! is_in_range {
+            ($( $variant:ident ),+) => {
+                match (value, lower, upper) {
+                    $( (Literal::$variant(v), Literal::$variant(lo), Literal::$variant(hi)) => lo <= v && v <= hi, )+
+                    _ => bail!("Instruction '{}' expects three integers of the same type", Self::opcode()),
+                }
+            };
+        }
+
+        // Assert the value is within the range.
+        if !is_in_range!(I8, I16, I32, I64, I128, U8, U16, U32, U64, U128) {
+            bail!("'{}' failed: '{value}' is not in the range ['{lower}', '{upper}']", Self::opcode())
+        }
+        Ok(())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the operands are of the same type.
+        if input_types[0] != input_types[1] || input_types[0] != input_types[2] {
+            bail!(
+                "Instruction '{}' expects inputs of the same type. Found inputs of type '{}', '{}', and '{}'",
+                Self::opcode(),
+                input_types[0],
+                input_types[1],
+                input_types[2]
+            )
+        }
+        // Ensure the operands are integers.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) if is_integer_type(literal_type) => (),
+            _ => bail!("Instruction '{}' expects integer inputs, found '{}'", Self::opcode(), input_types[0]),
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        Ok(vec![])
+    }
+}
+
+/// Returns `true` if the given literal type is an integer type.
+fn is_integer_type(literal_type: &LiteralType) -> bool {
+    matches!(
+        literal_type,
+        LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32
+            | LiteralType::U64
+            | LiteralType::U128
+    )
+}
+
+/// Returns the given integer as a linear combination of its bits, offset so that the integer ordering is preserved.
+/// For signed integers, this flips the sign bit, mapping `[-2^(n-1), 2^(n-1))` onto `[0, 2^n)`.
+fn to_ordered_field<A: circuit::Aleo>(integer: &circuit::Literal<A>) -> LinearCombination<A::BaseField> {
+    use circuit::ToBits;
+
+    let is_signed = matches!(
+        integer,
+        circuit::Literal::I8(..)
+            | circuit::Literal::I16(..)
+            | circuit::Literal::I32(..)
+            | circuit::Literal::I64(..)
+            | circuit::Literal::I128(..)
+    );
+
+    let bits_le = integer.to_bits_le();
+    let num_bits = bits_le.len();
+    bits_le.iter().enumerate().fold(A::zero(), |lc, (i, bit)| {
+        let coefficient = A::BaseField::from(1u128 << i);
+        match is_signed && i == num_bits - 1 {
+            true => lc + (A::one() - &**bit) * &coefficient,
+            false => lc + &**bit * &coefficient,
+        }
+    })
+}
+
+impl<N: Network> Parser for AssertRange<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the value, lower bound, and upper bound from the string.
+        let (string, value) = parse_operand(string)?;
+        let (string, lower) = parse_operand(string)?;
+        let (string, upper) = parse_operand(string)?;
+
+        Ok((string, Self { operands: vec![value, lower, upper] }))
+    }
+}
+
+impl<N: Network> FromStr for AssertRange<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for AssertRange<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AssertRange<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{}", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))
+    }
+}
+
+impl<N: Network> FromBytes for AssertRange<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Ok(Self { operands })
+    }
+}
+
+impl<N: Network> ToBytes for AssertRange<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::{AleoV0, Inject};
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, Value},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Samples the stack for `assert.range r0 r1 r2;` on the given literal type.
+    fn sample_stack(literal_type: LiteralType) -> Stack<CurrentNetwork> {
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as {literal_type}.private;
+                input r1 as {literal_type}.private;
+                input r2 as {literal_type}.private;
+                assert.range r0 r1 r2;"
+        ))
+        .unwrap();
+        Stack::new(&Process::load_with_cache(&mut Default::default()).unwrap(), &program).unwrap()
+    }
+
+    /// Samples the registers. Note: Do not replicate this for real program use, it is insecure.
+    fn sample_registers(
+        stack: &Stack<CurrentNetwork>,
+        literals: [&Literal<CurrentNetwork>; 3],
+        mode: Option<circuit::Mode>,
+    ) -> Registers<CurrentNetwork, CurrentAleo> {
+        let function_name = Identifier::from_str("run").unwrap();
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[])).unwrap(),
+            stack.get_register_types(&function_name).unwrap().clone(),
+        );
+        for (index, literal) in literals.into_iter().enumerate() {
+            let register = Register::Locator(index as u64);
+            let value = Value::Plaintext(Plaintext::from(literal));
+            registers.store(stack, &register, value.clone()).unwrap();
+            if let Some(mode) = mode {
+                registers.store_circuit(stack, &register, circuit::Value::new(mode, value)).unwrap();
+            }
+        }
+        registers
+    }
+
+    /// Checks `assert.range` on the given value and bounds, in both evaluate and execute mode.
+    fn check_assert_range(
+        stack: &Stack<CurrentNetwork>,
+        value: Literal<CurrentNetwork>,
+        lower: Literal<CurrentNetwork>,
+        upper: Literal<CurrentNetwork>,
+        expected: bool,
+    ) {
+        let operation = AssertRange::<CurrentNetwork>::from_str("assert.range r0 r1 r2").unwrap();
+
+        // Check the operation in evaluate mode.
+        let mut registers = sample_registers(stack, [&value, &lower, &upper], None);
+        let result = operation.evaluate(stack, &mut registers);
+        assert_eq!(result.is_ok(), expected, "'{operation}' (console) is incorrect for {value} in [{lower}, {upper}]");
+
+        // Check the operation in execute mode.
+        let mut registers = sample_registers(stack, [&value, &lower, &upper], Some(circuit::Mode::Private));
+        CurrentAleo::add_lookup_table(AssertRange::<CurrentNetwork>::lookup_table::<CurrentAleo>());
+        let result = operation.execute::<CurrentAleo>(stack, &mut registers);
+        assert!(result.is_ok(), "'{operation}' (circuit) should not have failed for {value} in [{lower}, {upper}]");
+        assert_eq!(
+            <CurrentAleo as circuit::Environment>::is_satisfied(),
+            expected,
+            "'{operation}' (circuit) is incorrect for {value} in [{lower}, {upper}]"
+        );
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, assert) = AssertRange::<CurrentNetwork>::parse("assert.range r0 0u64 1000000u64").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operands().len(), 3, "The number of operands is incorrect");
+        assert_eq!(assert.operands()[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(assert.to_string(), "assert.range r0 0u64 1000000u64");
+
+        // Check the instruction round-trips through bytes.
+        let bytes = assert.to_bytes_le().unwrap();
+        assert_eq!(assert, AssertRange::<CurrentNetwork>::from_bytes_le(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_assert_range_unsigned() {
+        let stack = sample_stack(LiteralType::U64);
+        let literal = |value: u64| Literal::U64(console::types::U64::new(value));
+
+        let (lower, upper) = (literal(10), literal(1000000));
+        // Check in-range values.
+        check_assert_range(&stack, literal(500), lower.clone(), upper.clone(), true);
+        check_assert_range(&stack, literal(65536), lower.clone(), upper.clone(), true);
+        // Check the boundary values.
+        check_assert_range(&stack, literal(10), lower.clone(), upper.clone(), true);
+        check_assert_range(&stack, literal(1000000), lower.clone(), upper.clone(), true);
+        // Check out-of-range values.
+        check_assert_range(&stack, literal(9), lower.clone(), upper.clone(), false);
+        check_assert_range(&stack, literal(1000001), lower.clone(), upper.clone(), false);
+        check_assert_range(&stack, literal(u64::MAX), lower, upper, false);
+        // Check the full range of the type.
+        check_assert_range(&stack, literal(u64::MAX), literal(0), literal(u64::MAX), true);
+    }
+
+    #[test]
+    fn test_assert_range_signed() {
+        let stack = sample_stack(LiteralType::I128);
+        let literal = |value: i128| Literal::I128(console::types::I128::new(value));
+
+        let (lower, upper) = (literal(-1000), literal(1000));
+        // Check in-range values.
+        check_assert_range(&stack, literal(-1), lower.clone(), upper.clone(), true);
+        check_assert_range(&stack, literal(0), lower.clone(), upper.clone(), true);
+        // Check the boundary values.
+        check_assert_range(&stack, literal(-1000), lower.clone(), upper.clone(), true);
+        check_assert_range(&stack, literal(1000), lower.clone(), upper.clone(), true);
+        // Check out-of-range values.
+        check_assert_range(&stack, literal(-1001), lower.clone(), upper.clone(), false);
+        check_assert_range(&stack, literal(1001), lower.clone(), upper.clone(), false);
+        check_assert_range(&stack, literal(i128::MIN), lower.clone(), upper.clone(), false);
+        check_assert_range(&stack, literal(i128::MAX), lower, upper, false);
+        // Check the full range of the type.
+        check_assert_range(&stack, literal(i128::MIN), literal(i128::MIN), literal(i128::MAX), true);
+    }
+
+    #[test]
+    fn test_assert_range_small_types() {
+        let stack = sample_stack(LiteralType::U8);
+        let literal = |value: u8| Literal::U8(console::types::U8::new(value));
+        check_assert_range(&stack, literal(200), literal(100), literal(255), true);
+        check_assert_range(&stack, literal(99), literal(100), literal(255), false);
+
+        let stack = sample_stack(LiteralType::I8);
+        let literal = |value: i8| Literal::I8(console::types::I8::new(value));
+        check_assert_range(&stack, literal(-128), literal(-128), literal(-1), true);
+        check_assert_range(&stack, literal(0), literal(-128), literal(-1), false);
+    }
+
+    #[test]
+    fn test_assert_range_rejects_non_integers() {
+        let program = Program::<CurrentNetwork>::from_str(
+            "program testing.aleo;
+            function run:
+                input r0 as field.private;
+                assert.range r0 0field 1field;",
+        )
+        .unwrap();
+        assert!(Stack::new(&Process::load_with_cache(&mut Default::default()).unwrap(), &program).is_err());
+    }
+
+    #[test]
+    fn test_assert_range_constraint_count() {
+        use circuit::{prelude::Compare, Environment};
+
+        let stack = sample_stack(LiteralType::U64);
+        let operation = AssertRange::<CurrentNetwork>::from_str("assert.range r0 r1 r2").unwrap();
+        let (value, lower, upper) = (500u64, 10u64, 1000000u64);
+
+        // Count the constraints of the lookup-based range check.
+        let literals = [value, lower, upper].map(|value| Literal::U64(console::types::U64::new(value)));
+        let mut registers = sample_registers(&stack, [&literals[0], &literals[1], &literals[2]], Some(Mode::Private));
+        CurrentAleo::add_lookup_table(AssertRange::<CurrentNetwork>::lookup_table::<CurrentAleo>());
+        let num_input_constraints = CurrentAleo::num_constraints();
+        operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+        assert!(CurrentAleo::is_satisfied());
+        let assignment = CurrentAleo::eject_assignment_and_reset();
+        let num_recomposition_constraints = assignment.num_constraints() - num_input_constraints;
+        let num_lookup_constraints = assignment.num_lookup_constraints();
+        // Each bound costs one recomposition constraint, and one lookup constraint per 16-bit limb.
+        assert_eq!(num_recomposition_constraints, 2);
+        assert_eq!(num_lookup_constraints, 2 * (64 / LIMB_SIZE_IN_BITS) as u64);
+        let num_range_constraints = num_recomposition_constraints + num_lookup_constraints;
+
+        // Count the constraints of the comparison-based range check.
+        let [value, lower, upper] = [value, lower, upper]
+            .map(|value| circuit::U64::<CurrentAleo>::new(Mode::Private, console::types::U64::new(value)));
+        let num_input_constraints = CurrentAleo::num_constraints();
+        CurrentAleo::assert(lower.is_less_than_or_equal(&value) & value.is_less_than_or_equal(&upper));
+        assert!(CurrentAleo::is_satisfied());
+        let num_comparison_constraints = CurrentAleo::num_constraints() - num_input_constraints;
+        CurrentAleo::reset();
+
+        assert!(
+            num_range_constraints < num_comparison_constraints,
+            "Expected fewer constraints ({num_range_constraints}) than the comparison ({num_comparison_constraints})"
+        );
+    }
+
+    #[test]
+    fn test_assert_range_rejects_limb_outside_table() {
+        use circuit::Environment;
+
+        // Returns `true` if the given limb satisfies the range check lookup.
+        let is_satisfied = |limb: u64| {
+            CurrentAleo::add_lookup_table(AssertRange::<CurrentNetwork>::lookup_table::<CurrentAleo>());
+            let limb = <CurrentAleo as Environment>::BaseField::from(limb);
+            let limb = LinearCombination::from(CurrentAleo::new_variable(Mode::Private, limb));
+            CurrentAleo::enforce_lookup(|| "limb", |lc| lc + &limb, |lc| lc, |lc| lc, 0).unwrap();
+            let is_satisfied = CurrentAleo::is_satisfied();
+            CurrentAleo::reset();
+            is_satisfied
+        };
+
+        // Ensure limbs of 16 bits are accepted.
+        assert!(is_satisfied(0));
+        assert!(is_satisfied(u16::MAX as u64));
+        // Ensure a limb that does not fit in 16 bits is rejected, even though a recomposition may still hold.
+        assert!(!is_satisfied(1 << LIMB_SIZE_IN_BITS));
+    }
+}
@@ -500,9 +500,9 @@ impl<N: Network> Call<N> {
                 .into_iter()
                 .map(|output_type| match (is_external, output_type) {
                     // If the output is a record and the function is external, return the external record type.
-                    (true, ValueType::Record(record_name)) => Ok(RegisterType::ExternalRecord(Locator::from_str(
-                        &format!("{}/{}", program.id(), record_name),
-                    )?)),
+                    (true, ValueType::Record(record_name)) => {
+                        Ok(RegisterType::ExternalRecord(Locator::new(*program.id(), record_name)))
+                    }
                     // Else, return the register type.
                     (_, _) => Ok(RegisterType::from(output_type)),
                 })
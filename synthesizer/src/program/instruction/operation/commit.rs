@@ -172,6 +172,12 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
         }
 
+        // Ensure the randomizer is a scalar.
+        let scalar_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar));
+        if input_types[1] != scalar_type {
+            bail!("Instruction '{}' expects the randomizer to be a scalar, found '{}'", Self::opcode(), input_types[1])
+        }
+
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
@@ -283,9 +289,135 @@ impl<N: Network, const VARIANT: u8> ToBytes for CommitInstruction<N, VARIANT> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::network::Testnet3;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::{AleoV0, Eject};
+    use console::{
+        network::Testnet3,
+        program::Identifier,
+        types::{Field, Scalar},
+    };
 
     type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Samples the stack for `{opcode} r0 r1 into r2`, on a field input and a scalar randomizer.
+    fn sample_stack(opcode: &str) -> Stack<CurrentNetwork> {
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as field.private;
+                input r1 as scalar.private;
+                {opcode} r0 r1 into r2;"
+        ))
+        .unwrap();
+        Stack::new(&Process::load_with_cache(&mut Default::default()).unwrap(), &program).unwrap()
+    }
+
+    /// Executes the given commit instruction on a private input and randomizer,
+    /// and returns the number of constraints it added, along with the console and circuit outputs.
+    fn check_commit<const VARIANT: u8>(
+        stack: &Stack<CurrentNetwork>,
+        instruction: &str,
+        input: Literal<CurrentNetwork>,
+        randomizer: Literal<CurrentNetwork>,
+    ) -> (u64, Value<CurrentNetwork>, Value<CurrentNetwork>) {
+        let operation = CommitInstruction::<CurrentNetwork, VARIANT>::from_str(instruction).unwrap();
+        let destination = Operand::Register(Register::Locator(2));
+
+        // Samples the registers. Note: Do not replicate this for real program use, it is insecure.
+        let function_name = Identifier::from_str("run").unwrap();
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[])).unwrap(),
+            stack.get_register_types(&function_name).unwrap().clone(),
+        );
+        for (index, literal) in [input, randomizer].into_iter().enumerate() {
+            let register = Register::Locator(index as u64);
+            let value = Value::Plaintext(Plaintext::from(literal));
+            registers.store(stack, &register, value.clone()).unwrap();
+            registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value)).unwrap();
+        }
+
+        // Evaluate the operation.
+        operation.evaluate(stack, &mut registers).unwrap();
+        let expected = registers.load(stack, &destination).unwrap();
+
+        // Execute the operation.
+        let num_constraints = <CurrentAleo as circuit::Environment>::num_constraints();
+        operation.execute::<CurrentAleo>(stack, &mut registers).unwrap();
+        let num_constraints = <CurrentAleo as circuit::Environment>::num_constraints() - num_constraints;
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        let candidate = registers.load_circuit(stack, &destination).unwrap().eject_value();
+        <CurrentAleo as circuit::Environment>::reset();
+
+        (num_constraints, expected, candidate)
+    }
+
+    fn check_literal_randomizer<const VARIANT: u8>(opcode: &str, rng: &mut TestRng) {
+        let stack = sample_stack(opcode);
+        let input = Literal::Field(Field::rand(rng));
+        let randomizer = Scalar::rand(rng);
+
+        // Commit with the randomizer in a register.
+        let (register_constraints, expected, candidate) = check_commit::<VARIANT>(
+            &stack,
+            &format!("{opcode} r0 r1 into r2"),
+            input.clone(),
+            Literal::Scalar(randomizer),
+        );
+        assert_eq!(expected, candidate);
+
+        // Commit with the randomizer as a literal operand.
+        let (literal_constraints, literal_expected, literal_candidate) = check_commit::<VARIANT>(
+            &stack,
+            &format!("{opcode} r0 {randomizer} into r2"),
+            input,
+            Literal::Scalar(randomizer),
+        );
+        assert_eq!(literal_expected, literal_candidate);
+        assert_eq!(expected, literal_expected);
+
+        // Ensure the literal randomizer is injected as a constant, requiring fewer constraints.
+        assert!(
+            literal_constraints < register_constraints,
+            "'{opcode}' with a literal randomizer used {literal_constraints} constraints, \
+            expected fewer than {register_constraints}"
+        );
+    }
+
+    #[test]
+    fn test_literal_randomizer() {
+        let mut rng = TestRng::default();
+        check_literal_randomizer::<0>("commit.bhp256", &mut rng);
+        check_literal_randomizer::<1>("commit.bhp512", &mut rng);
+        check_literal_randomizer::<2>("commit.bhp768", &mut rng);
+        check_literal_randomizer::<3>("commit.bhp1024", &mut rng);
+    }
+
+    #[test]
+    fn test_randomizer_must_be_scalar() {
+        let process = Process::load_with_cache(&mut Default::default()).unwrap();
+        for randomizer in ["1field", "r1"] {
+            let program = Program::<CurrentNetwork>::from_str(&format!(
+                "program testing.aleo;
+                function run:
+                    input r0 as field.private;
+                    input r1 as field.private;
+                    commit.bhp256 r0 {randomizer} into r2;"
+            ))
+            .unwrap();
+            let error = Stack::new(&process, &program).unwrap_err().to_string();
+            assert!(error.contains("expects the randomizer to be a scalar"), "Unexpected error: {error}");
+        }
+        // Ensure a grouped literal randomizer type checks.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program testing.aleo;
+            function run:
+                input r0 as field.private;
+                commit.bhp256 r0 (1scalar) into r1;",
+        )
+        .unwrap();
+        assert!(Stack::new(&process, &program).is_ok());
+    }
 
     #[test]
     fn test_parse() {
@@ -295,5 +427,15 @@ mod tests {
         assert_eq!(commit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(commit.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
+
+        // Ensure the randomizer may be given as a literal.
+        let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 1scalar into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.operands[1], Operand::Literal(Literal::Scalar(Scalar::one())), "The randomizer is incorrect");
+
+        // Ensure the randomizer may be given as a grouped literal.
+        let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 (1scalar) into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.operands[1], Operand::Literal(Literal::Scalar(Scalar::one())), "The randomizer is incorrect");
     }
 }
@@ -41,6 +41,9 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// Poseidon4 hash-to-group maps inputs in 4-field chunks to a group element, using Elligator 2 and cofactor clearing.
+pub type HashToGroupPSD4<N> = HashInstruction<N, { Hasher::HashToGroupPSD4 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,6 +54,7 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    HashToGroupPSD4,
 }
 
 /// Hashes the operand into the declared type.
@@ -76,6 +80,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash_to_group.psd4"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -112,19 +117,20 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         let input = registers.load(stack, &self.operands[0])?;
         // Hash the input.
         let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+            0 => Literal::Field(N::hash_bhp256(&input.to_bits_le())?),
+            1 => Literal::Field(N::hash_bhp512(&input.to_bits_le())?),
+            2 => Literal::Field(N::hash_bhp768(&input.to_bits_le())?),
+            3 => Literal::Field(N::hash_bhp1024(&input.to_bits_le())?),
+            4 => Literal::Field(N::hash_ped64(&input.to_bits_le())?),
+            5 => Literal::Field(N::hash_ped128(&input.to_bits_le())?),
+            6 => Literal::Field(N::hash_psd2(&input.to_fields()?)?),
+            7 => Literal::Field(N::hash_psd4(&input.to_fields()?)?),
+            8 => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
+            9 => Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
 
     /// Executes the instruction.
@@ -144,20 +150,20 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         let input = registers.load_circuit(stack, &self.operands[0])?;
         // Hash the input.
         let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+            0 => circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le())),
+            1 => circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le())),
+            2 => circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le())),
+            3 => circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le())),
+            4 => circuit::Literal::Field(A::hash_ped64(&input.to_bits_le())),
+            5 => circuit::Literal::Field(A::hash_ped128(&input.to_bits_le())),
+            6 => circuit::Literal::Field(A::hash_psd2(&input.to_fields())),
+            7 => circuit::Literal::Field(A::hash_psd4(&input.to_fields())),
+            8 => circuit::Literal::Field(A::hash_psd8(&input.to_fields())),
+            9 => circuit::Literal::Group(A::hash_to_group_psd4(&input.to_fields())),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
         // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
@@ -180,6 +186,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
                 Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
             }
+            9 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group))]),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
@@ -289,4 +296,17 @@ mod tests {
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
     }
+
+    #[test]
+    fn test_parse_hash_to_group() {
+        let (string, hash) = HashToGroupPSD4::<CurrentNetwork>::parse("hash_to_group.psd4 r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.to_string(), "hash_to_group.psd4 r0 into r1");
+
+        // Ensure the instruction does not parse as a `hash.psd4` instruction.
+        assert!(HashPSD4::<CurrentNetwork>::parse("hash_to_group.psd4 r0 into r1").is_err());
+    }
 }
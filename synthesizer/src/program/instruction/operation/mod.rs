@@ -17,6 +17,9 @@
 mod assert;
 pub use assert::*;
 
+mod assert_range;
+pub use assert_range::*;
+
 mod call;
 pub use call::*;
 
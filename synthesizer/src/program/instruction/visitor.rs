@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Instruction, Opcode};
+use console::network::prelude::*;
+
+/// A visitor over the instructions of a program, for use in program tooling (i.e. linters and analyzers).
+///
+/// Each instruction is passed to `visit_instruction`, which by default dispatches to the method
+/// for its category. Every method is a no-op by default, so a visitor only overrides the categories it needs.
+pub trait InstructionVisitor<N: Network> {
+    /// Visits the given instruction, dispatching to the method for its category.
+    fn visit_instruction(&mut self, instruction: &Instruction<N>) -> Result<()> {
+        match instruction.opcode() {
+            Opcode::Assert(..) => self.visit_assert(instruction),
+            Opcode::Call => self.visit_call(instruction),
+            Opcode::Cast => self.visit_cast(instruction),
+            Opcode::Commit(..) => self.visit_commit(instruction),
+            Opcode::Hash(..) => self.visit_hash(instruction),
+            Opcode::Is(..) => self.visit_is(instruction),
+            Opcode::Literal(..) => match instruction {
+                Instruction::Ternary(..) => self.visit_ternary(instruction),
+                _ => self.visit_arithmetic(instruction),
+            },
+            Opcode::Lookup => self.visit_lookup(instruction),
+            Opcode::Command(..) | Opcode::Finalize(..) => {
+                bail!("Instruction '{instruction}' has a non-instruction opcode")
+            }
+        }
+    }
+
+    /// Visits an arithmetic, logical, or comparison instruction on literals (i.e. `add`, `and`, `lt`).
+    fn visit_arithmetic(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits a hash instruction (i.e. `hash.bhp256`, `hash_to_group.psd4`).
+    fn visit_hash(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits a commit instruction (i.e. `commit.ped64`).
+    fn visit_commit(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits a `call` instruction.
+    fn visit_call(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits a `cast` instruction.
+    fn visit_cast(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits an assert instruction (i.e. `assert.eq`, `assert.range`).
+    fn visit_assert(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits an `is` instruction (i.e. `is.eq`).
+    fn visit_is(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits a `ternary` instruction.
+    fn visit_ternary(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Visits a `lookup` instruction.
+    fn visit_lookup(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    /// A visitor that counts the hash instructions and the destination registers.
+    #[derive(Default)]
+    struct Counter {
+        num_hashes: usize,
+        num_destinations: usize,
+    }
+
+    impl<N: Network> InstructionVisitor<N> for Counter {
+        fn visit_instruction(&mut self, instruction: &Instruction<N>) -> Result<()> {
+            self.num_destinations += instruction.destinations().len();
+            match instruction.opcode() {
+                Opcode::Hash(..) => self.visit_hash(instruction),
+                _ => Ok(()),
+            }
+        }
+
+        fn visit_hash(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+            self.num_hashes += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_visit_program() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program visitor.aleo;
+
+closure digest:
+    input r0 as field;
+    hash.psd2 r0 into r1;
+    add r1 r0 into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    hash.bhp256 r0 into r2;
+    hash_to_group.psd4 r1 into r3;
+    commit.bhp256 r0 1scalar into r4;
+    assert.neq r0 r1;
+    is.eq r0 r1 into r5;
+    ternary r5 r0 r1 into r6;
+    output r6 as field.private;",
+        )?;
+
+        let mut counter = Counter::default();
+        program.visit(&mut counter)?;
+        assert_eq!(counter.num_hashes, 3);
+        assert_eq!(counter.num_destinations, 2 + 5);
+
+        // Ensure a function can be visited on its own.
+        let mut counter = Counter::default();
+        program.get_function(&Identifier::from_str("compute")?)?.visit(&mut counter)?;
+        assert_eq!(counter.num_hashes, 2);
+        assert_eq!(counter.num_destinations, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_visit_dispatch() -> Result<()> {
+        /// A visitor that records the category of each instruction.
+        #[derive(Default)]
+        struct Categories(Vec<&'static str>);
+
+        impl<N: Network> InstructionVisitor<N> for Categories {
+            fn visit_arithmetic(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("arithmetic");
+                Ok(())
+            }
+
+            fn visit_hash(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("hash");
+                Ok(())
+            }
+
+            fn visit_commit(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("commit");
+                Ok(())
+            }
+
+            fn visit_call(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("call");
+                Ok(())
+            }
+
+            fn visit_cast(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("cast");
+                Ok(())
+            }
+
+            fn visit_assert(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("assert");
+                Ok(())
+            }
+
+            fn visit_is(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("is");
+                Ok(())
+            }
+
+            fn visit_ternary(&mut self, _instruction: &Instruction<N>) -> Result<()> {
+                self.0.push("ternary");
+                Ok(())
+            }
+        }
+
+        let instructions = [
+            ("lte r0 r1 into r2;", "arithmetic"),
+            ("hash.psd8 r0 into r1;", "hash"),
+            ("commit.bhp512 r0 r1 into r2;", "commit"),
+            ("call foo r0 into r1;", "call"),
+            ("cast r0 r1 into r2 as foo;", "cast"),
+            ("assert.range r0 r1 r2;", "assert"),
+            ("is.neq r0 r1 into r2;", "is"),
+            ("ternary r0 r1 r2 into r3;", "ternary"),
+        ];
+
+        let mut visitor = Categories::default();
+        for (instruction, _) in &instructions {
+            visitor.visit_instruction(&Instruction::<CurrentNetwork>::from_str(instruction)?)?;
+        }
+        assert_eq!(visitor.0, instructions.iter().map(|(_, category)| *category).collect::<Vec<_>>());
+        Ok(())
+    }
+}
@@ -14,9 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod canonicalize;
+pub use canonicalize::*;
+
 mod closure;
 pub use closure::*;
 
+mod cost;
+pub use cost::*;
+
 pub mod finalize;
 
 mod function;
@@ -173,6 +179,11 @@ function fee:
         &self.mappings
     }
 
+    /// Returns the structs in the program.
+    pub const fn structs(&self) -> &IndexMap<Identifier<N>, Struct<N>> {
+        &self.structs
+    }
+
     /// Returns the closures in the program.
     pub const fn closures(&self) -> &IndexMap<Identifier<N>, Closure<N>> {
         &self.closures
@@ -188,6 +199,13 @@ function fee:
         &self.tables
     }
 
+    /// Visits each instruction of the program with the given visitor,
+    /// starting with the closures, followed by the functions, each in order of declaration.
+    pub fn visit<V: InstructionVisitor<N>>(&self, visitor: &mut V) -> Result<()> {
+        self.closures.values().try_for_each(|closure| closure.visit(visitor))?;
+        self.functions.values().try_for_each(|function| function.visit(visitor))
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -16,11 +16,12 @@
 
 #![cfg_attr(not(feature = "aleo-cli"), allow(unused_variables))]
 
+use crate::block::Transition;
 use console::{
     network::{prelude::*, FiatShamir},
     program::Identifier,
 };
-use snarkvm_algorithms::{snark::marlin, traits::SNARK};
+use snarkvm_algorithms::{polycommit::sonic_pc::TrimMetadata, snark::marlin, traits::SNARK};
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -44,3 +45,21 @@ pub use universal_srs::UniversalSRS;
 
 mod verifying_key;
 pub use verifying_key::VerifyingKey;
+
+/// Verifies the proof of the given transition, using only the verifying key of its function.
+/// The `calls` are the transitions of the external functions called by the transition,
+/// in the order they are defined in the function.
+pub fn verify_transition_proof<N: Network>(
+    verifying_key: &VerifyingKey<N>,
+    transition: &Transition<N>,
+    calls: &[&Transition<N>],
+) -> Result<()> {
+    // Construct the verifier inputs.
+    let inputs = transition.to_verifier_inputs(calls)?;
+    // Ensure the transition proof is valid.
+    ensure!(
+        verifying_key.verify_batch(&transition.function_name().to_string(), &inputs, transition.proof()),
+        "Transition is invalid - failed to verify transition proof"
+    );
+    Ok(())
+}
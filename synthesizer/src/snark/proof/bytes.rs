@@ -16,29 +16,47 @@
 
 use super::*;
 
+use snarkvm_utilities::{Compress, Validate};
+
+/// The version of proofs with the legacy fixed layout, such as those in the genesis block.
+const LEGACY_VERSION: u16 = 0;
+/// The version of proofs with the current layout, in which the lookup elements are optional.
+const VERSION: u16 = 1;
+
 impl<N: Network> FromBytes for Proof<N> {
     /// Reads the proof from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 0 {
-            return Err(error("Invalid proof version"));
-        }
         // Read the proof.
-        let proof = FromBytes::read_le(&mut reader)?;
-        // Return the proof.
-        Ok(Self { proof })
+        match version {
+            LEGACY_VERSION => {
+                let proof = marlin::Proof::deserialize_legacy_with_mode(&mut reader, Compress::Yes, Validate::Yes)?;
+                Ok(Self { proof, is_legacy: true })
+            }
+            VERSION => Ok(Self { proof: FromBytes::read_le(&mut reader)?, is_legacy: false }),
+            _ => Err(error("Invalid proof version")),
+        }
     }
 }
 
 impl<N: Network> ToBytes for Proof<N> {
     /// Writes the proof to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        0u16.write_le(&mut writer)?;
-        // Write the bytes.
-        self.proof.write_le(&mut writer)
+        match self.is_legacy {
+            true => {
+                // Write the version.
+                LEGACY_VERSION.write_le(&mut writer)?;
+                // Write the bytes, with the original encoding.
+                Ok(self.proof.serialize_legacy_with_mode(&mut writer, Compress::Yes)?)
+            }
+            false => {
+                // Write the version.
+                VERSION.write_le(&mut writer)?;
+                // Write the bytes.
+                self.proof.write_le(&mut writer)
+            }
+        }
     }
 }
 
@@ -61,4 +79,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_legacy_bytes() -> Result<()> {
+        // Read the genesis block, whose proofs use the legacy fixed layout.
+        let genesis_block = crate::Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+
+        for transition in genesis_block.transitions() {
+            let proof = transition.proof();
+            assert!(proof.is_legacy);
+
+            // Ensure the proof is written back with the legacy version and the original encoding.
+            let bytes = proof.to_bytes_le()?;
+            assert_eq!(&bytes[..2], &LEGACY_VERSION.to_le_bytes());
+            let candidate = Proof::<CurrentNetwork>::read_le(&bytes[..])?;
+            assert_eq!(proof, &candidate);
+            assert_eq!(bytes, candidate.to_bytes_le()?);
+
+            // Ensure the legacy encoding is rejected with the current version.
+            let mut current = bytes.clone();
+            current[..2].copy_from_slice(&VERSION.to_le_bytes());
+            assert!(Proof::<CurrentNetwork>::read_le(&current[..]).is_err());
+        }
+        Ok(())
+    }
 }
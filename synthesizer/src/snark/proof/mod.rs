@@ -24,12 +24,14 @@ mod serialize;
 pub struct Proof<N: Network> {
     /// The proof.
     proof: marlin::Proof<N::PairingCurve>,
+    /// Whether the proof was read with the legacy fixed layout, and is written back with it.
+    is_legacy: bool,
 }
 
 impl<N: Network> Proof<N> {
     /// Initializes a new proof.
     pub(super) const fn new(proof: marlin::Proof<N::PairingCurve>) -> Self {
-        Self { proof }
+        Self { proof, is_legacy: false }
     }
 }
 
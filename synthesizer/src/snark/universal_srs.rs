@@ -44,7 +44,11 @@ impl<N: Network> UniversalSRS<N> {
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Built '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
 
-        Ok((ProvingKey::new(Arc::new(proving_key)), VerifyingKey::new(Arc::new(verifying_key))))
+        // Record the trim metadata of the committer key in the verifying key.
+        let committer_metadata = proving_key.committer_key.trim_metadata();
+        let verifying_key = VerifyingKey::new(Arc::new(verifying_key)).with_committer_metadata(committer_metadata);
+
+        Ok((ProvingKey::new(Arc::new(proving_key)), verifying_key))
     }
 }
 
@@ -22,22 +22,73 @@ impl<N: Network> FromBytes for VerifyingKey<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid verifying key version"));
         }
         // Read the verifying key.
         let verifying_key = Arc::new(FromBytes::read_le(&mut reader)?);
+        // Read the committer metadata, which is present from version 1.
+        let committer_metadata = match version == 1 {
+            true => Some(FromBytes::read_le(&mut reader)?),
+            false => None,
+        };
         // Return the verifying key.
-        Ok(Self { verifying_key })
+        Ok(Self { verifying_key, committer_metadata })
     }
 }
 
 impl<N: Network> ToBytes for VerifyingKey<N> {
     /// Writes the verifying key to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        0u16.write_le(&mut writer)?;
+        // Write the version, which is 1 only if the committer metadata is recorded.
+        match self.committer_metadata {
+            Some(_) => 1u16.write_le(&mut writer)?,
+            None => 0u16.write_le(&mut writer)?,
+        }
         // Write the bytes.
-        self.verifying_key.write_le(&mut writer)
+        self.verifying_key.write_le(&mut writer)?;
+        // Write the committer metadata, if it is recorded.
+        match &self.committer_metadata {
+            Some(committer_metadata) => committer_metadata.write_le(&mut writer),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Sample the verifying key, which records the committer metadata.
+        let (_, _, expected) = crate::process::test_helpers::sample_key();
+        assert!(expected.committer_metadata().is_some());
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(u16::from_bytes_le(&expected_bytes[0..2])?, 1);
+        assert_eq!(expected, VerifyingKey::read_le(&expected_bytes[..])?);
+
+        // Ensure a verifying key without the committer metadata is still written as version 0.
+        let legacy = VerifyingKey::new(expected.verifying_key.clone());
+        let legacy_bytes = legacy.to_bytes_le()?;
+        assert_eq!(u16::from_bytes_le(&legacy_bytes[0..2])?, 0);
+        assert_eq!(legacy, VerifyingKey::read_le(&legacy_bytes[..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_incompatible_committer_metadata() {
+        // Sample the verifying key, which records the committer metadata.
+        let (_, _, verifying_key) = crate::process::test_helpers::sample_key();
+        assert!(verifying_key.is_compatible());
+
+        // Ensure a committer key that enforces a degree bound the verifier key does not support is rejected.
+        let mut committer_metadata = verifying_key.committer_metadata().unwrap().clone();
+        let mut degree_bounds = committer_metadata.degree_bounds.unwrap_or_default();
+        degree_bounds.push(usize::MAX);
+        committer_metadata.degree_bounds = Some(degree_bounds);
+        assert!(!verifying_key.with_committer_metadata(committer_metadata).is_compatible());
     }
 }
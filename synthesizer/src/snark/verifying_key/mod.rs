@@ -24,6 +24,8 @@ mod serialize;
 pub struct VerifyingKey<N: Network> {
     /// The verifying key for the function.
     verifying_key: Arc<marlin::CircuitVerifyingKey<N::PairingCurve, marlin::MarlinHidingMode>>,
+    /// The trim metadata of the committer key that the verifying key was set up with, if it is recorded.
+    committer_metadata: Option<TrimMetadata>,
 }
 
 impl<N: Network> VerifyingKey<N> {
@@ -31,7 +33,28 @@ impl<N: Network> VerifyingKey<N> {
     pub(crate) const fn new(
         verifying_key: Arc<marlin::CircuitVerifyingKey<N::PairingCurve, marlin::MarlinHidingMode>>,
     ) -> Self {
-        Self { verifying_key }
+        Self { verifying_key, committer_metadata: None }
+    }
+
+    /// Records the trim metadata of the committer key that the verifying key was set up with.
+    pub(crate) fn with_committer_metadata(mut self, committer_metadata: TrimMetadata) -> Self {
+        self.committer_metadata = Some(committer_metadata);
+        self
+    }
+
+    /// Returns the trim metadata of the committer key that the verifying key was set up with, if it is recorded.
+    pub const fn committer_metadata(&self) -> Option<&TrimMetadata> {
+        self.committer_metadata.as_ref()
+    }
+
+    /// Returns `true` if the recorded committer key is compatible with the verifier key, or if none is recorded.
+    fn is_compatible(&self) -> bool {
+        match &self.committer_metadata {
+            Some(committer_metadata) => {
+                committer_metadata.is_compatible_with(&self.verifying_key.verifier_key.trim_metadata())
+            }
+            None => true,
+        }
     }
 
     /// Returns `true` if the proof is valid for the given public inputs.
@@ -39,6 +62,13 @@ impl<N: Network> VerifyingKey<N> {
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
+        // Ensure the committer key is compatible with the verifier key.
+        if !self.is_compatible() {
+            #[cfg(feature = "aleo-cli")]
+            println!("{}", format!(" • Verifier failed: incompatible keys for '{function_name}'").dimmed());
+            return false;
+        }
+
         // Verify the proof.
         match Marlin::<N>::verify_batch(N::marlin_fs_parameters(), self, std::slice::from_ref(&inputs), proof) {
             Ok(is_valid) => {
@@ -63,6 +93,13 @@ impl<N: Network> VerifyingKey<N> {
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
+        // Ensure the committer key is compatible with the verifier key.
+        if !self.is_compatible() {
+            #[cfg(feature = "aleo-cli")]
+            println!("{}", format!(" • Verifier failed: incompatible keys for '{function_name}'").dimmed());
+            return false;
+        }
+
         // Verify the batch proof.
         match Marlin::<N>::verify_batch(N::marlin_fs_parameters(), self, inputs, proof) {
             Ok(is_valid) => {
@@ -32,15 +32,15 @@ use crate::{
     Program,
 };
 use console::{
-    account::Signature,
+    account::{Signature, ViewKey},
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockTree, Plaintext, ProgramID, Record, StatePath},
     types::Field,
 };
 
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, ops::Range, sync::Arc};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -193,22 +193,22 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             // Store the transaction IDs.
             self.transactions_map().insert(block.hash(), block.transaction_ids().copied().collect())?;
 
+            // Store the reverse transaction IDs.
+            self.reverse_transactions_map()
+                .insert_batch(block.transaction_ids().map(|transaction_id| (*transaction_id, block.hash())))?;
             // Store the block transactions.
             for transaction in block.transactions().values() {
-                // Store the reverse transaction ID.
-                self.reverse_transactions_map().insert(transaction.id(), block.hash())?;
-                // Store the transaction.
                 self.transaction_store().insert(transaction)?;
             }
 
             // Store the block coinbase solution.
             self.coinbase_solution_map().insert(block.hash(), block.coinbase().cloned())?;
 
-            // Store the block coinbase puzzle commitment.
+            // Store the block coinbase puzzle commitments.
             if let Some(coinbase) = block.coinbase() {
-                for puzzle_commitment in coinbase.partial_solutions().iter().map(|s| s.commitment()) {
-                    self.coinbase_puzzle_commitment_map().insert(puzzle_commitment, block.hash())?;
-                }
+                self.coinbase_puzzle_commitment_map().insert_batch(
+                    coinbase.partial_solutions().iter().map(|solution| (solution.commitment(), block.hash())),
+                )?;
             }
 
             // Store the block signature.
@@ -342,67 +342,20 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(block_hash) => block_hash,
             None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
         };
-
-        // Retrieve the transition.
-        let transition = match self.transition_store().get_transition(&transition_id)? {
-            Some(transition) => transition,
-            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
-        };
         // Retrieve the block.
         let block = match self.get_block(&block_hash)? {
             Some(block) => block,
             None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
         };
 
-        // Construct the global state root and block path.
-        let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
-
         // Ensure the global state root exists in storage.
+        let global_state_root = *block_tree.root();
         if !self.reverse_state_root_map().contains_key(&global_state_root.into())? {
             bail!("The global state root '{global_state_root}' for commitment '{commitment}' is missing in storage");
         }
 
-        // Construct the transition path and transaction leaf.
-        let transition_leaf = transition.to_leaf(commitment, false)?;
-        let transition_path = transition.to_path(&transition_leaf)?;
-
-        // Construct the transactions path.
-        let transactions = block.transactions();
-        let transactions_path = match transactions.to_path(transaction_id) {
-            Ok(transactions_path) => transactions_path,
-            Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
-        };
-
-        // Construct the transaction path and transaction leaf.
-        let transaction = match transactions.get(&transaction_id) {
-            Some(transaction) => transaction,
-            None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
-        };
-        let transaction_leaf = transaction.to_leaf(transition.id())?;
-        let transaction_path = transaction.to_path(&transaction_leaf)?;
-
-        // Construct the block header path.
-        let block_header = block.header();
-        let header_root = block_header.to_root()?;
-        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
-        let header_path = block_header.to_path(&header_leaf)?;
-
-        Ok(StatePath::from(
-            global_state_root.into(),
-            block_path,
-            block.hash(),
-            block.previous_hash(),
-            header_root,
-            header_path,
-            header_leaf,
-            transactions_path,
-            transaction.id(),
-            transaction_path,
-            transaction_leaf,
-            transition_path,
-            transition_leaf,
-        ))
+        // Construct the state path.
+        block.to_state_path(commitment, block_tree)
     }
 
     /// Returns the previous block hash of the given `block height`.
@@ -745,6 +698,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Rebuilds the secondary indices of the block store from the stored blocks and transactions.
+    pub fn rebuild_indices(&self) -> Result<()> {
+        self.storage.transaction_store().rebuild_program_index()
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -817,6 +775,59 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns a state path for the given `commitment`, against the global state root at the given `block height`.
+    /// This allows records to be spent against a historical state root, e.g. at a given confirmation depth.
+    pub fn get_state_path_for_commitment_at_height(&self, commitment: &Field<N>, height: u32) -> Result<StatePath<N>> {
+        // Retrieve the state root at the given block height.
+        let state_root = match self.storage.get_state_root(height)? {
+            Some(state_root) => state_root,
+            None => bail!("The state root for block {height} is missing in storage"),
+        };
+
+        // Recompute the block tree as of the given block height.
+        let hashes = cfg_into_iter!(0..=height)
+            .map(|height| match self.storage.get_block_hash(height)? {
+                Some(hash) => Ok(hash.to_bits_le()),
+                None => bail!("Missing block hash for block {height}"),
+            })
+            .collect::<Result<Vec<Vec<bool>>>>()?;
+        let block_tree = N::merkle_tree_bhp(&hashes)?;
+
+        // Ensure the recomputed block tree matches the state root in storage.
+        ensure!(
+            state_root == (*block_tree.root()).into(),
+            "The recomputed state root for block {height} does not match the state root in storage"
+        );
+
+        self.storage.get_state_path_for_commitment(commitment, &block_tree)
+    }
+
+    /// Returns a state path for the given `commitment` in the given candidate block, which is not yet in storage.
+    /// The state path is constructed against the global state root that results from appending the candidate block.
+    pub fn get_state_path_for_candidate_block(
+        &self,
+        candidate_block: &Block<N>,
+        commitment: &Field<N>,
+    ) -> Result<StatePath<N>> {
+        // Acquire the read lock on the block tree.
+        let tree = self.tree.read();
+
+        // Ensure the candidate block is the next block in the chain.
+        let height = candidate_block.height();
+        ensure!(height as usize == tree.number_of_leaves(), "Candidate block {height} is not the next block");
+        if let Some(previous_hash) = self.storage.get_previous_block_hash(height)? {
+            ensure!(
+                candidate_block.previous_hash() == previous_hash,
+                "Candidate block {height} does not extend the latest block"
+            );
+        }
+
+        // Prepare the block tree with the candidate block hash appended.
+        let block_tree = tree.prepare_append(&[candidate_block.hash().to_bits_le()])?;
+
+        candidate_block.to_state_path(commitment, &block_tree)
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -905,11 +916,141 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn puzzle_commitments(&self) -> impl '_ + Iterator<Item = Cow<'_, PuzzleCommitment<N>>> {
         self.storage.coinbase_puzzle_commitment_map().keys()
     }
+
+    /// Returns an iterator over the blocks in the given range of block heights, in ascending order.
+    /// The range is truncated to the blocks that are currently in `self`.
+    pub fn blocks_in_range(&self, heights: Range<u32>) -> impl '_ + Iterator<Item = Result<Block<N>>> {
+        // Truncate the range to the number of blocks in storage.
+        let end = heights.end.min(self.number_of_blocks());
+        (heights.start..end).map(|height| self.get_block_at_height(height))
+    }
+
+    /// Returns the records that the owner of the given view key sent in the given range of block heights,
+    /// as `(transition ID, commitment, record)` tuples. This includes the records sent to other addresses.
+    /// Transitions that were not signed by the owner of the view key, such as those of other accounts, are skipped.
+    #[allow(clippy::type_complexity)]
+    pub fn scan_outgoing(
+        &self,
+        view_key: &ViewKey<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(N::TransitionID, Field<N>, Record<N, Plaintext<N>>)>> {
+        let mut records = Vec::new();
+        for block in self.blocks_in_range(heights) {
+            for transition in block?.transitions() {
+                for (commitment, record_view_key) in transition.output_record_view_keys(view_key)? {
+                    // Retrieve the record.
+                    let record = match transition.find_record(&commitment) {
+                        Some(record) => record,
+                        None => bail!("Missing record '{commitment}' in transition '{}'", transition.id()),
+                    };
+                    // Decrypt the record.
+                    records.push((*transition.id(), commitment, record.decrypt_symmetric(&record_view_key)?));
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    /// Returns an iterator over all blocks in `self`, in descending order of block height.
+    pub fn blocks_rev(&self) -> impl '_ + Iterator<Item = Result<Block<N>>> {
+        (0..self.number_of_blocks()).rev().map(|height| self.get_block_at_height(height))
+    }
+
+    /// Returns the number of blocks in `self`.
+    fn number_of_blocks(&self) -> u32 {
+        // Note: The number of leaves is bounded by the block height, which is a `u32`.
+        self.tree.read().number_of_leaves() as u32
+    }
+
+    /// Returns the block at the given `block height`.
+    fn get_block_at_height(&self, height: u32) -> Result<Block<N>> {
+        // Retrieve the block hash.
+        let block_hash = match self.get_block_hash(height)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block hash for block {height}"),
+        };
+        // Retrieve the block.
+        match self.get_block(&block_hash)? {
+            Some(block) => Ok(block),
+            None => bail!("Missing block {height} ('{block_hash}')"),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{block::Metadata, Transaction};
+    use console::{
+        account::{Address, PrivateKey},
+        program::Value,
+    };
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Returns a block store containing the genesis block and `num_blocks - 1` blocks that alternate
+    /// between a deployment and an execution transaction, along with the inserted blocks.
+    fn sample_block_store(
+        num_blocks: u32,
+        rng: &mut TestRng,
+    ) -> (BlockStore<CurrentNetwork, BlockMemory<CurrentNetwork>>, Vec<Block<CurrentNetwork>>) {
+        // Sample the genesis block and the transactions.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&genesis).unwrap();
+
+        let mut blocks = vec![genesis];
+        for height in 1..num_blocks {
+            // Alternate between the deployment and the execution transaction.
+            let transaction = match height % 2 == 0 {
+                true => deployment_transaction.clone(),
+                false => execution_transaction.clone(),
+            };
+            let transactions = Transactions::from(&[transaction]);
+
+            // Construct and insert the block.
+            let block = insert_next_block(&block_store, blocks.last().unwrap(), transactions, &private_key, rng);
+            blocks.push(block);
+        }
+        (block_store, blocks)
+    }
+
+    /// Constructs the next block with the given transactions, and inserts it into the block store.
+    fn insert_next_block(
+        block_store: &BlockStore<CurrentNetwork, BlockMemory<CurrentNetwork>>,
+        previous_block: &Block<CurrentNetwork>,
+        transactions: Transactions<CurrentNetwork>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        // Construct the block header.
+        let height = previous_block.height() + 1;
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            height as u64,
+            height,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            previous_block.last_coinbase_target(),
+            previous_block.last_coinbase_timestamp(),
+            CurrentNetwork::GENESIS_TIMESTAMP + height as i64,
+            0,
+        )
+        .unwrap();
+        let header =
+            Header::from(*block_store.current_state_root(), transactions.to_root().unwrap(), Field::zero(), metadata)
+                .unwrap();
+
+        // Construct and insert the block.
+        let block = Block::new(private_key, previous_block.hash(), header, transactions, None, rng).unwrap();
+        block_store.insert(&block).unwrap();
+        block
+    }
 
     #[test]
     fn test_insert_get_remove() {
@@ -981,4 +1122,180 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_get_state_path_for_commitment_at_height() {
+        let mut rng = TestRng::default();
+
+        // Sample the block store.
+        let (block_store, blocks) = sample_block_store(12, &mut rng);
+        // Select a commitment from the genesis block.
+        let commitment = *blocks[0].commitments().next().unwrap();
+
+        // Construct the state path against the state root 10 blocks back.
+        let state_path = block_store.get_state_path_for_commitment_at_height(&commitment, 1).unwrap();
+        let state_root = state_path.global_state_root();
+        assert_eq!(Some(state_root), block_store.get_state_root(1).unwrap());
+        assert_ne!(state_root, block_store.current_state_root());
+        assert!(block_store.contains_state_root(&state_root).unwrap());
+        // Ensure the state path passes the inclusion checks.
+        state_path.verify(true, Field::zero()).unwrap();
+
+        // Ensure a state path built against a different root is rejected.
+        let latest_path = block_store.get_state_path_for_commitment(&commitment).unwrap();
+        let mismatched_path = StatePath::from(
+            state_root,
+            latest_path.block_path().clone(),
+            latest_path.block_hash(),
+            latest_path.previous_block_hash(),
+            *latest_path.header_root(),
+            latest_path.header_path().clone(),
+            *latest_path.header_leaf(),
+            latest_path.transactions_path().clone(),
+            *latest_path.transaction_id(),
+            latest_path.transaction_path().clone(),
+            *latest_path.transaction_leaf(),
+            latest_path.transition_path().clone(),
+            *latest_path.transition_leaf(),
+        );
+        assert!(mismatched_path.verify(true, Field::zero()).is_err());
+
+        // Ensure a state path cannot be built for a height that does not exist.
+        assert!(block_store.get_state_path_for_commitment_at_height(&commitment, 12).is_err());
+    }
+
+    #[test]
+    fn test_get_state_path_for_candidate_block() {
+        let mut rng = TestRng::default();
+
+        // Sample the block store, and remove the latest block to use as the candidate block.
+        let (block_store, blocks) = sample_block_store(12, &mut rng);
+        let candidate_block = blocks.last().unwrap();
+        block_store.remove_last_n(1).unwrap();
+        let commitment = *candidate_block.commitments().next().unwrap();
+
+        // Construct the state path against the candidate block.
+        let state_path = block_store.get_state_path_for_candidate_block(candidate_block, &commitment).unwrap();
+        state_path.verify(true, Field::zero()).unwrap();
+        assert!(!block_store.contains_state_root(&state_path.global_state_root()).unwrap());
+
+        // Ensure a block that does not extend the latest block is rejected.
+        assert!(block_store.get_state_path_for_candidate_block(&blocks[5], &commitment).is_err());
+
+        // Insert the candidate block, and ensure the state root matches.
+        block_store.insert(candidate_block).unwrap();
+        assert_eq!(state_path.global_state_root(), block_store.current_state_root());
+    }
+
+    #[test]
+    fn test_blocks_in_range_and_rev() {
+        let mut rng = TestRng::default();
+
+        // Sample the block store.
+        let (block_store, blocks) = sample_block_store(50, &mut rng);
+
+        // Check a range in the middle of the chain.
+        let candidate = block_store.blocks_in_range(10..20).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(blocks[10..20], candidate[..]);
+
+        // Check a range that extends past the tip of the chain.
+        let candidate = block_store.blocks_in_range(45..100).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(blocks[45..], candidate[..]);
+
+        // Check an empty range.
+        assert_eq!(block_store.blocks_in_range(20..20).count(), 0);
+        assert_eq!(block_store.blocks_in_range(60..70).count(), 0);
+
+        // Check the reverse iterator.
+        let candidate = block_store.blocks_rev().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(blocks.iter().rev().cloned().collect::<Vec<_>>(), candidate);
+    }
+
+    #[test]
+    fn test_transactions_for_program() {
+        let mut rng = TestRng::default();
+
+        // Sample the block store.
+        let (block_store, blocks) = sample_block_store(50, &mut rng);
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(&mut rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction(&mut rng);
+
+        let check_index = || {
+            let transaction_store = block_store.transaction_store();
+
+            // Ensure the deployed program is only indexed by its deployment.
+            let program_id = ProgramID::from_str("testing.aleo").unwrap();
+            let candidate = transaction_store.transactions_for_program(&program_id).unwrap();
+            let candidate = candidate.collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(vec![deployment_transaction.clone()], candidate);
+
+            // Ensure the credits program is indexed once by every transaction, in insertion order.
+            let program_id = ProgramID::from_str("credits.aleo").unwrap();
+            let candidate = transaction_store.transactions_for_program(&program_id).unwrap();
+            let candidate = candidate.map(|transaction| transaction.unwrap().id()).collect::<Vec<_>>();
+            let mut expected = blocks[0].transaction_ids().copied().collect::<Vec<_>>();
+            expected.extend([execution_transaction.id(), deployment_transaction.id()]);
+            assert_eq!(expected, candidate);
+
+            // Ensure an unknown program is not indexed.
+            let program_id = ProgramID::from_str("unknown.aleo").unwrap();
+            assert_eq!(transaction_store.transactions_for_program(&program_id).unwrap().count(), 0);
+        };
+
+        check_index();
+        // Rebuild the indices, and ensure they are unchanged.
+        block_store.rebuild_indices().unwrap();
+        check_index();
+    }
+
+    #[test]
+    fn test_scan_outgoing() {
+        let rng = &mut TestRng::default();
+
+        // Sample the sender, who owns the records in the genesis block, and the recipient.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let sender_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let sender_view_key = ViewKey::try_from(&sender_private_key).unwrap();
+        let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+        let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+        // Transfer a record from the sender to the recipient.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let record = genesis.records().next().unwrap().1.decrypt(&sender_view_key).unwrap();
+        let inputs = [
+            Value::<CurrentNetwork>::Record(record),
+            Value::<CurrentNetwork>::from_str(&recipient.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let authorization =
+            vm.authorize(&sender_private_key, "credits.aleo", "transfer", inputs.into_iter(), rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let transition_id = *transaction.transition_ids().next().unwrap();
+
+        // Insert the genesis block and the block with the transfer.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&genesis).unwrap();
+        let transactions = Transactions::from(&[transaction]);
+        let block = insert_next_block(&block_store, &genesis, transactions, &sender_private_key, rng);
+
+        // Ensure the sender finds the record sent to the recipient and the change record.
+        let outgoing = block_store.scan_outgoing(&sender_view_key, 1..2).unwrap();
+        assert_eq!(outgoing.len(), 2);
+        assert!(outgoing.iter().all(|(id, ..)| *id == transition_id));
+
+        // Ensure the recipient finds the same record with their view key.
+        let (commitment, ciphertext) =
+            block.records().find(|(_, record)| record.is_owner(&recipient_view_key)).unwrap();
+        let record = ciphertext.decrypt(&recipient_view_key).unwrap();
+        assert_eq!(**record.owner(), recipient);
+        assert!(outgoing.contains(&(transition_id, *commitment, record)));
+
+        // Ensure the change record is found by the sender with their view key.
+        let (commitment, ciphertext) = block.records().find(|(_, record)| record.is_owner(&sender_view_key)).unwrap();
+        assert!(outgoing.contains(&(transition_id, *commitment, ciphertext.decrypt(&sender_view_key).unwrap())));
+
+        // Ensure the transitions signed by the sender are skipped when scanning as the recipient.
+        assert!(block_store.scan_outgoing(&recipient_view_key, 0..2).unwrap().is_empty());
+    }
 }
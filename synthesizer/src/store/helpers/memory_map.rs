@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::store::helpers::{Map, MapRead};
+use crate::store::helpers::{CheckpointId, Map, MapRead};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
@@ -37,6 +37,7 @@ pub struct MemoryMap<
     map: Arc<RwLock<IndexMap<K, V>>>,
     batch_in_progress: Arc<AtomicBool>,
     atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    checkpoints: Arc<Mutex<Vec<IndexMap<K, Option<V>>>>>,
 }
 
 impl<
@@ -45,7 +46,12 @@ impl<
 > Default for MemoryMap<K, V>
 {
     fn default() -> Self {
-        Self { map: Default::default(), batch_in_progress: Default::default(), atomic_batch: Default::default() }
+        Self {
+            map: Default::default(),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
     }
 }
 
@@ -60,6 +66,33 @@ impl<
             map: Arc::new(RwLock::new(IndexMap::from_iter(iter))),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MemoryMap<K, V>
+{
+    /// Applies the given insertions (`Some(value)`) and removals (`None`) under a single lock.
+    /// If an atomic batch is in progress, they are queued in the latest checkpoint, or in the batch.
+    fn apply_operations(&self, operations: Vec<(K, Option<V>)>) {
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            true => match self.checkpoints.lock().last_mut() {
+                Some(checkpoint) => checkpoint.extend(operations),
+                None => self.atomic_batch.lock().extend(operations),
+            },
+            false => {
+                let mut locked_map = self.map.write();
+                for operation in operations {
+                    match operation {
+                        (key, Some(value)) => locked_map.insert(key, value),
+                        (key, None) => locked_map.shift_remove(&key),
+                    };
+                }
+            }
         }
     }
 }
@@ -78,10 +111,15 @@ impl<
         let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
 
         match is_batch {
-            // If a batch is in progress, add the key-value pair to the batch.
-            true => {
-                self.atomic_batch.lock().insert(key, Some(value));
-            }
+            // If a batch is in progress, add the key-value pair to the latest checkpoint, or to the batch.
+            true => match self.checkpoints.lock().last_mut() {
+                Some(checkpoint) => {
+                    checkpoint.insert(key, Some(value));
+                }
+                None => {
+                    self.atomic_batch.lock().insert(key, Some(value));
+                }
+            },
             // Otherwise, insert the key-value pair directly into the map.
             false => {
                 self.map.write().insert(key, value);
@@ -98,10 +136,15 @@ impl<
         let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
 
         match is_batch {
-            // If a batch is in progress, add the key-None pair to the batch.
-            true => {
-                self.atomic_batch.lock().insert(*key, None);
-            }
+            // If a batch is in progress, add the key-None pair to the latest checkpoint, or to the batch.
+            true => match self.checkpoints.lock().last_mut() {
+                Some(checkpoint) => {
+                    checkpoint.insert(*key, None);
+                }
+                None => {
+                    self.atomic_batch.lock().insert(*key, None);
+                }
+            },
             // Otherwise, remove the key-value pair directly from the map.
             false => {
                 self.map.write().shift_remove(key);
@@ -110,6 +153,28 @@ impl<
         Ok(())
     }
 
+    ///
+    /// Inserts the given key-value pairs into the map, as a single atomic write.
+    /// If any insertion fails, none of the given key-value pairs are written.
+    ///
+    fn insert_batch(&self, entries: impl Iterator<Item = (K, V)>) -> Result<()> {
+        // Collect the entries first, so that no lock is held while the iterator is advanced.
+        let entries = entries.map(|(key, value)| (key, Some(value))).collect::<Vec<_>>();
+        self.apply_operations(entries);
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pairs for the given keys from the map, as a single atomic write.
+    /// If any removal fails, none of the given keys are removed.
+    ///
+    fn remove_batch(&self, keys: impl Iterator<Item = K>) -> Result<()> {
+        // Collect the keys first, so that no lock is held while the iterator is advanced.
+        let operations = keys.map(|key| (key, None)).collect::<Vec<_>>();
+        self.apply_operations(operations);
+        Ok(())
+    }
+
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
     /// without an actual write taking place until `finish_atomic` is called.
@@ -117,7 +182,8 @@ impl<
     fn start_atomic(&self) {
         // Set the atomic batch flag to `true`.
         self.batch_in_progress.store(true, Ordering::SeqCst);
-        // Ensure that the atomic batch is empty.
+        // Ensure that the atomic batch and its checkpoints are empty.
+        assert!(self.checkpoints.lock().is_empty());
         assert!(self.atomic_batch.lock().is_empty());
     }
 
@@ -134,6 +200,8 @@ impl<
     /// Aborts the current atomic operation.
     ///
     fn abort_atomic(&self) {
+        // Clear the checkpoints.
+        *self.checkpoints.lock() = Default::default();
         // Clear the atomic batch.
         *self.atomic_batch.lock() = Default::default();
         // Set the atomic batch flag to `false`.
@@ -144,6 +212,13 @@ impl<
     /// Finishes an atomic operation, performing all the queued writes.
     ///
     fn finish_atomic(&self) -> Result<()> {
+        // Ensure that all checkpoints have been committed or rolled back.
+        let num_checkpoints = self.checkpoints.lock().len();
+        if num_checkpoints != 0 {
+            self.abort_atomic();
+            bail!("Cannot finish an atomic batch with {num_checkpoints} open checkpoint(s)");
+        }
+
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
@@ -154,7 +229,7 @@ impl<
             for operation in operations {
                 match operation {
                     (key, Some(value)) => locked_map.insert(key, value),
-                    (key, None) => locked_map.remove(&key),
+                    (key, None) => locked_map.shift_remove(&key),
                 };
             }
         }
@@ -164,6 +239,52 @@ impl<
 
         Ok(())
     }
+
+    ///
+    /// Opens a checkpoint inside the current atomic operation. Any further calls to `insert` and `remove`
+    /// will be queued in a new write buffer, layered over the atomic batch and any enclosing checkpoints.
+    ///
+    fn atomic_checkpoint(&self) -> Result<CheckpointId> {
+        // Ensure that an atomic batch is in progress.
+        ensure!(self.is_atomic_in_progress(), "Cannot open a checkpoint without an atomic batch in progress");
+        // Open a new checkpoint.
+        let mut checkpoints = self.checkpoints.lock();
+        checkpoints.push(Default::default());
+        Ok(CheckpointId(checkpoints.len() - 1))
+    }
+
+    ///
+    /// Discards the writes queued since the given checkpoint was opened, including those of any nested checkpoints.
+    ///
+    fn atomic_rollback_to(&self, checkpoint: CheckpointId) -> Result<()> {
+        let mut checkpoints = self.checkpoints.lock();
+        // Ensure that the checkpoint is open.
+        ensure!(checkpoint.0 < checkpoints.len(), "Checkpoint {} is not open - cannot roll back", checkpoint.0);
+        // Discard the checkpoint and any nested checkpoints.
+        checkpoints.truncate(checkpoint.0);
+        Ok(())
+    }
+
+    ///
+    /// Closes the given checkpoint, along with any nested checkpoints, and moves their queued writes
+    /// into the enclosing checkpoint, or into the atomic batch if there is none.
+    ///
+    fn atomic_commit(&self, checkpoint: CheckpointId) -> Result<()> {
+        let mut checkpoints = self.checkpoints.lock();
+        // Ensure that the checkpoint is open.
+        ensure!(checkpoint.0 < checkpoints.len(), "Checkpoint {} is not open - cannot commit", checkpoint.0);
+        // Remove the checkpoint and any nested checkpoints.
+        let operations = checkpoints.split_off(checkpoint.0);
+        // Move the queued writes into the enclosing checkpoint, or into the atomic batch.
+        match checkpoints.last_mut() {
+            Some(enclosing) => operations.into_iter().for_each(|ops| enclosing.extend(ops)),
+            None => {
+                let mut atomic_batch = self.atomic_batch.lock();
+                operations.into_iter().for_each(|ops| atomic_batch.extend(ops));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<
@@ -198,6 +319,20 @@ impl<
         Ok(self.map.read().get(key).cloned().map(Cow::Owned))
     }
 
+    ///
+    /// Returns the values for the given keys from the map, in the order of the given keys.
+    ///
+    fn get_batch<'b, Q>(&'a self, keys: impl Iterator<Item = &'b Q>) -> Result<Vec<Option<Cow<'a, V>>>>
+    where
+        K: Borrow<Q>,
+        Q: 'b + PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Collect the keys first, so that no lock is held while the iterator is advanced.
+        let keys = keys.collect::<Vec<_>>();
+        let locked_map = self.map.read();
+        Ok(keys.into_iter().map(|key| locked_map.get(key).cloned().map(Cow::Owned)).collect())
+    }
+
     ///
     /// Returns the current value for the given key if it is scheduled
     /// to be inserted as part of an atomic batch.
@@ -212,7 +347,14 @@ impl<
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
         // Return early if there is no atomic batch in progress.
-        if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
+        if !self.batch_in_progress.load(Ordering::SeqCst) {
+            return None;
+        }
+        // Retrieve the value from the latest checkpoint that contains the key, or from the batch.
+        match self.checkpoints.lock().iter().rev().find_map(|checkpoint| checkpoint.get(key).cloned()) {
+            Some(value) => Some(value),
+            None => self.atomic_batch.lock().get(key).cloned(),
+        }
     }
 
     ///
@@ -479,4 +621,132 @@ mod tests {
         // The map should contain NUM_ITEMS items now.
         assert_eq!(map.iter().count(), NUM_ITEMS);
     }
+
+    #[test]
+    fn test_atomic_checkpoints() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+
+        // Ensure a checkpoint cannot be opened without an atomic write batch.
+        assert!(map.atomic_checkpoint().is_err());
+
+        // Start an atomic write batch.
+        map.start_atomic();
+        map.insert(1, "1".to_string()).unwrap();
+
+        // Open a checkpoint, and queue writes in it.
+        let outer = map.atomic_checkpoint().unwrap();
+        map.insert(2, "2".to_string()).unwrap();
+        map.remove(&0).unwrap();
+        // Check that the writes are visible speculatively.
+        assert_eq!(map.get_speculative(&2).unwrap(), Some(Cow::Owned("2".to_string())));
+        assert_eq!(map.get_speculative(&0).unwrap(), None);
+        assert!(!map.contains_key_speculative(&0).unwrap());
+
+        // Open a nested checkpoint, and override the writes of the outer checkpoint.
+        let inner = map.atomic_checkpoint().unwrap();
+        assert!(inner > outer);
+        map.insert(0, "zero".to_string()).unwrap();
+        map.insert(2, "two".to_string()).unwrap();
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("zero".to_string())));
+        assert_eq!(map.get_speculative(&2).unwrap(), Some(Cow::Owned("two".to_string())));
+
+        // Roll back the nested checkpoint, and check that the outer checkpoint is visible again.
+        map.atomic_rollback_to(inner).unwrap();
+        assert_eq!(map.get_speculative(&0).unwrap(), None);
+        assert_eq!(map.get_speculative(&2).unwrap(), Some(Cow::Owned("2".to_string())));
+        // Ensure the nested checkpoint cannot be closed twice.
+        assert!(map.atomic_rollback_to(inner).is_err());
+        assert!(map.atomic_commit(inner).is_err());
+
+        // Open another nested checkpoint, and commit it into the outer checkpoint.
+        let inner = map.atomic_checkpoint().unwrap();
+        map.insert(3, "3".to_string()).unwrap();
+        map.atomic_commit(inner).unwrap();
+        assert_eq!(map.get_speculative(&3).unwrap(), Some(Cow::Owned("3".to_string())));
+
+        // Commit the outer checkpoint into the atomic batch.
+        map.atomic_commit(outer).unwrap();
+        assert_eq!(map.get_batched(&3), Some(Some("3".to_string())));
+
+        // The map should still contain only the initial item.
+        assert_eq!(map.iter().count(), 1);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the committed writes are present in the map now.
+        assert!(map.get(&0).unwrap().is_none());
+        assert_eq!(map.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+        assert_eq!(map.get(&2).unwrap(), Some(Cow::Owned("2".to_string())));
+        assert_eq!(map.get(&3).unwrap(), Some(Cow::Owned("3".to_string())));
+
+        // Ensure an atomic write batch cannot be finished with an open checkpoint.
+        map.start_atomic();
+        map.insert(4, "4".to_string()).unwrap();
+        map.atomic_checkpoint().unwrap();
+        assert!(map.finish_atomic().is_err());
+        assert!(!map.is_atomic_in_progress());
+        assert!(map.get(&4).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_batch_operations() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+
+        // Insert a batch directly into the map.
+        map.insert_batch((1..4).map(|i| (i, i.to_string()))).unwrap();
+        assert_eq!(map.iter().count(), 4);
+        let values = map.get_batch([3, 0, 4].iter()).unwrap();
+        assert_eq!(values, vec![Some(Cow::Owned("3".to_string())), Some(Cow::Owned("0".to_string())), None]);
+
+        // Queue a batch of insertions and removals in an atomic write batch.
+        map.start_atomic();
+        map.insert_batch((4..6).map(|i| (i, i.to_string()))).unwrap();
+        map.remove_batch([0, 1].into_iter()).unwrap();
+        // Check that the writes are only visible speculatively.
+        assert_eq!(map.iter().count(), 4);
+        assert_eq!(map.get_batched(&5), Some(Some("5".to_string())));
+        assert_eq!(map.get_batched(&0), Some(None));
+        map.finish_atomic().unwrap();
+
+        // Check that the writes are present in the map now.
+        let keys = map.keys().map(|key| *key).collect::<Vec<_>>();
+        assert_eq!(keys, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_apply_batch_is_atomic() {
+        use crate::store::helpers::apply_batch;
+
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+
+        // Prepares an operation that fails on the third item.
+        let insert_or_fail = |i: usize| match i {
+            3 => bail!("Failed to insert item {i}"),
+            _ => map.insert(i, i.to_string()),
+        };
+
+        // Ensure a failing item leaves no partial writes.
+        assert!(apply_batch(&map, 1..5, insert_or_fail).is_err());
+        assert!(!map.is_atomic_in_progress());
+        assert_eq!(map.iter().count(), 1);
+
+        // Ensure a failing item inside an atomic write batch discards only the writes of its own batch.
+        map.start_atomic();
+        map.insert(10, "10".to_string()).unwrap();
+        assert!(apply_batch(&map, 1..5, insert_or_fail).is_err());
+        assert!(map.is_atomic_in_progress());
+        assert_eq!(map.get_batched(&1), None);
+        map.finish_atomic().unwrap();
+
+        let keys = map.keys().map(|key| *key).collect::<Vec<_>>();
+        assert_eq!(keys, vec![0, 10]);
+
+        // Ensure a batch without failing items is written in full.
+        apply_batch(&map, 1..3, insert_or_fail).unwrap();
+        assert_eq!(map.iter().count(), 4);
+    }
 }
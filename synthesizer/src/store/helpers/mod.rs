@@ -21,6 +21,10 @@ use console::network::prelude::*;
 use core::{borrow::Borrow, hash::Hash};
 use std::borrow::Cow;
 
+/// The identifier of a checkpoint inside an atomic batch, given by its depth in the stack of open checkpoints.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CheckpointId(pub(crate) usize);
+
 /// A trait representing map-like storage operations with read-write capabilities.
 pub trait Map<
     'a,
@@ -38,6 +42,22 @@ pub trait Map<
     ///
     fn remove(&self, key: &K) -> Result<()>;
 
+    ///
+    /// Inserts the given key-value pairs into the map, as a single atomic write.
+    /// If any insertion fails, none of the given key-value pairs are written.
+    ///
+    fn insert_batch(&self, entries: impl Iterator<Item = (K, V)>) -> Result<()> {
+        apply_batch(self, entries, |(key, value)| self.insert(key, value))
+    }
+
+    ///
+    /// Removes the key-value pairs for the given keys from the map, as a single atomic write.
+    /// If any removal fails, none of the given keys are removed.
+    ///
+    fn remove_batch(&self, keys: impl Iterator<Item = K>) -> Result<()> {
+        apply_batch(self, keys, |key| self.remove(&key))
+    }
+
     ///
     /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
     /// without an actual write taking place until `finish_atomic` is called.
@@ -60,6 +80,23 @@ pub trait Map<
     /// Finishes an atomic operation, performing all the queued writes.
     ///
     fn finish_atomic(&self) -> Result<()>;
+
+    ///
+    /// Opens a checkpoint inside the current atomic operation. Any further calls to `insert` and `remove`
+    /// will be queued in a new write buffer, layered over the atomic batch and any enclosing checkpoints.
+    ///
+    fn atomic_checkpoint(&self) -> Result<CheckpointId>;
+
+    ///
+    /// Discards the writes queued since the given checkpoint was opened, including those of any nested checkpoints.
+    ///
+    fn atomic_rollback_to(&self, checkpoint: CheckpointId) -> Result<()>;
+
+    ///
+    /// Closes the given checkpoint, along with any nested checkpoints, and moves their queued writes
+    /// into the enclosing checkpoint, or into the atomic batch if there is none.
+    ///
+    fn atomic_commit(&self, checkpoint: CheckpointId) -> Result<()>;
 }
 
 /// A trait representing map-like storage operations with read-only capabilities.
@@ -89,6 +126,17 @@ pub trait MapRead<
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
 
+    ///
+    /// Returns the values for the given keys from the map, in the order of the given keys.
+    ///
+    fn get_batch<'b, Q>(&'a self, keys: impl Iterator<Item = &'b Q>) -> Result<Vec<Option<Cow<'a, V>>>>
+    where
+        K: Borrow<Q>,
+        Q: 'b + PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        keys.map(|key| self.get(key)).collect()
+    }
+
     ///
     /// Returns the current value for the given key if it is scheduled
     /// to be inserted as part of an atomic batch.
@@ -125,6 +173,26 @@ pub trait MapRead<
         }
     }
 
+    ///
+    /// Returns `true` if the given key exists in the atomic batch first, if it is scheduled,
+    /// or in the map, otherwise.
+    ///
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early in case of errors in order to not conceal them.
+        let map_contains_key = self.contains_key(key)?;
+
+        // Return whether the key exists in the atomic batch, if it is scheduled, or in the map, otherwise.
+        match self.get_batched(key) {
+            Some(Some(_)) => Ok(true),
+            Some(None) => Ok(false),
+            None => Ok(map_contains_key),
+        }
+    }
+
     ///
     /// Returns an iterator visiting each key-value pair in the map.
     ///
@@ -141,6 +209,47 @@ pub trait MapRead<
     fn values(&'a self) -> Self::Values;
 }
 
+/// Applies the given operation to each of the given items as a single atomic write on the given map.
+/// If an atomic batch is already in progress, the items are written inside a checkpoint of it instead,
+/// so that a failing operation discards the writes of this call only.
+fn apply_batch<'a, K, V, M, T>(
+    map: &M,
+    items: impl Iterator<Item = T>,
+    mut operation: impl FnMut(T) -> Result<()>,
+) -> Result<()>
+where
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + Deserialize<'a> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Send + Sync,
+    M: Map<'a, K, V>,
+{
+    // Start an atomic batch write operation IFF it's not already part of one.
+    let is_part_of_atomic_batch = map.is_atomic_in_progress();
+    if !is_part_of_atomic_batch {
+        map.start_atomic();
+    }
+    // Open a checkpoint for the writes of this call.
+    let checkpoint = map.atomic_checkpoint()?;
+
+    for item in items {
+        if let Err(error) = operation(item) {
+            // Discard the writes of this call, and abort the batch IFF it was started here.
+            match is_part_of_atomic_batch {
+                true => map.atomic_rollback_to(checkpoint)?,
+                false => map.abort_atomic(),
+            }
+            return Err(error);
+        }
+    }
+
+    // Move the writes of this call into the enclosing batch.
+    map.atomic_commit(checkpoint)?;
+    // Finish an atomic batch write operation IFF it's not already part of a larger one.
+    if !is_part_of_atomic_batch {
+        map.finish_atomic()?;
+    }
+    Ok(())
+}
+
 /// This macro executes the given block of operations as a new atomic write batch IFF there is no
 /// atomic write batch in progress yet. This ensures that complex atomic operations consisting of
 /// multiple lower-level operations - which might also need to be atomic if executed individually -
@@ -18,7 +18,7 @@ use crate::{
     atomic_write_batch,
     cow_to_cloned,
     cow_to_copied,
-    store::helpers::{memory_map::MemoryMap, Map, MapRead},
+    store::helpers::{memory_map::MemoryMap, CheckpointId, Map, MapRead},
 };
 use console::{
     network::prelude::*,
@@ -107,17 +107,49 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.value_map().finish_atomic()
     }
 
+    /// Opens a checkpoint inside the current atomic batch write operation.
+    fn atomic_checkpoint(&self) -> Result<CheckpointId> {
+        let checkpoints = [
+            self.program_id_map().atomic_checkpoint()?,
+            self.mapping_id_map().atomic_checkpoint()?,
+            self.key_value_id_map().atomic_checkpoint()?,
+            self.key_map().atomic_checkpoint()?,
+            self.value_map().atomic_checkpoint()?,
+        ];
+        // Ensure the maps agree on the checkpoint.
+        ensure!(checkpoints.iter().all(|c| *c == checkpoints[0]), "Found inconsistent checkpoints in program storage");
+        Ok(checkpoints[0])
+    }
+
+    /// Discards the writes made since the given checkpoint, including those of any nested checkpoints.
+    fn atomic_rollback_to(&self, checkpoint: CheckpointId) -> Result<()> {
+        self.program_id_map().atomic_rollback_to(checkpoint)?;
+        self.mapping_id_map().atomic_rollback_to(checkpoint)?;
+        self.key_value_id_map().atomic_rollback_to(checkpoint)?;
+        self.key_map().atomic_rollback_to(checkpoint)?;
+        self.value_map().atomic_rollback_to(checkpoint)
+    }
+
+    /// Keeps the writes made since the given checkpoint, and closes the checkpoint.
+    fn atomic_commit(&self, checkpoint: CheckpointId) -> Result<()> {
+        self.program_id_map().atomic_commit(checkpoint)?;
+        self.mapping_id_map().atomic_commit(checkpoint)?;
+        self.key_value_id_map().atomic_commit(checkpoint)?;
+        self.key_map().atomic_commit(checkpoint)?;
+        self.value_map().atomic_commit(checkpoint)
+    }
+
     /// Initializes the given `program ID` and `mapping name` in storage.
     fn initialize_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<()> {
         // Ensure the mapping name does not already exist.
-        if self.mapping_id_map().contains_key(&(*program_id, *mapping_name))? {
+        if self.mapping_id_map().contains_key_speculative(&(*program_id, *mapping_name))? {
             bail!("Illegal operation: mapping '{mapping_name}' already exists in storage - cannot initialize again.")
         }
 
         // Compute the mapping ID.
         let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
         // Ensure the mapping ID does not already exist.
-        if self.key_value_id_map().contains_key(&mapping_id)? {
+        if self.key_value_id_map().contains_key_speculative(&mapping_id)? {
             bail!("Illegal operation: mapping ID '{mapping_id}' already exists in storage - cannot initialize again.")
         }
 
@@ -153,40 +185,60 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         mapping_name: &Identifier<N>,
         key: Plaintext<N>,
         value: Value<N>,
+    ) -> Result<()> {
+        self.insert_key_values(program_id, mapping_name, vec![(key, value)])
+    }
+
+    /// Stores the given `(key, value)` pairs at the given `program ID` and `mapping name` in storage,
+    /// as a single atomic write. If any `key` already exists, the method returns an error,
+    /// and none of the pairs are stored.
+    fn insert_key_values(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<()> {
         // Retrieve the mapping ID.
         let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
             Some(mapping_id) => mapping_id,
             None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot insert key-value."),
         };
-        // Compute the key ID.
-        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
-        // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
-
-        // Ensure the key ID does not already exist.
-        if self.key_map().contains_key(&key_id)? {
-            bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
-        }
         // Retrieve the key-value IDs for the mapping ID.
         let mut key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
             Some(key_value_ids) => cow_to_cloned!(key_value_ids),
             None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot insert key-value."),
         };
-        // Ensure the key ID does not already exist.
-        if key_value_ids.contains_key(&key_id) {
-            bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert key-value.");
+
+        let mut keys = Vec::with_capacity(entries.len());
+        let mut values = Vec::with_capacity(entries.len());
+        for (key, value) in entries {
+            // Compute the key ID.
+            let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+            // Compute the value ID.
+            let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+            // Ensure the key ID does not already exist.
+            if self.key_map().contains_key_speculative(&key_id)? {
+                bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
+            }
+            // Ensure the key ID does not already exist.
+            if key_value_ids.contains_key(&key_id) {
+                bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert key-value.");
+            }
+            // Insert the new key-value ID.
+            key_value_ids.insert(key_id, value_id);
+
+            keys.push((key_id, key));
+            values.push((key_id, value));
         }
-        // Insert the new key-value ID.
-        key_value_ids.insert(key_id, value_id);
 
         atomic_write_batch!(self, {
-            // Update the key-value ID map with the new key-value ID.
+            // Update the key-value ID map with the new key-value IDs.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
-            // Insert the key.
-            self.key_map().insert(key_id, key)?;
-            // Insert the value.
-            self.value_map().insert(key_id, value)?;
+            // Insert the keys.
+            self.key_map().insert_batch(keys.into_iter())?;
+            // Insert the values.
+            self.value_map().insert_batch(values.into_iter())?;
 
             Ok(())
         });
@@ -203,17 +255,24 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         mapping_name: &Identifier<N>,
         key: Plaintext<N>,
         value: Value<N>,
+    ) -> Result<()> {
+        self.update_key_values(program_id, mapping_name, vec![(key, value)])
+    }
+
+    /// Stores the given `(key, value)` pairs at the given `program ID` and `mapping name` in storage,
+    /// as a single atomic write. If a `key` does not exist, its `(key, value)` pair is initialized.
+    /// If a `key` already exists, its `value` is overwritten.
+    fn update_key_values(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<()> {
         // Retrieve the mapping ID.
         let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
             Some(mapping_id) => mapping_id,
             None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot update key-value."),
         };
-        // Compute the key ID.
-        let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
-        // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
-
         // Retrieve the key-value IDs for the mapping ID.
         let mut key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
             Some(key_value_ids) => cow_to_cloned!(key_value_ids),
@@ -221,24 +280,38 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
                 bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot update key-value.")
             }
         };
-        // If the key ID does not exist, insert it in the key-value ID map.
-        if !self.key_map().contains_key(&key_id)? {
-            // Ensure the key ID does not already exist.
-            // If this fails, then there is inconsistent state, and likely data corruption.
-            if key_value_ids.contains_key(&key_id) {
-                bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot update key-value.");
+
+        // Note: A key that is updated more than once in the batch retains its last value.
+        let mut keys = IndexMap::with_capacity(entries.len());
+        let mut values = IndexMap::with_capacity(entries.len());
+        for (key, value) in entries {
+            // Compute the key ID.
+            let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+            // Compute the value ID.
+            let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+
+            // If the key ID does not exist, insert it in the key-value ID map.
+            if !self.key_map().contains_key_speculative(&key_id)? {
+                // Ensure the key ID does not already exist, unless it was initialized earlier in this batch.
+                // If this fails, then there is inconsistent state, and likely data corruption.
+                if key_value_ids.contains_key(&key_id) && !keys.contains_key(&key_id) {
+                    bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot update key-value.");
+                }
             }
+            // Insert the new key-value ID.
+            key_value_ids.insert(key_id, value_id);
+
+            keys.insert(key_id, key);
+            values.insert(key_id, value);
         }
-        // Insert the new key-value ID.
-        key_value_ids.insert(key_id, value_id);
 
         atomic_write_batch!(self, {
-            // Update the key-value ID map with the new key-value ID.
+            // Update the key-value ID map with the new key-value IDs.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
-            // Insert the key.
-            self.key_map().insert(key_id, key)?;
-            // Insert the value.
-            self.value_map().insert(key_id, value)?;
+            // Insert the keys.
+            self.key_map().insert_batch(keys.into_iter())?;
+            // Insert the values.
+            self.value_map().insert_batch(values.into_iter())?;
 
             Ok(())
         });
@@ -382,12 +455,12 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns `true` if the given `program ID` exist.
     fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
-        self.program_id_map().contains_key(program_id)
+        self.program_id_map().contains_key_speculative(program_id)
     }
 
     /// Returns `true` if the given `program ID` and `mapping name` exist.
     fn contains_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
-        self.mapping_id_map().contains_key(&(*program_id, *mapping_name))
+        self.mapping_id_map().contains_key_speculative(&(*program_id, *mapping_name))
     }
 
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
@@ -405,7 +478,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Return whether the key ID exists.
-        self.key_map().contains_key(&key_id)
+        self.key_map().contains_key_speculative(&key_id)
     }
 
     /// Returns the mapping names for the given `program ID`.
@@ -440,7 +513,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Ensure the key ID exists.
-        match self.key_map().contains_key(&key_id)? {
+        match self.key_map().contains_key_speculative(&key_id)? {
             true => Ok(Some(key_id)),
             false => Ok(None),
         }
@@ -605,6 +678,18 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.insert_key_value(program_id, mapping_name, key, value)
     }
 
+    /// Stores the given `(key, value)` pairs at the given `program ID` and `mapping name` in storage,
+    /// as a single atomic write. If any `key` already exists, the method returns an error,
+    /// and none of the pairs are stored.
+    pub fn insert_key_values(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
+    ) -> Result<()> {
+        self.storage.insert_key_values(program_id, mapping_name, entries)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `key` does not exist, the `(key, value)` pair is initialized.
     /// If the `key` already exists, the `value` is overwritten.
@@ -618,6 +703,18 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.update_key_value(program_id, mapping_name, key, value)
     }
 
+    /// Stores the given `(key, value)` pairs at the given `program ID` and `mapping name` in storage,
+    /// as a single atomic write. If a `key` does not exist, its `(key, value)` pair is initialized.
+    /// If a `key` already exists, its `value` is overwritten.
+    pub fn update_key_values(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
+    ) -> Result<()> {
+        self.storage.update_key_values(program_id, mapping_name, entries)
+    }
+
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
     pub fn remove_key_value(
         &self,
@@ -660,6 +757,22 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.finish_atomic()
     }
 
+    /// Opens a checkpoint inside the current atomic batch write operation.
+    /// Checkpoints may be nested, and must be closed with `rollback_to` or `commit`.
+    pub fn checkpoint(&self) -> Result<CheckpointId> {
+        self.storage.atomic_checkpoint()
+    }
+
+    /// Discards the writes made since the given checkpoint, including those of any nested checkpoints.
+    pub fn rollback_to(&self, checkpoint: CheckpointId) -> Result<()> {
+        self.storage.atomic_rollback_to(checkpoint)
+    }
+
+    /// Keeps the writes made since the given checkpoint, and closes the checkpoint.
+    pub fn commit(&self, checkpoint: CheckpointId) -> Result<()> {
+        self.storage.atomic_commit(checkpoint)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
@@ -1175,4 +1288,174 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_checkpoints_match_sequential_application() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store, and initialize the mapping.
+        let program_store = ProgramStore::<CurrentNetwork, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Ensures the mapping in the program store matches the given reference state.
+        let check_state = |expected: &IndexMap<u64, u64>| {
+            for key in 0..8u64 {
+                let key_plaintext = Plaintext::from_str(&format!("{key}u64")).unwrap();
+                let candidate = program_store.get_value(&program_id, &mapping_name, &key_plaintext).unwrap();
+                let expected = expected.get(&key).map(|value| Value::from_str(&format!("{value}u64")).unwrap());
+                let contains_key = program_store.contains_key(&program_id, &mapping_name, &key_plaintext).unwrap();
+                assert_eq!(contains_key, expected.is_some());
+                assert_eq!(candidate, expected);
+            }
+        };
+
+        // The reference state, given by a sequential application of the accepted transactions.
+        let mut expected = IndexMap::<u64, u64>::new();
+
+        // Start an atomic write batch.
+        program_store.start_atomic();
+
+        for _ in 0..50 {
+            // Open a checkpoint for the transaction.
+            let transaction = program_store.checkpoint().unwrap();
+            let mut transaction_state = expected.clone();
+
+            for _ in 0..rng.gen_range(1..4) {
+                // Open a nested checkpoint for the finalize scope.
+                let scope = program_store.checkpoint().unwrap();
+                let mut scope_state = transaction_state.clone();
+
+                for _ in 0..rng.gen_range(1..4) {
+                    let key = rng.gen_range(0..8u64);
+                    let key_plaintext = Plaintext::from_str(&format!("{key}u64")).unwrap();
+                    match scope_state.contains_key(&key) && rng.gen_bool(0.25) {
+                        // Remove the key.
+                        true => {
+                            program_store.remove_key_value(&program_id, &mapping_name, &key_plaintext).unwrap();
+                            scope_state.remove(&key);
+                        }
+                        // Update the key with a new value.
+                        false => {
+                            let value = rng.gen::<u64>();
+                            let value_plaintext = Value::from_str(&format!("{value}u64")).unwrap();
+                            program_store
+                                .update_key_value(&program_id, &mapping_name, key_plaintext, value_plaintext)
+                                .unwrap();
+                            scope_state.insert(key, value);
+                        }
+                    }
+                }
+                check_state(&scope_state);
+
+                // Abort the finalize scope, or keep its writes.
+                match rng.gen_bool(0.2) {
+                    true => program_store.rollback_to(scope).unwrap(),
+                    false => {
+                        program_store.commit(scope).unwrap();
+                        transaction_state = scope_state;
+                    }
+                }
+                check_state(&transaction_state);
+            }
+
+            // Abort the transaction, or keep its writes.
+            match rng.gen_bool(0.3) {
+                true => program_store.rollback_to(transaction).unwrap(),
+                false => {
+                    program_store.commit(transaction).unwrap();
+                    expected = transaction_state;
+                }
+            }
+            check_state(&expected);
+        }
+
+        // Finish the atomic write batch, and ensure the state persists.
+        program_store.finish_atomic().unwrap();
+        check_state(&expected);
+    }
+
+    #[test]
+    fn test_insert_key_values_is_atomic() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store, and initialize the mapping.
+        let program_store = ProgramStore::<CurrentNetwork, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Prepares the (key, value) pairs for the given keys.
+        let entries = |keys: &[u64]| {
+            keys.iter()
+                .map(|key| {
+                    let key_plaintext = Plaintext::from_str(&format!("{key}u64")).unwrap();
+                    (key_plaintext, Value::from_str(&format!("{}u64", key * 10)).unwrap())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Insert a batch of keys.
+        program_store.insert_key_values(&program_id, &mapping_name, entries(&[0, 1, 2])).unwrap();
+
+        // Ensure a batch with an existing key in the middle leaves no partial writes.
+        assert!(program_store.insert_key_values(&program_id, &mapping_name, entries(&[3, 4, 1, 5])).is_err());
+        // Ensure a batch with a duplicate key leaves no partial writes.
+        assert!(program_store.insert_key_values(&program_id, &mapping_name, entries(&[3, 4, 3])).is_err());
+        // Ensure a failing batch inside an atomic write batch leaves no partial writes either.
+        program_store.start_atomic();
+        assert!(program_store.insert_key_values(&program_id, &mapping_name, entries(&[6, 7, 2])).is_err());
+        program_store.finish_atomic().unwrap();
+
+        for key in 0..8u64 {
+            let key_plaintext = Plaintext::from_str(&format!("{key}u64")).unwrap();
+            let value = program_store.get_value(&program_id, &mapping_name, &key_plaintext).unwrap();
+            match key < 3 {
+                true => assert_eq!(value, Some(Value::from_str(&format!("{}u64", key * 10)).unwrap())),
+                false => assert!(value.is_none()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_key_values_matches_sequential_updates() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize two program stores, and initialize the mapping in each.
+        let batched_store = ProgramStore::<CurrentNetwork, ProgramMemory<_>>::open(None).unwrap();
+        batched_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        let sequential_store = ProgramStore::<CurrentNetwork, ProgramMemory<_>>::open(None).unwrap();
+        sequential_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        for _ in 0..10 {
+            // Sample a batch of updates, which may update the same key more than once.
+            let entries = (0..rng.gen_range(1..8))
+                .map(|_| {
+                    let key = Plaintext::from_str(&format!("{}u64", rng.gen_range(0..8u64))).unwrap();
+                    (key, Value::from_str(&format!("{}u64", rng.gen::<u64>())).unwrap())
+                })
+                .collect::<Vec<_>>();
+
+            for (key, value) in entries.iter().cloned() {
+                sequential_store.update_key_value(&program_id, &mapping_name, key, value).unwrap();
+            }
+            batched_store.update_key_values(&program_id, &mapping_name, entries).unwrap();
+
+            for key in 0..8u64 {
+                let key = Plaintext::from_str(&format!("{key}u64")).unwrap();
+                assert_eq!(
+                    batched_store.get_value(&program_id, &mapping_name, &key).unwrap(),
+                    sequential_store.get_value(&program_id, &mapping_name, &key).unwrap()
+                );
+            }
+        }
+        assert_eq!(batched_store.storage.get_checksum().unwrap(), sequential_store.storage.get_checksum().unwrap());
+    }
 }
@@ -379,8 +379,12 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             verifying_keys.push((*function_name, (verifying_key, certificate)));
         }
 
+        // Construct the deployment.
+        let deployment = Deployment::new(edition, program, verifying_keys)?;
+        // Ensure the stored program matches the program ID it is indexed under.
+        deployment.verify_program_id_matches(&program_id)?;
         // Return the deployment.
-        Ok(Some(Deployment::new(edition, program, verifying_keys)?))
+        Ok(Some(deployment))
     }
 
     /// Returns the fee for the given `transaction ID`.
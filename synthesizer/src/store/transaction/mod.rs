@@ -23,6 +23,7 @@ pub use execution::*;
 use crate::{
     atomic_write_batch,
     block::Transaction,
+    cow_to_cloned,
     cow_to_copied,
     process::{Deployment, Execution},
     program::Program,
@@ -40,6 +41,7 @@ use console::{
 };
 
 use anyhow::Result;
+use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
@@ -55,6 +57,12 @@ pub enum TransactionType {
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of `(program ID, index)` to `transaction ID`.
+    type ProgramTransactionsMap: for<'a> Map<'a, (ProgramID<N>, u32), N::TransactionID>;
+    /// The mapping of `(program ID, transaction ID)` to `index`.
+    type ProgramTransactionIndexMap: for<'a> Map<'a, (ProgramID<N>, N::TransactionID), u32>;
+    /// The mapping of `program ID` to the number of indexed transactions.
+    type ProgramTransactionCountMap: for<'a> Map<'a, ProgramID<N>, u32>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The execution storage.
@@ -67,6 +75,12 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap;
+    /// Returns the program transaction index map.
+    fn program_transaction_index_map(&self) -> &Self::ProgramTransactionIndexMap;
+    /// Returns the program transaction count map.
+    fn program_transaction_count_map(&self) -> &Self::ProgramTransactionCountMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -85,6 +99,9 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.program_transactions_map().start_atomic();
+        self.program_transaction_index_map().start_atomic();
+        self.program_transaction_count_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
     }
@@ -92,6 +109,9 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.program_transactions_map().is_atomic_in_progress()
+            || self.program_transaction_index_map().is_atomic_in_progress()
+            || self.program_transaction_count_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
     }
@@ -99,6 +119,9 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.program_transactions_map().abort_atomic();
+        self.program_transaction_index_map().abort_atomic();
+        self.program_transaction_count_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
     }
@@ -106,6 +129,9 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.program_transactions_map().finish_atomic()?;
+        self.program_transaction_index_map().finish_atomic()?;
+        self.program_transaction_count_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()
     }
@@ -127,6 +153,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                     self.execution_store().insert(transaction)?;
                 }
             }
+            // Store the transaction ID for each program it touches.
+            self.insert_program_transaction_ids(transaction)?;
 
             Ok(())
         });
@@ -141,10 +169,17 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             Some(transaction_type) => cow_to_copied!(transaction_type),
             None => bail!("Failed to get the type for transaction '{transaction_id}'"),
         };
+        // Retrieve the transaction.
+        let transaction = match self.get_transaction(transaction_id)? {
+            Some(transaction) => transaction,
+            None => bail!("Failed to get transaction '{transaction_id}'"),
+        };
 
         atomic_write_batch!(self, {
             // Remove the transaction type.
             self.id_map().remove(transaction_id)?;
+            // Remove the transaction ID for each program it touches.
+            self.remove_program_transaction_ids(&transaction)?;
             // Remove the transaction.
             match transaction_type {
                 // Remove the deployment transaction.
@@ -159,6 +194,109 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given `transaction` ID under each program ID that the transaction touches.
+    fn insert_program_transaction_ids(&self, transaction: &Transaction<N>) -> Result<()> {
+        for program_id in program_ids_for_transaction(transaction) {
+            // Skip the transaction ID, if it is already indexed.
+            if self.program_transaction_index_map().contains_key_speculative(&(program_id, transaction.id()))? {
+                continue;
+            }
+            // Retrieve the number of transactions indexed for the program ID.
+            let index = match self.program_transaction_count_map().get_speculative(&program_id)? {
+                Some(index) => cow_to_copied!(index),
+                None => 0,
+            };
+            // Append the transaction ID at the next index.
+            self.program_transactions_map().insert((program_id, index), transaction.id())?;
+            self.program_transaction_index_map().insert((program_id, transaction.id()), index)?;
+            self.program_transaction_count_map().insert(program_id, index.saturating_add(1))?;
+        }
+        Ok(())
+    }
+
+    /// Removes the given `transaction` ID from each program ID that the transaction touches.
+    /// The last transaction ID of the program moves into the freed index, so the indices stay contiguous.
+    fn remove_program_transaction_ids(&self, transaction: &Transaction<N>) -> Result<()> {
+        for program_id in program_ids_for_transaction(transaction) {
+            // Retrieve the index of the transaction ID, skipping it if it is not indexed.
+            let key = (program_id, transaction.id());
+            let index = match self.program_transaction_index_map().get_speculative(&key)? {
+                Some(index) => cow_to_copied!(index),
+                None => continue,
+            };
+            // Retrieve the index of the last transaction ID for the program ID.
+            let last = match self.program_transaction_count_map().get_speculative(&program_id)? {
+                Some(count) => cow_to_copied!(count).saturating_sub(1),
+                None => bail!("Missing the transaction count for program '{program_id}'"),
+            };
+            // Move the last transaction ID into the freed index.
+            if index != last {
+                let last_id = match self.program_transactions_map().get_speculative(&(program_id, last))? {
+                    Some(transaction_id) => cow_to_copied!(transaction_id),
+                    None => bail!("Missing transaction {last} in the index of program '{program_id}'"),
+                };
+                self.program_transactions_map().insert((program_id, index), last_id)?;
+                self.program_transaction_index_map().insert((program_id, last_id), index)?;
+            }
+            // Remove the last index.
+            self.program_transactions_map().remove(&(program_id, last))?;
+            self.program_transaction_index_map().remove(&key)?;
+            match last {
+                0 => self.program_transaction_count_map().remove(&program_id)?,
+                _ => self.program_transaction_count_map().insert(program_id, last)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the mapping of `program ID` to `[transaction ID]` from the stored transactions.
+    fn rebuild_program_transactions_map(&self) -> Result<()> {
+        // Retrieve the currently indexed keys.
+        let entries = self.program_transactions_map().keys().map(|key| cow_to_copied!(key)).collect::<Vec<_>>();
+        let indices = self.program_transaction_index_map().keys().map(|key| cow_to_copied!(key)).collect::<Vec<_>>();
+        let program_ids = self.program_transaction_count_map().keys().map(|id| cow_to_copied!(id)).collect::<Vec<_>>();
+        // Retrieve the stored transaction IDs.
+        let transaction_ids = self.id_map().keys().map(|id| cow_to_copied!(id)).collect::<Vec<_>>();
+
+        atomic_write_batch!(self, {
+            // Clear the existing index.
+            self.program_transactions_map().remove_batch(entries.into_iter())?;
+            self.program_transaction_index_map().remove_batch(indices.into_iter())?;
+            self.program_transaction_count_map().remove_batch(program_ids.into_iter())?;
+            // Index each stored transaction.
+            for transaction_id in &transaction_ids {
+                match self.get_transaction(transaction_id)? {
+                    Some(transaction) => self.insert_program_transaction_ids(&transaction)?,
+                    None => bail!("Failed to rebuild the program index: missing transaction '{transaction_id}'"),
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Returns the number of transactions that touch the given `program ID`.
+    fn num_transactions_for_program(&self, program_id: &ProgramID<N>) -> Result<u32> {
+        match self.program_transaction_count_map().get(program_id)? {
+            Some(count) => Ok(cow_to_copied!(count)),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the transaction ID at the given `index` of the transactions that touch the given `program ID`.
+    fn find_transaction_id_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+        index: u32,
+    ) -> Result<Option<N::TransactionID>> {
+        match self.program_transactions_map().get(&(*program_id, index))? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -189,11 +327,29 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     }
 }
 
+/// Returns the program IDs that the given `transaction` deploys or executes, without duplicates.
+fn program_ids_for_transaction<N: Network>(transaction: &Transaction<N>) -> IndexSet<ProgramID<N>> {
+    let mut program_ids = IndexSet::new();
+    // Include the deployed program ID.
+    if let Transaction::Deploy(_, deployment, _) = transaction {
+        program_ids.insert(*deployment.program_id());
+    }
+    // Include the program ID of each transition.
+    program_ids.extend(transaction.transitions().map(|transition| *transition.program_id()));
+    program_ids
+}
+
 /// An in-memory transaction storage.
 #[derive(Clone)]
 pub struct TransactionMemory<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: MemoryMap<N::TransactionID, TransactionType>,
+    /// The mapping of `(program ID, index)` to `transaction ID`.
+    program_transactions_map: MemoryMap<(ProgramID<N>, u32), N::TransactionID>,
+    /// The mapping of `(program ID, transaction ID)` to `index`.
+    program_transaction_index_map: MemoryMap<(ProgramID<N>, N::TransactionID), u32>,
+    /// The mapping of `program ID` to the next `index`.
+    program_transaction_count_map: MemoryMap<ProgramID<N>, u32>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentMemory<N>>,
     /// The execution store.
@@ -203,6 +359,9 @@ pub struct TransactionMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, TransactionType>;
+    type ProgramTransactionsMap = MemoryMap<(ProgramID<N>, u32), N::TransactionID>;
+    type ProgramTransactionIndexMap = MemoryMap<(ProgramID<N>, N::TransactionID), u32>;
+    type ProgramTransactionCountMap = MemoryMap<ProgramID<N>, u32>;
     type DeploymentStorage = DeploymentMemory<N>;
     type ExecutionStorage = ExecutionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
@@ -214,7 +373,14 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(transition_store)?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store })
+        Ok(Self {
+            id_map: MemoryMap::default(),
+            program_transactions_map: MemoryMap::default(),
+            program_transaction_index_map: MemoryMap::default(),
+            program_transaction_count_map: MemoryMap::default(),
+            deployment_store,
+            execution_store,
+        })
     }
 
     /// Returns the ID map.
@@ -222,6 +388,21 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap {
+        &self.program_transactions_map
+    }
+
+    /// Returns the program transaction index map.
+    fn program_transaction_index_map(&self) -> &Self::ProgramTransactionIndexMap {
+        &self.program_transaction_index_map
+    }
+
+    /// Returns the program transaction count map.
+    fn program_transaction_count_map(&self) -> &Self::ProgramTransactionCountMap {
+        &self.program_transaction_count_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -266,6 +447,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Rebuilds the mapping of `program ID` to `[transaction ID]` from the stored transactions.
+    pub fn rebuild_program_index(&self) -> Result<()> {
+        self.storage.rebuild_program_transactions_map()
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, T::TransitionStorage> {
         self.storage.transition_store()
@@ -358,6 +544,26 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         }
     }
 
+    /// Returns an iterator over the transactions that deploy or execute the given `program ID`.
+    /// The transactions are in insertion order, unless a transaction other than the latest one was removed.
+    pub fn transactions_for_program(
+        &self,
+        program_id: &ProgramID<N>,
+    ) -> Result<impl '_ + Iterator<Item = Result<Transaction<N>>>> {
+        let program_id = *program_id;
+        let num_transactions = self.storage.num_transactions_for_program(&program_id)?;
+        Ok((0..num_transactions).map(move |index| {
+            let transaction_id = match self.storage.find_transaction_id_for_program(&program_id, index)? {
+                Some(transaction_id) => transaction_id,
+                None => bail!("Missing transaction {index} in the index of program '{program_id}'"),
+            };
+            match self.get_transaction(&transaction_id)? {
+                Some(transaction) => Ok(transaction),
+                None => bail!("Missing transaction '{transaction_id}' for the program index"),
+            }
+        }))
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.deployment_store().get_program_id(transaction_id)
@@ -503,6 +709,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_remove_keeps_program_index_contiguous() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution = crate::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Insert the transactions.
+        let genesis_ids = genesis.transaction_ids().copied().collect::<Vec<_>>();
+        for transaction in genesis.transactions().iter().chain([&execution, &deployment]) {
+            transaction_store.insert(transaction).unwrap();
+        }
+
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let testing = ProgramID::from_str("testing.aleo").unwrap();
+        let index_of = |program_id: &ProgramID<_>| {
+            let transactions = transaction_store.transactions_for_program(program_id).unwrap();
+            transactions.map(|transaction| transaction.unwrap().id()).collect::<Vec<_>>()
+        };
+
+        // Remove the execution, and ensure the latest transaction moves into its index.
+        transaction_store.remove(&execution.id()).unwrap();
+        let mut expected = genesis_ids.clone();
+        expected.push(deployment.id());
+        assert_eq!(index_of(&credits), expected);
+        assert_eq!(transaction_store.storage.num_transactions_for_program(&credits).unwrap() as usize, expected.len());
+
+        // Remove the deployment, and ensure it is removed from every program it touches.
+        transaction_store.remove(&deployment.id()).unwrap();
+        assert_eq!(index_of(&credits), genesis_ids);
+        assert!(index_of(&testing).is_empty());
+        assert_eq!(transaction_store.storage.num_transactions_for_program(&testing).unwrap(), 0);
+
+        // Remove the genesis transactions, and ensure the index is empty.
+        for transaction_id in &genesis_ids {
+            transaction_store.remove(transaction_id).unwrap();
+        }
+        assert!(index_of(&credits).is_empty());
+        assert!(transaction_store.storage.program_transaction_count_map().get(&credits).unwrap().is_none());
+
+        // Ensure a reinserted transaction starts a new index.
+        transaction_store.insert(&execution).unwrap();
+        assert_eq!(index_of(&credits), vec![execution.id()]);
+    }
+
     #[test]
     fn test_find_transaction_id() {
         let rng = &mut TestRng::default();
@@ -546,4 +804,41 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_transactions_for_program() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let program_id = *crate::vm::test_helpers::sample_program().id();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        // Initialize a new transaction store.
+        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+        // Insert the transactions.
+        transaction_store.insert(&deployment_transaction).unwrap();
+        transaction_store.insert(&execution_transaction).unwrap();
+
+        // Ensure the deployment is indexed under the program ID.
+        let candidate = transaction_store.transactions_for_program(&program_id).unwrap();
+        assert_eq!(vec![deployment_transaction.clone()], candidate.collect::<Result<Vec<_>>>().unwrap());
+
+        // Clear the index, and ensure it can be rebuilt.
+        transaction_store.storage.program_transaction_count_map().remove(&program_id).unwrap();
+        assert_eq!(transaction_store.transactions_for_program(&program_id).unwrap().count(), 0);
+        transaction_store.rebuild_program_index().unwrap();
+        let candidate = transaction_store.transactions_for_program(&program_id).unwrap();
+        assert_eq!(vec![deployment_transaction.clone()], candidate.collect::<Result<Vec<_>>>().unwrap());
+
+        // Remove the deployment, and ensure it is no longer indexed.
+        transaction_store.remove(&deployment_transaction.id()).unwrap();
+        assert_eq!(transaction_store.transactions_for_program(&program_id).unwrap().count(), 0);
+        let key = (program_id, deployment_transaction.id());
+        assert!(!transaction_store.storage.program_transaction_index_map().contains_key(&key).unwrap());
+        assert!(!transaction_store.storage.program_transactions_map().contains_key(&(program_id, 0)).unwrap());
+    }
 }
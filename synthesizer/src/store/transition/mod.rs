@@ -879,4 +879,43 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_contains_spent_record() {
+        use console::account::{GraphKey, ViewKey};
+
+        let rng = &mut TestRng::default();
+
+        // Retrieve the owner of the genesis records.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let graph_key = GraphKey::try_from(&view_key).unwrap();
+
+        // Select the genesis record that is spent by the sample execution.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let (commitment, record) = genesis.transitions().cloned().flat_map(Transition::into_records).next().unwrap();
+        let record = record.decrypt(&view_key).unwrap();
+
+        // Compute the serial number and tag of the record, outside of the request.
+        let serial_number = record.to_serial_number(&private_key, commitment).unwrap();
+        let tag = record.to_tag(&graph_key, commitment).unwrap();
+
+        // Spend the record.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transition = transaction.transitions().next().unwrap();
+        assert_eq!(transition.serial_numbers().collect::<Vec<_>>(), vec![&serial_number]);
+        assert_eq!(transition.tags().collect::<Vec<_>>(), vec![&tag]);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        assert!(!transition_store.contains_input_id(&serial_number).unwrap());
+        assert!(!transition_store.contains_serial_number(&serial_number).unwrap());
+        assert!(!transition_store.contains_tag(&tag).unwrap());
+
+        // Insert the transition, and ensure the record is marked as spent.
+        transition_store.insert(transition).unwrap();
+        assert!(transition_store.contains_input_id(&serial_number).unwrap());
+        assert!(transition_store.contains_serial_number(&serial_number).unwrap());
+        assert!(transition_store.contains_tag(&tag).unwrap());
+    }
 }
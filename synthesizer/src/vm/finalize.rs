@@ -46,6 +46,101 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         Ok(())
     }
+
+    /// Speculatively finalizes the given transactions, and returns the IDs of the transactions that succeeded.
+    /// Each transaction is finalized in its own checkpoint, so that a failing transaction is rolled back
+    /// without affecting the others. The resulting state is discarded, and the VM is left unchanged.
+    pub fn speculate(&self, transactions: &Transactions<N>) -> Result<Vec<N::TransactionID>> {
+        self.speculate_internal(transactions, None).map(|(accepted, _)| accepted)
+    }
+
+    /// Speculatively finalizes the given transactions, and returns the IDs of the transactions that succeeded,
+    /// along with a trace of the `finalize` commands evaluated for each transaction.
+    /// Each trace records at most `max_entries` commands, and the error that aborted the transaction, if any.
+    pub fn speculate_with_trace(
+        &self,
+        transactions: &Transactions<N>,
+        max_entries: usize,
+    ) -> Result<(Vec<N::TransactionID>, Vec<FinalizeTrace<N>>)> {
+        self.speculate_internal(transactions, Some(max_entries))
+    }
+
+    /// Speculatively finalizes the given transactions, tracing each transaction if `max_entries` is given.
+    fn speculate_internal(
+        &self,
+        transactions: &Transactions<N>,
+        max_entries: Option<usize>,
+    ) -> Result<(Vec<N::TransactionID>, Vec<FinalizeTrace<N>>)> {
+        let timer = timer!("VM::speculate");
+
+        // Retrieve the program store.
+        let store = self.program_store();
+        // Clone the process, so that any deployed programs are only added speculatively.
+        let mut process = self.process.read().clone();
+
+        // Start an atomic batch write operation IFF it's not already part of one.
+        let is_part_of_atomic_batch = store.is_atomic_in_progress();
+        if !is_part_of_atomic_batch {
+            store.start_atomic();
+        }
+
+        let mut run_speculation = || -> Result<(Vec<N::TransactionID>, Vec<FinalizeTrace<N>>)> {
+            let mut accepted = Vec::with_capacity(transactions.len());
+            let mut traces = Vec::new();
+            for transaction in transactions.values() {
+                // Open a checkpoint for the transaction.
+                let checkpoint = store.checkpoint()?;
+                // Initialize the trace for the transaction, if tracing.
+                let mut trace = max_entries.map(|max_entries| FinalizeTrace::new(transaction.id(), max_entries));
+
+                // Finalize the transaction.
+                let outcome = match (transaction, &mut trace) {
+                    (Transaction::Deploy(_, deployment, _), _) => process.finalize_deployment(store, deployment),
+                    (Transaction::Execute(_, execution, _), Some(trace)) => {
+                        process.finalize_execution_with_trace(store, execution, trace)
+                    }
+                    (Transaction::Execute(_, execution, _), None) => process.finalize_execution(store, execution),
+                };
+
+                // Keep the writes of the transaction if it succeeded, and discard them otherwise.
+                match outcome {
+                    Ok(()) => {
+                        store.commit(checkpoint)?;
+                        accepted.push(transaction.id());
+                    }
+                    Err(error) => {
+                        store.rollback_to(checkpoint)?;
+                        warn!("Speculation aborted transaction '{}': {error}", transaction.id());
+                        // Record the error in the trace, if tracing.
+                        if let Some(trace) = &mut trace {
+                            trace.set_error(&error);
+                        }
+                    }
+                }
+                // Store the trace, if tracing.
+                traces.extend(trace);
+                lap!(timer, "Speculate on a transaction");
+            }
+            Ok((accepted, traces))
+        };
+        // Open a checkpoint, so that the speculative writes can be discarded afterwards.
+        let result = match store.checkpoint() {
+            Ok(speculation) => {
+                let accepted = run_speculation();
+                store.rollback_to(speculation).and(accepted)
+            }
+            Err(error) => Err(error),
+        };
+
+        // Abort the atomic batch write operation IFF it's not already part of a larger one.
+        if !is_part_of_atomic_batch {
+            store.abort_atomic();
+        }
+
+        finish!(timer);
+
+        result
+    }
 }
 
 #[cfg(test)]
@@ -68,4 +163,61 @@ mod tests {
         // Ensure the VM can't redeploy the same transaction.
         assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
     }
+
+    #[test]
+    fn test_speculate() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Fetch a deployment transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = match &deployment_transaction {
+            Transaction::Deploy(_, deployment, _) => *deployment.program_id(),
+            _ => panic!("Expected a deployment transaction"),
+        };
+
+        // Speculate on the deployment, and ensure it is accepted.
+        let transactions = Transactions::from(&[deployment_transaction.clone()]);
+        let accepted = vm.speculate(&transactions).unwrap();
+        assert_eq!(accepted, vec![deployment_transaction.id()]);
+
+        // Ensure the speculation left the VM unchanged.
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.program_store().contains_program(&program_id).unwrap());
+        assert!(!vm.program_store().is_atomic_in_progress());
+
+        // Ensure the VM can still finalize the transaction.
+        vm.finalize(&transactions).unwrap();
+        assert!(vm.contains_program(&program_id));
+
+        // Ensure a speculative redeployment is rejected.
+        assert!(vm.speculate(&transactions).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_speculate_with_trace() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Fetch a deployment transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transactions = Transactions::from(&[deployment_transaction.clone()]);
+
+        // Speculate on the deployment, and ensure it is traced without an error.
+        let (accepted, traces) = vm.speculate_with_trace(&transactions, 8).unwrap();
+        assert_eq!(accepted, vec![deployment_transaction.id()]);
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].transaction_id(), &deployment_transaction.id());
+        assert!(traces[0].entries().is_empty());
+        assert_eq!(traces[0].error(), None);
+
+        // Finalize the deployment, and ensure a speculative redeployment is traced with its error.
+        vm.finalize(&transactions).unwrap();
+        let (accepted, traces) = vm.speculate_with_trace(&transactions, 8).unwrap();
+        assert!(accepted.is_empty());
+        assert_eq!(traces.len(), 1);
+        assert!(traces[0].error().is_some());
+    }
 }
@@ -27,7 +27,17 @@ use crate::{
     block::{Block, Transaction, Transactions, Transition},
     cast_ref,
     process,
-    process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
+    process::{
+        Authorization,
+        Deployment,
+        Execution,
+        Fee,
+        FinalizeTrace,
+        Inclusion,
+        InclusionAssignment,
+        Process,
+        Query,
+    },
     program::Program,
     store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
     CallMetrics,
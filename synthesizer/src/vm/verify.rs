@@ -28,6 +28,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
     }
 
+    /// Returns `true` if the unconfirmed transaction is valid, without synthesizing deployment circuits.
+    pub fn verify_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> bool {
+        match self.check_unconfirmed_transaction(transaction) {
+            Ok(_) => true,
+            Err(error) => {
+                warn!("{error}");
+                false
+            }
+        }
+    }
+
     /// Returns `true` if the deployment is valid.
     pub fn verify_deployment(&self, deployment: &Deployment<N>) -> bool {
         match self.check_deployment(deployment) {
@@ -64,6 +75,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.check_transaction_internal::<false>(transaction)
+    }
+
+    /// Verifies the unconfirmed transaction in the VM, for admission into the mempool. On failure, returns an error.
+    ///
+    /// Deployments are only checked structurally, and their certificates are not verified.
+    /// As such, `check_transaction` must still be called before the transaction is included in a block.
+    #[inline]
+    pub fn check_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.check_transaction_internal::<true>(transaction)
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If `IS_UNCONFIRMED` is `true`, deployments are verified without synthesizing their circuits.
+    #[inline]
+    fn check_transaction_internal<const IS_UNCONFIRMED: bool>(&self, transaction: &Transaction<N>) -> Result<()> {
         let timer = timer!("VM::verify");
 
         // Compute the Merkle root of the transaction.
@@ -113,7 +140,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Invalid transaction size (deployment): {error}");
                 }
                 // Verify the deployment.
-                self.check_deployment(deployment)?;
+                match IS_UNCONFIRMED {
+                    true => self.check_deployment_cheap(deployment)?,
+                    false => self.check_deployment(deployment)?,
+                }
 
                 // Verify the fee.
                 self.check_fee(fee)?;
@@ -174,6 +204,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
     }
 
+    /// Verifies the structure of the given deployment, without its certificates. On failure, returns an error.
+    #[inline]
+    fn check_deployment_cheap(&self, deployment: &Deployment<N>) -> Result<()> {
+        let timer = timer!("VM::verify_deployment_cheap");
+
+        // Verify the deployment structure.
+        let verification = self.process.read().verify_deployment_cheap(deployment);
+        finish!(timer);
+
+        match verification {
+            Ok(()) => Ok(()),
+            Err(error) => bail!("Deployment verification failed: {error}"),
+        }
+    }
+
     /// Verifies the given execution. On failure, returns an error.
     #[inline]
     fn check_execution(&self, execution: &Execution<N>) -> Result<()> {
@@ -267,6 +312,20 @@ mod tests {
         assert!(vm.verify_deployment(&deployment_transaction));
     }
 
+    #[test]
+    fn test_verify_unconfirmed_transaction() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure the transaction passes both the unconfirmed and the full verification.
+        assert!(vm.check_unconfirmed_transaction(&transaction).is_ok());
+        assert!(vm.verify_unconfirmed_transaction(&transaction));
+        assert!(vm.check_transaction(&transaction).is_ok());
+    }
+
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
@@ -338,6 +397,7 @@ mod tests {
             genesis.last_coinbase_target(),
             genesis.last_coinbase_timestamp(),
             CurrentNetwork::GENESIS_TIMESTAMP + 1,
+            0,
         )
         .unwrap();
 